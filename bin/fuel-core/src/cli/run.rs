@@ -22,6 +22,7 @@ use fuel_core::{
         CombinedDatabaseConfig,
     },
     fuel_core_graphql_api::{
+        coins_blacklist::CoinsToSpendBlacklist,
         worker_service::DaCompressionConfig,
         Costs,
         ServiceConfig as GraphQLConfig,
@@ -80,7 +81,10 @@ use rlimit::{
 use std::{
     env,
     net,
-    num::NonZeroU64,
+    num::{
+        NonZeroU32,
+        NonZeroU64,
+    },
     path::PathBuf,
     str::FromStr,
     time::Duration,
@@ -234,6 +238,17 @@ pub struct Command {
     #[arg(long = "da-compression", env)]
     pub da_compression: Option<humantime::Duration>,
 
+    /// The maximum number of entries in each table of the da-compression registry.
+    /// New registrations overwrite the oldest entries above this number.
+    #[arg(long = "da-compression-max-registry-entries", env)]
+    pub da_compression_max_registry_entries: Option<NonZeroU32>,
+
+    /// How often the da-compression registry entries past the retention are removed,
+    /// measured by the block time. The entries may stay up to this interval past
+    /// the retention. If not set, the stale entries are not removed.
+    #[arg(long = "da-compression-compaction-interval", env)]
+    pub da_compression_compaction_interval: Option<humantime::Duration>,
+
     /// A new block is produced instantly when transactions are available.
     #[clap(flatten)]
     pub poa_trigger: PoATriggerArgs,
@@ -332,6 +347,8 @@ impl Command {
             #[cfg(feature = "aws-kms")]
             consensus_aws_kms,
             da_compression,
+            da_compression_max_registry_entries,
+            da_compression_compaction_interval,
             poa_trigger,
             predefined_blocks_path,
             coinbase_recipient,
@@ -529,6 +546,9 @@ impl Command {
             Some(retention) => {
                 DaCompressionConfig::Enabled(fuel_core_compression::Config {
                     temporal_registry_retention: retention.into(),
+                    temporal_registry_max_entries: da_compression_max_registry_entries,
+                    temporal_registry_compaction_interval:
+                        da_compression_compaction_interval.map(Into::into),
                 })
             }
             None => DaCompressionConfig::Disabled,
@@ -622,6 +642,8 @@ impl Command {
                 database_batch_size: graphql.database_batch_size,
                 max_queries_depth: graphql.graphql_max_depth,
                 max_queries_complexity: graphql.graphql_max_complexity,
+                trusted_api_keys: graphql.graphql_trusted_api_keys.into_iter().collect(),
+                trusted_max_queries_complexity: graphql.graphql_trusted_max_complexity,
                 max_queries_recursive_depth: graphql.graphql_max_recursive_depth,
                 max_queries_resolver_recursive_depth: graphql
                     .max_queries_resolver_recursive_depth,
@@ -632,6 +654,23 @@ impl Command {
                 assemble_tx_dry_run_limit: graphql.assemble_tx_dry_run_limit,
                 assemble_tx_estimate_predicates_limit: graphql
                     .assemble_tx_estimate_predicates_limit,
+                coins_to_spend_cache_fallback: graphql.coins_to_spend_cache_fallback,
+                coins_to_spend_blacklist: CoinsToSpendBlacklist::new(
+                    graphql.coins_to_spend_blacklist_addresses,
+                    graphql.coins_to_spend_blacklist_coins,
+                    graphql.coins_to_spend_blacklist_messages,
+                ),
+                // The tags come from the off-chain source of the embedder.
+                coins_to_spend_tags: Default::default(),
+                coins_to_spend_max_total_coins: graphql.coins_to_spend_max_total_coins,
+                coins_to_spend_min_amounts: graphql
+                    .coins_to_spend_min_amounts
+                    .into_iter()
+                    .collect(),
+                coin_set_root: graphql.coin_set_root,
+                dry_run_max_txs: graphql.dry_run_max_txs,
+                dry_run_max_concurrency: graphql.dry_run_max_concurrency,
+                dry_run_queue_timeout: graphql.dry_run_queue_timeout.into(),
                 query_log_threshold_time: graphql.query_log_threshold_time.into(),
                 costs: Costs {
                     balance_query: graphql.costs.balance_query,
@@ -657,6 +696,7 @@ impl Command {
                         .costs
                         .state_transition_bytecode_read,
                     da_compressed_block_read: graphql.costs.da_compressed_block_read,
+                    coin_set_root: graphql.costs.coin_set_root,
                 },
                 required_fuel_block_height_tolerance: graphql
                     .required_fuel_block_height_tolerance,
@@ -819,6 +859,7 @@ fn start_pyroscope_agent(
 #[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
+    use fuel_core_types::fuel_tx::AssetId;
     use strum::IntoEnumIterator;
 
     fn parse_command(args: &[&str]) -> anyhow::Result<Command> {
@@ -894,4 +935,35 @@ mod tests {
             \n\nFor more information, try '--help'.\n"
         );
     }
+
+    #[test]
+    fn parse_coins_to_spend_min_amounts__asset_and_amount_pairs() {
+        // Given
+        let asset_a = AssetId::from([1; 32]);
+        let asset_b = AssetId::from([2; 32]);
+        let min_amounts = format!("{asset_a}:100,{asset_b}:5");
+        let args = ["--coins-to-spend-min-amounts", min_amounts.as_str()];
+
+        // When
+        let command = parse_command(&args).unwrap();
+
+        // Then
+        assert_eq!(
+            command.graphql.coins_to_spend_min_amounts,
+            vec![(asset_a, 100), (asset_b, 5)]
+        );
+    }
+
+    #[test]
+    fn parse_coins_to_spend_min_amounts__missing_amount() {
+        // Given
+        let args = ["--coins-to-spend-min-amounts", "0x01"];
+
+        // When
+        let command = parse_command(&args);
+
+        // Then
+        let err = command.expect_err("should fail to parse");
+        assert!(err.to_string().contains("expected `<asset_id>:<amount>`"));
+    }
 }
@@ -2,7 +2,19 @@
 
 use std::net;
 
-use fuel_core::fuel_core_graphql_api::DEFAULT_QUERY_COSTS;
+use fuel_core::{
+    fuel_core_graphql_api::DEFAULT_QUERY_COSTS,
+    service::adapters::dry_run_limiter::validate_max_concurrent_dry_runs,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        Address,
+        AssetId,
+        UtxoId,
+    },
+    fuel_types::Nonce,
+};
+use std::str::FromStr;
 
 #[derive(Debug, Clone, clap::Args)]
 pub struct GraphQLArgs {
@@ -30,6 +42,15 @@ pub struct GraphQLArgs {
     #[clap(long = "graphql-max-complexity", default_value = "80000", env)]
     pub graphql_max_complexity: usize,
 
+    /// The API keys of the trusted clients. Queries with one of these keys
+    /// in the `x-api-key` header can use the `graphql-trusted-max-complexity`.
+    #[clap(long = "graphql-trusted-api-keys", value_delimiter = ',', env)]
+    pub graphql_trusted_api_keys: Vec<String>,
+
+    /// The max complexity of GraphQL queries from the trusted clients.
+    #[clap(long = "graphql-trusted-max-complexity", default_value = "800000", env)]
+    pub graphql_trusted_max_complexity: usize,
+
     /// The max recursive depth of GraphQL queries.
     #[clap(long = "graphql-max-recursive-depth", default_value = "24", env)]
     pub graphql_max_recursive_depth: usize,
@@ -80,6 +101,71 @@ pub struct GraphQLArgs {
     )]
     pub assemble_tx_estimate_predicates_limit: usize,
 
+    /// If the `coins_to_spend` index doesn't have enough coins for the query,
+    /// retry the selection without the index before failing. It helps when the
+    /// index lags behind the latest blocks at the cost of the query latency.
+    #[clap(long = "coins-to-spend-cache-fallback", env)]
+    pub coins_to_spend_cache_fallback: bool,
+
+    /// The list of addresses whose coins are never selected by the `coinsToSpend`
+    /// and `assembleTx` GraphQL queries.
+    #[clap(long = "coins-to-spend-blacklist-addresses", value_delimiter = ',', env)]
+    pub coins_to_spend_blacklist_addresses: Vec<Address>,
+
+    /// The list of coins never selected by the `coinsToSpend`
+    /// and `assembleTx` GraphQL queries.
+    #[clap(long = "coins-to-spend-blacklist-coins", value_delimiter = ',', env)]
+    pub coins_to_spend_blacklist_coins: Vec<UtxoId>,
+
+    /// The list of messages never selected by the `coinsToSpend`
+    /// and `assembleTx` GraphQL queries.
+    #[clap(long = "coins-to-spend-blacklist-messages", value_delimiter = ',', env)]
+    pub coins_to_spend_blacklist_messages: Vec<Nonce>,
+
+    /// The max number of coins returned by one `coinsToSpend` GraphQL request
+    /// across all requested assets.
+    #[clap(long = "coins-to-spend-max-total-coins", default_value = "1000", env)]
+    pub coins_to_spend_max_total_coins: usize,
+
+    /// The minimum spendable amount per asset in the `<asset_id>:<amount>` format.
+    /// The coins of the asset below the minimum are selected by the `coinsToSpend`
+    /// and `assembleTx` GraphQL queries only if the target can't be met without them.
+    #[clap(
+        long = "coins-to-spend-min-amounts",
+        value_delimiter = ',',
+        value_parser = parse_asset_min_amount,
+        env
+    )]
+    pub coins_to_spend_min_amounts: Vec<(AssetId, u64)>,
+
+    /// Enables the `coinSetRoot` GraphQL query returning the Merkle root
+    /// of the unspent coins to the trusted clients. The root is computed on each
+    /// request by iterating over all coins, and the query is priced accordingly
+    /// by the `query-cost-coin-set-root`.
+    #[clap(long = "coin-set-root", env)]
+    pub coin_set_root: bool,
+
+    /// The max number of transactions that can be dry-run
+    /// during one `dry_run` GraphQL request.
+    #[clap(long = "dry-run-max-txs", default_value = "1000", env)]
+    pub dry_run_max_txs: usize,
+
+    /// The max number of dry runs executed concurrently. The dry runs above
+    /// the limit wait for a free slot up to the `dry-run-queue-timeout`.
+    /// Must be in the range from `1` to `usize::MAX >> 3`.
+    #[clap(
+        long = "dry-run-max-concurrency",
+        default_value = "64",
+        value_parser = parse_dry_run_max_concurrency,
+        env
+    )]
+    pub dry_run_max_concurrency: usize,
+
+    /// The time a dry run waits for a free slot before failing with
+    /// the "server busy" error.
+    #[clap(long = "dry-run-queue-timeout", default_value = "10s", env)]
+    pub dry_run_queue_timeout: humantime::Duration,
+
     /// Maximum allowed block lag for GraphQL fuel block height requests.
     /// The client waits for the node to catch up if it's behind by no more blocks than
     /// this tolerance.
@@ -272,4 +358,29 @@ pub struct QueryCosts {
         env
     )]
     pub da_compressed_block_read: usize,
+
+    /// Query costs for computing the Merkle root of the whole coin set.
+    #[clap(
+        long = "query-cost-coin-set-root",
+        default_value = DEFAULT_QUERY_COSTS.coin_set_root.to_string(),
+        env
+    )]
+    pub coin_set_root: usize,
+}
+
+/// Parses the minimum spendable amount of the asset in the `<asset_id>:<amount>` format.
+fn parse_dry_run_max_concurrency(s: &str) -> anyhow::Result<usize> {
+    let max_concurrency = s.parse()?;
+    validate_max_concurrent_dry_runs(max_concurrency)?;
+    Ok(max_concurrency)
+}
+
+fn parse_asset_min_amount(s: &str) -> anyhow::Result<(AssetId, u64)> {
+    let (asset_id, amount) = s
+        .split_once(':')
+        .ok_or_else(|| anyhow::anyhow!("expected `<asset_id>:<amount>`, got `{s}`"))?;
+    let asset_id = AssetId::from_str(asset_id)
+        .map_err(|e| anyhow::anyhow!("invalid asset id `{asset_id}`: {e}"))?;
+    let amount = amount.parse()?;
+    Ok((asset_id, amount))
 }
@@ -5,15 +5,19 @@ use crate::{
         schema::{
             block::BlockByHeightArgs,
             coins::{
+                CoinsSelectionInput,
                 ExcludeInput,
+                SelectionStrategy,
                 SpendQueryElementInput,
             },
             contract::ContractBalanceQueryArgs,
             gas_price::EstimateGasPrice,
             message::MessageStatusArgs,
+            primitives::HexFormatted,
             relayed_tx::RelayedTransactionStatusArgs,
             tx::{
                 DryRunArg,
+                EstimateBatchFeesArg,
                 TxWithEstimatedPredicatesArg,
             },
             Tai64Timestamp,
@@ -21,8 +25,14 @@ use crate::{
         },
         types::{
             asset::AssetDetail,
+            fee_estimate::{
+                BatchFeeEstimate,
+                BlockGasLimitCheck,
+            },
             gas_price::LatestGasPrice,
             message::MessageStatus,
+            output_breakdown::TransactionOutputsBreakdown,
+            predicate_verification::PredicatesVerification,
             primitives::{
                 Address,
                 AssetId,
@@ -70,6 +80,8 @@ use fuel_core_types::{
         Receipt,
         Transaction,
         TxId,
+        TxParameters,
+        TxPointer,
     },
     fuel_types::{
         self,
@@ -97,7 +109,10 @@ use schema::{
     assets::AssetInfoArg,
     balance::BalanceArgs,
     blob::BlobByIdArgs,
-    block::BlockByIdArgs,
+    block::{
+        BlockByIdArgs,
+        BlockGasUtilizationArgs,
+    },
     coins::{
         CoinByIdArgs,
         CoinsConnectionArgs,
@@ -115,6 +130,7 @@ use schema::{
     tx::{
         AssembleTxArg,
         TransactionsByOwnerConnectionArgs,
+        TransactionsBySpenderConnectionArgs,
         TxArg,
         TxIdArgs,
     },
@@ -153,6 +169,7 @@ use std::{
         Arc,
         Mutex,
     },
+    time::Duration,
 };
 use tai64::Tai64;
 use tracing as _;
@@ -169,6 +186,8 @@ use self::schema::{
     block::ProduceBlockArgs,
     message::{
         MessageProofArgs,
+        MessageProofByNonceArgs,
+        MessageProofsByNoncesArgs,
         NonceArgs,
     },
 };
@@ -179,6 +198,9 @@ pub mod types;
 
 type RegisterId = u32;
 
+/// The header with the API key of the trusted client.
+const API_KEY_HEADER: &str = "x-api-key";
+
 #[derive(Debug, derive_more::Display, derive_more::From)]
 #[non_exhaustive]
 /// Error occurring during interaction with the FuelClient
@@ -252,6 +274,7 @@ pub struct FuelClient {
     url: reqwest::Url,
     require_height: ConsistencyPolicy,
     chain_state_info: ChainStateInfo,
+    api_key: Option<String>,
 }
 
 impl FromStr for FuelClient {
@@ -283,6 +306,7 @@ impl FromStr for FuelClient {
                     height: Arc::new(Mutex::new(None)),
                 },
                 chain_state_info: Default::default(),
+                api_key: None,
             })
         }
 
@@ -296,6 +320,7 @@ impl FromStr for FuelClient {
                     height: Arc::new(Mutex::new(None)),
                 },
                 chain_state_info: Default::default(),
+                api_key: None,
             })
         }
     }
@@ -352,6 +377,13 @@ impl FuelClient {
         self
     }
 
+    /// Sends the `api_key` in the `x-api-key` header of the queries. The node grants
+    /// the trusted clients with a known API key the access to privileged endpoints.
+    pub fn with_api_key(&mut self, api_key: impl Into<String>) -> &mut Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
     pub fn required_block_height(&self) -> Option<BlockHeight> {
         match &self.require_height {
             ConsistencyPolicy::Auto { height } => height.lock().ok().and_then(|h| *h),
@@ -415,9 +447,11 @@ impl FuelClient {
     {
         let required_fuel_block_height = self.required_block_height();
         let fuel_operation = FuelOperation::new(q, required_fuel_block_height);
-        let response = self
-            .client
-            .post(self.url.clone())
+        let mut request = self.client.post(self.url.clone());
+        if let Some(api_key) = &self.api_key {
+            request = request.header(API_KEY_HEADER, api_key);
+        }
+        let response = request
             .run_fuel_graphql(fuel_operation)
             .await
             .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
@@ -611,6 +645,30 @@ impl FuelClient {
         self.query(query).await.map(|r| r.node_info.into())
     }
 
+    /// Returns the status of each off-chain indexer of the node.
+    pub async fn indexers(
+        &self,
+    ) -> io::Result<Vec<schema::node_info::IndexerStatus>> {
+        let query = schema::node_info::QueryIndexers::build(());
+        self.query(query).await.map(|r| r.indexers)
+    }
+
+    /// Returns the range of the block heights for which the node retains
+    /// the historical state.
+    pub async fn historical_state(
+        &self,
+    ) -> io::Result<schema::node_info::HistoricalState> {
+        let query = schema::node_info::QueryHistoricalState::build(());
+        self.query(query).await.map(|r| r.historical_state)
+    }
+
+    /// Returns the height of the latest block visible to the read view
+    /// of the request on the node.
+    pub async fn read_view_height(&self) -> io::Result<BlockHeight> {
+        let query = schema::node_info::QueryReadViewHeight::build(());
+        self.query(query).await.map(|r| r.read_view_height.into())
+    }
+
     pub async fn latest_gas_price(&self) -> io::Result<LatestGasPrice> {
         let query = schema::gas_price::QueryLatestGasPrice::build(());
         self.query(query).await.map(|r| r.latest_gas_price.into())
@@ -627,6 +685,30 @@ impl FuelClient {
         self.query(query).await.map(|r| r.estimate_gas_price)
     }
 
+    /// Best-effort estimation of the gas price and the tip per unit of gas required
+    /// for inclusion into the next block, based on the current pool contents.
+    pub async fn estimate_next_block_gas_price(
+        &self,
+    ) -> io::Result<schema::gas_price::NextBlockGasPrice> {
+        let query = schema::gas_price::QueryEstimateNextBlockGasPrice::build(());
+        self.query(query)
+            .await
+            .map(|r| r.estimate_next_block_gas_price)
+    }
+
+    /// Estimates the increase of the transaction fee caused by one more coin input
+    /// at the `gas_price`, or at the estimated gas price of the next block.
+    pub async fn estimate_input_fee(
+        &self,
+        gas_price: Option<u64>,
+    ) -> io::Result<schema::gas_price::InputFeeEstimate> {
+        let args = schema::gas_price::EstimateInputFeeArgs {
+            gas_price: gas_price.map(Into::into),
+        };
+        let query = schema::gas_price::QueryEstimateInputFee::build(args);
+        self.query(query).await.map(|r| r.estimate_input_fee)
+    }
+
     #[cfg(feature = "std")]
     pub async fn connected_peers_info(
         &self,
@@ -637,6 +719,23 @@ impl FuelClient {
             .map(|r| r.node_info.peers.into_iter().map(Into::into).collect())
     }
 
+    /// Returns the connected peers that advertise the `supported_protocol`
+    /// and don't advertise the `unsupported_protocol`.
+    pub async fn connected_peers_info_by_protocol(
+        &self,
+        supported_protocol: Option<&str>,
+        unsupported_protocol: Option<&str>,
+    ) -> io::Result<Vec<fuel_core_types::services::p2p::PeerInfo>> {
+        let args = schema::node_info::PeersArgs {
+            supported_protocol: supported_protocol.map(str::to_string),
+            unsupported_protocol: unsupported_protocol.map(str::to_string),
+        };
+        let query = schema::node_info::QueryFilteredPeersInfo::build(args);
+        self.query(query)
+            .await
+            .map(|r| r.node_info.peers.into_iter().map(Into::into).collect())
+    }
+
     pub async fn chain_info(&self) -> io::Result<types::ChainInfo> {
         let query = schema::chain::ChainQuery::build(());
         self.query(query).await.and_then(|r| {
@@ -645,6 +744,20 @@ impl FuelClient {
         })
     }
 
+    /// Returns the transaction parameters of the current consensus parameters.
+    pub async fn tx_params(&self) -> io::Result<TxParameters> {
+        let query = schema::chain::TxParamsQuery::build(());
+        let params = self.query(query).await?.tx_params.try_into()?;
+        Ok(params)
+    }
+
+    /// Returns the chain id and the name of the network the node belongs to.
+    pub async fn network_info(&self) -> io::Result<types::NetworkInfo> {
+        let query = schema::chain::NetworkInfoQuery::build(());
+        let network_info = self.query(query).await?.network_info.into();
+        Ok(network_info)
+    }
+
     pub async fn consensus_parameters(
         &self,
         version: i32,
@@ -733,6 +846,65 @@ impl FuelClient {
             .collect()
     }
 
+    /// Estimate the fees of a batch of dependent transactions.
+    /// The transactions are dry-run one after another on top of the same state.
+    pub async fn estimate_batch_fees(
+        &self,
+        txs: &[Transaction],
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+        gas_price: Option<u64>,
+    ) -> io::Result<BatchFeeEstimate> {
+        let txs = txs
+            .iter()
+            .map(|tx| HexString(Bytes(tx.to_bytes())))
+            .collect::<Vec<HexString>>();
+        let query = schema::tx::EstimateBatchFees::build(EstimateBatchFeesArg {
+            txs,
+            utxo_validation,
+            gas_price: gas_price.map(|gp| gp.into()),
+        });
+        let estimate = self.query(query).await?.estimate_batch_fees;
+        Ok(estimate.into())
+    }
+
+    /// Breaks down the outputs of the transaction without executing it.
+    pub async fn transaction_outputs_breakdown(
+        &self,
+        tx: &Transaction,
+    ) -> io::Result<TransactionOutputsBreakdown> {
+        let query = schema::tx::TransactionOutputsBreakdownQuery::build(TxArg {
+            tx: HexString(Bytes(tx.to_bytes())),
+        });
+        let breakdown = self.query(query).await?.transaction_outputs_breakdown;
+        Ok(breakdown.into())
+    }
+
+    /// Verifies the predicates of the transaction against the current state
+    /// without executing it.
+    pub async fn verify_predicates(
+        &self,
+        tx: &Transaction,
+    ) -> io::Result<PredicatesVerification> {
+        let query = schema::tx::VerifyPredicatesQuery::build(TxArg {
+            tx: HexString(Bytes(tx.to_bytes())),
+        });
+        let verification = self.query(query).await?.verify_predicates;
+        Ok(verification.into())
+    }
+
+    /// Checks whether the gas of the transaction exceeds the block gas limit.
+    pub async fn block_gas_limit_check(
+        &self,
+        tx: &Transaction,
+    ) -> io::Result<BlockGasLimitCheck> {
+        let query = schema::tx::BlockGasLimitCheckQuery::build(TxArg {
+            tx: HexString(Bytes(tx.to_bytes())),
+        });
+        let check = self.query(query).await?.block_gas_limit_check;
+        Ok(check.into())
+    }
+
     /// Get storage read replay for a block
     pub async fn storage_read_replay(
         &self,
@@ -847,6 +1019,29 @@ impl FuelClient {
         Ok(id)
     }
 
+    /// Submits the transaction with the `idempotency_key`. If a transaction of the
+    /// same owner was already submitted with the same key, the node doesn't insert
+    /// the new one and returns the id of the originally submitted transaction.
+    /// The node remembers only a limited number of the latest keys until it restarts.
+    pub async fn submit_with_idempotency_key(
+        &self,
+        tx: &Transaction,
+        estimate_predicates: Option<bool>,
+        idempotency_key: &str,
+    ) -> io::Result<types::primitives::TransactionId> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::SubmitWithIdempotencyKey::build(
+            schema::tx::SubmitWithIdempotencyKeyArg {
+                tx: HexString(Bytes(tx)),
+                estimate_predicates,
+                idempotency_key: idempotency_key.to_string(),
+            },
+        );
+
+        let id = self.query(query).await.map(|r| r.submit)?.id.into();
+        Ok(id)
+    }
+
     /// Similar to [`Self::submit_and_await_commit_opt`], but with default options.
     #[cfg(feature = "subscriptions")]
     pub async fn submit_and_await_commit(
@@ -1065,6 +1260,40 @@ impl FuelClient {
         })
     }
 
+    /// Returns the state of the coin at the `block_height`.
+    /// Requires historical execution to be enabled on the node.
+    pub async fn coin_at_height(
+        &self,
+        utxo_id: &UtxoId,
+        block_height: BlockHeight,
+    ) -> io::Result<types::coins::HistoricalCoin> {
+        let query =
+            schema::storage::CoinAtHeight::build(schema::storage::CoinAtHeightArgs {
+                utxo_id: (*utxo_id).into(),
+                block_height: (*block_height).into(),
+            });
+
+        let coin = self.query(query).await?.coin_at_height.try_into()?;
+
+        Ok(coin)
+    }
+
+    /// Returns the transaction and the block that spent the coin.
+    /// Requires historical execution to be enabled on the node.
+    pub async fn coin_spender(
+        &self,
+        utxo_id: &UtxoId,
+    ) -> io::Result<types::coins::CoinSpender> {
+        let query =
+            schema::storage::CoinSpenderQuery::build(schema::storage::CoinSpenderArgs {
+                utxo_id: (*utxo_id).into(),
+            });
+
+        let spender = self.query(query).await?.coin_spender.try_into()?;
+
+        Ok(spender)
+    }
+
     pub async fn start_session(&self) -> io::Result<String> {
         let query = schema::StartSession::build(());
 
@@ -1204,6 +1433,56 @@ impl FuelClient {
         Ok(status)
     }
 
+    /// Returns the status of the transaction together with the receipts of its execution.
+    /// The receipts are empty if the transaction is not executed yet.
+    pub async fn transaction_status_with_receipts(
+        &self,
+        id: &TxId,
+    ) -> io::Result<(TransactionStatus, Vec<Receipt>)> {
+        let query = schema::tx::TransactionStatusWithReceiptsQuery::build(TxIdArgs {
+            id: (*id).into(),
+        });
+
+        let result = self
+            .query(query)
+            .await?
+            .transaction_status_with_receipts
+            .ok_or_else(|| {
+                io::Error::new(
+                    ErrorKind::NotFound,
+                    format!("status not found for transaction {id}"),
+                )
+            })?;
+
+        let status = result.status.try_into()?;
+        let receipts = result
+            .receipts
+            .into_iter()
+            .map(TryInto::<Receipt>::try_into)
+            .collect::<Result<Vec<Receipt>, ConversionError>>()?;
+        Ok((status, receipts))
+    }
+
+    /// Returns the statuses of the transactions in one request, in the order of the `ids`.
+    /// The status is `None` if the transaction is not found.
+    pub async fn transaction_statuses(
+        &self,
+        ids: &[TxId],
+    ) -> io::Result<Vec<Option<TransactionStatus>>> {
+        let query = schema::tx::TransactionStatusesQuery::build(schema::tx::TxIdsArgs {
+            ids: ids.iter().map(|id| (*id).into()).collect(),
+        });
+
+        let statuses = self
+            .query(query)
+            .await?
+            .transaction_statuses
+            .into_iter()
+            .map(|lookup| lookup.status.map(TransactionStatus::try_from).transpose())
+            .collect::<Result<_, _>>()?;
+        Ok(statuses)
+    }
+
     #[tracing::instrument(skip(self), level = "debug")]
     #[cfg(feature = "subscriptions")]
     /// Subscribe to the status of a transaction
@@ -1281,6 +1560,59 @@ impl FuelClient {
         Ok(transactions)
     }
 
+    /// Returns a paginated set of the ids of the transactions associated with
+    /// a txo owner address, starting from the most recent ones.
+    pub async fn recent_transaction_ids_by_owner(
+        &self,
+        owner: &Address,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<TxId, String>> {
+        let owner: schema::Address = (*owner).into();
+        let args = TransactionsByOwnerConnectionArgs::from((owner, request));
+        let query = schema::tx::RecentTransactionIdsByOwnerQuery::build(args);
+
+        let ids = self
+            .query(query)
+            .await?
+            .recent_transaction_ids_by_owner
+            .into();
+        Ok(ids)
+    }
+
+    /// Returns a paginated set of transactions spending the coins of the `owner`,
+    /// included in the blocks from the `start_height` up to the `end_height`.
+    pub async fn transactions_by_spender(
+        &self,
+        owner: &Address,
+        start_height: Option<BlockHeight>,
+        end_height: Option<BlockHeight>,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+        let (after, before, first, last) = match request.direction {
+            PageDirection::Forward => (request.cursor, None, Some(request.results), None),
+            PageDirection::Backward => {
+                (None, request.cursor, None, Some(request.results))
+            }
+        };
+        let args = TransactionsBySpenderConnectionArgs {
+            owner: (*owner).into(),
+            start_height: start_height.map(|height| U32(height.into())),
+            end_height: end_height.map(|height| U32(height.into())),
+            after,
+            before,
+            first,
+            last,
+        };
+        let query = schema::tx::TransactionsBySpenderQuery::build(args);
+
+        let transactions = self
+            .query(query)
+            .await?
+            .transactions_by_spender
+            .try_into()?;
+        Ok(transactions)
+    }
+
     pub async fn receipts(&self, id: &TxId) -> io::Result<Option<Vec<Receipt>>> {
         let query =
             schema::tx::TransactionStatusQuery::build(TxIdArgs { id: (*id).into() });
@@ -1392,6 +1724,96 @@ impl FuelClient {
             .map(|b| b.bytes.into()))
     }
 
+    /// Returns the height and the compressed bytes of the latest block,
+    /// or `None` if the latest block wasn't compressed.
+    pub async fn latest_da_compressed_block(
+        &self,
+    ) -> io::Result<Option<(BlockHeight, Vec<u8>)>> {
+        let query = schema::da_compressed::LatestDaCompressedBlockQuery::build(());
+
+        Ok(self
+            .query(query)
+            .await?
+            .latest_da_compressed_block
+            .map(|latest| (latest.height.into(), latest.block.bytes.into())))
+    }
+
+    /// Returns a stream of the heights and the compressed forms of the new blocks.
+    /// Requires the DA compression to be enabled on the node.
+    #[cfg(feature = "subscriptions")]
+    pub async fn subscribe_da_compressed_blocks(
+        &self,
+    ) -> io::Result<impl Stream<Item = io::Result<(BlockHeight, Vec<u8>)>> + '_> {
+        use cynic::SubscriptionBuilder;
+        use schema::da_compressed::NewDaCompressedBlocksSubscription;
+        let s = NewDaCompressedBlocksSubscription::build(());
+
+        let stream = self.subscribe(s).await?.map(
+            |result: io::Result<NewDaCompressedBlocksSubscription>| {
+                let block = result?.new_da_compressed_blocks;
+                let height: BlockHeight = block.height.into();
+                Result::<_, io::Error>::Ok((height, block.block.bytes.into()))
+            },
+        );
+
+        Ok(stream)
+    }
+
+    /// Returns the DA compression retention of the node,
+    /// or `None` if the DA compression is disabled.
+    pub async fn da_compression_retention(&self) -> io::Result<Option<Duration>> {
+        let query = schema::da_compressed::DaCompressionConfigQuery::build(());
+        let config = self.query(query).await?.da_compression_config;
+
+        Ok(config
+            .temporal_registry_retention_seconds
+            .filter(|_| config.enabled)
+            .map(|seconds| Duration::from_secs(seconds.into())))
+    }
+
+    /// Returns the compressed and uncompressed sizes of the latest `last` blocks
+    /// compressed by the node, starting from the most recent one.
+    pub async fn da_compression_ratios(
+        &self,
+        last: u32,
+    ) -> io::Result<Vec<types::block::DaCompressionRatio>> {
+        let last = i32::try_from(last)
+            .map_err(|_| io::Error::new(ErrorKind::InvalidInput, "`last` is too big"))?;
+        let query = schema::da_compressed::DaCompressionRatiosQuery::build(
+            schema::da_compressed::DaCompressionRatiosArgs { last },
+        );
+        let ratios = self
+            .query(query)
+            .await?
+            .da_compression_ratios
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        Ok(ratios)
+    }
+
+    /// Decompresses the `block` (the bytes of the `VersionedCompressedBlock`)
+    /// against the temporal registry of the node and returns its transactions.
+    /// The node doesn't persist the changes made by the decompression.
+    pub async fn decompress_da_block(
+        &self,
+        block: &[u8],
+    ) -> io::Result<Vec<Transaction>> {
+        let query = schema::da_compressed::DecompressDaBlockQuery::build(
+            schema::da_compressed::DecompressDaBlockArgs {
+                bytes: HexString(Bytes(block.to_vec())),
+            },
+        );
+        let transactions = self
+            .query(query)
+            .await?
+            .decompress_da_block
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, ConversionError>>()?;
+        Ok(transactions)
+    }
+
     /// Retrieve a blob by its ID
     pub async fn blob(&self, id: BlobId) -> io::Result<Option<types::Blob>> {
         let query = schema::blob::BlobByIdQuery::build(BlobByIdArgs { id: id.into() });
@@ -1418,6 +1840,106 @@ impl FuelClient {
         Ok(blocks)
     }
 
+    /// Returns the current block gas limit and the gas used by the latest `last` blocks.
+    pub async fn block_gas_utilization(
+        &self,
+        last: u32,
+    ) -> io::Result<types::block::BlockGasUtilization> {
+        let last = i32::try_from(last)
+            .map_err(|_| io::Error::new(ErrorKind::InvalidInput, "`last` is too big"))?;
+        let query = schema::block::BlockGasUtilizationQuery::build(
+            BlockGasUtilizationArgs { last },
+        );
+
+        let utilization = self.query(query).await?.block_gas_utilization.into();
+
+        Ok(utilization)
+    }
+
+    /// Returns the total fee paid by the transactions of the block at the `height`,
+    /// or `None` if the block doesn't exist.
+    pub async fn block_fees(&self, height: BlockHeight) -> io::Result<Option<u64>> {
+        let query = schema::block::BlockFeesQuery::build(schema::block::BlockFeesArgs {
+            height: U32(height.into()),
+        });
+
+        let fees = self.query(query).await?.block_fees.map(Into::into);
+
+        Ok(fees)
+    }
+
+    /// Returns the number of transactions, the gas used, the fees and the time
+    /// of the block at the `height`, or `None` if the block doesn't exist.
+    pub async fn block_summary(
+        &self,
+        height: BlockHeight,
+    ) -> io::Result<Option<types::block::BlockSummary>> {
+        let query =
+            schema::block::BlockSummaryQuery::build(schema::block::BlockFeesArgs {
+                height: U32(height.into()),
+            });
+
+        let summary = self.query(query).await?.block_summary.map(Into::into);
+
+        Ok(summary)
+    }
+
+    /// Returns the height and the id of the latest block from the same view.
+    pub async fn chain_tip(&self) -> io::Result<types::block::ChainTip> {
+        let query = schema::block::ChainTipQuery::build(());
+        let chain_tip = self.query(query).await?.chain_tip.into();
+
+        Ok(chain_tip)
+    }
+
+    /// Returns the headers of the blocks from the `start` up to the `end` height
+    /// inclusive, in ascending order.
+    pub async fn block_headers(
+        &self,
+        start: BlockHeight,
+        end: BlockHeight,
+    ) -> io::Result<Vec<types::block::Header>> {
+        let query =
+            schema::block::BlockHeadersQuery::build(schema::block::BlockHeadersArgs {
+                start: U32(start.into()),
+                end: U32(end.into()),
+            });
+
+        let headers = self
+            .query(query)
+            .await?
+            .block_headers
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
+
+        Ok(headers)
+    }
+
+    /// Returns the message outbox roots of the blocks from the `start` up to
+    /// the `end` height inclusive, in ascending order.
+    pub async fn message_outbox_roots(
+        &self,
+        start: BlockHeight,
+        end: BlockHeight,
+    ) -> io::Result<Vec<types::block::BlockMessageOutboxRoot>> {
+        let query =
+            schema::block::MessageOutboxRootsQuery::build(schema::block::BlockHeadersArgs {
+                start: U32(start.into()),
+                end: U32(end.into()),
+            });
+
+        let roots = self
+            .query(query)
+            .await?
+            .message_outbox_roots
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        Ok(roots)
+    }
+
     pub async fn coin(&self, id: &UtxoId) -> io::Result<Option<types::Coin>> {
         let query = schema::coins::CoinByIdQuery::build(CoinByIdArgs {
             utxo_id: (*id).into(),
@@ -1426,6 +1948,24 @@ impl FuelClient {
         Ok(coin)
     }
 
+    /// Retrieve a coin together with the transaction that created it.
+    /// The transaction is `None` if the node can't resolve it.
+    pub async fn coin_with_transaction(
+        &self,
+        id: &UtxoId,
+    ) -> io::Result<Option<types::coins::CoinWithTransaction>> {
+        let query = schema::coins::CoinWithTransactionQuery::build(CoinByIdArgs {
+            utxo_id: (*id).into(),
+        });
+        let coin = self
+            .query(query)
+            .await?
+            .coin_with_transaction
+            .map(TryInto::try_into)
+            .transpose()?;
+        Ok(coin)
+    }
+
     /// Retrieve a page of coins by their owner
     pub async fn coins(
         &self,
@@ -1445,24 +1985,118 @@ impl FuelClient {
         Ok(coins)
     }
 
-    /// Retrieve coins to spend in a transaction
-    pub async fn coins_to_spend(
+    /// Retrieve a page of the messages of the `owner` that can be spent
+    /// as message coins, sorted by the `order`. Only available on nodes
+    /// whose off-chain database was indexed from the genesis.
+    pub async fn message_coins(
         &self,
         owner: &Address,
-        spend_query: Vec<(AssetId, u128, Option<u16>)>,
-        // (Utxos, Messages Nonce)
-        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
-    ) -> io::Result<Vec<Vec<types::CoinType>>> {
+        order: Option<types::coins::MessageCoinOrder>,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::coins::MessageCoin, String>> {
         let owner: schema::Address = (*owner).into();
-        let spend_query: Vec<SpendQueryElementInput> = spend_query
-            .iter()
-            .map(|(asset_id, amount, max)| -> Result<_, ConversionError> {
-                Ok(SpendQueryElementInput {
-                    asset_id: (*asset_id).into(),
-                    amount: (*amount).into(),
-                    max: (*max).map(|max| max.into()),
-                })
-            })
+        let args = schema::coins::MessageCoinsConnectionArgs::from((
+            owner,
+            order.map(Into::into),
+            request,
+        ));
+        let query = schema::coins::MessageCoinsQuery::build(args);
+
+        let coins = self.query(query).await?.message_coins.into();
+        Ok(coins)
+    }
+
+    /// Retrieve a page of the unspent message coins sent by the `sender`
+    /// on the DA layer, ordered by the nonce.
+    pub async fn message_coins_by_sender(
+        &self,
+        sender: &Address,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::coins::MessageCoin, String>> {
+        let sender: schema::Address = (*sender).into();
+        let args =
+            schema::coins::MessageCoinsBySenderConnectionArgs::from((sender, request));
+        let query = schema::coins::MessageCoinsBySenderQuery::build(args);
+
+        let coins = self.query(query).await?.message_coins_by_sender.into();
+        Ok(coins)
+    }
+
+    /// Retrieve a page of coins by their owner with any of the `asset_ids`
+    pub async fn coins_of_assets(
+        &self,
+        owner: &Address,
+        asset_ids: &[AssetId],
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::Coin, String>> {
+        let owner: schema::Address = (*owner).into();
+        let asset_ids = asset_ids.iter().map(|id| (*id).into()).collect();
+        let args = CoinsConnectionArgs::with_asset_ids(owner, asset_ids, request);
+        let query = schema::coins::CoinsQuery::build(args);
+
+        let coins = self.query(query).await?.coins.into();
+        Ok(coins)
+    }
+
+    /// Retrieve a page of coins by their owner with at least `min_confirmations`
+    /// blocks on top of the block that created them
+    pub async fn coins_with_min_confirmations(
+        &self,
+        owner: &Address,
+        min_confirmations: u32,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::Coin, String>> {
+        let owner: schema::Address = (*owner).into();
+        let args = CoinsConnectionArgs::with_min_confirmations(
+            owner,
+            min_confirmations.into(),
+            request,
+        );
+        let query = schema::coins::CoinsQuery::build(args);
+
+        let coins = self.query(query).await?.coins.into();
+        Ok(coins)
+    }
+
+    /// Retrieve a page of coins by their owner, excluding the base asset coins
+    pub async fn coins_excluding_base_asset(
+        &self,
+        owner: &Address,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::Coin, String>> {
+        let owner: schema::Address = (*owner).into();
+        let args = CoinsConnectionArgs::excluding_base_asset(owner, request);
+        let query = schema::coins::CoinsQuery::build(args);
+
+        let coins = self.query(query).await?.coins.into();
+        Ok(coins)
+    }
+
+    /// Retrieve coins to spend in a transaction
+    pub async fn coins_to_spend(
+        &self,
+        owner: &Address,
+        spend_query: Vec<(AssetId, u128, Option<u16>)>,
+        // (Utxos, Messages Nonce)
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+    ) -> io::Result<Vec<Vec<types::CoinType>>> {
+        let owner: schema::Address = (*owner).into();
+        let spend_query: Vec<SpendQueryElementInput> = spend_query
+            .iter()
+            .map(|(asset_id, amount, max)| -> Result<_, ConversionError> {
+                Ok(SpendQueryElementInput {
+                    asset_id: (*asset_id).into(),
+                    amount: (*amount).into(),
+                    max: (*max).map(|max| max.into()),
+                    preferred_count: None,
+                    max_overshoot: None,
+                    reserve_count: None,
+                    avoid_largest_coin: None,
+                    prefer_deep_coins: None,
+                    scan_direction: None,
+                    preferred_change_outputs: None,
+                })
+            })
             .try_collect()?;
         let excluded_ids: Option<ExcludeInput> = excluded_ids.map(Into::into);
         let args =
@@ -1479,6 +2113,1201 @@ impl FuelClient {
         Ok(coins_per_asset)
     }
 
+    /// Same as [`FuelClient::coins_to_spend`], but each element of the `spend_query`
+    /// also specifies the preferred number of coins in the selection of the asset.
+    pub async fn coins_to_spend_with_preferred_count(
+        &self,
+        owner: &Address,
+        // (AssetId, amount, max, preferred count)
+        spend_query: Vec<(AssetId, u128, Option<u16>, Option<u16>)>,
+        // (Utxos, Messages Nonce)
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+    ) -> io::Result<Vec<Vec<types::CoinType>>> {
+        let owner: schema::Address = (*owner).into();
+        let spend_query: Vec<SpendQueryElementInput> = spend_query
+            .into_iter()
+            .map(|(asset_id, amount, max, preferred_count)| SpendQueryElementInput {
+                asset_id: asset_id.into(),
+                amount: amount.into(),
+                max: max.map(|max| max.into()),
+                preferred_count: preferred_count.map(|count| count.into()),
+                max_overshoot: None,
+                reserve_count: None,
+                avoid_largest_coin: None,
+                prefer_deep_coins: None,
+                scan_direction: None,
+                preferred_change_outputs: None,
+            })
+            .collect();
+        let excluded_ids: Option<ExcludeInput> = excluded_ids.map(Into::into);
+        let args =
+            schema::coins::CoinsToSpendArgs::from((owner, spend_query, excluded_ids));
+        let query = schema::coins::CoinsToSpendQuery::build(args);
+
+        let coins_per_asset = self
+            .query(query)
+            .await?
+            .coins_to_spend
+            .into_iter()
+            .map(|v| v.into_iter().map(Into::into).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        Ok(coins_per_asset)
+    }
+
+    /// Same as [`FuelClient::coins_to_spend`], but each element of the `spend_query`
+    /// also specifies the maximum amount by which the selection of the asset may
+    /// exceed the target amount.
+    pub async fn coins_to_spend_with_max_overshoot(
+        &self,
+        owner: &Address,
+        // (AssetId, amount, max, max overshoot)
+        spend_query: Vec<(AssetId, u128, Option<u16>, Option<u128>)>,
+        // (Utxos, Messages Nonce)
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+    ) -> io::Result<Vec<Vec<types::CoinType>>> {
+        let owner: schema::Address = (*owner).into();
+        let spend_query: Vec<SpendQueryElementInput> = spend_query
+            .into_iter()
+            .map(|(asset_id, amount, max, max_overshoot)| SpendQueryElementInput {
+                asset_id: asset_id.into(),
+                amount: amount.into(),
+                max: max.map(|max| max.into()),
+                preferred_count: None,
+                max_overshoot: max_overshoot.map(|overshoot| overshoot.into()),
+                reserve_count: None,
+                avoid_largest_coin: None,
+                prefer_deep_coins: None,
+                scan_direction: None,
+                preferred_change_outputs: None,
+            })
+            .collect();
+        let excluded_ids: Option<ExcludeInput> = excluded_ids.map(Into::into);
+        let args =
+            schema::coins::CoinsToSpendArgs::from((owner, spend_query, excluded_ids));
+        let query = schema::coins::CoinsToSpendQuery::build(args);
+
+        let coins_per_asset = self
+            .query(query)
+            .await?
+            .coins_to_spend
+            .into_iter()
+            .map(|v| v.into_iter().map(Into::into).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        Ok(coins_per_asset)
+    }
+
+    /// Same as [`FuelClient::coins_to_spend`], but each element of the `spend_query`
+    /// also specifies the number of coins of the asset that must stay unspent.
+    pub async fn coins_to_spend_with_reserve_count(
+        &self,
+        owner: &Address,
+        // (AssetId, amount, max, reserve count)
+        spend_query: Vec<(AssetId, u128, Option<u16>, Option<u16>)>,
+        // (Utxos, Messages Nonce)
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+    ) -> io::Result<Vec<Vec<types::CoinType>>> {
+        let owner: schema::Address = (*owner).into();
+        let spend_query: Vec<SpendQueryElementInput> = spend_query
+            .into_iter()
+            .map(|(asset_id, amount, max, reserve_count)| SpendQueryElementInput {
+                asset_id: asset_id.into(),
+                amount: amount.into(),
+                max: max.map(|max| max.into()),
+                preferred_count: None,
+                max_overshoot: None,
+                reserve_count: reserve_count.map(|count| count.into()),
+                avoid_largest_coin: None,
+                prefer_deep_coins: None,
+                scan_direction: None,
+                preferred_change_outputs: None,
+            })
+            .collect();
+        let excluded_ids: Option<ExcludeInput> = excluded_ids.map(Into::into);
+        let args =
+            schema::coins::CoinsToSpendArgs::from((owner, spend_query, excluded_ids));
+        let query = schema::coins::CoinsToSpendQuery::build(args);
+
+        let coins_per_asset = self
+            .query(query)
+            .await?
+            .coins_to_spend
+            .into_iter()
+            .map(|v| v.into_iter().map(Into::into).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        Ok(coins_per_asset)
+    }
+
+    /// Same as [`FuelClient::coins_to_spend`], but the selection avoids the largest
+    /// coin of each asset unless the target can't be met within `max` without it.
+    pub async fn coins_to_spend_avoiding_largest_coin(
+        &self,
+        owner: &Address,
+        spend_query: Vec<(AssetId, u128, Option<u16>)>,
+        // (Utxos, Messages Nonce)
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+    ) -> io::Result<Vec<Vec<types::CoinType>>> {
+        let owner: schema::Address = (*owner).into();
+        let spend_query: Vec<SpendQueryElementInput> = spend_query
+            .into_iter()
+            .map(|(asset_id, amount, max)| SpendQueryElementInput {
+                asset_id: asset_id.into(),
+                amount: amount.into(),
+                max: max.map(|max| max.into()),
+                preferred_count: None,
+                max_overshoot: None,
+                reserve_count: None,
+                avoid_largest_coin: Some(true),
+                prefer_deep_coins: None,
+                scan_direction: None,
+                preferred_change_outputs: None,
+            })
+            .collect();
+        let excluded_ids: Option<ExcludeInput> = excluded_ids.map(Into::into);
+        let args =
+            schema::coins::CoinsToSpendArgs::from((owner, spend_query, excluded_ids));
+        let query = schema::coins::CoinsToSpendQuery::build(args);
+
+        let coins_per_asset = self
+            .query(query)
+            .await?
+            .coins_to_spend
+            .into_iter()
+            .map(|v| v.into_iter().map(Into::into).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        Ok(coins_per_asset)
+    }
+
+    /// Same as [`FuelClient::coins_to_spend`], but the selection prefers the coins
+    /// created at lower heights, using the shallower coins only if the target can't
+    /// be met within `max` without them.
+    pub async fn coins_to_spend_preferring_deep_coins(
+        &self,
+        owner: &Address,
+        spend_query: Vec<(AssetId, u128, Option<u16>)>,
+        // (Utxos, Messages Nonce)
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+    ) -> io::Result<Vec<Vec<types::CoinType>>> {
+        let owner: schema::Address = (*owner).into();
+        let spend_query: Vec<SpendQueryElementInput> = spend_query
+            .into_iter()
+            .map(|(asset_id, amount, max)| SpendQueryElementInput {
+                asset_id: asset_id.into(),
+                amount: amount.into(),
+                max: max.map(|max| max.into()),
+                preferred_count: None,
+                max_overshoot: None,
+                reserve_count: None,
+                avoid_largest_coin: None,
+                prefer_deep_coins: Some(true),
+                scan_direction: None,
+                preferred_change_outputs: None,
+            })
+            .collect();
+        let excluded_ids: Option<ExcludeInput> = excluded_ids.map(Into::into);
+        let args =
+            schema::coins::CoinsToSpendArgs::from((owner, spend_query, excluded_ids));
+        let query = schema::coins::CoinsToSpendQuery::build(args);
+
+        let coins_per_asset = self
+            .query(query)
+            .await?
+            .coins_to_spend
+            .into_iter()
+            .map(|v| v.into_iter().map(Into::into).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        Ok(coins_per_asset)
+    }
+
+    /// Same as [`FuelClient::coins_to_spend`], but the selection of each asset prefers
+    /// the coins producing the preferred number of change outputs: `0` for the coins
+    /// matching the amount exactly, and any other number for the coins exceeding it.
+    pub async fn coins_to_spend_with_change_outputs(
+        &self,
+        owner: &Address,
+        // (AssetId, amount, max, preferred change outputs)
+        spend_query: Vec<(AssetId, u128, Option<u16>, u16)>,
+        // (Utxos, Messages Nonce)
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+    ) -> io::Result<Vec<Vec<types::CoinType>>> {
+        let owner: schema::Address = (*owner).into();
+        let spend_query: Vec<SpendQueryElementInput> = spend_query
+            .into_iter()
+            .map(|(asset_id, amount, max, change_outputs)| SpendQueryElementInput {
+                asset_id: asset_id.into(),
+                amount: amount.into(),
+                max: max.map(|max| max.into()),
+                preferred_count: None,
+                max_overshoot: None,
+                reserve_count: None,
+                avoid_largest_coin: None,
+                prefer_deep_coins: None,
+                scan_direction: None,
+                preferred_change_outputs: Some(change_outputs.into()),
+            })
+            .collect();
+        let excluded_ids: Option<ExcludeInput> = excluded_ids.map(Into::into);
+        let args =
+            schema::coins::CoinsToSpendArgs::from((owner, spend_query, excluded_ids));
+        let query = schema::coins::CoinsToSpendQuery::build(args);
+
+        let coins_per_asset = self
+            .query(query)
+            .await?
+            .coins_to_spend
+            .into_iter()
+            .map(|v| v.into_iter().map(Into::into).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        Ok(coins_per_asset)
+    }
+
+    /// Same as [`FuelClient::coins_to_spend`], but the coins to spend index is scanned
+    /// from the smallest coins, which consolidates them. The direction applies only
+    /// if the node selects the coins from the index.
+    pub async fn coins_to_spend_smallest_first(
+        &self,
+        owner: &Address,
+        spend_query: Vec<(AssetId, u128, Option<u16>)>,
+        // (Utxos, Messages Nonce)
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+    ) -> io::Result<Vec<Vec<types::CoinType>>> {
+        let owner: schema::Address = (*owner).into();
+        let spend_query: Vec<SpendQueryElementInput> = spend_query
+            .into_iter()
+            .map(|(asset_id, amount, max)| SpendQueryElementInput {
+                asset_id: asset_id.into(),
+                amount: amount.into(),
+                max: max.map(|max| max.into()),
+                preferred_count: None,
+                max_overshoot: None,
+                reserve_count: None,
+                avoid_largest_coin: None,
+                prefer_deep_coins: None,
+                scan_direction: Some(schema::coins::IndexScanDirection::SmallestFirst),
+                preferred_change_outputs: None,
+            })
+            .collect();
+        let excluded_ids: Option<ExcludeInput> = excluded_ids.map(Into::into);
+        let args =
+            schema::coins::CoinsToSpendArgs::from((owner, spend_query, excluded_ids));
+        let query = schema::coins::CoinsToSpendQuery::build(args);
+
+        let coins_per_asset = self
+            .query(query)
+            .await?
+            .coins_to_spend
+            .into_iter()
+            .map(|v| v.into_iter().map(Into::into).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        Ok(coins_per_asset)
+    }
+
+    /// Same as [`FuelClient::coins_to_spend`], but returns a flat list of coins
+    /// where each coin is tagged with the asset id from the `spend_query`.
+    pub async fn coins_to_spend_flat(
+        &self,
+        owner: &Address,
+        spend_query: Vec<(AssetId, u128, Option<u16>)>,
+        // (Utxos, Messages Nonce)
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+        session: Option<&str>,
+    ) -> io::Result<Vec<(AssetId, types::CoinType)>> {
+        let query_per_asset = spend_query
+            .into_iter()
+            .map(|(asset_id, amount, max)| SpendQueryElementInput {
+                asset_id: asset_id.into(),
+                amount: amount.into(),
+                max: max.map(|max| max.into()),
+                preferred_count: None,
+                max_overshoot: None,
+                reserve_count: None,
+                avoid_largest_coin: None,
+                prefer_deep_coins: None,
+                scan_direction: None,
+                preferred_change_outputs: None,
+            })
+            .collect();
+        let query = schema::coins::CoinsToSpendFlatQuery::build(
+            schema::coins::CoinsToSpendPerAssetArgs {
+                selection: CoinsSelectionInput {
+                    owner: (*owner).into(),
+                    query_per_asset,
+                    excluded_ids: ExcludeInput::for_session(excluded_ids, session),
+                },
+            },
+        );
+
+        let coins = self
+            .query(query)
+            .await?
+            .coins_to_spend_flat
+            .into_iter()
+            .map(|coin| (coin.asset_id.into(), coin.coin.into()))
+            .collect();
+        Ok(coins)
+    }
+
+    /// Same as [`FuelClient::coins_to_spend`], but also returns the summed amount
+    /// of the selected coins per asset and whether it meets the target.
+    pub async fn coins_to_spend_with_summary(
+        &self,
+        owner: &Address,
+        spend_query: Vec<(AssetId, u128, Option<u16>)>,
+        // (Utxos, Messages Nonce)
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+    ) -> io::Result<types::coins::CoinsToSpendWithSummary> {
+        let query_per_asset = spend_query
+            .into_iter()
+            .map(|(asset_id, amount, max)| SpendQueryElementInput {
+                asset_id: asset_id.into(),
+                amount: amount.into(),
+                max: max.map(|max| max.into()),
+                preferred_count: None,
+                max_overshoot: None,
+                reserve_count: None,
+                avoid_largest_coin: None,
+                prefer_deep_coins: None,
+                scan_direction: None,
+                preferred_change_outputs: None,
+            })
+            .collect();
+        let query = schema::coins::CoinsToSpendWithSummaryQuery::build(
+            schema::coins::CoinsToSpendPerAssetArgs {
+                selection: CoinsSelectionInput {
+                    owner: (*owner).into(),
+                    query_per_asset,
+                    excluded_ids: excluded_ids.map(Into::into),
+                },
+            },
+        );
+
+        let selection = self.query(query).await?.coins_to_spend_with_summary.into();
+        Ok(selection)
+    }
+
+    /// Same as [`FuelClient::coins_to_spend`], but each coin is returned with the fields
+    /// required to construct the transaction input signed by the `witness_index`.
+    /// If `include_predicate` is `true`, the inputs also contain the bytecode of
+    /// the predicate of the `owner`, if the node has indexed it.
+    pub async fn coins_to_spend_as_inputs(
+        &self,
+        owner: &Address,
+        spend_query: Vec<(AssetId, u128, Option<u16>)>,
+        // (Utxos, Messages Nonce)
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+        witness_index: Option<u16>,
+        include_predicate: bool,
+    ) -> io::Result<Vec<Vec<types::InputFragment>>> {
+        let query_per_asset = spend_query
+            .into_iter()
+            .map(|(asset_id, amount, max)| SpendQueryElementInput {
+                asset_id: asset_id.into(),
+                amount: amount.into(),
+                max: max.map(|max| max.into()),
+                preferred_count: None,
+                max_overshoot: None,
+                reserve_count: None,
+                avoid_largest_coin: None,
+                prefer_deep_coins: None,
+                scan_direction: None,
+                preferred_change_outputs: None,
+            })
+            .collect();
+        let query = schema::coins::CoinsToSpendAsInputsQuery::build(
+            schema::coins::CoinsToSpendAsInputsArgs {
+                selection: CoinsSelectionInput {
+                    owner: (*owner).into(),
+                    query_per_asset,
+                    excluded_ids: excluded_ids.map(Into::into),
+                },
+                witness_index: witness_index.map(Into::into),
+                include_predicate: Some(include_predicate),
+            },
+        );
+
+        let inputs_per_asset = self
+            .query(query)
+            .await?
+            .coins_to_spend_as_inputs
+            .into_iter()
+            .map(|v| v.into_iter().map(Into::into).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        Ok(inputs_per_asset)
+    }
+
+    /// Same as [`FuelClient::coins_to_spend`], but also returns the skeleton of
+    /// the transaction spending the selected coins, sending the target amounts
+    /// to the `recipient` and the change back to the `owner`. The skeleton must be
+    /// signed by the `owner`, and may be adjusted, for example, to cover the fee,
+    /// before submission.
+    pub async fn coins_to_spend_as_transaction(
+        &self,
+        owner: &Address,
+        recipient: &Address,
+        spend_query: Vec<(AssetId, u128, Option<u16>)>,
+        // (Utxos, Messages Nonce)
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+    ) -> io::Result<types::coins::CoinsToSpendAsTransaction> {
+        let query_per_asset = spend_query
+            .into_iter()
+            .map(|(asset_id, amount, max)| SpendQueryElementInput {
+                asset_id: asset_id.into(),
+                amount: amount.into(),
+                max: max.map(|max| max.into()),
+                preferred_count: None,
+                max_overshoot: None,
+                reserve_count: None,
+                avoid_largest_coin: None,
+                prefer_deep_coins: None,
+                scan_direction: None,
+                preferred_change_outputs: None,
+            })
+            .collect();
+        let query = schema::coins::CoinsToSpendAsTransactionQuery::build(
+            schema::coins::CoinsToSpendAsTransactionArgs {
+                selection: CoinsSelectionInput {
+                    owner: (*owner).into(),
+                    query_per_asset,
+                    excluded_ids: excluded_ids.map(Into::into),
+                },
+                recipient: (*recipient).into(),
+            },
+        );
+
+        let selection = self
+            .query(query)
+            .await?
+            .coins_to_spend_as_transaction
+            .try_into()?;
+        Ok(selection)
+    }
+
+    /// Same as [`FuelClient::coins_to_spend`], but also excludes coins reserved
+    /// by sessions other than the `session`.
+    pub async fn coins_to_spend_for_session(
+        &self,
+        owner: &Address,
+        spend_query: Vec<(AssetId, u128, Option<u16>)>,
+        // (Utxos, Messages Nonce)
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+        session: &str,
+    ) -> io::Result<Vec<Vec<types::CoinType>>> {
+        let query_per_asset = spend_query
+            .into_iter()
+            .map(|(asset_id, amount, max)| SpendQueryElementInput {
+                asset_id: asset_id.into(),
+                amount: amount.into(),
+                max: max.map(|max| max.into()),
+                preferred_count: None,
+                max_overshoot: None,
+                reserve_count: None,
+                avoid_largest_coin: None,
+                prefer_deep_coins: None,
+                scan_direction: None,
+                preferred_change_outputs: None,
+            })
+            .collect();
+        let excluded_ids = ExcludeInput::for_session(excluded_ids, Some(session));
+        let args = schema::coins::CoinsToSpendArgs::from((
+            (*owner).into(),
+            query_per_asset,
+            excluded_ids,
+        ));
+        let query = schema::coins::CoinsToSpendQuery::build(args);
+
+        let coins_per_asset = self
+            .query(query)
+            .await?
+            .coins_to_spend
+            .into_iter()
+            .map(|v| v.into_iter().map(Into::into).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        Ok(coins_per_asset)
+    }
+
+    /// The same as [`FuelClient::coins_to_spend`], but the selection uses the consensus
+    /// parameters of the `consensus_parameters_version`, like its base asset id.
+    pub async fn coins_to_spend_at_version(
+        &self,
+        owner: &Address,
+        spend_query: Vec<(AssetId, u128, Option<u16>)>,
+        // (Utxos, Messages Nonce)
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+        consensus_parameters_version: i32,
+    ) -> io::Result<Vec<Vec<types::CoinType>>> {
+        let query_per_asset = spend_query
+            .into_iter()
+            .map(|(asset_id, amount, max)| SpendQueryElementInput {
+                asset_id: asset_id.into(),
+                amount: amount.into(),
+                max: max.map(|max| max.into()),
+                preferred_count: None,
+                max_overshoot: None,
+                reserve_count: None,
+                avoid_largest_coin: None,
+                prefer_deep_coins: None,
+                scan_direction: None,
+                preferred_change_outputs: None,
+            })
+            .collect();
+        let query = schema::coins::CoinsToSpendAtVersionQuery::build(
+            schema::coins::CoinsToSpendAtVersionArgs {
+                owner: (*owner).into(),
+                query_per_asset,
+                excluded_ids: excluded_ids.map(Into::into),
+                consensus_parameters_version,
+            },
+        );
+
+        let coins_per_asset = self
+            .query(query)
+            .await?
+            .coins_to_spend
+            .into_iter()
+            .map(|v| v.into_iter().map(Into::into).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        Ok(coins_per_asset)
+    }
+
+    /// The same as [`FuelClient::coins_to_spend`], but only the coins with any of
+    /// the `allowed_tags` and without any of the `denied_tags` are selected.
+    /// The tags are ignored if the tagging isn't configured on the node.
+    pub async fn coins_to_spend_with_tags(
+        &self,
+        owner: &Address,
+        spend_query: Vec<(AssetId, u128, Option<u16>)>,
+        // (Utxos, Messages Nonce)
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+        allowed_tags: Option<Vec<String>>,
+        denied_tags: Option<Vec<String>>,
+    ) -> io::Result<Vec<Vec<types::CoinType>>> {
+        let query_per_asset = spend_query
+            .into_iter()
+            .map(|(asset_id, amount, max)| SpendQueryElementInput {
+                asset_id: asset_id.into(),
+                amount: amount.into(),
+                max: max.map(|max| max.into()),
+                preferred_count: None,
+                max_overshoot: None,
+                reserve_count: None,
+                avoid_largest_coin: None,
+                prefer_deep_coins: None,
+                scan_direction: None,
+                preferred_change_outputs: None,
+            })
+            .collect();
+        let query = schema::coins::CoinsToSpendWithTagsQuery::build(
+            schema::coins::CoinsToSpendWithTagsArgs {
+                owner: (*owner).into(),
+                query_per_asset,
+                excluded_ids: excluded_ids.map(Into::into),
+                allowed_tags,
+                denied_tags,
+            },
+        );
+
+        let coins_per_asset = self
+            .query(query)
+            .await?
+            .coins_to_spend
+            .into_iter()
+            .map(|v| v.into_iter().map(Into::into).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        Ok(coins_per_asset)
+    }
+
+    /// The best effort version of the [`FuelClient::coins_to_spend`]. Returns the result
+    /// of the selection for each asset instead of failing the whole request.
+    pub async fn coins_to_spend_best_effort(
+        &self,
+        owner: &Address,
+        spend_query: Vec<(AssetId, u128, Option<u16>)>,
+        // (Utxos, Messages Nonce)
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+        session: Option<&str>,
+    ) -> io::Result<Vec<types::coins::CoinsToSpendAssetResult>> {
+        self.coins_to_spend_best_effort_inner(
+            owner,
+            spend_query,
+            excluded_ids,
+            session,
+            None,
+        )
+        .await
+    }
+
+    /// The same as [`FuelClient::coins_to_spend_best_effort`], but when the `max` number
+    /// of coins can't reach the target, the biggest coins up to `max` are returned
+    /// in the `partial_coins` of the asset result.
+    pub async fn coins_to_spend_best_effort_allow_partial(
+        &self,
+        owner: &Address,
+        spend_query: Vec<(AssetId, u128, Option<u16>)>,
+        // (Utxos, Messages Nonce)
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+        session: Option<&str>,
+    ) -> io::Result<Vec<types::coins::CoinsToSpendAssetResult>> {
+        self.coins_to_spend_best_effort_inner(
+            owner,
+            spend_query,
+            excluded_ids,
+            session,
+            Some(true),
+        )
+        .await
+    }
+
+    async fn coins_to_spend_best_effort_inner(
+        &self,
+        owner: &Address,
+        spend_query: Vec<(AssetId, u128, Option<u16>)>,
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+        session: Option<&str>,
+        allow_partial: Option<bool>,
+    ) -> io::Result<Vec<types::coins::CoinsToSpendAssetResult>> {
+        let query_per_asset = spend_query
+            .into_iter()
+            .map(|(asset_id, amount, max)| SpendQueryElementInput {
+                asset_id: asset_id.into(),
+                amount: amount.into(),
+                max: max.map(|max| max.into()),
+                preferred_count: None,
+                max_overshoot: None,
+                reserve_count: None,
+                avoid_largest_coin: None,
+                prefer_deep_coins: None,
+                scan_direction: None,
+                preferred_change_outputs: None,
+            })
+            .collect();
+        let query = schema::coins::CoinsToSpendBestEffortQuery::build(
+            schema::coins::CoinsToSpendBestEffortArgs {
+                selection: CoinsSelectionInput {
+                    owner: (*owner).into(),
+                    query_per_asset,
+                    excluded_ids: ExcludeInput::for_session(excluded_ids, session),
+                },
+                allow_partial,
+            },
+        );
+
+        let results = self
+            .query(query)
+            .await?
+            .coins_to_spend_best_effort
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        Ok(results)
+    }
+
+    /// Estimates the number of inputs required to cover the `spend_query` target.
+    pub async fn input_count_estimate(
+        &self,
+        owner: &Address,
+        spend_query: Vec<(AssetId, u128, Option<u16>)>,
+        // (Utxos, Messages Nonce)
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+        session: Option<&str>,
+    ) -> io::Result<types::coins::InputCountEstimate> {
+        let query_per_asset = spend_query
+            .into_iter()
+            .map(|(asset_id, amount, max)| SpendQueryElementInput {
+                asset_id: asset_id.into(),
+                amount: amount.into(),
+                max: max.map(|max| max.into()),
+                preferred_count: None,
+                max_overshoot: None,
+                reserve_count: None,
+                avoid_largest_coin: None,
+                prefer_deep_coins: None,
+                scan_direction: None,
+                preferred_change_outputs: None,
+            })
+            .collect();
+        let query = schema::coins::InputCountEstimateQuery::build(
+            schema::coins::CoinsToSpendPerAssetArgs {
+                selection: CoinsSelectionInput {
+                    owner: (*owner).into(),
+                    query_per_asset,
+                    excluded_ids: ExcludeInput::for_session(excluded_ids, session),
+                },
+            },
+        );
+
+        let estimate = self.query(query).await?.input_count_estimate.into();
+        Ok(estimate)
+    }
+
+    /// Estimates the work of the coins to spend selection of the `spend_query`
+    /// without running it.
+    pub async fn coins_to_spend_cost(
+        &self,
+        owner: &Address,
+        spend_query: Vec<(AssetId, u128, Option<u16>)>,
+        // (Utxos, Messages Nonce)
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+        session: Option<&str>,
+    ) -> io::Result<types::coins::SelectionCost> {
+        let query_per_asset = spend_query
+            .into_iter()
+            .map(|(asset_id, amount, max)| SpendQueryElementInput {
+                asset_id: asset_id.into(),
+                amount: amount.into(),
+                max: max.map(|max| max.into()),
+                preferred_count: None,
+                max_overshoot: None,
+                reserve_count: None,
+                avoid_largest_coin: None,
+                prefer_deep_coins: None,
+                scan_direction: None,
+                preferred_change_outputs: None,
+            })
+            .collect();
+        let query = schema::coins::CoinsToSpendCostQuery::build(
+            schema::coins::CoinsToSpendPerAssetArgs {
+                selection: CoinsSelectionInput {
+                    owner: (*owner).into(),
+                    query_per_asset,
+                    excluded_ids: ExcludeInput::for_session(excluded_ids, session),
+                },
+            },
+        );
+
+        let cost = self.query(query).await?.coins_to_spend_cost.into();
+        Ok(cost)
+    }
+
+    /// Checks whether the `amount` of the `asset_id` can be covered by the coins
+    /// of the `owner` without selecting them.
+    pub async fn coins_feasibility(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        amount: u128,
+    ) -> io::Result<types::coins::CoinsFeasibility> {
+        let query = schema::coins::CoinsFeasibilityQuery::build(
+            schema::coins::CoinsFeasibilityArgs {
+                owner: (*owner).into(),
+                asset_id: (*asset_id).into(),
+                amount: amount.into(),
+            },
+        );
+
+        let feasibility = self.query(query).await?.coins_feasibility.into();
+        Ok(feasibility)
+    }
+
+    /// Resolves the coins with the `utxo_ids` and the message coins with the `nonces`
+    /// and checks whether the unspent coins of the `asset_id` cover the `target`.
+    pub async fn coins_coverage(
+        &self,
+        asset_id: &AssetId,
+        target: u128,
+        utxo_ids: Vec<UtxoId>,
+        nonces: Vec<Nonce>,
+    ) -> io::Result<types::coins::CoinsCoverage> {
+        let query = schema::coins::CoinsCoverageQuery::build(
+            schema::coins::CoinsCoverageArgs {
+                asset_id: (*asset_id).into(),
+                target: target.into(),
+                utxo_ids: utxo_ids.into_iter().map(Into::into).collect(),
+                nonces: nonces.into_iter().map(Into::into).collect(),
+            },
+        );
+
+        let coverage = self.query(query).await?.coins_coverage.into();
+        Ok(coverage)
+    }
+
+    /// Returns the coins of the `owner` created and spent by the transactions included
+    /// in the blocks from the `start_height` up to the `end_height` inclusive.
+    /// The page covers at most `first` transactions after the `after` transaction.
+    pub async fn coin_activity(
+        &self,
+        owner: &Address,
+        start_height: BlockHeight,
+        end_height: BlockHeight,
+        after: Option<TxPointer>,
+        first: Option<u32>,
+    ) -> io::Result<types::coins::CoinActivity> {
+        let query = schema::coins::CoinActivityQuery::build(
+            schema::coins::CoinActivityArgs {
+                owner: (*owner).into(),
+                start_height: U32(start_height.into()),
+                end_height: U32(end_height.into()),
+                after: after
+                    .map(|after| schema::primitives::TxPointer(HexFormatted(after))),
+                first: first.map(U32),
+            },
+        );
+
+        let activity = self.query(query).await?.coin_activity.into();
+        Ok(activity)
+    }
+
+    /// Selects the coins for the largest subset of the `spend_query` assets that fits
+    /// into the `max_inputs` budget of one transaction. The assets are taken in the
+    /// order defined by the `priority`, the rest of them are dropped.
+    pub async fn coins_to_spend_within_budget(
+        &self,
+        owner: &Address,
+        spend_query: Vec<(AssetId, u128, Option<u16>)>,
+        // (Utxos, Messages Nonce)
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+        session: Option<&str>,
+        priority: Option<types::coins::AssetPriority>,
+    ) -> io::Result<types::coins::CoinsWithinBudget> {
+        let query_per_asset = spend_query
+            .into_iter()
+            .map(|(asset_id, amount, max)| SpendQueryElementInput {
+                asset_id: asset_id.into(),
+                amount: amount.into(),
+                max: max.map(|max| max.into()),
+                preferred_count: None,
+                max_overshoot: None,
+                reserve_count: None,
+                avoid_largest_coin: None,
+                prefer_deep_coins: None,
+                scan_direction: None,
+                preferred_change_outputs: None,
+            })
+            .collect();
+        let query = schema::coins::CoinsToSpendWithinBudgetQuery::build(
+            schema::coins::CoinsToSpendWithinBudgetArgs {
+                selection: CoinsSelectionInput {
+                    owner: (*owner).into(),
+                    query_per_asset,
+                    excluded_ids: ExcludeInput::for_session(excluded_ids, session),
+                },
+                priority: priority.map(Into::into),
+            },
+        );
+
+        let coins = self.query(query).await?.coins_to_spend_within_budget.into();
+        Ok(coins)
+    }
+
+    /// Selects the biggest coins of the `asset_id` covering the `amount`, partitioned
+    /// into groups that fit into one transaction each.
+    pub async fn coins_to_spend_in_groups(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        amount: u128,
+        // (Utxos, Messages Nonce)
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+        session: Option<&str>,
+    ) -> io::Result<types::coins::CoinGroups> {
+        let query = schema::coins::CoinsToSpendInGroupsQuery::build(
+            schema::coins::CoinsToSpendInGroupsArgs {
+                owner: (*owner).into(),
+                asset_id: (*asset_id).into(),
+                amount: amount.into(),
+                excluded_ids: ExcludeInput::for_session(excluded_ids, session),
+            },
+        );
+
+        let groups = self.query(query).await?.coins_to_spend_in_groups.into();
+        Ok(groups)
+    }
+
+    /// Returns up to `count` candidate selections of the coins of the `owner` covering
+    /// the `amount` of the asset, ranked by their composite cost from the cheapest.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn coin_selection_candidates(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        amount: u128,
+        max: Option<u16>,
+        count: u16,
+        // (Utxos, Messages Nonce)
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+        session: Option<&str>,
+    ) -> io::Result<Vec<types::coins::CoinSelectionCandidate>> {
+        let query = schema::coins::CoinSelectionCandidatesQuery::build(
+            schema::coins::CoinSelectionCandidatesArgs {
+                owner: (*owner).into(),
+                asset_id: (*asset_id).into(),
+                amount: amount.into(),
+                max: max.map(Into::into),
+                count: count.into(),
+                excluded_ids: ExcludeInput::for_session(excluded_ids, session),
+            },
+        );
+
+        let candidates = self
+            .query(query)
+            .await?
+            .coin_selection_candidates
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        Ok(candidates)
+    }
+
+    /// Selects the biggest coins of the `spend_query` assets while the estimated size
+    /// of the inputs stays within the `max_size` bytes, or the maximum transaction size.
+    /// The inputs are estimated as predicate inputs if the `predicate_size` is known.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn coins_to_spend_within_size(
+        &self,
+        owner: &Address,
+        spend_query: Vec<(AssetId, u128, Option<u16>)>,
+        max_size: Option<u64>,
+        predicate_size: Option<u64>,
+        // (Utxos, Messages Nonce)
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+        session: Option<&str>,
+    ) -> io::Result<Vec<Vec<types::CoinType>>> {
+        let query_per_asset = spend_query
+            .into_iter()
+            .map(|(asset_id, amount, max)| SpendQueryElementInput {
+                asset_id: asset_id.into(),
+                amount: amount.into(),
+                max: max.map(|max| max.into()),
+                preferred_count: None,
+                max_overshoot: None,
+                reserve_count: None,
+                avoid_largest_coin: None,
+                prefer_deep_coins: None,
+                scan_direction: None,
+                preferred_change_outputs: None,
+            })
+            .collect();
+        let query = schema::coins::CoinsToSpendWithinSizeQuery::build(
+            schema::coins::CoinsToSpendWithinSizeArgs {
+                owner: (*owner).into(),
+                query_per_asset,
+                excluded_ids: ExcludeInput::for_session(excluded_ids, session),
+                strategy: SelectionStrategy::WithinSize,
+                max_size: max_size.map(Into::into),
+                predicate_size: predicate_size.map(Into::into),
+            },
+        );
+
+        let coins_per_asset = self
+            .query(query)
+            .await?
+            .coins_to_spend
+            .into_iter()
+            .map(|v| v.into_iter().map(Into::into).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        Ok(coins_per_asset)
+    }
+
+    /// Selects the coins of the `spend_query` assets, and then the base asset coins
+    /// covering the `base_amount` and the fee `base_fee + fee_per_input * inputs`,
+    /// where `inputs` is the number of all selected coins.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn coins_to_spend_with_fee(
+        &self,
+        owner: &Address,
+        spend_query: Vec<(AssetId, u128, Option<u16>)>,
+        base_amount: Option<u128>,
+        base_fee: u64,
+        fee_per_input: u64,
+        // (Utxos, Messages Nonce)
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+        session: Option<&str>,
+    ) -> io::Result<types::coins::CoinsToSpendWithFee> {
+        let query_per_asset = spend_query
+            .into_iter()
+            .map(|(asset_id, amount, max)| SpendQueryElementInput {
+                asset_id: asset_id.into(),
+                amount: amount.into(),
+                max: max.map(|max| max.into()),
+                preferred_count: None,
+                max_overshoot: None,
+                reserve_count: None,
+                avoid_largest_coin: None,
+                prefer_deep_coins: None,
+                scan_direction: None,
+                preferred_change_outputs: None,
+            })
+            .collect();
+        let query = schema::coins::CoinsToSpendWithFeeQuery::build(
+            schema::coins::CoinsToSpendWithFeeArgs {
+                selection: CoinsSelectionInput {
+                    owner: (*owner).into(),
+                    query_per_asset,
+                    excluded_ids: ExcludeInput::for_session(excluded_ids, session),
+                },
+                base_amount: base_amount.map(Into::into),
+                base_fee: base_fee.into(),
+                fee_per_input: fee_per_input.into(),
+            },
+        );
+
+        let coins = self.query(query).await?.coins_to_spend_with_fee.into();
+        Ok(coins)
+    }
+
+    /// Computes the change per asset that a transaction spending the `selected` coins
+    /// must output to pay the `spend` amounts. Both are lists of `(asset_id, amount)`.
+    pub async fn change_outputs(
+        &self,
+        selected: &[(AssetId, u128)],
+        spend: &[(AssetId, u128)],
+    ) -> io::Result<Vec<(AssetId, u128)>> {
+        let query =
+            schema::coins::ChangeOutputsQuery::build(schema::coins::ChangeOutputsArgs {
+                selected: selected.iter().copied().map(Into::into).collect(),
+                spend: spend.iter().copied().map(Into::into).collect(),
+            });
+
+        let change = self
+            .query(query)
+            .await?
+            .change_outputs
+            .into_iter()
+            .map(|change| (change.asset_id.into(), change.amount.into()))
+            .collect();
+        Ok(change)
+    }
+
+    /// Returns the number and the total amount of the coins of the `owner` with an amount
+    /// below the `threshold` per asset. Requires the coins to spend index on the node.
+    pub async fn dust_coins(
+        &self,
+        owner: &Address,
+        threshold: u64,
+    ) -> io::Result<types::coins::DustCoins> {
+        let query = schema::coins::DustCoinsQuery::build(schema::coins::DustCoinsArgs {
+            owner: (*owner).into(),
+            threshold: threshold.into(),
+        });
+
+        let dust_coins = self.query(query).await?.dust_coins.into();
+        Ok(dust_coins)
+    }
+
+    /// Returns the coins of the `owner` that are excluded from the coin selection
+    /// of the node, because they are blacklisted or reserved by a session.
+    pub async fn locked_coins(
+        &self,
+        owner: &Address,
+    ) -> io::Result<types::coins::LockedCoins> {
+        let query =
+            schema::coins::LockedCoinsQuery::build(schema::coins::LockedCoinsArgs {
+                owner: (*owner).into(),
+            });
+
+        let locked_coins = self.query(query).await?.locked_coins.into();
+        Ok(locked_coins)
+    }
+
+    /// Returns the coins of the `owner` with the `asset_id` and the reason why
+    /// the coin selection of the node can't use them, if any.
+    /// Requires `debug` to be enabled on the node.
+    pub async fn coins_eligibility(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        session: Option<&str>,
+    ) -> io::Result<types::coins::CoinsEligibility> {
+        let query = schema::coins::CoinsEligibilityQuery::build(
+            schema::coins::CoinsEligibilityArgs {
+                owner: (*owner).into(),
+                asset_id: (*asset_id).into(),
+                session: session.map(ToString::to_string),
+            },
+        );
+
+        let eligibility = self.query(query).await?.coins_eligibility.into();
+        Ok(eligibility)
+    }
+
+    /// Returns the total number of unspent coins in the UTXO set.
+    pub async fn utxo_count(&self) -> io::Result<types::coins::UtxoCount> {
+        let query = schema::coins::UtxoCountQuery::build(());
+
+        let utxo_count = self.query(query).await?.utxo_count.into();
+        Ok(utxo_count)
+    }
+
+    /// Returns the Merkle root of the unspent coins at the latest block height.
+    /// Requires the coin set root to be enabled on the node and the API key of
+    /// a trusted client, see [`Self::with_api_key`].
+    pub async fn coin_set_root(&self) -> io::Result<types::coins::CoinSetRoot> {
+        let query = schema::coins::CoinSetRootQuery::build(());
+
+        let coin_set_root = self.query(query).await?.coin_set_root.into();
+        Ok(coin_set_root)
+    }
+
+    /// Reserves the coins under the `session` for `ttl_seconds`. Reserved coins are
+    /// excluded from the `coins_to_spend` selection of other sessions.
+    /// Requires the API key of a trusted client, see [`Self::with_api_key`].
+    pub async fn reserve_coins(
+        &self,
+        session: &str,
+        utxos: &[UtxoId],
+        messages: &[Nonce],
+        ttl_seconds: u32,
+    ) -> io::Result<bool> {
+        let query = schema::coins::ReserveCoinsMutation::build(
+            schema::coins::ReserveCoinsArgs {
+                session: session.to_string(),
+                utxos: utxos.iter().map(|utxo| (*utxo).into()).collect(),
+                messages: messages.iter().map(|nonce| (*nonce).into()).collect(),
+                ttl_seconds: ttl_seconds.into(),
+            },
+        );
+
+        Ok(self.query(query).await?.reserve_coins)
+    }
+
+    /// Releases all coins reserved by the `session`.
+    /// Requires the API key of the client that reserved the coins.
+    pub async fn release_coins(&self, session: &str) -> io::Result<bool> {
+        let query = schema::coins::ReleaseCoinsMutation::build(
+            schema::coins::ReleaseCoinsArgs {
+                session: session.to_string(),
+            },
+        );
+
+        Ok(self.query(query).await?.release_coins)
+    }
+
+    /// Starts the rebuild of the coins to spend index entries of the `owner`,
+    /// optionally only for the `asset_id`, and returns the id of the rebuild.
+    /// Use [`Self::coins_to_spend_index_rebuild`] to get its status. Requires the node
+    /// to run with `debug` enabled and the API key of a trusted client,
+    /// see [`Self::with_api_key`].
+    pub async fn rebuild_coins_to_spend_index(
+        &self,
+        owner: &Address,
+        asset_id: Option<&AssetId>,
+    ) -> io::Result<u64> {
+        let query = schema::coins::RebuildCoinsToSpendIndexMutation::build(
+            schema::coins::RebuildCoinsToSpendIndexArgs {
+                owner: (*owner).into(),
+                asset_id: asset_id.map(|id| (*id).into()),
+            },
+        );
+
+        Ok(self.query(query).await?.rebuild_coins_to_spend_index.into())
+    }
+
+    /// Returns the status of the coins to spend index rebuild with the `id`,
+    /// or `None` if the node doesn't know it.
+    pub async fn coins_to_spend_index_rebuild(
+        &self,
+        id: u64,
+    ) -> io::Result<Option<types::coins::CoinsToSpendIndexRebuildStatus>> {
+        let query = schema::coins::CoinsToSpendIndexRebuildQuery::build(
+            schema::coins::CoinsToSpendIndexRebuildArgs { id: id.into() },
+        );
+
+        let status = self
+            .query(query)
+            .await?
+            .coins_to_spend_index_rebuild
+            .map(Into::into);
+        Ok(status)
+    }
+
     pub async fn contract(&self, id: &ContractId) -> io::Result<Option<types::Contract>> {
         let query = schema::contract::ContractByIdQuery::build(ContractByIdArgs {
             id: (*id).into(),
@@ -1537,6 +3366,49 @@ impl FuelClient {
         Ok(balances)
     }
 
+    /// Projects the balance of the `owner` for the `asset_id` after spending
+    /// the `amount`.
+    /// The `fee` is included in the outflow only for the base asset.
+    pub async fn projected_balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        amount: u128,
+        fee: Option<u64>,
+    ) -> io::Result<types::ProjectedBalance> {
+        let args = schema::balance::ProjectedBalanceArgs {
+            owner: (*owner).into(),
+            asset_id: (*asset_id).into(),
+            amount: amount.into(),
+            fee: fee.map(Into::into),
+        };
+        let query = schema::balance::ProjectedBalanceQuery::build(args);
+
+        let balance = self.query(query).await?.projected_balance.into();
+        Ok(balance)
+    }
+
+    /// Returns the balance and the number of the spendable coins per asset for each
+    /// of the `owners`, in the order of the `owners`.
+    pub async fn portfolio(
+        &self,
+        owners: &[Address],
+    ) -> io::Result<Vec<types::OwnerPortfolio>> {
+        let args = schema::balance::PortfolioArgs {
+            owners: owners.iter().map(|owner| (*owner).into()).collect(),
+        };
+        let query = schema::balance::PortfolioQuery::build(args);
+
+        let portfolio = self
+            .query(query)
+            .await?
+            .portfolio
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        Ok(portfolio)
+    }
+
     pub async fn contract_balances(
         &self,
         contract: &ContractId,
@@ -1585,6 +3457,8 @@ impl FuelClient {
         Ok(contract_info)
     }
 
+    /// Returns whether the message with the `nonce` is unspent, already spent,
+    /// or has never existed on the chain.
     pub async fn message_status(&self, nonce: &Nonce) -> io::Result<MessageStatus> {
         let query = schema::message::MessageStatusQuery::build(MessageStatusArgs {
             nonce: (*nonce).into(),
@@ -1594,6 +3468,64 @@ impl FuelClient {
         Ok(status)
     }
 
+    /// Returns whether the inbound message with the `nonce` is included into the chain,
+    /// is known to the relayer but not yet included, or is unknown to the node.
+    pub async fn message_inclusion_status(
+        &self,
+        nonce: &Nonce,
+    ) -> io::Result<types::MessageInclusionState> {
+        let query = schema::message::MessageInclusionStatusQuery::build(NonceArgs {
+            nonce: (*nonce).into(),
+        });
+        let state = self.query(query).await?.message_inclusion_status.into();
+
+        Ok(state)
+    }
+
+    /// Returns the number of the inbound messages synced by the relayer, but not yet
+    /// included into the chain, or `None` if the relayer is disabled.
+    pub async fn relayer_backlog(&self) -> io::Result<Option<types::RelayerBacklog>> {
+        let query = schema::message::RelayerBacklogQuery::build(());
+        let backlog = self.query(query).await?.relayer_backlog.map(Into::into);
+
+        Ok(backlog)
+    }
+
+    /// Returns the value bridged between the DA layer and the chain by the blocks
+    /// from the `start` up to the `end` height inclusive.
+    pub async fn bridged_value(
+        &self,
+        start: BlockHeight,
+        end: BlockHeight,
+    ) -> io::Result<types::BridgedValue> {
+        let query =
+            schema::message::BridgedValueQuery::build(schema::block::BlockHeadersArgs {
+                start: U32(start.into()),
+                end: U32(end.into()),
+            });
+        let value = self.query(query).await?.bridged_value.into();
+
+        Ok(value)
+    }
+
+    /// Returns the height of the first block where the message with the `nonce`
+    /// could be spent, or `None` if the message doesn't exist.
+    pub async fn message_spendable_height(
+        &self,
+        nonce: &Nonce,
+    ) -> io::Result<Option<types::MessageSpendableHeight>> {
+        let query = schema::message::MessageSpendableHeightQuery::build(NonceArgs {
+            nonce: (*nonce).into(),
+        });
+        let height = self
+            .query(query)
+            .await?
+            .message_spendable_height
+            .map(Into::into);
+
+        Ok(height)
+    }
+
     /// Request a merkle proof of an output message.
     pub async fn message_proof(
         &self,
@@ -1601,6 +3533,98 @@ impl FuelClient {
         nonce: &Nonce,
         commit_block_id: Option<&BlockId>,
         commit_block_height: Option<BlockHeight>,
+    ) -> io::Result<types::MessageProof> {
+        self.message_proof_inner(
+            transaction_id,
+            nonce,
+            commit_block_id,
+            commit_block_height,
+            None,
+            false,
+            None,
+        )
+        .await
+    }
+
+    /// Request a merkle proof of an output message. The node waits until the chain
+    /// is `confirmations` blocks above the commit block before generating the proof.
+    pub async fn message_proof_with_confirmations(
+        &self,
+        transaction_id: &TxId,
+        nonce: &Nonce,
+        commit_block_id: Option<&BlockId>,
+        commit_block_height: Option<BlockHeight>,
+        confirmations: u32,
+    ) -> io::Result<types::MessageProof> {
+        self.message_proof_inner(
+            transaction_id,
+            nonce,
+            commit_block_id,
+            commit_block_height,
+            Some(confirmations),
+            false,
+            None,
+        )
+        .await
+    }
+
+    /// Request the block headers and the block proof of an output message, without
+    /// the Merkle path of the message inside of the message block. The headers can be
+    /// cached and reused for the proofs of other messages from the same block.
+    /// The `message_proof` of the returned proof is empty.
+    pub async fn message_proof_headers(
+        &self,
+        transaction_id: &TxId,
+        nonce: &Nonce,
+        commit_block_id: Option<&BlockId>,
+        commit_block_height: Option<BlockHeight>,
+    ) -> io::Result<types::MessageProof> {
+        self.message_proof_inner(
+            transaction_id,
+            nonce,
+            commit_block_id,
+            commit_block_height,
+            None,
+            true,
+            None,
+        )
+        .await
+    }
+
+    /// Request a merkle proof of an output message relatively to a commit block
+    /// anchored at or below the `da_height_anchor`. If neither `commit_block_id`
+    /// nor `commit_block_height` is set, the latest block at or below the anchor
+    /// is used as the commit block.
+    pub async fn message_proof_anchored(
+        &self,
+        transaction_id: &TxId,
+        nonce: &Nonce,
+        commit_block_id: Option<&BlockId>,
+        commit_block_height: Option<BlockHeight>,
+        da_height_anchor: u64,
+    ) -> io::Result<types::MessageProof> {
+        self.message_proof_inner(
+            transaction_id,
+            nonce,
+            commit_block_id,
+            commit_block_height,
+            None,
+            false,
+            Some(da_height_anchor),
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn message_proof_inner(
+        &self,
+        transaction_id: &TxId,
+        nonce: &Nonce,
+        commit_block_id: Option<&BlockId>,
+        commit_block_height: Option<BlockHeight>,
+        confirmations: Option<u32>,
+        headers_only: bool,
+        da_height_anchor: Option<u64>,
     ) -> io::Result<types::MessageProof> {
         let transaction_id: TransactionId = (*transaction_id).into();
         let nonce: schema::Nonce = (*nonce).into();
@@ -1612,11 +3636,67 @@ impl FuelClient {
             nonce,
             commit_block_id,
             commit_block_height,
+            confirmations: confirmations.map(Into::into),
+            headers_only: Some(headers_only),
+            da_height_anchor: da_height_anchor.map(Into::into),
         });
         let proof = self.query(query).await?.message_proof.try_into()?;
         Ok(proof)
     }
 
+    /// Request a merkle proof of an output message by its nonce.
+    /// The transaction that emitted the message is resolved by the node.
+    pub async fn message_proof_by_nonce(
+        &self,
+        nonce: &Nonce,
+        commit_block_id: Option<&BlockId>,
+        commit_block_height: Option<BlockHeight>,
+    ) -> io::Result<types::MessageProof> {
+        let nonce: schema::Nonce = (*nonce).into();
+        let commit_block_id: Option<schema::BlockId> =
+            commit_block_id.map(|commit_block_id| (*commit_block_id).into());
+        let commit_block_height = commit_block_height.map(Into::into);
+        let query =
+            schema::message::MessageProofByNonceQuery::build(MessageProofByNonceArgs {
+                nonce,
+                commit_block_id,
+                commit_block_height,
+                confirmations: None,
+            });
+        let proof = self.query(query).await?.message_proof_by_nonce.try_into()?;
+        Ok(proof)
+    }
+
+    /// Request merkle proofs of several output messages by their nonces relatively
+    /// to the same commit block. The proofs are returned in the order of the `nonces`.
+    pub async fn message_proofs_by_nonces(
+        &self,
+        nonces: &[Nonce],
+        commit_block_id: Option<&BlockId>,
+        commit_block_height: Option<BlockHeight>,
+    ) -> io::Result<Vec<types::MessageProof>> {
+        let nonces = nonces.iter().map(|nonce| (*nonce).into()).collect();
+        let commit_block_id: Option<schema::BlockId> =
+            commit_block_id.map(|commit_block_id| (*commit_block_id).into());
+        let commit_block_height = commit_block_height.map(Into::into);
+        let query = schema::message::MessageProofsByNoncesQuery::build(
+            MessageProofsByNoncesArgs {
+                nonces,
+                commit_block_id,
+                commit_block_height,
+                confirmations: None,
+            },
+        );
+        let proofs = self
+            .query(query)
+            .await?
+            .message_proofs_by_nonces
+            .into_iter()
+            .map(TryInto::try_into)
+            .try_collect()?;
+        Ok(proofs)
+    }
+
     pub async fn relayed_transaction_status(
         &self,
         id: &Bytes32,
@@ -1642,6 +3722,19 @@ impl FuelClient {
         let asset_info = self.query(query).await?.asset_details.into();
         Ok(asset_info)
     }
+
+    /// Returns the distinct asset ids seen on the chain, ordered by the asset id.
+    pub async fn known_assets(
+        &self,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::asset::KnownAsset, String>> {
+        let args = schema::ConnectionArgs::from(request);
+        let query = schema::assets::KnownAssetsQuery::build(args);
+
+        let assets = self.query(query).await?.known_assets.into();
+
+        Ok(assets)
+    }
 }
 
 #[cfg(any(test, feature = "test-helpers"))]
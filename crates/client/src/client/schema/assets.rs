@@ -1,7 +1,9 @@
 use crate::client::schema::{
     schema,
     AssetId,
+    ConnectionArgsFields,
     ContractId,
+    PageInfo,
     SubId,
     U128,
 };
@@ -29,3 +31,52 @@ pub struct AssetInfoDetails {
     pub contract_id: ContractId,
     pub total_supply: U128,
 }
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct KnownAsset {
+    pub asset_id: AssetId,
+    pub total_supply: Option<U128>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct KnownAssetEdge {
+    pub cursor: String,
+    pub node: KnownAsset,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct KnownAssetConnection {
+    pub edges: Vec<KnownAssetEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ConnectionArgs"
+)]
+pub struct KnownAssetsQuery {
+    #[arguments(after: $after, before: $before, first: $first, last: $last)]
+    pub known_assets: KnownAssetConnection,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_assets_connection_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = KnownAssetsQuery::build(crate::client::schema::ConnectionArgs {
+            after: None,
+            before: None,
+            first: None,
+            last: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+}
@@ -4,6 +4,7 @@ use crate::client::{
         Address,
         AssetId,
         PageInfo,
+        U128,
         U64,
     },
     PageDirection,
@@ -103,6 +104,68 @@ pub struct Balance {
     pub asset_id: AssetId,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ProjectedBalanceArgs {
+    pub owner: Address,
+    pub asset_id: AssetId,
+    pub amount: U128,
+    pub fee: Option<U64>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ProjectedBalanceArgs"
+)]
+pub struct ProjectedBalanceQuery {
+    #[arguments(owner: $owner, assetId: $asset_id, amount: $amount, fee: $fee)]
+    pub projected_balance: ProjectedBalance,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ProjectedBalance {
+    pub owner: Address,
+    pub asset_id: AssetId,
+    pub current_balance: U128,
+    pub outflow: U128,
+    pub remaining_balance: U128,
+    pub sufficient: bool,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct PortfolioArgs {
+    pub owners: Vec<Address>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "PortfolioArgs"
+)]
+pub struct PortfolioQuery {
+    #[arguments(owners: $owners)]
+    pub portfolio: Vec<OwnerPortfolio>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct OwnerPortfolio {
+    pub owner: Address,
+    pub assets: Vec<AssetPortfolioEntry>,
+    pub complete: bool,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AssetPortfolioEntry {
+    pub asset_id: AssetId,
+    pub amount: U128,
+    pub coin_count: U64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -117,6 +180,27 @@ mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn projected_balance_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = ProjectedBalanceQuery::build(ProjectedBalanceArgs {
+            owner: Address::default(),
+            asset_id: AssetId::default(),
+            amount: U128(0),
+            fee: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn portfolio_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = PortfolioQuery::build(PortfolioArgs {
+            owners: vec![Address::default()],
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn balances_connection_query_gql_output() {
         use cynic::QueryBuilder;
@@ -59,6 +59,121 @@ pub struct BlocksQuery {
     pub blocks: BlockConnection,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct BlockGasUtilizationArgs {
+    pub last: i32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "BlockGasUtilizationArgs"
+)]
+pub struct BlockGasUtilizationQuery {
+    #[arguments(last: $last)]
+    pub block_gas_utilization: BlockGasUtilization,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BlockGasUtilization {
+    pub block_gas_limit: U64,
+    pub blocks: Vec<BlockGasUsage>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BlockGasUsage {
+    pub height: U32,
+    pub gas_used: U64,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct BlockFeesArgs {
+    pub height: U32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "BlockFeesArgs"
+)]
+pub struct BlockFeesQuery {
+    #[arguments(height: $height)]
+    pub block_fees: Option<U64>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "BlockFeesArgs"
+)]
+pub struct BlockSummaryQuery {
+    #[arguments(height: $height)]
+    pub block_summary: Option<BlockSummary>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BlockSummary {
+    pub height: U32,
+    pub time: Tai64Timestamp,
+    pub transaction_count: U16,
+    pub gas_used: U64,
+    pub fees: U64,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct ChainTipQuery {
+    pub chain_tip: ChainTip,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ChainTip {
+    pub height: U32,
+    pub id: BlockId,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct BlockHeadersArgs {
+    pub start: U32,
+    pub end: U32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "BlockHeadersArgs"
+)]
+pub struct BlockHeadersQuery {
+    #[arguments(start: $start, end: $end)]
+    pub block_headers: Vec<Header>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "BlockHeadersArgs"
+)]
+pub struct MessageOutboxRootsQuery {
+    #[arguments(start: $start, end: $end)]
+    pub message_outbox_roots: Vec<BlockMessageOutboxRoot>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BlockMessageOutboxRoot {
+    pub height: U32,
+    pub message_outbox_root: Bytes32,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct BlockConnection {
@@ -1,10 +1,15 @@
 use crate::client::{
     schema::{
         schema,
+        tx::OpaqueTransaction,
         Address,
         AssetId,
+        Bytes32,
+        HexString,
         Nonce,
         PageInfo,
+        TransactionId,
+        TxPointer,
         UtxoId,
         U128,
         U16,
@@ -35,6 +40,24 @@ pub struct CoinByIdQuery {
     pub coin: Option<Coin>,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinByIdArgs"
+)]
+pub struct CoinWithTransactionQuery {
+    #[arguments(utxoId: $utxo_id)]
+    pub coin_with_transaction: Option<CoinWithTransaction>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinWithTransaction {
+    pub coin: Coin,
+    pub transaction: Option<OpaqueTransaction>,
+}
+
 #[derive(cynic::InputObject, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct CoinFilterInput {
@@ -42,6 +65,12 @@ pub struct CoinFilterInput {
     pub owner: Address,
     /// Filter coins based on the `asset_id` field
     pub asset_id: Option<AssetId>,
+    /// Filter coins with the `asset_id` from the set
+    pub asset_ids: Option<Vec<AssetId>>,
+    /// Exclude coins of the base asset
+    pub exclude_base_asset: Option<bool>,
+    /// Filter coins with at least `min_confirmations` blocks on top of them
+    pub min_confirmations: Option<U32>,
 }
 
 #[derive(cynic::QueryVariables, Debug)]
@@ -66,6 +95,9 @@ impl From<(Address, AssetId, PaginationRequest<String>)> for CoinsConnectionArgs
                 filter: CoinFilterInput {
                     owner: r.0,
                     asset_id: Some(r.1),
+                    asset_ids: None,
+                    exclude_base_asset: None,
+                    min_confirmations: None,
                 },
                 after: r.2.cursor,
                 before: None,
@@ -76,6 +108,9 @@ impl From<(Address, AssetId, PaginationRequest<String>)> for CoinsConnectionArgs
                 filter: CoinFilterInput {
                     owner: r.0,
                     asset_id: Some(r.1),
+                    asset_ids: None,
+                    exclude_base_asset: None,
+                    min_confirmations: None,
                 },
                 after: None,
                 before: r.2.cursor,
@@ -86,6 +121,75 @@ impl From<(Address, AssetId, PaginationRequest<String>)> for CoinsConnectionArgs
     }
 }
 
+impl CoinsConnectionArgs {
+    /// Arguments to query all coins of the `owner` except the base asset coins.
+    pub fn excluding_base_asset(
+        owner: Address,
+        request: PaginationRequest<String>,
+    ) -> Self {
+        let filter = CoinFilterInput {
+            owner,
+            asset_id: None,
+            asset_ids: None,
+            exclude_base_asset: Some(true),
+            min_confirmations: None,
+        };
+        Self::with_filter(filter, request)
+    }
+
+    /// Arguments to query the coins of the `owner` with any of the `asset_ids`.
+    pub fn with_asset_ids(
+        owner: Address,
+        asset_ids: Vec<AssetId>,
+        request: PaginationRequest<String>,
+    ) -> Self {
+        let filter = CoinFilterInput {
+            owner,
+            asset_id: None,
+            asset_ids: Some(asset_ids),
+            exclude_base_asset: None,
+            min_confirmations: None,
+        };
+        Self::with_filter(filter, request)
+    }
+
+    /// Arguments to query the coins of the `owner` with at least `min_confirmations`
+    /// blocks on top of the block that created them.
+    pub fn with_min_confirmations(
+        owner: Address,
+        min_confirmations: U32,
+        request: PaginationRequest<String>,
+    ) -> Self {
+        let filter = CoinFilterInput {
+            owner,
+            asset_id: None,
+            asset_ids: None,
+            exclude_base_asset: None,
+            min_confirmations: Some(min_confirmations),
+        };
+        Self::with_filter(filter, request)
+    }
+
+    fn with_filter(filter: CoinFilterInput, request: PaginationRequest<String>) -> Self {
+        match request.direction {
+            PageDirection::Forward => CoinsConnectionArgs {
+                filter,
+                after: request.cursor,
+                before: None,
+                first: Some(request.results),
+                last: None,
+            },
+            PageDirection::Backward => CoinsConnectionArgs {
+                filter,
+                after: None,
+                before: request.cursor,
+                first: None,
+                last: Some(request.results),
+            },
+        }
+    }
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -135,12 +239,35 @@ pub struct ExcludeInput {
     utxos: Vec<UtxoId>,
     /// Messages to exclude from the result.
     messages: Vec<Nonce>,
+    /// The session on behalf of which the coins are selected.
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    session: Option<String>,
+}
+
+impl ExcludeInput {
+    /// Combines the `excluded_ids` with the `session` on behalf of which
+    /// the coins are selected.
+    pub fn for_session(
+        excluded_ids: Option<(Vec<fuel_tx::UtxoId>, Vec<fuel_types::Nonce>)>,
+        session: Option<&str>,
+    ) -> Option<Self> {
+        let Some(session) = session else {
+            return excluded_ids.map(Into::into);
+        };
+        let mut exclude: Self = excluded_ids.unwrap_or_default().into();
+        exclude.session = Some(session.to_string());
+        Some(exclude)
+    }
 }
 
 impl From<(Vec<UtxoId>, Vec<Nonce>)> for ExcludeInput {
     fn from(value: (Vec<UtxoId>, Vec<Nonce>)) -> Self {
         let (utxos, messages) = value;
-        Self { utxos, messages }
+        Self {
+            utxos,
+            messages,
+            session: None,
+        }
     }
 }
 
@@ -155,6 +282,17 @@ impl From<(Vec<fuel_tx::UtxoId>, Vec<fuel_types::Nonce>)> for ExcludeInput {
     }
 }
 
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinsSelectionInput {
+    /// The `Address` of the assets' coins owner.
+    pub owner: Address,
+    /// The total amount of each asset type to spend.
+    pub query_per_asset: Vec<SpendQueryElementInput>,
+    /// A list of ids to exclude from the selection.
+    pub excluded_ids: Option<ExcludeInput>,
+}
+
 #[derive(cynic::InputObject, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct SpendQueryElementInput {
@@ -164,6 +302,34 @@ pub struct SpendQueryElementInput {
     pub amount: U128,
     /// the maximum number of coins per asset from the owner to return.
     pub max: Option<U16>,
+    /// the preferred number of coins per asset in the selection.
+    pub preferred_count: Option<U16>,
+    /// the maximum amount by which the selection may exceed the target amount.
+    pub max_overshoot: Option<U128>,
+    /// the number of coins of the asset that must stay unspent after the selection.
+    pub reserve_count: Option<U16>,
+    /// whether the selection avoids the largest coin of the asset.
+    pub avoid_largest_coin: Option<bool>,
+    /// whether the selection prefers the coins created at lower heights.
+    pub prefer_deep_coins: Option<bool>,
+    /// the direction in which the coins to spend index is scanned.
+    pub scan_direction: Option<IndexScanDirection>,
+    /// the number of change outputs the selection is biased towards.
+    pub preferred_change_outputs: Option<U16>,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum SelectionStrategy {
+    Default,
+    WithinSize,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum IndexScanDirection {
+    LargestFirst,
+    SmallestFirst,
 }
 
 #[derive(cynic::QueryFragment, Debug, Clone)]
@@ -176,6 +342,141 @@ pub struct MessageCoin {
     pub da_height: U64,
 }
 
+#[derive(cynic::Enum, Clone, Copy, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum MessageCoinOrder {
+    Amount,
+    DaHeight,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct MessageCoinsConnectionArgs {
+    /// The recipient of the messages.
+    pub owner: Address,
+    /// The order of the message coins.
+    pub order: Option<MessageCoinOrder>,
+    /// Skip until cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n message coins in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n message coins in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(Address, Option<MessageCoinOrder>, PaginationRequest<String>)>
+    for MessageCoinsConnectionArgs
+{
+    fn from(r: (Address, Option<MessageCoinOrder>, PaginationRequest<String>)) -> Self {
+        match r.2.direction {
+            PageDirection::Forward => MessageCoinsConnectionArgs {
+                owner: r.0,
+                order: r.1,
+                after: r.2.cursor,
+                before: None,
+                first: Some(r.2.results),
+                last: None,
+            },
+            PageDirection::Backward => MessageCoinsConnectionArgs {
+                owner: r.0,
+                order: r.1,
+                after: None,
+                before: r.2.cursor,
+                first: None,
+                last: Some(r.2.results),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "MessageCoinsConnectionArgs"
+)]
+pub struct MessageCoinsQuery {
+    #[arguments(owner: $owner, order: $order, after: $after, before: $before, first: $first, last: $last)]
+    pub message_coins: MessageCoinConnection,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct MessageCoinsBySenderConnectionArgs {
+    /// The sender of the messages on the DA layer.
+    pub sender: Address,
+    /// Skip until cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n message coins in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n message coins in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(Address, PaginationRequest<String>)> for MessageCoinsBySenderConnectionArgs {
+    fn from(r: (Address, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => MessageCoinsBySenderConnectionArgs {
+                sender: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results),
+                last: None,
+            },
+            PageDirection::Backward => MessageCoinsBySenderConnectionArgs {
+                sender: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "MessageCoinsBySenderConnectionArgs"
+)]
+pub struct MessageCoinsBySenderQuery {
+    #[arguments(sender: $sender, after: $after, before: $before, first: $first, last: $last)]
+    pub message_coins_by_sender: MessageCoinBySenderConnection,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct MessageCoinBySenderConnection {
+    pub edges: Vec<MessageCoinBySenderEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct MessageCoinBySenderEdge {
+    pub cursor: String,
+    pub node: MessageCoin,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct MessageCoinConnection {
+    pub edges: Vec<MessageCoinEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct MessageCoinEdge {
+    pub cursor: String,
+    pub node: MessageCoin,
+}
+
 #[derive(cynic::InlineFragments, Debug, Clone)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub enum CoinType {
@@ -229,6 +530,861 @@ pub struct CoinsToSpendQuery {
     pub coins_to_spend: Vec<Vec<CoinType>>,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CoinsToSpendAtVersionArgs {
+    /// The `Address` of the assets' coins owner.
+    pub owner: Address,
+    /// The total amount of each asset type to spend.
+    pub query_per_asset: Vec<SpendQueryElementInput>,
+    /// A list of ids to exclude from the selection.
+    pub excluded_ids: Option<ExcludeInput>,
+    /// The version of the consensus parameters used by the selection.
+    pub consensus_parameters_version: i32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinsToSpendAtVersionArgs"
+)]
+pub struct CoinsToSpendAtVersionQuery {
+    #[arguments(owner: $owner, queryPerAsset: $query_per_asset, excludedIds: $excluded_ids, consensusParametersVersion: $consensus_parameters_version)]
+    pub coins_to_spend: Vec<Vec<CoinType>>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CoinsToSpendWithTagsArgs {
+    /// The `Address` of the assets' coins owner.
+    pub owner: Address,
+    /// The total amount of each asset type to spend.
+    pub query_per_asset: Vec<SpendQueryElementInput>,
+    /// A list of ids to exclude from the selection.
+    pub excluded_ids: Option<ExcludeInput>,
+    /// Only the coins with any of the tags are selected.
+    pub allowed_tags: Option<Vec<String>>,
+    /// The coins with any of the tags are not selected.
+    pub denied_tags: Option<Vec<String>>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinsToSpendWithTagsArgs"
+)]
+pub struct CoinsToSpendWithTagsQuery {
+    #[arguments(owner: $owner, queryPerAsset: $query_per_asset, excludedIds: $excluded_ids, allowedTags: $allowed_tags, deniedTags: $denied_tags)]
+    pub coins_to_spend: Vec<Vec<CoinType>>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CoinsToSpendPerAssetArgs {
+    /// The coins to select.
+    pub selection: CoinsSelectionInput,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinsToSpendPerAssetArgs"
+)]
+pub struct CoinsToSpendFlatQuery {
+    #[arguments(selection: $selection)]
+    pub coins_to_spend_flat: Vec<AssetCoin>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AssetCoin {
+    pub asset_id: AssetId,
+    pub coin: CoinType,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinsToSpendPerAssetArgs"
+)]
+pub struct CoinsToSpendWithSummaryQuery {
+    #[arguments(selection: $selection)]
+    pub coins_to_spend_with_summary: CoinsToSpendWithSummary,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinsToSpendWithSummary {
+    pub coins: Vec<Vec<CoinType>>,
+    pub summary: Vec<AssetSelectionSummary>,
+    pub gas_cost: SelectionGasCost,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AssetSelectionSummary {
+    pub asset_id: AssetId,
+    pub target: U128,
+    pub total: U128,
+    pub meets_target: bool,
+    pub exact_match: bool,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct SelectionGasCost {
+    pub inputs: U32,
+    pub signature_gas: U64,
+    pub bytes: U64,
+    pub bytes_gas: U64,
+    pub total_gas: U64,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CoinsToSpendAsInputsArgs {
+    /// The coins to select.
+    pub selection: CoinsSelectionInput,
+    /// The index of the witness with the signature of the owner.
+    pub witness_index: Option<U16>,
+    /// Whether to include the bytecode and the predicate data length hint
+    /// of the predicate of the owner.
+    pub include_predicate: Option<bool>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinsToSpendAsInputsArgs"
+)]
+pub struct CoinsToSpendAsInputsQuery {
+    #[arguments(
+        selection: $selection,
+        witnessIndex: $witness_index,
+        includePredicate: $include_predicate
+    )]
+    pub coins_to_spend_as_inputs: Vec<Vec<InputFragment>>,
+}
+
+#[derive(cynic::InlineFragments, Debug, Clone)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum InputFragment {
+    CoinInputFragment(CoinInputFragment),
+    MessageCoinInputFragment(MessageCoinInputFragment),
+    #[cynic(fallback)]
+    Unknown,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinInputFragment {
+    pub utxo_id: UtxoId,
+    pub owner: Address,
+    pub amount: U64,
+    pub asset_id: AssetId,
+    pub tx_pointer: TxPointer,
+    pub witness_index: U16,
+    pub predicate: Option<HexString>,
+    pub predicate_data_length: Option<U64>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct MessageCoinInputFragment {
+    pub sender: Address,
+    pub recipient: Address,
+    pub amount: U64,
+    pub nonce: Nonce,
+    pub witness_index: U16,
+    pub predicate: Option<HexString>,
+    pub predicate_data_length: Option<U64>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CoinsToSpendAsTransactionArgs {
+    /// The coins to select.
+    pub selection: CoinsSelectionInput,
+    /// The `Address` receiving the target amounts of the assets.
+    pub recipient: Address,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinsToSpendAsTransactionArgs"
+)]
+pub struct CoinsToSpendAsTransactionQuery {
+    #[arguments(selection: $selection, recipient: $recipient)]
+    pub coins_to_spend_as_transaction: CoinsToSpendAsTransaction,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinsToSpendAsTransaction {
+    pub coins: Vec<Vec<CoinType>>,
+    pub raw_payload: HexString,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CoinsToSpendBestEffortArgs {
+    /// The coins to select.
+    pub selection: CoinsSelectionInput,
+    /// Return the biggest coins up to `max` if the target can't be reached.
+    pub allow_partial: Option<bool>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinsToSpendBestEffortArgs"
+)]
+pub struct CoinsToSpendBestEffortQuery {
+    #[arguments(selection: $selection, allowPartial: $allow_partial)]
+    pub coins_to_spend_best_effort: Vec<CoinsToSpendAssetResult>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinsToSpendAssetResult {
+    pub asset_id: AssetId,
+    pub success: bool,
+    pub partial: bool,
+    pub coins: Vec<CoinType>,
+    pub error: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinsToSpendPerAssetArgs"
+)]
+pub struct InputCountEstimateQuery {
+    #[arguments(selection: $selection)]
+    pub input_count_estimate: InputCountEstimate,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct InputCountEstimate {
+    pub total_inputs: U32,
+    pub max_inputs: U16,
+    pub within_limit: bool,
+    pub inputs_per_asset: Vec<AssetInputCount>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AssetInputCount {
+    pub asset_id: AssetId,
+    pub success: bool,
+    pub inputs: U32,
+    pub error: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinsToSpendPerAssetArgs"
+)]
+pub struct CoinsToSpendCostQuery {
+    #[arguments(selection: $selection)]
+    pub coins_to_spend_cost: SelectionCost,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct SelectionCost {
+    pub indexed: bool,
+    pub entries_to_scan: U64,
+    pub complete: bool,
+    pub cost_per_asset: Vec<AssetSelectionCost>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AssetSelectionCost {
+    pub asset_id: AssetId,
+    pub coin_count: U64,
+    pub entries_to_scan: U64,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CoinsFeasibilityArgs {
+    /// The `Address` of the coins owner.
+    pub owner: Address,
+    /// The asset id of the coins.
+    pub asset_id: AssetId,
+    /// The target amount to reach.
+    pub amount: U128,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinsFeasibilityArgs"
+)]
+pub struct CoinsFeasibilityQuery {
+    #[arguments(owner: $owner, assetId: $asset_id, amount: $amount)]
+    pub coins_feasibility: CoinsFeasibility,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinsFeasibility {
+    pub feasible: bool,
+    pub coins_needed: U16,
+    pub total_available: U128,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CoinsCoverageArgs {
+    /// The asset id of the coins.
+    pub asset_id: AssetId,
+    /// The target amount the coins should cover.
+    pub target: U128,
+    /// The ids of the coins.
+    pub utxo_ids: Vec<UtxoId>,
+    /// The nonces of the message coins.
+    pub nonces: Vec<Nonce>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinsCoverageArgs"
+)]
+pub struct CoinsCoverageQuery {
+    #[arguments(assetId: $asset_id, target: $target, utxoIds: $utxo_ids, nonces: $nonces)]
+    pub coins_coverage: CoinsCoverage,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinsCoverage {
+    pub asset_id: AssetId,
+    pub target: U128,
+    pub total: U128,
+    pub covers_target: bool,
+    pub coins: Vec<CoinResolution>,
+    pub message_coins: Vec<MessageCoinResolution>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinResolution {
+    pub utxo_id: UtxoId,
+    pub status: CoinResolutionStatus,
+    pub amount: Option<U64>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct MessageCoinResolution {
+    pub nonce: Nonce,
+    pub status: CoinResolutionStatus,
+    pub amount: Option<U64>,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum CoinResolutionStatus {
+    Unspent,
+    OtherAsset,
+    #[cynic(rename = "NOT_A_COIN")]
+    NotACoin,
+    NotFound,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CoinActivityArgs {
+    /// The `Address` of the coins owner.
+    pub owner: Address,
+    /// The height of the first block.
+    pub start_height: U32,
+    /// The height of the last block.
+    pub end_height: U32,
+    /// The pointer of the last transaction of the previous page.
+    pub after: Option<TxPointer>,
+    /// The maximum number of the transactions covered by the page.
+    pub first: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinActivityArgs"
+)]
+pub struct CoinActivityQuery {
+    #[arguments(
+        owner: $owner,
+        startHeight: $start_height,
+        endHeight: $end_height,
+        after: $after,
+        first: $first
+    )]
+    pub coin_activity: CoinActivity,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinActivity {
+    pub created: Vec<CoinMovement>,
+    pub spent: Vec<CoinMovement>,
+    pub last_tx_pointer: Option<TxPointer>,
+    pub has_next_page: bool,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinMovement {
+    pub utxo_id: UtxoId,
+    pub asset_id: AssetId,
+    pub amount: U64,
+    pub block_height: U32,
+    pub transaction_id: TransactionId,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum AssetPriority {
+    QueryOrder,
+    FewestInputs,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CoinsToSpendWithinBudgetArgs {
+    /// The coins to select.
+    pub selection: CoinsSelectionInput,
+    /// The order in which assets are taken into the budget.
+    pub priority: Option<AssetPriority>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinsToSpendWithinBudgetArgs"
+)]
+pub struct CoinsToSpendWithinBudgetQuery {
+    #[arguments(selection: $selection, priority: $priority)]
+    pub coins_to_spend_within_budget: CoinsWithinBudget,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinsWithinBudget {
+    pub max_inputs: U16,
+    pub used_inputs: U16,
+    pub covered: Vec<CoinsToSpendAssetResult>,
+    pub dropped: Vec<AssetInputCount>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CoinsToSpendInGroupsArgs {
+    /// The `Address` of the coins owner.
+    pub owner: Address,
+    /// The asset id of the coins.
+    pub asset_id: AssetId,
+    /// The target amount to reach.
+    pub amount: U128,
+    /// A list of ids to exclude from the selection.
+    pub excluded_ids: Option<ExcludeInput>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinsToSpendInGroupsArgs"
+)]
+pub struct CoinsToSpendInGroupsQuery {
+    #[arguments(owner: $owner, assetId: $asset_id, amount: $amount, excludedIds: $excluded_ids)]
+    pub coins_to_spend_in_groups: CoinGroups,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinGroups {
+    pub max_inputs: U16,
+    pub total: U128,
+    pub groups: Vec<CoinGroup>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinGroup {
+    pub coins: Vec<CoinType>,
+    pub total: U128,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CoinSelectionCandidatesArgs {
+    /// The `Address` of the coins owner.
+    pub owner: Address,
+    /// The asset id of the coins.
+    pub asset_id: AssetId,
+    /// The target amount to reach.
+    pub amount: U128,
+    /// The maximum number of coins in a candidate.
+    pub max: Option<U16>,
+    /// The number of the best candidates to return.
+    pub count: U16,
+    /// A list of ids to exclude from the selection.
+    pub excluded_ids: Option<ExcludeInput>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinSelectionCandidatesArgs"
+)]
+pub struct CoinSelectionCandidatesQuery {
+    #[arguments(owner: $owner, assetId: $asset_id, amount: $amount, max: $max, count: $count, excludedIds: $excluded_ids)]
+    pub coin_selection_candidates: Vec<CoinSelectionCandidate>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinSelectionCandidate {
+    pub coins: Vec<CoinType>,
+    pub inputs: U16,
+    pub total: U128,
+    pub overshoot: U128,
+    pub gas: U64,
+    pub cost: U128,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CoinsToSpendWithinSizeArgs {
+    /// The `Address` of the assets' coins owner.
+    pub owner: Address,
+    /// The total amount of each asset type to spend.
+    pub query_per_asset: Vec<SpendQueryElementInput>,
+    /// A list of ids to exclude from the selection.
+    pub excluded_ids: Option<ExcludeInput>,
+    /// The strategy of the selection.
+    pub strategy: SelectionStrategy,
+    /// The maximum size of the inputs in bytes.
+    pub max_size: Option<U64>,
+    /// The size of the predicate with its data that owns the coins.
+    pub predicate_size: Option<U64>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinsToSpendWithinSizeArgs"
+)]
+pub struct CoinsToSpendWithinSizeQuery {
+    #[arguments(owner: $owner, queryPerAsset: $query_per_asset, excludedIds: $excluded_ids, strategy: $strategy, maxSize: $max_size, predicateSize: $predicate_size)]
+    pub coins_to_spend: Vec<Vec<CoinType>>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CoinsToSpendWithFeeArgs {
+    /// The coins of the non-base assets to select.
+    pub selection: CoinsSelectionInput,
+    /// The amount of the base asset to spend besides the fee.
+    pub base_amount: Option<U128>,
+    /// The fee of the transaction without the inputs.
+    pub base_fee: U64,
+    /// The increase of the fee caused by each input.
+    pub fee_per_input: U64,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinsToSpendWithFeeArgs"
+)]
+pub struct CoinsToSpendWithFeeQuery {
+    #[arguments(selection: $selection, baseAmount: $base_amount, baseFee: $base_fee, feePerInput: $fee_per_input)]
+    pub coins_to_spend_with_fee: CoinsToSpendWithFee,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinsToSpendWithFee {
+    pub coins: Vec<Vec<CoinType>>,
+    pub base_coins: Vec<CoinType>,
+    pub fee: U64,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AssetAmountInput {
+    pub asset_id: AssetId,
+    pub amount: U128,
+}
+
+impl From<(fuel_tx::AssetId, u128)> for AssetAmountInput {
+    fn from((asset_id, amount): (fuel_tx::AssetId, u128)) -> Self {
+        Self {
+            asset_id: asset_id.into(),
+            amount: amount.into(),
+        }
+    }
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ChangeOutputsArgs {
+    /// The asset id and the amount of each selected coin.
+    pub selected: Vec<AssetAmountInput>,
+    /// The amounts of the assets spent by the transaction.
+    pub spend: Vec<AssetAmountInput>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ChangeOutputsArgs"
+)]
+pub struct ChangeOutputsQuery {
+    #[arguments(selected: $selected, spend: $spend)]
+    pub change_outputs: Vec<super::tx::AssetAmount>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct DustCoinsArgs {
+    /// The owner of the coins.
+    pub owner: Address,
+    /// The coins with an amount below the threshold are dust.
+    pub threshold: U64,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "DustCoinsArgs"
+)]
+pub struct DustCoinsQuery {
+    #[arguments(owner: $owner, threshold: $threshold)]
+    pub dust_coins: DustCoins,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DustCoins {
+    pub assets: Vec<AssetDustCoinsSummary>,
+    pub complete: bool,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AssetDustCoinsSummary {
+    pub asset_id: AssetId,
+    pub count: U32,
+    pub amount: U128,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct LockedCoinsArgs {
+    /// The owner of the coins.
+    pub owner: Address,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "LockedCoinsArgs"
+)]
+pub struct LockedCoinsQuery {
+    #[arguments(owner: $owner)]
+    pub locked_coins: LockedCoins,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct LockedCoins {
+    pub owner_locked: bool,
+    pub coins: Vec<LockedCoin>,
+    pub complete: bool,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct LockedCoin {
+    pub coin: CoinType,
+    pub reason: CoinLockReason,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum CoinLockReason {
+    Blacklisted,
+    Reserved,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CoinsEligibilityArgs {
+    /// The owner of the coins.
+    pub owner: Address,
+    /// The asset id of the coins.
+    pub asset_id: AssetId,
+    /// The session on behalf of which the coins are selected.
+    pub session: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinsEligibilityArgs"
+)]
+pub struct CoinsEligibilityQuery {
+    #[arguments(owner: $owner, assetId: $asset_id, session: $session)]
+    pub coins_eligibility: CoinsEligibility,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinsEligibility {
+    pub coins: Vec<CoinEligibility>,
+    pub complete: bool,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinEligibility {
+    pub coin: CoinType,
+    pub eligible: bool,
+    pub reason: Option<CoinIneligibilityReason>,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum CoinIneligibilityReason {
+    OwnerBlacklisted,
+    Blacklisted,
+    Reserved,
+    BelowMinAmount,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct UtxoCountQuery {
+    pub utxo_count: UtxoCount,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct UtxoCount {
+    pub count: U64,
+    pub block_height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct CoinSetRootQuery {
+    pub coin_set_root: CoinSetRoot,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinSetRoot {
+    pub root: Bytes32,
+    pub block_height: U32,
+    pub count: U64,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ReserveCoinsArgs {
+    pub session: String,
+    pub utxos: Vec<UtxoId>,
+    pub messages: Vec<Nonce>,
+    pub ttl_seconds: U32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "ReserveCoinsArgs"
+)]
+pub struct ReserveCoinsMutation {
+    #[arguments(session: $session, utxos: $utxos, messages: $messages, ttlSeconds: $ttl_seconds)]
+    pub reserve_coins: bool,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ReleaseCoinsArgs {
+    pub session: String,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "ReleaseCoinsArgs"
+)]
+pub struct ReleaseCoinsMutation {
+    #[arguments(session: $session)]
+    pub release_coins: bool,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct RebuildCoinsToSpendIndexArgs {
+    pub owner: Address,
+    pub asset_id: Option<AssetId>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "RebuildCoinsToSpendIndexArgs"
+)]
+pub struct RebuildCoinsToSpendIndexMutation {
+    #[arguments(owner: $owner, assetId: $asset_id)]
+    pub rebuild_coins_to_spend_index: U64,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CoinsToSpendIndexRebuildArgs {
+    pub id: U64,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinsToSpendIndexRebuildArgs"
+)]
+pub struct CoinsToSpendIndexRebuildQuery {
+    #[arguments(id: $id)]
+    pub coins_to_spend_index_rebuild: Option<CoinsToSpendIndexRebuild>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinsToSpendIndexRebuild {
+    pub state: CoinsToSpendIndexRebuildState,
+    pub entries: Option<U64>,
+    pub error: Option<String>,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum CoinsToSpendIndexRebuildState {
+    Pending,
+    Completed,
+    Failed,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -249,6 +1405,9 @@ mod tests {
             filter: CoinFilterInput {
                 owner: Address::default(),
                 asset_id: Some(AssetId::default()),
+                asset_ids: None,
+                exclude_base_asset: None,
+                min_confirmations: None,
             },
             after: None,
             before: None,
@@ -1,6 +1,8 @@
 use crate::client::schema::{
     schema,
+    tx::OpaqueTransaction,
     U32,
+    U64,
 };
 
 use super::HexString;
@@ -28,6 +30,79 @@ pub struct DaCompressedBlock {
     pub bytes: HexString,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct LatestDaCompressedBlockQuery {
+    pub latest_da_compressed_block: Option<LatestDaCompressedBlock>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct LatestDaCompressedBlock {
+    pub height: U32,
+    pub block: DaCompressedBlock,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Subscription")]
+pub struct NewDaCompressedBlocksSubscription {
+    pub new_da_compressed_blocks: LatestDaCompressedBlock,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct DecompressDaBlockArgs {
+    pub bytes: HexString,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "DecompressDaBlockArgs"
+)]
+pub struct DecompressDaBlockQuery {
+    #[arguments(bytes: $bytes)]
+    pub decompress_da_block: Vec<OpaqueTransaction>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct DaCompressionConfigQuery {
+    pub da_compression_config: DaCompressionInfo,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DaCompressionInfo {
+    pub enabled: bool,
+    pub temporal_registry_retention_seconds: Option<U64>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct DaCompressionRatiosArgs {
+    pub last: i32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "DaCompressionRatiosArgs"
+)]
+pub struct DaCompressionRatiosQuery {
+    #[arguments(last: $last)]
+    pub da_compression_ratios: Vec<DaCompressionRatio>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DaCompressionRatio {
+    pub height: U32,
+    pub compressed_size: U64,
+    pub uncompressed_size: U64,
+    pub ratio: f64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -21,6 +21,8 @@ pub struct QueryLatestGasPrice {
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct EstimateGasPrice {
     pub gas_price: U64,
+    pub execution_gas_price: Option<U64>,
+    pub da_gas_price: Option<U64>,
 }
 
 #[derive(cynic::QueryVariables, Debug)]
@@ -47,6 +49,45 @@ pub struct QueryEstimateGasPrice {
     pub estimate_gas_price: EstimateGasPrice,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct NextBlockGasPrice {
+    pub gas_price: U64,
+    pub tip_per_gas: U64,
+    pub pool_gas: U64,
+    pub block_gas_limit: U64,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QueryEstimateNextBlockGasPrice {
+    pub estimate_next_block_gas_price: NextBlockGasPrice,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct InputFeeEstimate {
+    pub gas_price: U64,
+    pub gas: U64,
+    pub fee: U64,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct EstimateInputFeeArgs {
+    pub gas_price: Option<U64>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "EstimateInputFeeArgs"
+)]
+pub struct QueryEstimateInputFee {
+    #[arguments(gasPrice: $gas_price)]
+    pub estimate_input_fee: InputFeeEstimate,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
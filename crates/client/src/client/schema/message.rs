@@ -1,5 +1,8 @@
 use super::{
-    block::Header,
+    block::{
+        BlockHeadersArgsFields,
+        Header,
+    },
     BlockId,
     Bytes32,
     HexString,
@@ -15,6 +18,7 @@ use crate::client::{
         schema,
         Address,
         Nonce,
+        U128,
         U32,
         U64,
     },
@@ -47,6 +51,24 @@ pub struct MessageQuery {
     pub message: Option<Message>,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "NonceArgs"
+)]
+pub struct MessageSpendableHeightQuery {
+    #[arguments(nonce: $nonce)]
+    pub message_spendable_height: Option<MessageSpendableHeight>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct MessageSpendableHeight {
+    pub da_height: U64,
+    pub block_height: Option<U32>,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct MessageStatus {
@@ -112,11 +134,46 @@ pub struct MessageProofQuery {
         transactionId: $transaction_id,
         nonce: $nonce,
         commitBlockId: $commit_block_id,
-        commitBlockHeight: $commit_block_height
+        commitBlockHeight: $commit_block_height,
+        confirmations: $confirmations,
+        headersOnly: $headers_only,
+        daHeightAnchor: $da_height_anchor
     )]
     pub message_proof: MessageProof,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "MessageProofByNonceArgs"
+)]
+pub struct MessageProofByNonceQuery {
+    #[arguments(
+        nonce: $nonce,
+        commitBlockId: $commit_block_id,
+        commitBlockHeight: $commit_block_height,
+        confirmations: $confirmations
+    )]
+    pub message_proof_by_nonce: MessageProof,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "MessageProofsByNoncesArgs"
+)]
+pub struct MessageProofsByNoncesQuery {
+    #[arguments(
+        nonces: $nonces,
+        commitBlockId: $commit_block_id,
+        commitBlockHeight: $commit_block_height,
+        confirmations: $confirmations
+    )]
+    pub message_proofs_by_nonces: Vec<MessageProof>,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct MerkleProof {
@@ -167,6 +224,106 @@ pub struct MessageProofArgs {
     /// The block height of the commitment block.
     /// If it is `None`, the `commit_block_id` should be `Some`.
     pub commit_block_height: Option<U32>,
+    /// The number of blocks to wait for on top of the commitment block
+    /// before generating the proof.
+    pub confirmations: Option<U32>,
+    /// Return only the block headers and the block proof,
+    /// leaving the `message_proof` empty.
+    pub headers_only: Option<bool>,
+    /// The DA height the commitment block must be anchored at or below.
+    /// If neither `commit_block_id` nor `commit_block_height` is set,
+    /// the latest block at or below the anchor is used.
+    pub da_height_anchor: Option<U64>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct MessageProofByNonceArgs {
+    /// The `Nonce` identifier of the output message that requires a proof.
+    pub nonce: Nonce,
+
+    /// The query supports either `commit_block_id`, or `commit_block_height` set on, not both.
+
+    /// The block id of the commitment block.
+    /// If it is `None`, the `commit_block_height` should be `Some`.
+    pub commit_block_id: Option<BlockId>,
+    /// The block height of the commitment block.
+    /// If it is `None`, the `commit_block_id` should be `Some`.
+    pub commit_block_height: Option<U32>,
+    /// The number of blocks to wait for on top of the commitment block
+    /// before generating the proof.
+    pub confirmations: Option<U32>,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum MessageInclusionState {
+    Included,
+    Pending,
+    Unknown,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "NonceArgs"
+)]
+pub struct MessageInclusionStatusQuery {
+    #[arguments(nonce: $nonce)]
+    pub message_inclusion_status: MessageInclusionState,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct RelayerBacklog {
+    pub included_da_height: U64,
+    pub synced_da_height: U64,
+    pub synced_to_tip: bool,
+    pub pending_messages: U64,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct RelayerBacklogQuery {
+    pub relayer_backlog: Option<RelayerBacklog>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BridgedValue {
+    pub inbound_messages: Option<U64>,
+    pub inbound_amount: Option<U128>,
+    pub outbound_messages: U64,
+    pub outbound_amount: U128,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "BlockHeadersArgs"
+)]
+pub struct BridgedValueQuery {
+    #[arguments(start: $start, end: $end)]
+    pub bridged_value: BridgedValue,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct MessageProofsByNoncesArgs {
+    /// The `Nonce` identifiers of the output messages that require proofs.
+    pub nonces: Vec<Nonce>,
+
+    /// The query supports either `commit_block_id`, or `commit_block_height` set on, not both.
+
+    /// The block id of the commitment block.
+    /// If it is `None`, the `commit_block_height` should be `Some`.
+    pub commit_block_id: Option<BlockId>,
+    /// The block height of the commitment block.
+    /// If it is `None`, the `commit_block_id` should be `Some`.
+    pub commit_block_height: Option<U32>,
+    /// The number of blocks to wait for on top of the commitment block
+    /// before generating the proofs.
+    pub confirmations: Option<U32>,
 }
 
 #[derive(cynic::QueryFragment, Clone, Debug)]
@@ -52,12 +52,43 @@ pub struct QueryPeersInfo {
     pub node_info: PeersInfo,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct PeersArgs {
+    pub supported_protocol: Option<String>,
+    pub unsupported_protocol: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "NodeInfo",
+    variables = "PeersArgs"
+)]
+pub struct FilteredPeersInfo {
+    #[arguments(
+        supportedProtocol: $supported_protocol,
+        unsupportedProtocol: $unsupported_protocol
+    )]
+    pub peers: Vec<PeerInfo>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "PeersArgs"
+)]
+pub struct QueryFilteredPeersInfo {
+    pub node_info: FilteredPeersInfo,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct PeerInfo {
     pub id: String,
     pub addresses: Vec<String>,
     pub client_version: Option<String>,
+    pub protocols: Vec<String>,
     pub block_height: Option<U32>,
     pub last_heartbeat_ms: U64,
     pub app_score: f64,
@@ -70,6 +101,7 @@ impl From<PeerInfo> for fuel_core_types::services::p2p::PeerInfo {
             id: PeerId::from_str(info.id.as_str()).unwrap_or_default(),
             peer_addresses: info.addresses.into_iter().collect(),
             client_version: info.client_version,
+            protocols: info.protocols.into_iter().collect(),
             heartbeat_data: HeartbeatData {
                 block_height: info.block_height.map(|h| h.0.into()),
                 last_heartbeat: UNIX_EPOCH
@@ -95,6 +127,64 @@ pub struct IndexationFlags {
     pub balances: bool,
     pub coins_to_spend: bool,
     pub asset_metadata: bool,
+    pub utxo_count: bool,
+    pub known_assets: bool,
+    pub sender_messages: bool,
+    pub transactions_by_spender: bool,
+    pub outbound_message_transactions: bool,
+    pub sorted_message_coins: bool,
+    pub predicates: bool,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum IndexerKind {
+    Balances,
+    CoinsToSpend,
+    AssetMetadata,
+    UtxoCount,
+    KnownAssets,
+    SenderMessages,
+    TransactionsBySpender,
+    OutboundMessageTransactions,
+    SortedMessageCoins,
+    Predicates,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct IndexerStatus {
+    pub kind: IndexerKind,
+    pub enabled: bool,
+    pub processed_height: Option<U32>,
+    pub lag: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QueryIndexers {
+    pub indexers: Vec<IndexerStatus>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct HistoricalState {
+    pub retains_history: bool,
+    pub earliest_height: U32,
+    pub latest_height: U32,
+    pub historical_execution: bool,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QueryHistoricalState {
+    pub historical_state: HistoricalState,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QueryReadViewHeight {
+    pub read_view_height: U32,
 }
 
 #[cfg(test)]
@@ -114,4 +204,14 @@ mod tests {
         let operation = QueryPeersInfo::build(());
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn filtered_peers_info_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = QueryFilteredPeersInfo::build(PeersArgs {
+            supported_protocol: Some("/fuel/req_res/0.0.2".to_string()),
+            unsupported_protocol: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
 }
@@ -1,10 +1,13 @@
 use crate::client::schema::{
+    coins::Coin,
     contract::ContractBalance,
     schema,
     AssetId,
     Bytes32,
     ContractId,
     HexString,
+    TransactionId,
+    UtxoId,
     U32,
 };
 use fuel_core_types::fuel_tx;
@@ -91,3 +94,67 @@ pub struct ContractBalanceValuesArgs {
     pub block_height: Option<U32>,
     pub assets: Vec<AssetId>,
 }
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinAtHeightArgs"
+)]
+pub struct CoinAtHeight {
+    #[arguments(utxoId: $utxo_id, blockHeight: $block_height)]
+    pub coin_at_height: HistoricalCoin,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CoinAtHeightArgs {
+    pub utxo_id: UtxoId,
+    pub block_height: U32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct HistoricalCoin {
+    pub state: HistoricalCoinState,
+    pub coin: Option<Coin>,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum HistoricalCoinState {
+    Unspent,
+    Spent,
+    NotCreated,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinSpenderArgs"
+)]
+pub struct CoinSpenderQuery {
+    #[arguments(utxoId: $utxo_id)]
+    pub coin_spender: CoinSpender,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CoinSpenderArgs {
+    pub utxo_id: UtxoId,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinSpender {
+    pub state: CoinSpenderState,
+    pub transaction_id: Option<TransactionId>,
+    pub block_height: Option<U32>,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum CoinSpenderState {
+    Unspent,
+    Spent,
+    NotFound,
+}
@@ -15,6 +15,7 @@ use crate::client::{
         Tai64Timestamp,
         TransactionId,
         TxPointer,
+        U128,
         U16,
         U32,
         U64,
@@ -73,6 +74,50 @@ pub struct TransactionStatusQuery {
     pub transaction: Option<OpaqueTransactionStatus>,
 }
 
+/// Retrieves the status of the transaction together with the receipts
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxIdArgs"
+)]
+pub struct TransactionStatusWithReceiptsQuery {
+    #[arguments(id: $id)]
+    pub transaction_status_with_receipts: Option<TransactionStatusWithReceipts>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransactionStatusWithReceipts {
+    pub status: TransactionStatus,
+    pub receipts: Vec<Receipt>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TxIdsArgs {
+    pub ids: Vec<TransactionId>,
+}
+
+/// Retrieves the statuses of several transactions in one request
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxIdsArgs"
+)]
+pub struct TransactionStatusesQuery {
+    #[arguments(ids: $ids)]
+    pub transaction_statuses: Vec<TransactionStatusLookup>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransactionStatusLookup {
+    pub id: TransactionId,
+    pub found: bool,
+    pub status: Option<TransactionStatus>,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -114,6 +159,31 @@ pub struct TransactionEdge {
     pub node: OpaqueTransactionWithStatus,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransactionIdConnection {
+    pub edges: Vec<TransactionIdEdge>,
+    pub page_info: PageInfo,
+}
+
+impl From<TransactionIdConnection> for PaginatedResult<fuel_tx::TxId, String> {
+    fn from(conn: TransactionIdConnection) -> Self {
+        PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn.edges.into_iter().map(|e| e.node.into()).collect(),
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransactionIdEdge {
+    pub cursor: String,
+    pub node: TransactionId,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(graphql_type = "Transaction", schema_path = "./assets/schema.sdl")]
 pub struct OpaqueTransaction {
@@ -464,6 +534,47 @@ pub struct TransactionsByOwnerQuery {
     pub transactions_by_owner: TransactionConnection,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TransactionsByOwnerConnectionArgs"
+)]
+pub struct RecentTransactionIdsByOwnerQuery {
+    #[arguments(owner: $owner, after: $after, before: $before, first: $first, last: $last)]
+    pub recent_transaction_ids_by_owner: TransactionIdConnection,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TransactionsBySpenderConnectionArgs {
+    /// Select transactions spending the coins of the `owner`
+    pub owner: Address,
+    /// The height of the first block
+    pub start_height: Option<U32>,
+    /// The height of the last block
+    pub end_height: Option<U32>,
+    /// Skip until cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n transactions in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n transactions in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TransactionsBySpenderConnectionArgs"
+)]
+pub struct TransactionsBySpenderQuery {
+    #[arguments(owner: $owner, startHeight: $start_height, endHeight: $end_height, after: $after, before: $before, first: $first, last: $last)]
+    pub transactions_by_spender: TransactionConnection,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -489,6 +600,14 @@ pub struct TxWithEstimatedPredicatesArg {
     pub estimate_predicates: Option<bool>,
 }
 
+#[derive(cynic::QueryVariables)]
+pub struct SubmitWithIdempotencyKeyArg {
+    pub tx: HexString,
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub estimate_predicates: Option<bool>,
+    pub idempotency_key: String,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -598,6 +717,153 @@ pub struct DryRun {
     pub dry_run: Vec<DryRunTransactionExecutionStatus>,
 }
 
+#[derive(cynic::QueryVariables)]
+pub struct EstimateBatchFeesArg {
+    pub txs: Vec<HexString>,
+    pub utxo_validation: Option<bool>,
+    pub gas_price: Option<U64>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "EstimateBatchFeesArg"
+)]
+pub struct EstimateBatchFees {
+    #[arguments(txs: $txs, utxoValidation: $utxo_validation, gasPrice: $gas_price)]
+    pub estimate_batch_fees: BatchFeeEstimate,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BatchFeeEstimate {
+    pub total_fee: U64,
+    pub total_gas: U64,
+    pub transactions: Vec<TransactionFeeEstimate>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransactionFeeEstimate {
+    pub id: TransactionId,
+    pub success: bool,
+    pub total_gas: U64,
+    pub total_fee: U64,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxArg"
+)]
+pub struct TransactionOutputsBreakdownQuery {
+    #[arguments(tx: $tx)]
+    pub transaction_outputs_breakdown: TransactionOutputsBreakdown,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransactionOutputsBreakdown {
+    pub outputs: Vec<OutputBreakdown>,
+    pub transferred_amounts: Vec<AssetAmount>,
+    pub fixed_coins: Vec<FixedCoin>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct FixedCoin {
+    pub index: U16,
+    pub to: Address,
+    pub asset_id: AssetId,
+    pub amount: U64,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct OutputBreakdown {
+    pub index: U16,
+    pub kind: OutputKind,
+    pub dynamic: bool,
+    pub to: Option<Address>,
+    pub asset_id: Option<AssetId>,
+    pub amount: Option<U64>,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum OutputKind {
+    Transfer,
+    SelfTransfer,
+    Change,
+    Variable,
+    Contract,
+    ContractCreated,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AssetAmount {
+    pub asset_id: AssetId,
+    pub amount: U128,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxArg"
+)]
+pub struct VerifyPredicatesQuery {
+    #[arguments(tx: $tx)]
+    pub verify_predicates: PredicatesVerification,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PredicatesVerification {
+    pub success: bool,
+    pub error: Option<String>,
+    pub inputs: Vec<PredicateInputVerification>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PredicateInputVerification {
+    pub index: U16,
+    pub owner: Address,
+    pub predicate_gas_used: U64,
+    pub status: PredicateVerificationStatus,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum PredicateVerificationStatus {
+    Passed,
+    Failed,
+    Unknown,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxArg"
+)]
+pub struct BlockGasLimitCheckQuery {
+    #[arguments(tx: $tx)]
+    pub block_gas_limit_check: BlockGasLimitCheck,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BlockGasLimitCheck {
+    pub exceeds_block_gas_limit: bool,
+    pub estimated_gas: U64,
+    pub block_gas_limit: U64,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -609,6 +875,17 @@ pub struct Submit {
     pub submit: TransactionIdFragment,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "SubmitWithIdempotencyKeyArg"
+)]
+pub struct SubmitWithIdempotencyKey {
+    #[arguments(tx: $tx, estimatePredicates: $estimate_predicates, idempotencyKey: $idempotency_key)]
+    pub submit: TransactionIdFragment,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -704,6 +981,20 @@ pub mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn recent_transaction_ids_by_owner_gql_output() {
+        use cynic::QueryBuilder;
+        let operation =
+            RecentTransactionIdsByOwnerQuery::build(TransactionsByOwnerConnectionArgs {
+                owner: Default::default(),
+                after: None,
+                before: None,
+                first: None,
+                last: None,
+            });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn dry_run_tx_gql_output() {
         use cynic::MutationBuilder;
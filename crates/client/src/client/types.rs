@@ -9,22 +9,35 @@ pub mod upgrades;
 
 pub mod assemble_tx;
 pub mod asset;
+pub mod fee_estimate;
 pub mod gas_price;
 pub mod merkle_proof;
 pub mod message;
 pub mod node_info;
+pub mod output_breakdown;
+pub mod predicate_verification;
 
-pub use balance::Balance;
+pub use balance::{
+    Balance,
+    OwnerPortfolio,
+    ProjectedBalance,
+};
 pub use blob::Blob;
 pub use block::{
     Block,
     Consensus,
 };
-pub use chain_info::ChainInfo;
+pub use chain_info::{
+    ChainInfo,
+    NetworkInfo,
+};
 pub use coins::{
     Coin,
+    CoinInputFragment,
     CoinType,
+    InputFragment,
     MessageCoin,
+    MessageCoinInputFragment,
 };
 pub use contract::{
     Contract,
@@ -36,8 +49,12 @@ pub use gas_costs::{
 };
 pub use merkle_proof::MerkleProof;
 pub use message::{
+    BridgedValue,
     Message,
+    MessageInclusionState,
     MessageProof,
+    MessageSpendableHeight,
+    RelayerBacklog,
 };
 pub use node_info::NodeInfo;
 
@@ -1,7 +1,13 @@
-use crate::client::schema;
+use crate::client::{
+    schema,
+    PaginatedResult,
+};
 use fuel_core_types::{
     fuel_tx::Bytes32,
-    fuel_types::ContractId,
+    fuel_types::{
+        AssetId,
+        ContractId,
+    },
 };
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -11,6 +17,13 @@ pub struct AssetDetail {
     pub total_supply: u128,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct KnownAsset {
+    pub asset_id: AssetId,
+    /// The total supply of the asset minted by a contract, if known.
+    pub total_supply: Option<u128>,
+}
+
 // GraphQL Translation
 
 impl From<schema::assets::AssetInfoDetails> for AssetDetail {
@@ -22,3 +35,23 @@ impl From<schema::assets::AssetInfoDetails> for AssetDetail {
         }
     }
 }
+
+impl From<schema::assets::KnownAsset> for KnownAsset {
+    fn from(value: schema::assets::KnownAsset) -> Self {
+        KnownAsset {
+            asset_id: value.asset_id.into(),
+            total_supply: value.total_supply.map(Into::into),
+        }
+    }
+}
+
+impl From<schema::assets::KnownAssetConnection> for PaginatedResult<KnownAsset, String> {
+    fn from(conn: schema::assets::KnownAssetConnection) -> Self {
+        PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn.edges.into_iter().map(|e| e.node.into()).collect(),
+        }
+    }
+}
@@ -14,6 +14,28 @@ pub struct Balance {
     pub asset_id: AssetId,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ProjectedBalance {
+    pub owner: Address,
+    pub asset_id: AssetId,
+    pub current_balance: u128,
+    /// The planned outflow, including the fee for the base asset.
+    pub outflow: u128,
+    pub remaining_balance: u128,
+    /// Whether the current balance covers the outflow.
+    pub sufficient: bool,
+}
+
+/// The summary of the balances of one owner.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OwnerPortfolio {
+    pub owner: Address,
+    /// The `(asset_id, balance, coin_count)` per asset, ordered by the asset id.
+    pub assets: Vec<(AssetId, u128, u64)>,
+    /// Whether all assets and coins of the owner are counted.
+    pub complete: bool,
+}
+
 // GraphQL Translation
 
 impl From<schema::balance::Balance> for Balance {
@@ -39,3 +61,36 @@ impl From<schema::balance::BalanceConnection> for PaginatedResult<Balance, Strin
         }
     }
 }
+
+impl From<schema::balance::ProjectedBalance> for ProjectedBalance {
+    fn from(value: schema::balance::ProjectedBalance) -> Self {
+        ProjectedBalance {
+            owner: value.owner.into(),
+            asset_id: value.asset_id.into(),
+            current_balance: value.current_balance.into(),
+            outflow: value.outflow.into(),
+            remaining_balance: value.remaining_balance.into(),
+            sufficient: value.sufficient,
+        }
+    }
+}
+
+impl From<schema::balance::OwnerPortfolio> for OwnerPortfolio {
+    fn from(value: schema::balance::OwnerPortfolio) -> Self {
+        OwnerPortfolio {
+            owner: value.owner.into(),
+            assets: value
+                .assets
+                .into_iter()
+                .map(|asset| {
+                    (
+                        asset.asset_id.into(),
+                        asset.amount.into(),
+                        asset.coin_count.into(),
+                    )
+                })
+                .collect(),
+            complete: value.complete,
+        }
+    }
+}
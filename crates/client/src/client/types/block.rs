@@ -73,6 +73,53 @@ pub struct PoAConsensus {
     pub signature: Signature,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockGasUsage {
+    pub height: u32,
+    pub gas_used: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockGasUtilization {
+    pub block_gas_limit: u64,
+    /// Gas used by the latest blocks, starting from the most recent one.
+    pub blocks: Vec<BlockGasUsage>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockSummary {
+    pub height: u32,
+    pub time: Tai64,
+    /// The number of transactions, including the mint transaction.
+    pub transaction_count: u16,
+    pub gas_used: u64,
+    pub fees: u64,
+}
+
+/// The height and the id of the latest block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChainTip {
+    pub height: u32,
+    pub id: BlockId,
+}
+
+/// The message outbox root from the header of the block at the `height`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockMessageOutboxRoot {
+    pub height: u32,
+    pub message_outbox_root: MerkleRoot,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DaCompressionRatio {
+    pub height: u32,
+    pub compressed_size: u64,
+    /// The size of the serialized block with its transactions.
+    pub uncompressed_size: u64,
+    /// The uncompressed size divided by the compressed size.
+    pub ratio: f64,
+}
+
 // GraphQL Translation
 
 impl TryFrom<schema::block::Header> for Header {
@@ -158,6 +205,65 @@ impl From<schema::block::PoAConsensus> for PoAConsensus {
     }
 }
 
+impl From<schema::block::BlockGasUsage> for BlockGasUsage {
+    fn from(value: schema::block::BlockGasUsage) -> Self {
+        Self {
+            height: value.height.into(),
+            gas_used: value.gas_used.into(),
+        }
+    }
+}
+
+impl From<schema::block::BlockGasUtilization> for BlockGasUtilization {
+    fn from(value: schema::block::BlockGasUtilization) -> Self {
+        Self {
+            block_gas_limit: value.block_gas_limit.into(),
+            blocks: value.blocks.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<schema::block::BlockSummary> for BlockSummary {
+    fn from(value: schema::block::BlockSummary) -> Self {
+        Self {
+            height: value.height.into(),
+            time: value.time.0,
+            transaction_count: value.transaction_count.into(),
+            gas_used: value.gas_used.into(),
+            fees: value.fees.into(),
+        }
+    }
+}
+
+impl From<schema::block::ChainTip> for ChainTip {
+    fn from(value: schema::block::ChainTip) -> Self {
+        Self {
+            height: value.height.into(),
+            id: value.id.into(),
+        }
+    }
+}
+
+impl From<schema::block::BlockMessageOutboxRoot> for BlockMessageOutboxRoot {
+    fn from(value: schema::block::BlockMessageOutboxRoot) -> Self {
+        Self {
+            height: value.height.into(),
+            message_outbox_root: value.message_outbox_root.into(),
+        }
+    }
+}
+
+impl From<schema::da_compressed::DaCompressionRatio> for DaCompressionRatio {
+    fn from(value: schema::da_compressed::DaCompressionRatio) -> Self {
+        Self {
+            height: value.height.into(),
+            compressed_size: value.compressed_size.into(),
+            uncompressed_size: value.uncompressed_size.into(),
+            ratio: value.ratio,
+        }
+    }
+}
+
 impl TryFrom<schema::block::Block> for Block {
     type Error = ConversionError;
 
@@ -6,6 +6,7 @@ use crate::client::{
 use fuel_core_types::{
     self,
     fuel_tx::ConsensusParameters,
+    fuel_types::ChainId,
 };
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -16,6 +17,12 @@ pub struct ChainInfo {
     pub consensus_parameters: ConsensusParameters,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NetworkInfo {
+    pub chain_id: ChainId,
+    pub name: String,
+}
+
 // GraphQL Translation
 
 impl TryFrom<schema::chain::ChainInfo> for ChainInfo {
@@ -30,3 +37,12 @@ impl TryFrom<schema::chain::ChainInfo> for ChainInfo {
         })
     }
 }
+
+impl From<schema::chain::NetworkInfo> for NetworkInfo {
+    fn from(value: schema::chain::NetworkInfo) -> Self {
+        Self {
+            chain_id: ChainId::new(value.chain_id.into()),
+            name: value.name,
+        }
+    }
+}
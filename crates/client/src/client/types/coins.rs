@@ -1,13 +1,29 @@
 use crate::client::{
-    schema,
+    schema::{
+        self,
+        ConversionError,
+    },
     types::primitives::{
         Address,
         AssetId,
+        Bytes32,
         Nonce,
+        TransactionId,
         UtxoId,
     },
     PaginatedResult,
 };
+use fuel_core_types::{
+    fuel_tx::{
+        Input,
+        Transaction,
+        TxPointer,
+    },
+    fuel_types::{
+        canonical::Deserialize,
+        BlockHeight,
+    },
+};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum CoinType {
@@ -26,6 +42,503 @@ impl CoinType {
     }
 }
 
+/// The selected coin with the fields required to construct the transaction input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputFragment {
+    Coin(CoinInputFragment),
+    MessageCoin(MessageCoinInputFragment),
+    Unknown,
+}
+
+impl InputFragment {
+    /// Returns the signed input spending the coin.
+    pub fn into_signed_input(self) -> Option<Input> {
+        match self {
+            InputFragment::Coin(coin) => Some(coin.into_signed_input()),
+            InputFragment::MessageCoin(coin) => Some(coin.into_signed_input()),
+            InputFragment::Unknown => None,
+        }
+    }
+
+    /// Returns the predicate input spending the coin with the `predicate_data`,
+    /// if the bytecode of the predicate was included in the selection output.
+    pub fn into_predicate_input(
+        self,
+        predicate_gas_used: u64,
+        predicate_data: Vec<u8>,
+    ) -> Option<Input> {
+        match self {
+            InputFragment::Coin(mut coin) => {
+                let predicate = coin.predicate.take()?;
+                Some(coin.into_predicate_input(
+                    predicate_gas_used,
+                    predicate,
+                    predicate_data,
+                ))
+            }
+            InputFragment::MessageCoin(mut coin) => {
+                let predicate = coin.predicate.take()?;
+                Some(coin.into_predicate_input(
+                    predicate_gas_used,
+                    predicate,
+                    predicate_data,
+                ))
+            }
+            InputFragment::Unknown => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoinInputFragment {
+    pub utxo_id: UtxoId,
+    pub owner: Address,
+    pub amount: u64,
+    pub asset_id: AssetId,
+    pub tx_pointer: TxPointer,
+    pub witness_index: u16,
+    /// The bytecode of the predicate of the owner, if it was requested and indexed.
+    pub predicate: Option<Vec<u8>>,
+    /// The length of the predicate data of the last spent input with the predicate.
+    pub predicate_data_length: Option<u64>,
+}
+
+impl CoinInputFragment {
+    /// Returns the `Input::CoinSigned` spending the coin.
+    pub fn into_signed_input(self) -> Input {
+        Input::coin_signed(
+            self.utxo_id,
+            self.owner,
+            self.amount,
+            self.asset_id,
+            self.tx_pointer,
+            self.witness_index,
+        )
+    }
+
+    /// Returns the `Input::CoinPredicate` spending the coin
+    /// owned by the `predicate`.
+    pub fn into_predicate_input(
+        self,
+        predicate_gas_used: u64,
+        predicate: Vec<u8>,
+        predicate_data: Vec<u8>,
+    ) -> Input {
+        Input::coin_predicate(
+            self.utxo_id,
+            self.owner,
+            self.amount,
+            self.asset_id,
+            self.tx_pointer,
+            predicate_gas_used,
+            predicate,
+            predicate_data,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MessageCoinInputFragment {
+    pub sender: Address,
+    pub recipient: Address,
+    pub amount: u64,
+    pub nonce: Nonce,
+    pub witness_index: u16,
+    /// The bytecode of the predicate of the recipient, if it was requested and indexed.
+    pub predicate: Option<Vec<u8>>,
+    /// The length of the predicate data of the last spent input with the predicate.
+    pub predicate_data_length: Option<u64>,
+}
+
+impl MessageCoinInputFragment {
+    /// Returns the `Input::MessageCoinSigned` spending the message coin.
+    pub fn into_signed_input(self) -> Input {
+        Input::message_coin_signed(
+            self.sender,
+            self.recipient,
+            self.amount,
+            self.nonce,
+            self.witness_index,
+        )
+    }
+
+    /// Returns the `Input::MessageCoinPredicate` spending the message coin
+    /// owned by the `predicate`.
+    pub fn into_predicate_input(
+        self,
+        predicate_gas_used: u64,
+        predicate: Vec<u8>,
+        predicate_data: Vec<u8>,
+    ) -> Input {
+        Input::message_coin_predicate(
+            self.sender,
+            self.recipient,
+            self.amount,
+            self.nonce,
+            predicate_gas_used,
+            predicate,
+            predicate_data,
+        )
+    }
+}
+
+/// The result of the best effort coins selection for one asset.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoinsToSpendAssetResult {
+    pub asset_id: AssetId,
+    /// The selected coins, or the reason why the selection failed.
+    pub coins: Result<Vec<CoinType>, String>,
+    /// The biggest coins up to `max` if the selection failed and
+    /// the partial selection was requested.
+    pub partial_coins: Option<Vec<CoinType>>,
+}
+
+/// The estimated number of inputs required to cover a multi-asset target.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputCountEstimate {
+    pub total_inputs: u32,
+    pub max_inputs: u16,
+    pub within_limit: bool,
+    /// The number of inputs per asset, or the reason why the selection failed.
+    pub inputs_per_asset: Vec<(AssetId, Result<u32, String>)>,
+}
+
+/// The estimated work of the coins to spend selection.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SelectionCost {
+    /// Whether the selection uses the coins to spend index.
+    pub indexed: bool,
+    pub entries_to_scan: u64,
+    /// Whether all coins were counted, otherwise the counts are capped.
+    pub complete: bool,
+    /// The number of the available coins and the scanned entries per asset.
+    pub cost_per_asset: Vec<AssetSelectionCost>,
+}
+
+/// The estimated work of the selection of one asset.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AssetSelectionCost {
+    pub asset_id: AssetId,
+    pub coin_count: u64,
+    pub entries_to_scan: u64,
+}
+
+/// Whether the target amount can be covered by the coins of the owner.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CoinsFeasibility {
+    pub feasible: bool,
+    /// The number of the biggest coins required to cover the target,
+    /// or the number of all available coins if they don't cover it.
+    /// The coins are counted up to `max_inputs + 1`.
+    pub coins_needed: u16,
+    /// The amount of the counted coins, which is the amount of all available
+    /// coins only if they don't cover the target within `max_inputs` coins.
+    pub total_available: u128,
+}
+
+/// The state of the coin resolved by the `coins_coverage` query.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CoinResolutionStatus {
+    /// The coin is unspent and counts towards the target.
+    Unspent,
+    /// The coin is unspent, but holds another asset.
+    OtherAsset,
+    /// The message has data, so it can't be spent as a coin.
+    NotACoin,
+    /// The coin is already spent or has never existed.
+    NotFound,
+}
+
+/// Whether the unspent coins of one asset cover the target.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoinsCoverage {
+    pub asset_id: AssetId,
+    pub target: u128,
+    /// The sum of the amounts of the unspent coins of the asset.
+    pub total: u128,
+    pub covers_target: bool,
+    /// The status and the amount of each coin in the order of the query.
+    pub coins: Vec<(UtxoId, CoinResolutionStatus, Option<u64>)>,
+    /// The status and the amount of each message coin in the order of the query.
+    pub message_coins: Vec<(Nonce, CoinResolutionStatus, Option<u64>)>,
+}
+
+/// The coin created or spent by the transaction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CoinMovement {
+    pub utxo_id: UtxoId,
+    pub asset_id: AssetId,
+    pub amount: u64,
+    pub block_height: BlockHeight,
+    pub transaction_id: TransactionId,
+}
+
+/// The coins of the owner created and spent within the range of blocks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoinActivity {
+    /// The coins created for the owner, in the order of the transactions.
+    pub created: Vec<CoinMovement>,
+    /// The coins of the owner spent, in the order of the transactions.
+    pub spent: Vec<CoinMovement>,
+    /// The pointer of the last transaction covered by the page.
+    pub last_tx_pointer: Option<TxPointer>,
+    /// Whether more transactions of the owner are within the blocks after the page.
+    pub has_next_page: bool,
+}
+
+/// The order in which assets are taken into the input budget.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AssetPriority {
+    /// Assets are taken in the same order as in the query.
+    #[default]
+    QueryOrder,
+    /// Assets that require fewer inputs are taken first.
+    FewestInputs,
+}
+
+/// The coins of the assets that fit into the input budget of one transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoinsWithinBudget {
+    pub max_inputs: u16,
+    pub used_inputs: u16,
+    /// The selected coins of the covered assets, in the priority order.
+    pub covered: Vec<(AssetId, Vec<CoinType>)>,
+    /// The assets that were dropped with the reason why.
+    pub dropped: Vec<(AssetId, String)>,
+}
+
+/// The coins selected in two phases: the coins of the value assets first,
+/// and then the base asset coins covering the fee for all inputs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoinsToSpendWithFee {
+    /// The selected coins per asset, in the order of the query.
+    pub coins: Vec<Vec<CoinType>>,
+    /// The base asset coins covering the base amount and the fee.
+    pub base_coins: Vec<CoinType>,
+    /// The fee of the transaction with all selected inputs.
+    pub fee: u64,
+}
+
+/// The `coins_to_spend` selection with the unsigned transaction spending it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoinsToSpendAsTransaction {
+    /// The selected coins per asset, in the order of the query.
+    pub coins: Vec<Vec<CoinType>>,
+    /// The skeleton of the transaction spending the selected coins. It must be
+    /// signed, and may be adjusted, for example, to cover the fee, before submission.
+    pub transaction: Transaction,
+}
+
+/// The `coins_to_spend` selection with the summary of the selected amounts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoinsToSpendWithSummary {
+    /// The selected coins per asset, in the order of the query.
+    pub coins: Vec<Vec<CoinType>>,
+    /// The summary of the selection per asset, in the order of the query.
+    pub summary: Vec<AssetSelectionSummary>,
+    /// The estimated gas cost contributed by the selected inputs.
+    pub gas_cost: SelectionGasCost,
+}
+
+/// The summed amount of the coins selected for one asset.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AssetSelectionSummary {
+    pub asset_id: AssetId,
+    /// The target amount from the query.
+    pub target: u128,
+    /// The sum of the amounts of the selected coins.
+    pub total: u128,
+    /// Whether the `total` meets or exceeds the `target`.
+    pub meets_target: bool,
+    /// Whether the `total` equals the `target`, so no change output is needed.
+    pub exact_match: bool,
+}
+
+/// The gas contributed to the transaction by the selected inputs,
+/// assuming they are signed by the owner with a single witness.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SelectionGasCost {
+    /// The number of the inputs in the selection.
+    pub inputs: u32,
+    /// The gas of the signature verification.
+    pub signature_gas: u64,
+    /// The size in bytes of the inputs and the signature witness.
+    pub bytes: u64,
+    /// The gas charged for the `bytes`.
+    pub bytes_gas: u64,
+    /// The sum of the `signature_gas` and the `bytes_gas`.
+    pub total_gas: u64,
+}
+
+/// The candidate selection of the coins with its cost.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoinSelectionCandidate {
+    /// The selected coins.
+    pub coins: Vec<CoinType>,
+    /// The number of the selected coins.
+    pub inputs: u16,
+    /// The sum of the amounts of the selected coins.
+    pub total: u128,
+    /// The amount by which the `total` exceeds the target.
+    pub overshoot: u128,
+    /// The estimated gas of the inputs.
+    pub gas: u64,
+    /// The composite cost used for the ranking: the sum of the `inputs`,
+    /// the `gas` and the `overshoot`.
+    pub cost: u128,
+}
+
+/// The coins covering the target partitioned into groups that fit
+/// into one transaction each.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoinGroups {
+    /// The maximum number of coins in one group.
+    pub max_inputs: u16,
+    /// The total amount of the coins of all groups.
+    pub total: u128,
+    /// The groups of coins, starting from the group with the biggest coins.
+    pub groups: Vec<CoinGroup>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoinGroup {
+    pub coins: Vec<CoinType>,
+    /// The total amount of the coins of the group.
+    pub total: u128,
+}
+
+/// The coins of the owner with an amount below the dust threshold.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DustCoins {
+    /// The dust coins per asset, ordered by the asset id.
+    pub assets: Vec<AssetDustCoins>,
+    /// Whether all dust coins of the owner were counted.
+    pub complete: bool,
+}
+
+/// The number and the total amount of the dust coins of one asset.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AssetDustCoins {
+    pub asset_id: AssetId,
+    pub count: u32,
+    pub amount: u128,
+}
+
+/// The reason why the coin is locked on the node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CoinLockReason {
+    /// The coin is blacklisted by the node operator.
+    Blacklisted,
+    /// The coin is reserved by a session.
+    Reserved,
+}
+
+/// The coins of the owner excluded from the coin selection of the node.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LockedCoins {
+    /// Whether all coins of the owner are locked.
+    pub owner_locked: bool,
+    /// The locked coins with the reason why they are locked.
+    pub coins: Vec<(CoinType, CoinLockReason)>,
+    /// Whether all coins of the owner were checked.
+    pub complete: bool,
+}
+
+/// The reason why the coin selection of the node can't use the coin.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CoinIneligibilityReason {
+    /// The owner of the coin is blacklisted by the node operator.
+    OwnerBlacklisted,
+    /// The coin is blacklisted by the node operator.
+    Blacklisted,
+    /// The coin is reserved by another session.
+    Reserved,
+    /// The amount of the coin is below the minimum amount of the asset.
+    BelowMinAmount,
+}
+
+/// The coins of the owner with the asset and whether the coin selection
+/// of the node may use them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoinsEligibility {
+    /// The coins with the reason why they aren't eligible, `None` if eligible.
+    pub coins: Vec<(CoinType, Option<CoinIneligibilityReason>)>,
+    /// Whether all coins of the owner with the asset are returned.
+    pub complete: bool,
+}
+
+/// The number of unspent coins in the UTXO set.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UtxoCount {
+    /// The total number of unspent coins.
+    pub count: u64,
+    /// The height of the last block that updated the count.
+    pub block_height: Option<BlockHeight>,
+}
+
+/// The status of the coins to spend index rebuild.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CoinsToSpendIndexRebuildStatus {
+    /// The rebuild is in progress.
+    Pending,
+    /// The index is rebuilt with the number of entries.
+    Completed { entries: u64 },
+    /// The rebuild failed, and the index is left unchanged.
+    Failed { error: String },
+}
+
+/// The Merkle root of the unspent coins.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CoinSetRoot {
+    /// The root of the binary Merkle tree over the unspent coins.
+    pub root: Bytes32,
+    /// The height of the block the root commits to.
+    pub block_height: BlockHeight,
+    /// The number of the unspent coins.
+    pub count: u64,
+}
+
+/// The order of the message coins.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MessageCoinOrder {
+    /// Message coins are sorted by the amount.
+    #[default]
+    Amount,
+    /// Message coins are sorted by the DA height of the message.
+    DaHeight,
+}
+
+/// The state of the coin at some block height.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HistoricalCoin {
+    /// The coin existed and was unspent.
+    Unspent(Coin),
+    /// The coin was already spent.
+    Spent,
+    /// The coin wasn't created yet.
+    NotCreated,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoinWithTransaction {
+    pub coin: Coin,
+    /// The transaction that created the coin, if the node could resolve it.
+    pub transaction: Option<Transaction>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CoinSpender {
+    /// The coin is still unspent.
+    Unspent,
+    /// The coin was spent by the transaction in the block.
+    Spent {
+        transaction_id: TransactionId,
+        block_height: BlockHeight,
+    },
+    /// The coin never existed.
+    NotFound,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Coin {
     pub amount: u64,
@@ -59,6 +572,114 @@ impl From<schema::coins::CoinType> for CoinType {
     }
 }
 
+impl From<schema::coins::InputFragment> for InputFragment {
+    fn from(value: schema::coins::InputFragment) -> Self {
+        match value {
+            schema::coins::InputFragment::CoinInputFragment(coin) => {
+                Self::Coin(CoinInputFragment {
+                    utxo_id: coin.utxo_id.into(),
+                    owner: coin.owner.into(),
+                    amount: coin.amount.into(),
+                    asset_id: coin.asset_id.into(),
+                    tx_pointer: coin.tx_pointer.into(),
+                    witness_index: coin.witness_index.into(),
+                    predicate: coin.predicate.map(Into::into),
+                    predicate_data_length: coin.predicate_data_length.map(Into::into),
+                })
+            }
+            schema::coins::InputFragment::MessageCoinInputFragment(coin) => {
+                Self::MessageCoin(MessageCoinInputFragment {
+                    sender: coin.sender.into(),
+                    recipient: coin.recipient.into(),
+                    amount: coin.amount.into(),
+                    nonce: coin.nonce.into(),
+                    witness_index: coin.witness_index.into(),
+                    predicate: coin.predicate.map(Into::into),
+                    predicate_data_length: coin.predicate_data_length.map(Into::into),
+                })
+            }
+            schema::coins::InputFragment::Unknown => Self::Unknown,
+        }
+    }
+}
+
+impl From<schema::coins::CoinsFeasibility> for CoinsFeasibility {
+    fn from(value: schema::coins::CoinsFeasibility) -> Self {
+        Self {
+            feasible: value.feasible,
+            coins_needed: value.coins_needed.into(),
+            total_available: value.total_available.into(),
+        }
+    }
+}
+
+impl From<schema::coins::CoinResolutionStatus> for CoinResolutionStatus {
+    fn from(value: schema::coins::CoinResolutionStatus) -> Self {
+        match value {
+            schema::coins::CoinResolutionStatus::Unspent => Self::Unspent,
+            schema::coins::CoinResolutionStatus::OtherAsset => Self::OtherAsset,
+            schema::coins::CoinResolutionStatus::NotACoin => Self::NotACoin,
+            schema::coins::CoinResolutionStatus::NotFound => Self::NotFound,
+        }
+    }
+}
+
+impl From<schema::coins::CoinsCoverage> for CoinsCoverage {
+    fn from(value: schema::coins::CoinsCoverage) -> Self {
+        Self {
+            asset_id: value.asset_id.into(),
+            target: value.target.into(),
+            total: value.total.into(),
+            covers_target: value.covers_target,
+            coins: value
+                .coins
+                .into_iter()
+                .map(|coin| {
+                    (
+                        coin.utxo_id.into(),
+                        coin.status.into(),
+                        coin.amount.map(Into::into),
+                    )
+                })
+                .collect(),
+            message_coins: value
+                .message_coins
+                .into_iter()
+                .map(|message| {
+                    (
+                        message.nonce.into(),
+                        message.status.into(),
+                        message.amount.map(Into::into),
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<schema::coins::CoinMovement> for CoinMovement {
+    fn from(value: schema::coins::CoinMovement) -> Self {
+        Self {
+            utxo_id: value.utxo_id.into(),
+            asset_id: value.asset_id.into(),
+            amount: value.amount.into(),
+            block_height: value.block_height.into(),
+            transaction_id: value.transaction_id.into(),
+        }
+    }
+}
+
+impl From<schema::coins::CoinActivity> for CoinActivity {
+    fn from(value: schema::coins::CoinActivity) -> Self {
+        Self {
+            created: value.created.into_iter().map(Into::into).collect(),
+            spent: value.spent.into_iter().map(Into::into).collect(),
+            last_tx_pointer: value.last_tx_pointer.map(Into::into),
+            has_next_page: value.has_next_page,
+        }
+    }
+}
+
 impl From<schema::coins::Coin> for Coin {
     fn from(value: schema::coins::Coin) -> Self {
         Self {
@@ -94,3 +715,392 @@ impl From<schema::coins::CoinConnection> for PaginatedResult<Coin, String> {
         }
     }
 }
+
+impl TryFrom<schema::storage::HistoricalCoin> for HistoricalCoin {
+    type Error = ConversionError;
+
+    fn try_from(value: schema::storage::HistoricalCoin) -> Result<Self, Self::Error> {
+        match value.state {
+            schema::storage::HistoricalCoinState::Unspent => {
+                let coin = value
+                    .coin
+                    .ok_or_else(|| ConversionError::MissingField("coin".to_string()))?;
+                Ok(Self::Unspent(coin.into()))
+            }
+            schema::storage::HistoricalCoinState::Spent => Ok(Self::Spent),
+            schema::storage::HistoricalCoinState::NotCreated => Ok(Self::NotCreated),
+        }
+    }
+}
+
+impl TryFrom<schema::coins::CoinWithTransaction> for CoinWithTransaction {
+    type Error = ConversionError;
+
+    fn try_from(value: schema::coins::CoinWithTransaction) -> Result<Self, Self::Error> {
+        Ok(Self {
+            coin: value.coin.into(),
+            transaction: value.transaction.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+impl TryFrom<schema::storage::CoinSpender> for CoinSpender {
+    type Error = ConversionError;
+
+    fn try_from(value: schema::storage::CoinSpender) -> Result<Self, Self::Error> {
+        match value.state {
+            schema::storage::CoinSpenderState::Unspent => Ok(Self::Unspent),
+            schema::storage::CoinSpenderState::Spent => {
+                let transaction_id = value.transaction_id.ok_or_else(|| {
+                    ConversionError::MissingField("transactionId".to_string())
+                })?;
+                let block_height = value.block_height.ok_or_else(|| {
+                    ConversionError::MissingField("blockHeight".to_string())
+                })?;
+                Ok(Self::Spent {
+                    transaction_id: transaction_id.into(),
+                    block_height: block_height.into(),
+                })
+            }
+            schema::storage::CoinSpenderState::NotFound => Ok(Self::NotFound),
+        }
+    }
+}
+
+impl From<schema::coins::CoinsToSpendAssetResult> for CoinsToSpendAssetResult {
+    fn from(value: schema::coins::CoinsToSpendAssetResult) -> Self {
+        let selected = value.coins.into_iter().map(Into::into).collect();
+        let (coins, partial_coins) = if value.success {
+            (Ok(selected), None)
+        } else if value.partial {
+            (Err(value.error.unwrap_or_default()), Some(selected))
+        } else {
+            (Err(value.error.unwrap_or_default()), None)
+        };
+
+        Self {
+            asset_id: value.asset_id.into(),
+            coins,
+            partial_coins,
+        }
+    }
+}
+
+impl From<AssetPriority> for schema::coins::AssetPriority {
+    fn from(value: AssetPriority) -> Self {
+        match value {
+            AssetPriority::QueryOrder => schema::coins::AssetPriority::QueryOrder,
+            AssetPriority::FewestInputs => schema::coins::AssetPriority::FewestInputs,
+        }
+    }
+}
+
+impl From<MessageCoinOrder> for schema::coins::MessageCoinOrder {
+    fn from(value: MessageCoinOrder) -> Self {
+        match value {
+            MessageCoinOrder::Amount => schema::coins::MessageCoinOrder::Amount,
+            MessageCoinOrder::DaHeight => schema::coins::MessageCoinOrder::DaHeight,
+        }
+    }
+}
+
+impl From<schema::coins::MessageCoinConnection> for PaginatedResult<MessageCoin, String> {
+    fn from(conn: schema::coins::MessageCoinConnection) -> Self {
+        PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn.edges.into_iter().map(|e| e.node.into()).collect(),
+        }
+    }
+}
+
+impl From<schema::coins::MessageCoinBySenderConnection>
+    for PaginatedResult<MessageCoin, String>
+{
+    fn from(conn: schema::coins::MessageCoinBySenderConnection) -> Self {
+        PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn.edges.into_iter().map(|e| e.node.into()).collect(),
+        }
+    }
+}
+
+impl From<schema::coins::CoinsWithinBudget> for CoinsWithinBudget {
+    fn from(value: schema::coins::CoinsWithinBudget) -> Self {
+        let covered = value
+            .covered
+            .into_iter()
+            .map(|asset| {
+                (
+                    asset.asset_id.into(),
+                    asset.coins.into_iter().map(Into::into).collect(),
+                )
+            })
+            .collect();
+        let dropped = value
+            .dropped
+            .into_iter()
+            .map(|asset| (asset.asset_id.into(), asset.error.unwrap_or_default()))
+            .collect();
+
+        Self {
+            max_inputs: value.max_inputs.into(),
+            used_inputs: value.used_inputs.into(),
+            covered,
+            dropped,
+        }
+    }
+}
+
+impl From<schema::coins::InputCountEstimate> for InputCountEstimate {
+    fn from(value: schema::coins::InputCountEstimate) -> Self {
+        let inputs_per_asset = value
+            .inputs_per_asset
+            .into_iter()
+            .map(|asset| {
+                let inputs = if asset.success {
+                    Ok(asset.inputs.into())
+                } else {
+                    Err(asset.error.unwrap_or_default())
+                };
+                (asset.asset_id.into(), inputs)
+            })
+            .collect();
+
+        Self {
+            total_inputs: value.total_inputs.into(),
+            max_inputs: value.max_inputs.into(),
+            within_limit: value.within_limit,
+            inputs_per_asset,
+        }
+    }
+}
+
+impl From<schema::coins::SelectionCost> for SelectionCost {
+    fn from(value: schema::coins::SelectionCost) -> Self {
+        Self {
+            indexed: value.indexed,
+            entries_to_scan: value.entries_to_scan.into(),
+            complete: value.complete,
+            cost_per_asset: value.cost_per_asset.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<schema::coins::AssetSelectionCost> for AssetSelectionCost {
+    fn from(value: schema::coins::AssetSelectionCost) -> Self {
+        Self {
+            asset_id: value.asset_id.into(),
+            coin_count: value.coin_count.into(),
+            entries_to_scan: value.entries_to_scan.into(),
+        }
+    }
+}
+
+impl From<schema::coins::CoinSelectionCandidate> for CoinSelectionCandidate {
+    fn from(value: schema::coins::CoinSelectionCandidate) -> Self {
+        Self {
+            coins: value.coins.into_iter().map(Into::into).collect(),
+            inputs: value.inputs.into(),
+            total: value.total.into(),
+            overshoot: value.overshoot.into(),
+            gas: value.gas.into(),
+            cost: value.cost.into(),
+        }
+    }
+}
+
+impl From<schema::coins::CoinGroups> for CoinGroups {
+    fn from(value: schema::coins::CoinGroups) -> Self {
+        Self {
+            max_inputs: value.max_inputs.into(),
+            total: value.total.into(),
+            groups: value.groups.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<schema::coins::CoinGroup> for CoinGroup {
+    fn from(value: schema::coins::CoinGroup) -> Self {
+        Self {
+            coins: value.coins.into_iter().map(Into::into).collect(),
+            total: value.total.into(),
+        }
+    }
+}
+
+impl From<schema::coins::CoinsToSpendWithFee> for CoinsToSpendWithFee {
+    fn from(value: schema::coins::CoinsToSpendWithFee) -> Self {
+        Self {
+            coins: value
+                .coins
+                .into_iter()
+                .map(|coins| coins.into_iter().map(Into::into).collect())
+                .collect(),
+            base_coins: value.base_coins.into_iter().map(Into::into).collect(),
+            fee: value.fee.into(),
+        }
+    }
+}
+
+impl TryFrom<schema::coins::CoinsToSpendAsTransaction> for CoinsToSpendAsTransaction {
+    type Error = ConversionError;
+
+    fn try_from(
+        value: schema::coins::CoinsToSpendAsTransaction,
+    ) -> Result<Self, Self::Error> {
+        let transaction = Transaction::from_bytes(&value.raw_payload)
+            .map_err(ConversionError::TransactionFromBytesError)?;
+
+        Ok(Self {
+            coins: value
+                .coins
+                .into_iter()
+                .map(|coins| coins.into_iter().map(Into::into).collect())
+                .collect(),
+            transaction,
+        })
+    }
+}
+
+impl From<schema::coins::CoinsToSpendWithSummary> for CoinsToSpendWithSummary {
+    fn from(value: schema::coins::CoinsToSpendWithSummary) -> Self {
+        Self {
+            coins: value
+                .coins
+                .into_iter()
+                .map(|coins| coins.into_iter().map(Into::into).collect())
+                .collect(),
+            summary: value.summary.into_iter().map(Into::into).collect(),
+            gas_cost: value.gas_cost.into(),
+        }
+    }
+}
+
+impl From<schema::coins::AssetSelectionSummary> for AssetSelectionSummary {
+    fn from(value: schema::coins::AssetSelectionSummary) -> Self {
+        Self {
+            asset_id: value.asset_id.into(),
+            target: value.target.into(),
+            total: value.total.into(),
+            meets_target: value.meets_target,
+            exact_match: value.exact_match,
+        }
+    }
+}
+
+impl From<schema::coins::SelectionGasCost> for SelectionGasCost {
+    fn from(value: schema::coins::SelectionGasCost) -> Self {
+        Self {
+            inputs: value.inputs.into(),
+            signature_gas: value.signature_gas.into(),
+            bytes: value.bytes.into(),
+            bytes_gas: value.bytes_gas.into(),
+            total_gas: value.total_gas.into(),
+        }
+    }
+}
+
+impl From<schema::coins::CoinLockReason> for CoinLockReason {
+    fn from(value: schema::coins::CoinLockReason) -> Self {
+        match value {
+            schema::coins::CoinLockReason::Blacklisted => Self::Blacklisted,
+            schema::coins::CoinLockReason::Reserved => Self::Reserved,
+        }
+    }
+}
+
+impl From<schema::coins::LockedCoins> for LockedCoins {
+    fn from(value: schema::coins::LockedCoins) -> Self {
+        Self {
+            owner_locked: value.owner_locked,
+            coins: value
+                .coins
+                .into_iter()
+                .map(|locked| (locked.coin.into(), locked.reason.into()))
+                .collect(),
+            complete: value.complete,
+        }
+    }
+}
+
+impl From<schema::coins::CoinIneligibilityReason> for CoinIneligibilityReason {
+    fn from(value: schema::coins::CoinIneligibilityReason) -> Self {
+        match value {
+            schema::coins::CoinIneligibilityReason::OwnerBlacklisted => {
+                Self::OwnerBlacklisted
+            }
+            schema::coins::CoinIneligibilityReason::Blacklisted => Self::Blacklisted,
+            schema::coins::CoinIneligibilityReason::Reserved => Self::Reserved,
+            schema::coins::CoinIneligibilityReason::BelowMinAmount => {
+                Self::BelowMinAmount
+            }
+        }
+    }
+}
+
+impl From<schema::coins::CoinsEligibility> for CoinsEligibility {
+    fn from(value: schema::coins::CoinsEligibility) -> Self {
+        Self {
+            coins: value
+                .coins
+                .into_iter()
+                .map(|coin| (coin.coin.into(), coin.reason.map(Into::into)))
+                .collect(),
+            complete: value.complete,
+        }
+    }
+}
+
+impl From<schema::coins::DustCoins> for DustCoins {
+    fn from(value: schema::coins::DustCoins) -> Self {
+        Self {
+            assets: value
+                .assets
+                .into_iter()
+                .map(|asset| AssetDustCoins {
+                    asset_id: asset.asset_id.into(),
+                    count: asset.count.into(),
+                    amount: asset.amount.into(),
+                })
+                .collect(),
+            complete: value.complete,
+        }
+    }
+}
+
+impl From<schema::coins::CoinSetRoot> for CoinSetRoot {
+    fn from(value: schema::coins::CoinSetRoot) -> Self {
+        Self {
+            root: value.root.into(),
+            block_height: value.block_height.into(),
+            count: value.count.into(),
+        }
+    }
+}
+
+impl From<schema::coins::CoinsToSpendIndexRebuild> for CoinsToSpendIndexRebuildStatus {
+    fn from(value: schema::coins::CoinsToSpendIndexRebuild) -> Self {
+        match value.state {
+            schema::coins::CoinsToSpendIndexRebuildState::Pending => Self::Pending,
+            schema::coins::CoinsToSpendIndexRebuildState::Completed => Self::Completed {
+                entries: value.entries.map(Into::into).unwrap_or_default(),
+            },
+            schema::coins::CoinsToSpendIndexRebuildState::Failed => Self::Failed {
+                error: value.error.unwrap_or_default(),
+            },
+        }
+    }
+}
+
+impl From<schema::coins::UtxoCount> for UtxoCount {
+    fn from(value: schema::coins::UtxoCount) -> Self {
+        Self {
+            count: value.count.into(),
+            block_height: value.block_height.map(Into::into),
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use crate::client::schema;
+use fuel_core_types::fuel_tx::TxId;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionFeeEstimate {
+    pub id: TxId,
+    pub success: bool,
+    pub total_gas: u64,
+    pub total_fee: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchFeeEstimate {
+    pub total_fee: u64,
+    pub total_gas: u64,
+    pub transactions: Vec<TransactionFeeEstimate>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockGasLimitCheck {
+    /// Whether the transaction can't be included into any block.
+    pub exceeds_block_gas_limit: bool,
+    pub estimated_gas: u64,
+    pub block_gas_limit: u64,
+}
+
+// GraphQL Translation
+impl From<schema::tx::TransactionFeeEstimate> for TransactionFeeEstimate {
+    fn from(value: schema::tx::TransactionFeeEstimate) -> Self {
+        Self {
+            id: value.id.into(),
+            success: value.success,
+            total_gas: value.total_gas.into(),
+            total_fee: value.total_fee.into(),
+        }
+    }
+}
+
+impl From<schema::tx::BatchFeeEstimate> for BatchFeeEstimate {
+    fn from(value: schema::tx::BatchFeeEstimate) -> Self {
+        Self {
+            total_fee: value.total_fee.into(),
+            total_gas: value.total_gas.into(),
+            transactions: value.transactions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<schema::tx::BlockGasLimitCheck> for BlockGasLimitCheck {
+    fn from(value: schema::tx::BlockGasLimitCheck) -> Self {
+        Self {
+            exceeds_block_gas_limit: value.exceeds_block_gas_limit,
+            estimated_gas: value.estimated_gas.into(),
+            block_gas_limit: value.block_gas_limit.into(),
+        }
+    }
+}
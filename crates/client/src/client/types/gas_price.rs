@@ -19,12 +19,20 @@ impl From<schema::gas_price::LatestGasPrice> for LatestGasPrice {
 
 pub struct EstimateGasPrice {
     pub gas_price: u64,
+    /// The part of the gas price that covers the execution,
+    /// if the node tracks the gas price components separately.
+    pub execution_gas_price: Option<u64>,
+    /// The part of the gas price that covers the DA commitment,
+    /// if the node tracks the gas price components separately.
+    pub da_gas_price: Option<u64>,
 }
 
 impl From<schema::gas_price::EstimateGasPrice> for EstimateGasPrice {
     fn from(value: schema::gas_price::EstimateGasPrice) -> Self {
         Self {
             gas_price: value.gas_price.into(),
+            execution_gas_price: value.execution_gas_price.map(Into::into),
+            da_gas_price: value.da_gas_price.map(Into::into),
         }
     }
 }
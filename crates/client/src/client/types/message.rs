@@ -12,6 +12,7 @@ use crate::client::{
     },
     PaginatedResult,
 };
+use fuel_core_types::fuel_types::BlockHeight;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Message {
@@ -47,6 +48,24 @@ pub struct MessageProof {
     pub data: Bytes,
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MessageSpendableHeight {
+    /// The DA height of the message.
+    pub da_height: u64,
+    /// The height of the first block where the message could be spent.
+    /// `None` if the relayer hasn't synced up to the DA height of the message yet.
+    pub block_height: Option<BlockHeight>,
+}
+
+impl From<schema::message::MessageSpendableHeight> for MessageSpendableHeight {
+    fn from(value: schema::message::MessageSpendableHeight) -> Self {
+        Self {
+            da_height: value.da_height.into(),
+            block_height: value.block_height.map(Into::into),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum MessageStatus {
     Unspent,
@@ -64,6 +83,75 @@ impl From<schema::message::MessageStatus> for MessageStatus {
     }
 }
 
+/// The inclusion state of an inbound message.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MessageInclusionState {
+    /// The message is included into the chain and is either spendable or spent.
+    Included,
+    /// The message is synced by the relayer, but not yet included into the chain.
+    Pending,
+    /// The message is unknown to the node.
+    Unknown,
+}
+
+/// The inbound messages known to the relayer, but not yet included into the chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RelayerBacklog {
+    /// The DA height of the latest block.
+    pub included_da_height: u64,
+    /// The DA height up to which the relayer synced the events.
+    pub synced_da_height: u64,
+    /// Whether the relayer reached the finalized tip of the DA layer.
+    pub synced_to_tip: bool,
+    /// The number of the messages synced by the relayer, but not yet included.
+    pub pending_messages: u64,
+}
+
+impl From<schema::message::RelayerBacklog> for RelayerBacklog {
+    fn from(value: schema::message::RelayerBacklog) -> Self {
+        Self {
+            included_da_height: value.included_da_height.into(),
+            synced_da_height: value.synced_da_height.into(),
+            synced_to_tip: value.synced_to_tip,
+            pending_messages: value.pending_messages.into(),
+        }
+    }
+}
+
+/// The value bridged between the DA layer and the chain over a range of blocks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BridgedValue {
+    /// The number of the inbound messages, `None` if the relayer is disabled.
+    pub inbound_messages: Option<u64>,
+    /// The total amount of the inbound messages, `None` if the relayer is disabled.
+    pub inbound_amount: Option<u128>,
+    /// The number of the outbound messages.
+    pub outbound_messages: u64,
+    /// The total amount of the outbound messages.
+    pub outbound_amount: u128,
+}
+
+impl From<schema::message::BridgedValue> for BridgedValue {
+    fn from(value: schema::message::BridgedValue) -> Self {
+        Self {
+            inbound_messages: value.inbound_messages.map(Into::into),
+            inbound_amount: value.inbound_amount.map(Into::into),
+            outbound_messages: value.outbound_messages.into(),
+            outbound_amount: value.outbound_amount.into(),
+        }
+    }
+}
+
+impl From<schema::message::MessageInclusionState> for MessageInclusionState {
+    fn from(value: schema::message::MessageInclusionState) -> Self {
+        match value {
+            schema::message::MessageInclusionState::Included => Self::Included,
+            schema::message::MessageInclusionState::Pending => Self::Pending,
+            schema::message::MessageInclusionState::Unknown => Self::Unknown,
+        }
+    }
+}
+
 // GraphQL Translation
 
 impl From<schema::message::Message> for Message {
@@ -0,0 +1,96 @@
+use crate::client::schema;
+use fuel_core_types::fuel_types::{
+    Address,
+    AssetId,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputKind {
+    /// The coin output to an address that doesn't own any of the inputs.
+    Transfer,
+    /// The coin output to an address that owns some of the inputs.
+    SelfTransfer,
+    /// The change output that receives the unspent amount of the asset.
+    Change,
+    /// The variable output, its value is set by the contracts during execution.
+    Variable,
+    /// The contract output, its state is set by the contracts during execution.
+    Contract,
+    /// The output of the newly created contract.
+    ContractCreated,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputBreakdown {
+    pub index: u16,
+    pub kind: OutputKind,
+    /// Whether the output is known only after the execution of the transaction.
+    pub dynamic: bool,
+    pub to: Option<Address>,
+    pub asset_id: Option<AssetId>,
+    /// `None` if the amount is calculated during execution.
+    pub amount: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionOutputsBreakdown {
+    pub outputs: Vec<OutputBreakdown>,
+    /// The total amount per asset sent to the addresses that don't own any of the inputs.
+    pub transferred_amounts: Vec<(AssetId, u128)>,
+    /// The coin outputs with the amounts fixed by the transaction itself, as
+    /// `(index, to, asset_id, amount)`. The UTXO ids of the coins are known only
+    /// after the transaction is included into the block.
+    pub fixed_coins: Vec<(u16, Address, AssetId, u64)>,
+}
+
+// GraphQL Translation
+impl From<schema::tx::OutputKind> for OutputKind {
+    fn from(value: schema::tx::OutputKind) -> Self {
+        match value {
+            schema::tx::OutputKind::Transfer => OutputKind::Transfer,
+            schema::tx::OutputKind::SelfTransfer => OutputKind::SelfTransfer,
+            schema::tx::OutputKind::Change => OutputKind::Change,
+            schema::tx::OutputKind::Variable => OutputKind::Variable,
+            schema::tx::OutputKind::Contract => OutputKind::Contract,
+            schema::tx::OutputKind::ContractCreated => OutputKind::ContractCreated,
+        }
+    }
+}
+
+impl From<schema::tx::OutputBreakdown> for OutputBreakdown {
+    fn from(value: schema::tx::OutputBreakdown) -> Self {
+        Self {
+            index: value.index.into(),
+            kind: value.kind.into(),
+            dynamic: value.dynamic,
+            to: value.to.map(Into::into),
+            asset_id: value.asset_id.map(Into::into),
+            amount: value.amount.map(Into::into),
+        }
+    }
+}
+
+impl From<schema::tx::TransactionOutputsBreakdown> for TransactionOutputsBreakdown {
+    fn from(value: schema::tx::TransactionOutputsBreakdown) -> Self {
+        Self {
+            outputs: value.outputs.into_iter().map(Into::into).collect(),
+            transferred_amounts: value
+                .transferred_amounts
+                .into_iter()
+                .map(|amount| (amount.asset_id.into(), amount.amount.into()))
+                .collect(),
+            fixed_coins: value
+                .fixed_coins
+                .into_iter()
+                .map(|coin| {
+                    (
+                        coin.index.into(),
+                        coin.to.into(),
+                        coin.asset_id.into(),
+                        coin.amount.into(),
+                    )
+                })
+                .collect(),
+        }
+    }
+}
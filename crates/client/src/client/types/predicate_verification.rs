@@ -0,0 +1,64 @@
+use crate::client::schema;
+use fuel_core_types::fuel_types::Address;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PredicateVerificationStatus {
+    /// The predicate returned `true` within `predicate_gas_used`.
+    Passed,
+    /// The predicate failed the verification.
+    Failed,
+    /// The verification failed, but it is unknown which of several predicates caused it.
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PredicateInputVerification {
+    pub index: u16,
+    pub owner: Address,
+    pub predicate_gas_used: u64,
+    pub status: PredicateVerificationStatus,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PredicatesVerification {
+    /// `Ok` if all predicates passed, otherwise the reason of the failure.
+    pub result: Result<(), String>,
+    pub inputs: Vec<PredicateInputVerification>,
+}
+
+// GraphQL Translation
+impl From<schema::tx::PredicateVerificationStatus> for PredicateVerificationStatus {
+    fn from(value: schema::tx::PredicateVerificationStatus) -> Self {
+        match value {
+            schema::tx::PredicateVerificationStatus::Passed => Self::Passed,
+            schema::tx::PredicateVerificationStatus::Failed => Self::Failed,
+            schema::tx::PredicateVerificationStatus::Unknown => Self::Unknown,
+        }
+    }
+}
+
+impl From<schema::tx::PredicateInputVerification> for PredicateInputVerification {
+    fn from(value: schema::tx::PredicateInputVerification) -> Self {
+        Self {
+            index: value.index.into(),
+            owner: value.owner.into(),
+            predicate_gas_used: value.predicate_gas_used.into(),
+            status: value.status.into(),
+        }
+    }
+}
+
+impl From<schema::tx::PredicatesVerification> for PredicatesVerification {
+    fn from(value: schema::tx::PredicatesVerification) -> Self {
+        let result = if value.success {
+            Ok(())
+        } else {
+            Err(value.error.unwrap_or_default())
+        };
+
+        Self {
+            result,
+            inputs: value.inputs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -136,7 +136,11 @@ macro_rules! compression {
                         $ident: CompressCtxKeyspace {
                             changes: Default::default(),
                             changes_lookup: Default::default(),
-                            cache_evictor: CacheEvictor::new_from_db(&mut self.db, self.accessed_keys.$ident.into())?,
+                            cache_evictor: CacheEvictor::new_from_db(
+                                &mut self.db,
+                                self.accessed_keys.$ident.into(),
+                                self.config.temporal_registry_max_entries,
+                            )?,
                         },
                     )*
                     config: self.config,
@@ -209,7 +213,7 @@ macro_rules! compression {
                         }
                     }
 
-                    let key = ctx.$ident.cache_evictor.next_key();
+                    let key = ctx.$ident.cache_evictor.next_key()?;
                     let old = ctx.$ident.changes.insert(key, self.clone());
                     let old_rev = ctx.$ident.changes_lookup.insert(self.clone(), key);
                     debug_assert!(old.is_none(), "Key collision in registry substitution");
@@ -1,4 +1,7 @@
-use core::time::Duration;
+use core::{
+    num::NonZeroU32,
+    time::Duration,
+};
 
 use fuel_core_types::tai64::{
     Tai64,
@@ -10,7 +13,26 @@ pub struct Config {
     /// How long entries in the temporal registry are valid.
     /// After this time has passed, the entry is considered stale and must not be used.
     /// If the value is needed again, it must be re-registered.
+    ///
+    /// Retention is checked when a key is looked up during compression.
+    /// The stale entries stay in the database until their key is reused
+    /// by the cache evictor, or until they are removed by the compaction.
     pub temporal_registry_retention: Duration,
+    /// The maximum number of entries in each table of the temporal registry.
+    /// The cache evictor reuses the keys in a cycle bounded by this number, so
+    /// a new registration overwrites the entry registered the longest time ago.
+    /// Both limits apply when set: an entry is used only while it is within
+    /// the retention and hasn't been overwritten yet.
+    /// If not set, the keys cycle through the whole key space. Lowering the limit
+    /// doesn't remove the entries with the keys above it.
+    pub temporal_registry_max_entries: Option<NonZeroU32>,
+    /// How often the entries past the retention are removed from the temporal
+    /// registry, measured by the block time. The compaction runs before
+    /// the compression of the first block after the interval has passed,
+    /// so an entry may stay in the database up to this interval past its retention.
+    /// A longer interval spends less CPU on the compaction.
+    /// If not set, the stale entries are never compacted.
+    pub temporal_registry_compaction_interval: Option<Duration>,
 }
 
 impl Config {
@@ -30,4 +52,30 @@ impl Config {
             .map_err(|_| anyhow::anyhow!("Invalid timestamp ordering"))?;
         Ok(duration <= self.temporal_registry_retention)
     }
+
+    /// The oldest timestamp of the keys accessible from the block with
+    /// the `block_timestamp`. The keys with older timestamps are stale.
+    pub fn oldest_accessible_timestamp(&self, block_timestamp: Tai64) -> Tai64 {
+        Tai64(
+            block_timestamp
+                .0
+                .saturating_sub(self.temporal_registry_retention.as_secs()),
+        )
+    }
+
+    /// Given the timestamp of the current block and of the last compaction,
+    /// is the compaction of the temporal registry due?
+    pub fn is_compaction_due(
+        &self,
+        block_timestamp: Tai64,
+        last_compaction: Option<Tai64>,
+    ) -> bool {
+        let Some(interval) = self.temporal_registry_compaction_interval else {
+            return false
+        };
+        match last_compaction {
+            Some(last) => block_timestamp.0.saturating_sub(last.0) >= interval.as_secs(),
+            None => true,
+        }
+    }
 }
@@ -36,11 +36,14 @@ use fuel_core_types::{
         Mint,
         ScriptCode,
         Transaction,
+        TxId,
         TxPointer as FuelTxPointer,
+        UniqueIdentifier,
         UtxoId,
     },
     fuel_types::{
         Address,
+        ChainId,
         ContractId,
     },
     tai64::Tai64,
@@ -52,7 +55,7 @@ impl<T> DecompressDb for T where T: TemporalRegistryAll + HistoryLookup {}
 /// This must be called for all decompressed blocks in sequence, otherwise the result will be garbage.
 pub async fn decompress<D>(
     config: Config,
-    mut db: D,
+    db: D,
     block: VersionedCompressedBlock,
 ) -> anyhow::Result<PartialFuelBlock>
 where
@@ -60,6 +63,47 @@ where
 {
     // TODO: merkle root verification: https://github.com/FuelLabs/fuel-core/issues/2232
 
+    let transactions = decompress_transactions(config, db, &block).await?;
+
+    Ok(PartialFuelBlock {
+        header: block.partial_block_header(),
+        transactions,
+    })
+}
+
+/// Resolves only the ids of the transactions of the compressed block.
+///
+/// The transaction id commits to every field of the transaction except
+/// the witnesses, so the inputs and outputs are still resolved against the
+/// registry and the history. What is skipped is the reconstruction of
+/// the block header, so the consensus and application header fields are
+/// only available via [`decompress`].
+///
+/// The registrations of the block are written to the registry the same way
+/// as during [`decompress`], so the same sequencing requirement applies:
+/// this must be called for all blocks in sequence.
+pub async fn decompress_transaction_ids<D>(
+    config: Config,
+    db: D,
+    block: VersionedCompressedBlock,
+    chain_id: &ChainId,
+) -> anyhow::Result<Vec<TxId>>
+where
+    D: DecompressDb,
+{
+    let transactions = decompress_transactions(config, db, &block).await?;
+
+    Ok(transactions.iter().map(|tx| tx.id(chain_id)).collect())
+}
+
+async fn decompress_transactions<D>(
+    config: Config,
+    mut db: D,
+    block: &VersionedCompressedBlock,
+) -> anyhow::Result<Vec<Transaction>>
+where
+    D: DecompressDb,
+{
     block
         .registrations()
         .write_to_registry(&mut db, block.consensus_header().time)?;
@@ -93,10 +137,7 @@ where
         anyhow::bail!("Last transaction is not a mint");
     }
 
-    Ok(PartialFuelBlock {
-        header: block.partial_block_header(),
-        transactions,
-    })
+    Ok(transactions)
 }
 
 pub struct DecompressCtx<D> {
@@ -1,4 +1,7 @@
-use std::collections::HashSet;
+use std::{
+    collections::HashSet,
+    num::NonZeroU32,
+};
 
 use fuel_core_types::fuel_compression::RegistryKey;
 
@@ -12,6 +15,8 @@ pub(crate) struct CacheEvictor<T> {
     keep_keys: HashSet<RegistryKey>,
     /// Next key to be used
     next_key: RegistryKey,
+    /// The maximum number of keys in the keyspace, if limited
+    max_entries: Option<NonZeroU32>,
     /// Marker for the keyspace type
     _keyspace_marker: std::marker::PhantomData<T>,
 }
@@ -21,13 +26,14 @@ impl<T> CacheEvictor<T> {
     pub fn new_from_db<D>(
         db: &mut D,
         keep_keys: HashSet<RegistryKey>,
+        max_entries: Option<NonZeroU32>,
     ) -> anyhow::Result<Self>
     where
         D: EvictorDb<T>,
     {
         let latest_key = db.get_latest_assigned_key()?;
         let next_key = if let Some(latest_key) = latest_key {
-            latest_key.next()
+            wrapping_next(latest_key, max_entries)
         } else {
             RegistryKey::ZERO
         };
@@ -35,22 +41,31 @@ impl<T> CacheEvictor<T> {
         Ok(Self {
             keep_keys,
             next_key,
+            max_entries,
             _keyspace_marker: std::marker::PhantomData,
         })
     }
 
-    pub fn next_key(&mut self) -> RegistryKey {
+    pub fn next_key(&mut self) -> anyhow::Result<RegistryKey> {
         // Pick first key not in the set
         // TODO: use a proper algo, maybe LRU?
 
+        if let Some(max_entries) = self.max_entries {
+            let max_entries = usize::try_from(max_entries.get()).unwrap_or(usize::MAX);
+            if self.keep_keys.len() >= max_entries {
+                anyhow::bail!(
+                    "The block uses more than {max_entries} keys of the registry table"
+                );
+            }
+        }
         debug_assert!(self.keep_keys.len() < 2usize.pow(24).saturating_sub(2));
 
         while self.keep_keys.contains(&self.next_key) {
-            self.next_key = self.next_key.next();
+            self.next_key = wrapping_next(self.next_key, self.max_entries);
         }
 
         self.keep_keys.insert(self.next_key);
-        self.next_key
+        Ok(self.next_key)
     }
 
     /// Commit the current state of the evictor to the database
@@ -61,3 +76,54 @@ impl<T> CacheEvictor<T> {
         db.set_latest_assigned_key(self.next_key)
     }
 }
+
+/// Returns the key after the `key`, starting over from zero
+/// when the key reaches the `max_entries` limit.
+fn wrapping_next(key: RegistryKey, max_entries: Option<NonZeroU32>) -> RegistryKey {
+    let next = key.next();
+    match max_entries {
+        Some(max_entries) if key_index(next) >= max_entries.get() => RegistryKey::ZERO,
+        _ => next,
+    }
+}
+
+/// Returns the big-endian value of the `key`.
+fn key_index(key: RegistryKey) -> u32 {
+    let mut bytes = [0u8; 4];
+    bytes[1..].copy_from_slice(key.as_ref());
+    u32::from_be_bytes(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    fn key(value: u32) -> RegistryKey {
+        RegistryKey::try_from(value).unwrap()
+    }
+
+    #[test]
+    fn wrapping_next__increments_the_key_below_the_limit() {
+        assert_eq!(wrapping_next(key(1), NonZeroU32::new(3)), key(2));
+    }
+
+    #[test]
+    fn wrapping_next__starts_over_at_the_limit() {
+        assert_eq!(wrapping_next(key(2), NonZeroU32::new(3)), RegistryKey::ZERO);
+    }
+
+    #[test]
+    fn wrapping_next__starts_over_if_the_key_is_above_the_limit() {
+        assert_eq!(
+            wrapping_next(key(10), NonZeroU32::new(3)),
+            RegistryKey::ZERO
+        );
+    }
+
+    #[test]
+    fn wrapping_next__uses_the_whole_key_space_without_the_limit() {
+        assert_eq!(wrapping_next(key(10), None), key(11));
+    }
+}
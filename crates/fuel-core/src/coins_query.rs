@@ -3,24 +3,40 @@ use crate::{
         database::ReadView,
         storage::coins::CoinsToSpendIndexKey,
     },
-    graphql_api::ports::CoinsToSpendIndexIter,
+    graphql_api::{
+        coin_reservations::{
+            CoinReservations,
+            SessionId,
+        },
+        ports::{
+            CoinTags,
+            CoinsBlacklist,
+            CoinsToSpendIndexIter,
+        },
+    },
     query::asset_query::{
         AssetQuery,
         AssetSpendTarget,
         Exclude,
     },
 };
+use async_graphql::ErrorExtensions;
 use core::mem::swap;
 use fuel_core_services::yield_stream::StreamYieldExt;
 use fuel_core_storage::{
+    iter::BoxedIter,
     Error as StorageError,
     Result as StorageResult,
 };
 use fuel_core_types::{
-    entities::coins::CoinType,
+    entities::coins::{
+        CoinId,
+        CoinType,
+    },
     fuel_types::{
         Address,
         AssetId,
+        BlockHeight,
     },
 };
 use futures::{
@@ -32,6 +48,8 @@ use rand::prelude::*;
 use std::{
     borrow::Cow,
     cmp::Reverse,
+    collections::HashSet,
+    sync::Arc,
 };
 use thiserror::Error;
 
@@ -44,15 +62,40 @@ pub enum CoinsQueryError {
         asset_id: AssetId,
         collected_amount: u128,
         max: u16,
+        diagnosis: SelectionDiagnosis,
+    },
+    #[error("the target cannot be met with at most {max} coins without exceeding it by more than {max_overshoot}.")]
+    OvershootCapExceeded {
+        asset_id: AssetId,
+        max_overshoot: u128,
+        max: u16,
+    },
+    #[error(
+        "the target cannot be met without exceeding the size limit of {max_size} bytes."
+    )]
+    SizeLimitExceeded {
+        asset_id: AssetId,
+        collected_amount: u128,
+        max_size: u64,
+    },
+    #[error("the target cannot be met while keeping {reserve_count} coins unspent.")]
+    InsufficientCoinsAboveReserve {
+        asset_id: AssetId,
+        collected_amount: u128,
+        reserve_count: u16,
     },
     #[error("the query contains duplicate assets")]
     DuplicateAssets(AssetId),
+    #[error("the coins of the owner {0} are blacklisted")]
+    BlacklistedOwner(Address),
     #[error(
         "too many excluded ids: provided ({provided}) is > than allowed ({allowed})"
     )]
     TooManyExcludedId { provided: usize, allowed: u16 },
     #[error("the query requires more coins than the max allowed coins: required ({required}) > max ({max})")]
     TooManyCoinsSelected { required: usize, max: u16 },
+    #[error("the selection contains too many coins across all assets: selected ({selected}) > max ({max})")]
+    TooManyCoinsInResult { selected: usize, max: usize },
     #[error("coins to spend index entry contains wrong coin foreign key")]
     IncorrectCoinForeignKeyInIndex,
     #[error("coins to spend index entry contains wrong message foreign key")]
@@ -65,6 +108,84 @@ pub enum CoinsQueryError {
     Other(anyhow::Error),
 }
 
+/// The constraint that prevented the selection from reaching the target.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum BindingConstraint {
+    /// The available coins cover the target, but not within the `max` number of coins.
+    Max,
+    /// The total amount of the available coins is below the target.
+    #[default]
+    Balance,
+}
+
+impl BindingConstraint {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BindingConstraint::Max => "MAX",
+            BindingConstraint::Balance => "BALANCE",
+        }
+    }
+}
+
+/// The maximum number of the coins counted by the diagnosis of the failed selection.
+pub const MAX_DIAGNOSED_COINS: usize = 10_000;
+
+/// The diagnosis of the selection that couldn't reach the target.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SelectionDiagnosis {
+    /// The requested target amount.
+    pub target: u128,
+    /// The total amount of the coins available for the selection.
+    pub available_amount: u128,
+    /// The number of the coins available for the selection.
+    pub available_count: usize,
+    /// The constraint that prevented the selection from reaching the target.
+    pub binding_constraint: BindingConstraint,
+    /// Whether all coins were counted. Otherwise, the counting stopped after
+    /// `MAX_DIAGNOSED_COINS` coins, and the available amount and count are lower bounds.
+    pub complete: bool,
+}
+
+impl SelectionDiagnosis {
+    pub fn new(
+        target: u128,
+        available_amount: u128,
+        available_count: usize,
+        complete: bool,
+    ) -> Self {
+        let binding_constraint = if available_amount >= target {
+            BindingConstraint::Max
+        } else {
+            BindingConstraint::Balance
+        };
+
+        Self {
+            target,
+            available_amount,
+            available_count,
+            binding_constraint,
+            complete,
+        }
+    }
+}
+
+impl ErrorExtensions for CoinsQueryError {
+    fn extend(&self) -> async_graphql::Error {
+        async_graphql::Error::new(self.to_string()).extend_with(|_, extensions| {
+            if let CoinsQueryError::InsufficientCoinsForTheMax { diagnosis, .. } = self {
+                // Amounts are strings, like the `U128` scalar, to not lose the precision.
+                extensions.set("target", diagnosis.target.to_string());
+                extensions
+                    .set("availableAmount", diagnosis.available_amount.to_string());
+                extensions.set("availableCount", diagnosis.available_count);
+                extensions
+                    .set("bindingConstraint", diagnosis.binding_constraint.as_str());
+                extensions.set("diagnosisComplete", diagnosis.complete);
+            }
+        })
+    }
+}
+
 #[cfg(test)]
 impl PartialEq for CoinsQueryError {
     fn eq(&self, other: &Self) -> bool {
@@ -129,22 +250,94 @@ impl<'s> SpendQuery<'s> {
     }
 }
 
+/// Layers the `blacklist` on top of the coins excluded by the caller.
+/// Fails if the coins of any of the `owners` are blacklisted.
+pub fn apply_blacklist(
+    blacklist: &dyn CoinsBlacklist,
+    owners: impl IntoIterator<Item = Address>,
+    exclude: &mut Exclude,
+) -> Result<(), CoinsQueryError> {
+    if let Some(owner) = owners
+        .into_iter()
+        .find(|owner| blacklist.contains_owner(owner))
+    {
+        return Err(CoinsQueryError::BlacklistedOwner(owner));
+    }
+
+    for coin_id in blacklist.coins() {
+        exclude.exclude(coin_id);
+    }
+
+    Ok(())
+}
+
+/// Layers the coin `reservations` on top of the coins excluded by the caller.
+/// The coins reserved by sessions other than the `session` aren't selected.
+pub fn apply_reservations(
+    reservations: &CoinReservations,
+    session: Option<SessionId>,
+    exclude: &mut Exclude,
+) {
+    let reservations = reservations.clone();
+    exclude.retain(Arc::new(move |coin_id| {
+        !reservations.is_reserved_by_others(coin_id, session.as_ref())
+    }));
+}
+
+/// Layers the tag filter on top of the coins excluded by the caller. The coins
+/// without any of the `allowed` tags or with any of the `denied` tags aren't selected.
+/// An empty `allowed` set allows all tags. Does nothing if the tagging isn't configured.
+pub fn apply_coin_tags(
+    coin_tags: Arc<dyn CoinTags>,
+    allowed: HashSet<String>,
+    denied: HashSet<String>,
+    exclude: &mut Exclude,
+) {
+    if !coin_tags.is_configured() || (allowed.is_empty() && denied.is_empty()) {
+        return
+    }
+
+    exclude.retain(Arc::new(move |coin_id: &CoinId| {
+        let tags = coin_tags.tags(coin_id);
+        let is_allowed =
+            allowed.is_empty() || tags.iter().any(|tag| allowed.contains(tag));
+        let is_denied = tags.iter().any(|tag| denied.contains(tag));
+        is_allowed && !is_denied
+    }));
+}
+
+/// Returns the coins of the `owner` for the asset available for the selection.
+/// The `reserve_count` smallest coins are left out, so they stay unspent.
+async fn unreserved_coins(
+    query: AssetQuery<'_>,
+) -> Result<Vec<CoinType>, CoinsQueryError> {
+    let reserve_count = query.asset.reserve_count.unwrap_or_default() as usize;
+    let mut inputs: Vec<CoinType> = query.coins().try_collect().await?;
+
+    if reserve_count > 0 {
+        inputs.sort_by_key(|coin| coin.amount());
+        inputs.drain(..reserve_count.min(inputs.len()));
+    }
+
+    Ok(inputs)
+}
+
 /// Returns the biggest inputs of the `owner` to satisfy the required `target` of the asset. The
 /// number of inputs for each asset can't exceed `max_inputs`, otherwise throw an error that query
 /// can't be satisfied.
 pub async fn largest_first(
     query: AssetQuery<'_>,
 ) -> Result<Vec<CoinType>, CoinsQueryError> {
-    let target = query.asset.target;
-    let max = query.asset.max;
-    let asset_id = query.asset.id;
-    let mut inputs: Vec<CoinType> = query.coins().try_collect().await?;
+    let asset = query.asset;
+    let target = asset.target;
+    let max = asset.max;
+    let mut inputs: Vec<CoinType> = unreserved_coins(query).await?;
     inputs.sort_by_key(|coin| Reverse(coin.amount()));
 
     let mut collected_amount = 0u128;
     let mut coins = vec![];
 
-    for coin in inputs {
+    for coin in &inputs {
         // Break if we don't need any more coins
         if collected_amount >= target {
             break
@@ -152,29 +345,232 @@ pub async fn largest_first(
 
         // Error if we can't fit more coins
         if coins.len() >= max as usize {
-            return Err(CoinsQueryError::InsufficientCoinsForTheMax {
-                asset_id,
-                collected_amount,
-                max,
-            })
+            return Err(insufficient_coins(asset, &inputs, collected_amount))
         }
 
         // Add to list
         collected_amount = collected_amount.saturating_add(coin.amount() as u128);
-        coins.push(coin);
+        coins.push(*coin);
     }
 
     if collected_amount < target {
-        return Err(CoinsQueryError::InsufficientCoinsForTheMax {
-            asset_id,
-            collected_amount,
-            max,
-        })
+        return Err(insufficient_coins(asset, &inputs, collected_amount))
     }
 
     Ok(coins)
 }
 
+/// Returns the error for the selection of the `asset` that collected only `collected_amount`
+/// from the available `inputs` sorted from the biggest to the smallest.
+fn insufficient_coins(
+    asset: &AssetSpendTarget,
+    inputs: &[CoinType],
+    collected_amount: u128,
+) -> CoinsQueryError {
+    match asset.reserve_count {
+        Some(reserve_count) if reserve_count > 0 => {
+            CoinsQueryError::InsufficientCoinsAboveReserve {
+                asset_id: asset.id,
+                collected_amount,
+                reserve_count,
+            }
+        }
+        _ => {
+            let diagnosed = &inputs[..inputs.len().min(MAX_DIAGNOSED_COINS)];
+            let available_amount = diagnosed.iter().fold(0u128, |total, coin| {
+                total.saturating_add(coin.amount() as u128)
+            });
+            CoinsQueryError::InsufficientCoinsForTheMax {
+                asset_id: asset.id,
+                collected_amount,
+                max: asset.max,
+                diagnosis: SelectionDiagnosis::new(
+                    asset.target,
+                    available_amount,
+                    diagnosed.len(),
+                    diagnosed.len() == inputs.len(),
+                ),
+            }
+        }
+    }
+}
+
+/// Returns the biggest inputs of the `owner` for each asset of the `spend_query`, like
+/// [`largest_first`], while the estimated size of the inputs of all assets stays within
+/// `max_size` bytes. The size of each input is estimated by the `input_size`. The coins
+/// that don't fit into the remaining size are skipped in favor of the smaller ones.
+pub async fn largest_first_within_size(
+    db: &ReadView,
+    spend_query: &SpendQuery<'_>,
+    input_size: impl Fn(&CoinType) -> u64,
+    max_size: u64,
+) -> Result<Vec<Vec<CoinType>>, CoinsQueryError> {
+    let mut size = 0u64;
+    let mut coins_per_asset = vec![];
+
+    for query in spend_query.asset_queries(db) {
+        let asset = query.asset;
+        let mut inputs: Vec<CoinType> = unreserved_coins(query).await?;
+        inputs.sort_by_key(|coin| Reverse(coin.amount()));
+
+        let mut collected_amount = 0u128;
+        let mut coins = vec![];
+        let mut skipped_for_size = false;
+
+        for coin in &inputs {
+            if collected_amount >= asset.target || coins.len() >= asset.max as usize {
+                break
+            }
+
+            let Some(new_size) = size
+                .checked_add(input_size(coin))
+                .filter(|new_size| *new_size <= max_size)
+            else {
+                skipped_for_size = true;
+                continue
+            };
+
+            size = new_size;
+            collected_amount = collected_amount.saturating_add(coin.amount() as u128);
+            coins.push(*coin);
+        }
+
+        if collected_amount < asset.target {
+            if skipped_for_size {
+                return Err(CoinsQueryError::SizeLimitExceeded {
+                    asset_id: asset.id,
+                    collected_amount,
+                    max_size,
+                })
+            }
+            return Err(insufficient_coins(asset, &inputs, collected_amount))
+        }
+        coins_per_asset.push(coins);
+    }
+
+    Ok(coins_per_asset)
+}
+
+/// Returns the biggest inputs of the `owner` for the asset, up to `max_inputs` of them.
+/// Unlike [`largest_first`], doesn't fail if the `target` can't be reached, so it can be
+/// used as the best partial selection when the `max` limit doesn't allow covering the target.
+pub async fn largest_up_to_max(
+    query: AssetQuery<'_>,
+) -> Result<Vec<CoinType>, CoinsQueryError> {
+    let max = query.asset.max;
+    let mut inputs: Vec<CoinType> = unreserved_coins(query).await?;
+    inputs.sort_by_key(|coin| Reverse(coin.amount()));
+    inputs.truncate(max as usize);
+
+    Ok(inputs)
+}
+
+/// Returns the inputs of the `owner` that cover the `target` of the asset without exceeding
+/// it by more than `max_overshoot`, preferring the selection with the smallest total.
+/// Considers the smallest single coin that fits the bounds and the biggest coins that
+/// together fit the bounds, so the selection may not be optimal, but is found in linear time.
+pub async fn closest_to_target(
+    query: AssetQuery<'_>,
+    max_overshoot: u128,
+) -> Result<Vec<CoinType>, CoinsQueryError> {
+    let target = query.asset.target;
+    let max = query.asset.max;
+    let asset_id = query.asset.id;
+    let upper_bound = target.saturating_add(max_overshoot);
+    let mut inputs: Vec<CoinType> = unreserved_coins(query).await?;
+    inputs.sort_by_key(|coin| Reverse(coin.amount()));
+
+    let overshoot_cap_exceeded = || CoinsQueryError::OvershootCapExceeded {
+        asset_id,
+        max_overshoot,
+        max,
+    };
+
+    if max == 0 {
+        return Err(overshoot_cap_exceeded())
+    }
+
+    let single_coin = inputs
+        .iter()
+        .rev()
+        .find(|coin| (target..=upper_bound).contains(&(coin.amount() as u128)));
+
+    let mut collected_amount = 0u128;
+    let mut coins = vec![];
+    for coin in &inputs {
+        if collected_amount >= target || coins.len() >= max as usize {
+            break
+        }
+
+        let amount = collected_amount.saturating_add(coin.amount() as u128);
+        // Skip the coin if it makes the selection exceed the overshoot cap.
+        if amount > upper_bound {
+            continue
+        }
+
+        collected_amount = amount;
+        coins.push(coin);
+    }
+
+    let selection = match single_coin {
+        Some(single_coin)
+            if collected_amount < target
+                || single_coin.amount() as u128 <= collected_amount =>
+        {
+            vec![single_coin]
+        }
+        _ if collected_amount >= target => coins,
+        _ => return Err(overshoot_cap_exceeded()),
+    };
+
+    Ok(selection.into_iter().copied().collect())
+}
+
+/// Enumerates the candidate selections of the inputs of the `owner` that cover
+/// the `target` of the asset with at most `max` coins. Each candidate takes the biggest
+/// coins starting from a different coin, so the candidates range from the fewest coins
+/// with the biggest overshoot to more coins with a smaller overshoot. At most `limit`
+/// candidates are returned, starting from the one with the biggest coins.
+pub async fn candidate_selections(
+    query: AssetQuery<'_>,
+    limit: usize,
+) -> Result<Vec<Vec<CoinType>>, CoinsQueryError> {
+    let target = query.asset.target;
+    let max = query.asset.max as usize;
+    if target == 0 {
+        return Ok(vec![vec![]])
+    }
+
+    let mut inputs: Vec<CoinType> = unreserved_coins(query).await?;
+    inputs.sort_by_key(|coin| Reverse(coin.amount()));
+
+    let mut candidates = vec![];
+    for start in 0..inputs.len() {
+        if candidates.len() >= limit {
+            break
+        }
+
+        let mut collected_amount = 0u128;
+        let mut coins = vec![];
+        for coin in inputs.iter().skip(start).take(max) {
+            if collected_amount >= target {
+                break
+            }
+            collected_amount = collected_amount.saturating_add(coin.amount() as u128);
+            coins.push(*coin);
+        }
+
+        // The following candidates start from smaller coins,
+        // so they can't cover the target either.
+        if collected_amount < target {
+            break
+        }
+        candidates.push(coins);
+    }
+
+    Ok(candidates)
+}
+
 // An implementation of the method described on: https://iohk.io/en/blog/posts/2018/07/03/self-organisation-in-coin-selection/
 pub async fn random_improve(
     db: &ReadView,
@@ -185,9 +581,33 @@ pub async fn random_improve(
     for query in spend_query.asset_queries(db) {
         let target = query.asset.target;
         let max = query.asset.max;
+        let preferred_count = query.asset.preferred_count;
+
+        // The random selection may overshoot the target up to twice,
+        // so the capped selection is used instead if the cap is requested.
+        if let Some(max_overshoot) = query.asset.max_overshoot {
+            coins_per_asset.push(closest_to_target(query, max_overshoot).await?);
+            continue
+        }
 
-        let mut inputs: Vec<_> = query.clone().coins().try_collect().await?;
+        let mut inputs = unreserved_coins(query.clone()).await?;
+        let preferred_change_outputs = query.asset.preferred_change_outputs;
+        let candidates = preferred_change_outputs.map(|_| inputs.clone());
+        // The largest coin is the most linkable one, so it is penalized if requested:
+        // it is left out of the random selection and is used only if the target
+        // can't be met within `max` without it.
+        let other_coins = if query.asset.avoid_largest_coin {
+            take_largest_coin(&mut inputs).map(|_| inputs.clone())
+        } else {
+            None
+        };
         inputs.shuffle(&mut thread_rng());
+        // The sort is stable, so the coins created at the same height stay shuffled.
+        // If the deepest coins can't meet the target within `max`, the selection
+        // falls back to the largest coins regardless of their depth.
+        if query.asset.prefer_deep_coins {
+            inputs.sort_by_key(creation_height);
+        }
         inputs.truncate(max as usize);
 
         let mut collected_amount = 0;
@@ -206,15 +626,26 @@ pub async fn random_improve(
                     break
                 }
 
-                // Break if adding doesn't improve the distance
-                let change_amount = collected_amount
-                    .checked_sub(target)
-                    .expect("We checked it above");
-                let distance = target.abs_diff(change_amount);
-                let next_distance =
-                    target.abs_diff(change_amount.saturating_add(coin.amount() as u128));
-                if next_distance >= distance {
-                    break
+                match preferred_count {
+                    // Reaching the preferred number of coins scores higher
+                    // than improving the distance.
+                    Some(preferred_count) => {
+                        if coins.len() >= preferred_count as usize {
+                            break
+                        }
+                    }
+                    None => {
+                        // Break if adding doesn't improve the distance
+                        let change_amount = collected_amount
+                            .checked_sub(target)
+                            .expect("We checked it above");
+                        let distance = target.abs_diff(change_amount);
+                        let next_distance = target
+                            .abs_diff(change_amount.saturating_add(coin.amount() as u128));
+                        if next_distance >= distance {
+                            break
+                        }
+                    }
                 }
             }
 
@@ -225,7 +656,31 @@ pub async fn random_improve(
 
         // Fallback to largest_first if we can't fit more coins
         if collected_amount < target {
-            swap(&mut coins, &mut largest_first(query).await?);
+            match other_coins.and_then(|other| largest_within_max(other, target, max)) {
+                Some(mut other) => swap(&mut coins, &mut other),
+                None => swap(&mut coins, &mut largest_first(query).await?),
+            }
+        }
+
+        // The random selection is kept if it already produces the preferred number
+        // of change outputs, or if no other coins within `max` produce it.
+        if let (Some(preferred_change_outputs), Some(candidates)) =
+            (preferred_change_outputs, candidates)
+        {
+            let collected_amount = coins.iter().fold(0u128, |total, coin| {
+                total.saturating_add(coin.amount() as u128)
+            });
+            let has_change = collected_amount > target;
+            let other = match (preferred_change_outputs, has_change) {
+                (0, true) => exact_within_max(candidates, target, max),
+                (1.., false) => {
+                    largest_within_max(candidates, target.saturating_add(1), max)
+                }
+                _ => None,
+            };
+            if let Some(mut other) = other {
+                swap(&mut coins, &mut other);
+            }
         }
 
         coins_per_asset.push(coins);
@@ -234,6 +689,72 @@ pub async fn random_improve(
     Ok(coins_per_asset)
 }
 
+/// Returns the height of the block that created the coin. The message coins are relayed
+/// from the finalized DA layer, so they are treated as the deepest ones.
+fn creation_height(coin: &CoinType) -> BlockHeight {
+    match coin {
+        CoinType::Coin(coin) => coin.tx_pointer.block_height(),
+        CoinType::MessageCoin(_) => BlockHeight::default(),
+    }
+}
+
+/// Removes the largest coin from the `inputs` and returns it.
+fn take_largest_coin(inputs: &mut Vec<CoinType>) -> Option<CoinType> {
+    let (index, _) = inputs
+        .iter()
+        .enumerate()
+        .max_by_key(|(_, coin)| coin.amount())?;
+    Some(inputs.swap_remove(index))
+}
+
+/// Returns the biggest of the `inputs` covering the `target` with at most `max` of them,
+/// or `None` if they can't cover it.
+fn largest_within_max(
+    mut inputs: Vec<CoinType>,
+    target: u128,
+    max: u16,
+) -> Option<Vec<CoinType>> {
+    inputs.sort_by_key(|coin| Reverse(coin.amount()));
+
+    let mut collected_amount = 0u128;
+    let mut coins = vec![];
+    for coin in inputs {
+        if collected_amount >= target || coins.len() >= max as usize {
+            break
+        }
+        collected_amount = collected_amount.saturating_add(coin.amount() as u128);
+        coins.push(coin);
+    }
+
+    (collected_amount >= target).then_some(coins)
+}
+
+/// Returns the coins of the `inputs` matching the `target` exactly with at most `max`
+/// of them, or `None` if they are not found. The biggest coins that fit into
+/// the remaining amount are taken first.
+fn exact_within_max(
+    mut inputs: Vec<CoinType>,
+    target: u128,
+    max: u16,
+) -> Option<Vec<CoinType>> {
+    inputs.sort_by_key(|coin| Reverse(coin.amount()));
+
+    let mut remaining_amount = target;
+    let mut coins = vec![];
+    for coin in inputs {
+        if remaining_amount == 0 || coins.len() >= max as usize {
+            break
+        }
+        if let Some(rest) = remaining_amount.checked_sub(coin.amount() as u128) {
+            remaining_amount = rest;
+            coins.push(coin);
+        }
+    }
+
+    (remaining_amount == 0).then_some(coins)
+}
+
+#[allow(clippy::too_many_arguments)]
 pub async fn select_coins_to_spend(
     CoinsToSpendIndexIter {
         big_coins_iter,
@@ -241,6 +762,7 @@ pub async fn select_coins_to_spend(
     }: CoinsToSpendIndexIter<'_>,
     total: u128,
     max: u16,
+    preferred_count: Option<u16>,
     asset_id: &AssetId,
     exclude: &Exclude,
     batch_size: usize,
@@ -271,17 +793,29 @@ pub async fn select_coins_to_spend(
 
     let adjusted_total = total.saturating_mul(TOTAL_AMOUNT_ADJUSTMENT_FACTOR);
 
-    let big_coins_stream = futures::stream::iter(big_coins_iter).yield_each(batch_size);
+    let mut big_coins_stream =
+        futures::stream::iter(big_coins_iter).yield_each(batch_size);
     let dust_coins_stream = futures::stream::iter(dust_coins_iter).yield_each(batch_size);
 
     let (selected_big_coins_total, selected_big_coins) =
-        big_coins(big_coins_stream, adjusted_total, max, exclude).await?;
+        big_coins(&mut big_coins_stream, adjusted_total, max, exclude).await?;
 
     if selected_big_coins_total < total {
+        // The selection stops before taking the coin above the `max`, so the rest
+        // of the stream contains the coins that were not considered.
+        let (remaining_total, remaining_count, complete) =
+            available_coins(big_coins_stream, exclude).await?;
+        let diagnosis = SelectionDiagnosis::new(
+            total,
+            selected_big_coins_total.saturating_add(remaining_total),
+            selected_big_coins.len().saturating_add(remaining_count),
+            complete,
+        );
         return Err(CoinsQueryError::InsufficientCoinsForTheMax {
             asset_id: *asset_id,
             collected_amount: selected_big_coins_total,
             max,
+            diagnosis,
         });
     }
 
@@ -304,8 +838,17 @@ pub async fn select_coins_to_spend(
         }
     })?;
 
-    let max_dust_count =
-        max_dust_count(max, number_of_big_coins, DUST_TO_BIG_COINS_FACTOR);
+    // If the preferred number of coins is requested, the number of dust coins is chosen
+    // to fill the selection up to it instead of being random.
+    let max_dust_count = match preferred_count {
+        Some(preferred_count) => preferred_dust_count(
+            max,
+            number_of_big_coins,
+            DUST_TO_BIG_COINS_FACTOR,
+            preferred_count,
+        ),
+        None => max_dust_count(max, number_of_big_coins, DUST_TO_BIG_COINS_FACTOR),
+    };
     let (dust_coins_total, selected_dust_coins) = dust_coins(
         dust_coins_stream,
         last_selected_big_coin,
@@ -323,6 +866,116 @@ pub async fn select_coins_to_spend(
     .collect())
 }
 
+/// Whether the target can be covered by the coins of the owner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CoinsFeasibility {
+    /// Whether the target can be covered by at most `max` coins.
+    pub feasible: bool,
+    /// The number of the biggest coins required to cover the target,
+    /// or the number of all available coins if they don't cover it.
+    /// The scan stops after `max + 1` coins, so it is at most `max + 1`.
+    pub coins_needed: u16,
+    /// The amount of the scanned coins. The scan stops at the coin that covers
+    /// the target or after `max + 1` coins, so it is the amount of all available
+    /// coins only if they can't cover the target within the `max`.
+    pub total_available: u128,
+}
+
+/// Checks whether the `total` can be covered by at most `max` coins without selecting
+/// them. The biggest coins are taken first, and the scan of the index stops as soon as
+/// the `total` is reached or more than `max` coins are needed.
+pub async fn coins_feasibility(
+    CoinsToSpendIndexIter { big_coins_iter, .. }: CoinsToSpendIndexIter<'_>,
+    total: u128,
+    max: u16,
+    exclude: &Exclude,
+    batch_size: usize,
+) -> Result<CoinsFeasibility, CoinsQueryError> {
+    let mut big_coins_stream =
+        futures::stream::iter(big_coins_iter).yield_each(batch_size);
+
+    let mut total_available: u128 = 0;
+    let mut coins_needed: usize = 0;
+    while total_available < total && coins_needed <= max as usize {
+        let Some(coin) = big_coins_stream.next().await else {
+            break
+        };
+        let coin = coin?;
+        if !is_excluded(&coin, exclude) {
+            total_available = total_available.saturating_add(coin.amount() as u128);
+            coins_needed = coins_needed.saturating_add(1);
+        }
+    }
+
+    Ok(CoinsFeasibility {
+        feasible: total_available >= total && coins_needed <= max as usize,
+        coins_needed: u16::try_from(coins_needed).unwrap_or(u16::MAX),
+        total_available,
+    })
+}
+
+/// Selects the biggest coins from the index until they cover the `total`.
+/// Fails if more than `max` coins are needed.
+pub async fn largest_from_index(
+    CoinsToSpendIndexIter { big_coins_iter, .. }: CoinsToSpendIndexIter<'_>,
+    total: u128,
+    max: u16,
+    asset_id: &AssetId,
+    exclude: &Exclude,
+    batch_size: usize,
+) -> Result<Vec<CoinsToSpendIndexKey>, CoinsQueryError> {
+    select_from_index(big_coins_iter, total, max, asset_id, exclude, batch_size).await
+}
+
+/// Selects the smallest coins from the index until they cover the `total`,
+/// which consolidates them. Fails if more than `max` coins are needed.
+pub async fn smallest_from_index(
+    CoinsToSpendIndexIter {
+        dust_coins_iter, ..
+    }: CoinsToSpendIndexIter<'_>,
+    total: u128,
+    max: u16,
+    asset_id: &AssetId,
+    exclude: &Exclude,
+    batch_size: usize,
+) -> Result<Vec<CoinsToSpendIndexKey>, CoinsQueryError> {
+    select_from_index(dust_coins_iter, total, max, asset_id, exclude, batch_size).await
+}
+
+/// Selects the coins in the order of the `coins_iter` until they cover the `total`.
+async fn select_from_index(
+    coins_iter: BoxedIter<'_, StorageResult<CoinsToSpendIndexKey>>,
+    total: u128,
+    max: u16,
+    asset_id: &AssetId,
+    exclude: &Exclude,
+    batch_size: usize,
+) -> Result<Vec<CoinsToSpendIndexKey>, CoinsQueryError> {
+    let mut coins_stream = futures::stream::iter(coins_iter).yield_each(batch_size);
+
+    let (selected_total, selected) =
+        big_coins(&mut coins_stream, total, max, exclude).await?;
+
+    if selected_total < total {
+        let (remaining_total, remaining_count, complete) =
+            available_coins(coins_stream, exclude).await?;
+        let diagnosis = SelectionDiagnosis::new(
+            total,
+            selected_total.saturating_add(remaining_total),
+            selected.len().saturating_add(remaining_count),
+            complete,
+        );
+        return Err(CoinsQueryError::InsufficientCoinsForTheMax {
+            asset_id: *asset_id,
+            collected_amount: selected_total,
+            max,
+            diagnosis,
+        });
+    }
+
+    Ok(selected)
+}
+
 async fn big_coins(
     big_coins_stream: impl Stream<Item = StorageResult<CoinsToSpendIndexKey>> + Unpin,
     total: u128,
@@ -358,10 +1011,13 @@ where
 {
     let mut coins_total_value: u128 = 0;
     let mut coins = Vec::with_capacity(max as usize);
-    while let Some(coin) = coins_stream.next().await {
+    while coins.len() < max as usize {
+        let Some(coin) = coins_stream.next().await else {
+            break;
+        };
         let coin = coin?;
         if !is_excluded(&coin, exclude) {
-            if coins.len() >= max as usize || predicate(&coin, coins_total_value) {
+            if predicate(&coin, coins_total_value) {
                 break;
             }
             let amount = coin.amount() as u128;
@@ -372,23 +1028,67 @@ where
     Ok((coins_total_value, coins))
 }
 
-fn is_excluded(key: &CoinsToSpendIndexKey, exclude: &Exclude) -> bool {
-    match key {
+/// Returns the total amount and the number of the not excluded coins in the stream,
+/// and whether the whole stream was scanned. The scan stops after
+/// `MAX_DIAGNOSED_COINS` coins.
+async fn available_coins(
+    coins_stream: impl Stream<Item = StorageResult<CoinsToSpendIndexKey>> + Unpin,
+    exclude: &Exclude,
+) -> Result<(u128, usize, bool), CoinsQueryError> {
+    let mut coins_stream = coins_stream.take(MAX_DIAGNOSED_COINS);
+    let mut total: u128 = 0;
+    let mut count: usize = 0;
+    let mut scanned: usize = 0;
+    while let Some(coin) = coins_stream.next().await {
+        let coin = coin?;
+        scanned = scanned.saturating_add(1);
+        if !is_excluded(&coin, exclude) {
+            total = total.saturating_add(coin.amount() as u128);
+            count = count.saturating_add(1);
+        }
+    }
+    let complete = scanned < MAX_DIAGNOSED_COINS
+        || coins_stream.into_inner().next().await.is_none();
+    Ok((total, count, complete))
+}
+
+fn is_excluded(key: &CoinsToSpendIndexKey, exclude: &Exclude) -> bool {
+    let excluded_id = match key {
         CoinsToSpendIndexKey::Coin { utxo_id, .. } => exclude.contains_coin(utxo_id),
         CoinsToSpendIndexKey::Message { nonce, .. } => exclude.contains_message(nonce),
-    }
+    };
+    excluded_id || exclude.is_below_min(key.asset_id(), key.amount())
 }
 
 fn max_dust_count(max: u16, big_coins_len: u16, dust_to_big_coins_factor: u16) -> u16 {
     let mut rng = rand::thread_rng();
 
-    let max_from_factor = big_coins_len.saturating_mul(dust_to_big_coins_factor);
-    let max_adjusted = max.saturating_sub(big_coins_len);
-    let upper_bound = max_from_factor.min(max_adjusted);
+    let upper_bound = dust_count_upper_bound(max, big_coins_len, dust_to_big_coins_factor);
 
     rng.gen_range(0..=upper_bound)
 }
 
+fn preferred_dust_count(
+    max: u16,
+    big_coins_len: u16,
+    dust_to_big_coins_factor: u16,
+    preferred_count: u16,
+) -> u16 {
+    let upper_bound = dust_count_upper_bound(max, big_coins_len, dust_to_big_coins_factor);
+
+    preferred_count.saturating_sub(big_coins_len).min(upper_bound)
+}
+
+fn dust_count_upper_bound(
+    max: u16,
+    big_coins_len: u16,
+    dust_to_big_coins_factor: u16,
+) -> u16 {
+    let max_from_factor = big_coins_len.saturating_mul(dust_to_big_coins_factor);
+    let max_adjusted = max.saturating_sub(big_coins_len);
+    max_from_factor.min(max_adjusted)
+}
+
 fn skip_big_coins_up_to_amount(
     big_coins: impl IntoIterator<Item = CoinsToSpendIndexKey>,
     skipped_amount: u128,
@@ -424,7 +1124,9 @@ mod tests {
     use crate::{
         coins_query::{
             largest_first,
+            largest_up_to_max,
             max_dust_count,
+            preferred_dust_count,
             random_improve,
             CoinsQueryError,
             SpendQuery,
@@ -631,7 +1333,8 @@ mod tests {
                             Err(CoinsQueryError::InsufficientCoinsForTheMax {
                                 asset_id: _,
                                 collected_amount: 15,
-                                max: u16::MAX
+                                max: u16::MAX,
+                                ..
                             })
                         )
                     }
@@ -685,29 +1388,657 @@ mod tests {
                     AssetSpendTarget::new(asset_ids[1], 6, u16::MAX),
                 ],
                 &owner,
-                base_asset_id,
-                &db.service_database(),
-            )
-            .await;
-            let expected = vec![
-                vec![(asset_ids[0], 5)],
-                vec![(asset_ids[1], 5), (asset_ids[1], 4)],
-            ];
-            assert_matches!(coins, Ok(coins) if coins == expected);
+                base_asset_id,
+                &db.service_database(),
+            )
+            .await;
+            let expected = vec![
+                vec![(asset_ids[0], 5)],
+                vec![(asset_ids[1], 5), (asset_ids[1], 4)],
+            ];
+            assert_matches!(coins, Ok(coins) if coins == expected);
+        }
+
+        #[tokio::test]
+        async fn multiple_assets_coins() {
+            // Setup coins
+            let (owner, asset_ids, base_asset_id, db) = setup_coins();
+            multiple_assets_helper(owner, &asset_ids, &base_asset_id, db).await;
+        }
+
+        #[tokio::test]
+        async fn multiple_assets_coins_and_messages() {
+            // Setup coins and messages
+            let (owner, asset_ids, base_asset_id, db) = setup_coins_and_messages();
+            multiple_assets_helper(owner, &asset_ids, &base_asset_id, db).await;
+        }
+    }
+
+    mod largest_up_to_max {
+        use super::*;
+
+        #[tokio::test]
+        async fn returns_biggest_coins_when_target_is_not_reachable() {
+            // Given
+            let (owner, asset_ids, base_asset_id, db) = setup_coins();
+            let asset = AssetSpendTarget::new(asset_ids[0], 100, 2);
+            let db = db.service_database();
+
+            // When
+            let coins = largest_up_to_max(AssetQuery::new(
+                &owner,
+                &asset,
+                &base_asset_id,
+                None,
+                &db.test_view(),
+            ))
+            .await
+            .unwrap();
+
+            // Then
+            let amounts = coins.iter().map(|coin| coin.amount()).collect_vec();
+            assert_eq!(amounts, vec![5, 4]);
+        }
+    }
+
+    mod closest_to_target {
+        use super::*;
+
+        async fn select(
+            target: u128,
+            max_overshoot: u128,
+            max: u16,
+        ) -> Result<Vec<Word>, CoinsQueryError> {
+            let (owner, asset_ids, base_asset_id, db) = setup_coins();
+            let asset = AssetSpendTarget::new(asset_ids[0], target, max);
+            let db = db.service_database();
+            let coins = closest_to_target(
+                AssetQuery::new(&owner, &asset, &base_asset_id, None, &db.test_view()),
+                max_overshoot,
+            )
+            .await?;
+
+            Ok(coins.iter().map(|coin| coin.amount()).collect())
+        }
+
+        #[tokio::test]
+        async fn skips_coins_exceeding_the_overshoot_cap() {
+            // When
+            let amounts = select(6, 0, 5).await.unwrap();
+
+            // Then
+            assert_eq!(amounts, vec![5, 1]);
+        }
+
+        #[tokio::test]
+        async fn prefers_single_coin_closer_to_the_target() {
+            // When
+            let amounts = select(3, 1, 5).await.unwrap();
+
+            // Then
+            assert_eq!(amounts, vec![3]);
+        }
+
+        #[tokio::test]
+        async fn fails_when_target_cannot_be_met_within_the_cap() {
+            // When
+            let result = select(14, 0, 2).await;
+
+            // Then
+            assert!(matches!(
+                result,
+                Err(CoinsQueryError::OvershootCapExceeded {
+                    max_overshoot: 0,
+                    max: 2,
+                    ..
+                })
+            ));
+        }
+    }
+
+    mod candidate_selections {
+        use super::*;
+
+        async fn select(target: u128, max: u16, limit: usize) -> Vec<Vec<Word>> {
+            let (owner, asset_ids, base_asset_id, db) = setup_coins();
+            let asset = AssetSpendTarget::new(asset_ids[0], target, max);
+            let db = db.service_database();
+            let candidates = candidate_selections(
+                AssetQuery::new(&owner, &asset, &base_asset_id, None, &db.test_view()),
+                limit,
+            )
+            .await
+            .unwrap();
+
+            candidates
+                .iter()
+                .map(|coins| coins.iter().map(|coin| coin.amount()).collect())
+                .collect()
+        }
+
+        #[tokio::test]
+        async fn enumerates_selections_starting_from_each_coin() {
+            // When
+            let candidates = select(6, 5, 10).await;
+
+            // Then
+            assert_eq!(candidates, vec![vec![5, 4], vec![4, 3], vec![3, 2, 1]]);
+        }
+
+        #[tokio::test]
+        async fn respects_the_max_and_the_limit() {
+            // When
+            let within_max = select(6, 2, 10).await;
+            let within_limit = select(6, 5, 1).await;
+
+            // Then
+            assert_eq!(within_max, vec![vec![5, 4], vec![4, 3]]);
+            assert_eq!(within_limit, vec![vec![5, 4]]);
+        }
+    }
+
+    mod largest_first_within_size {
+        use super::*;
+        use crate::query::asset_query::Exclude;
+        use std::borrow::Cow;
+
+        async fn select(
+            targets: [u128; 2],
+            max_size: u64,
+            input_size: impl Fn(&CoinType) -> u64,
+        ) -> Result<Vec<Vec<Word>>, CoinsQueryError> {
+            let (owner, asset_ids, base_asset_id, db) = setup_coins();
+            let assets = [
+                AssetSpendTarget::new(asset_ids[0], targets[0], 5),
+                AssetSpendTarget::new(asset_ids[1], targets[1], 5),
+            ];
+            let db = db.service_database();
+            let spend_query = SpendQuery::new(
+                owner,
+                &assets,
+                Cow::Owned(Exclude::default()),
+                base_asset_id,
+            )?;
+            let coins = largest_first_within_size(
+                &db.test_view(),
+                &spend_query,
+                input_size,
+                max_size,
+            )
+            .await?;
+
+            Ok(coins
+                .iter()
+                .map(|coins| coins.iter().map(|coin| coin.amount()).collect())
+                .collect())
+        }
+
+        #[tokio::test]
+        async fn selects_the_biggest_coins_within_the_size_limit() {
+            // When
+            let amounts = select([9, 6], 40, |_| 10).await.unwrap();
+
+            // Then
+            assert_eq!(amounts, vec![vec![5, 4], vec![5, 4]]);
+        }
+
+        #[tokio::test]
+        async fn skips_the_coins_that_do_not_fit_into_the_size_limit() {
+            // Given
+            let input_size = |coin: &CoinType| if coin.amount() == 5 { 50 } else { 10 };
+
+            // When
+            let amounts = select([7, 1], 40, input_size).await.unwrap();
+
+            // Then
+            assert_eq!(amounts, vec![vec![4, 3], vec![4]]);
+        }
+
+        #[tokio::test]
+        async fn fails_when_the_inputs_of_all_assets_exceed_the_size_limit() {
+            // When
+            let result = select([9, 10], 40, |_| 10).await;
+
+            // Then
+            assert!(matches!(
+                result,
+                Err(CoinsQueryError::SizeLimitExceeded {
+                    collected_amount: 9,
+                    max_size: 40,
+                    ..
+                })
+            ));
+        }
+    }
+
+    mod reserve_count {
+        use super::*;
+
+        async fn select(
+            target: u128,
+            reserve_count: u16,
+        ) -> Result<Vec<Word>, CoinsQueryError> {
+            let (owner, asset_ids, base_asset_id, db) = setup_coins();
+            let asset = AssetSpendTarget::new(asset_ids[0], target, u16::MAX)
+                .with_reserve_count(Some(reserve_count));
+            let db = db.service_database();
+            let coins = largest_first(AssetQuery::new(
+                &owner,
+                &asset,
+                &base_asset_id,
+                None,
+                &db.test_view(),
+            ))
+            .await?;
+
+            Ok(coins.iter().map(|coin| coin.amount()).collect())
+        }
+
+        #[tokio::test]
+        async fn keeps_the_smallest_coins_unspent() {
+            // When
+            let amounts = select(12, 2).await.unwrap();
+
+            // Then
+            assert_eq!(amounts, vec![5, 4, 3]);
+        }
+
+        #[tokio::test]
+        async fn fails_when_target_cannot_be_met_above_the_reserve() {
+            // When
+            let result = select(13, 2).await;
+
+            // Then
+            assert!(matches!(
+                result,
+                Err(CoinsQueryError::InsufficientCoinsAboveReserve {
+                    collected_amount: 12,
+                    reserve_count: 2,
+                    ..
+                })
+            ));
+        }
+    }
+
+    mod avoid_largest_coin {
+        use super::*;
+        use crate::query::asset_query::Exclude;
+        use std::borrow::Cow;
+
+        async fn select(target: u128, max: u16) -> Result<Vec<Word>, CoinsQueryError> {
+            let (owner, asset_ids, base_asset_id, db) = setup_coins();
+            let asset = AssetSpendTarget::new(asset_ids[0], target, max)
+                .with_avoid_largest_coin(true);
+            let db = db.service_database();
+            let spend_query = SpendQuery::new(
+                owner,
+                &[asset],
+                Cow::Owned(Exclude::default()),
+                base_asset_id,
+            )?;
+            let coins = random_improve(&db.test_view(), &spend_query).await?;
+
+            Ok(coins[0]
+                .iter()
+                .map(|coin| coin.amount())
+                .sorted_by_key(|amount| Reverse(*amount))
+                .collect())
+        }
+
+        #[tokio::test]
+        async fn skips_the_largest_coin_when_other_coins_cover_the_target() {
+            for _ in 0..10 {
+                // When
+                let amounts = select(6, 5).await.unwrap();
+
+                // Then
+                assert!(!amounts.contains(&5), "{amounts:?}");
+                assert!(amounts.iter().sum::<Word>() >= 6);
+            }
+        }
+
+        #[tokio::test]
+        async fn uses_the_largest_coin_when_other_coins_do_not_fit_into_max() {
+            // When
+            let amounts = select(10, 3).await.unwrap();
+
+            // Then
+            assert_eq!(amounts, vec![5, 4, 3]);
+        }
+    }
+
+    mod prefer_deep_coins {
+        use super::*;
+        use crate::query::asset_query::Exclude;
+        use std::borrow::Cow;
+
+        async fn select(
+            target: u128,
+            max: u16,
+            prefer_deep_coins: bool,
+        ) -> Result<Vec<Word>, CoinsQueryError> {
+            let mut rng = StdRng::seed_from_u64(0xf00df00d);
+            let owner = Address::default();
+            let asset_id = rng.gen();
+            let base_asset_id = rng.gen();
+            let mut db = TestDatabase::new();
+            // The biggest coins are the shallowest ones.
+            for (amount, height) in [(1, 1), (2, 2), (3, 3), (4, 4), (5, 5)] {
+                db.make_coin_at(owner, amount, asset_id, height);
+            }
+            let asset = AssetSpendTarget::new(asset_id, target, max)
+                .with_prefer_deep_coins(prefer_deep_coins);
+            let db = db.service_database();
+            let spend_query = SpendQuery::new(
+                owner,
+                &[asset],
+                Cow::Owned(Exclude::default()),
+                base_asset_id,
+            )?;
+            let coins = random_improve(&db.test_view(), &spend_query).await?;
+
+            Ok(coins[0].iter().map(|coin| coin.amount()).sorted().collect())
+        }
+
+        #[tokio::test]
+        async fn selects_the_deepest_coins_covering_the_target() {
+            for _ in 0..10 {
+                // When
+                let amounts = select(3, 5, true).await.unwrap();
+
+                // Then
+                assert_eq!(amounts, vec![1, 2, 3]);
+            }
+        }
+
+        #[tokio::test]
+        async fn uses_shallow_coins_when_deep_ones_do_not_fit_into_max() {
+            // When
+            let amounts = select(9, 2, true).await.unwrap();
+
+            // Then
+            assert_eq!(amounts, vec![4, 5]);
+        }
+    }
+
+    mod preferred_change_outputs {
+        use super::*;
+        use crate::query::asset_query::Exclude;
+        use std::borrow::Cow;
+
+        async fn select(
+            amounts: &[Word],
+            target: u128,
+            max: u16,
+            preferred_change_outputs: u16,
+        ) -> Result<Vec<Word>, CoinsQueryError> {
+            let mut rng = StdRng::seed_from_u64(0xf00df00d);
+            let owner = Address::default();
+            let asset_id = rng.gen();
+            let base_asset_id = rng.gen();
+            let mut db = TestDatabase::new();
+            for amount in amounts {
+                db.make_coin(owner, *amount, asset_id);
+            }
+            let asset = AssetSpendTarget::new(asset_id, target, max)
+                .with_preferred_change_outputs(Some(preferred_change_outputs));
+            let db = db.service_database();
+            let spend_query = SpendQuery::new(
+                owner,
+                &[asset],
+                Cow::Owned(Exclude::default()),
+                base_asset_id,
+            )?;
+            let coins = random_improve(&db.test_view(), &spend_query).await?;
+
+            Ok(coins[0].iter().map(|coin| coin.amount()).sorted().collect())
+        }
+
+        #[tokio::test]
+        async fn no_change_output_prefers_coins_matching_the_target_exactly() {
+            for _ in 0..10 {
+                // When
+                let amounts = select(&[1, 2, 3, 4, 5], 6, 5, 0).await.unwrap();
+
+                // Then
+                assert_eq!(amounts.iter().sum::<Word>(), 6);
+            }
+        }
+
+        #[tokio::test]
+        async fn one_change_output_prefers_coins_exceeding_the_target() {
+            for _ in 0..10 {
+                // When
+                let amounts = select(&[2, 4, 10], 6, 3, 1).await.unwrap();
+
+                // Then
+                assert!(amounts.iter().sum::<Word>() > 6, "{amounts:?}");
+            }
+        }
+
+        #[tokio::test]
+        async fn keeps_the_selection_when_no_coins_match_the_target_exactly() {
+            // When
+            let amounts = select(&[4, 5], 6, 2, 0).await.unwrap();
+
+            // Then
+            assert_eq!(amounts, vec![4, 5]);
+        }
+    }
+
+    mod blacklist {
+        use super::*;
+        use crate::{
+            fuel_core_graphql_api::coins_blacklist::CoinsToSpendBlacklist,
+            query::asset_query::Exclude,
+        };
+        use fuel_core_types::{
+            entities::coins::CoinId,
+            fuel_tx::UtxoId,
+        };
+
+        #[test]
+        fn excludes_blacklisted_coins_on_top_of_the_excluded_ones() {
+            // Given
+            let excluded = UtxoId::new([1; 32].into(), 0);
+            let blacklisted = UtxoId::new([2; 32].into(), 0);
+            let blacklist = CoinsToSpendBlacklist::new(vec![], vec![blacklisted], vec![]);
+            let mut exclude = Exclude::new(vec![CoinId::Utxo(excluded)]);
+
+            // When
+            let result = apply_blacklist(&blacklist, [Address::default()], &mut exclude);
+
+            // Then
+            assert_eq!(result, Ok(()));
+            assert!(exclude.contains_coin(&excluded));
+            assert!(exclude.contains_coin(&blacklisted));
+        }
+
+        #[test]
+        fn fails_for_blacklisted_owner() {
+            // Given
+            let owner = Address::from([3; 32]);
+            let blacklist = CoinsToSpendBlacklist::new(vec![owner], vec![], vec![]);
+            let mut exclude = Exclude::default();
+
+            // When
+            let result =
+                apply_blacklist(&blacklist, [Address::default(), owner], &mut exclude);
+
+            // Then
+            assert_eq!(result, Err(CoinsQueryError::BlacklistedOwner(owner)));
+        }
+
+        #[test]
+        fn empty_blacklist_does_not_change_the_exclude() {
+            // Given
+            let blacklist = CoinsToSpendBlacklist::default();
+            let mut exclude = Exclude::default();
+
+            // When
+            let result = apply_blacklist(&blacklist, [Address::default()], &mut exclude);
+
+            // Then
+            assert_eq!(result, Ok(()));
+            assert!(exclude.coin_ids.is_empty());
+        }
+    }
+
+    mod coin_tags {
+        use super::*;
+        use crate::{
+            fuel_core_graphql_api::coin_tags::CoinsToSpendTags,
+            query::asset_query::Exclude,
+        };
+        use fuel_core_types::{
+            entities::coins::CoinId,
+            fuel_tx::UtxoId,
+        };
+
+        fn coin(byte: u8) -> CoinId {
+            CoinId::Utxo(UtxoId::new([byte; 32].into(), 0))
+        }
+
+        fn tags(tags: &[&str]) -> HashSet<String> {
+            tags.iter().map(|tag| tag.to_string()).collect()
+        }
+
+        fn coin_tags() -> Arc<CoinsToSpendTags> {
+            Arc::new(CoinsToSpendTags::new(vec![
+                (coin(1), "hot".to_string()),
+                (coin(2), "cold".to_string()),
+                (coin(3), "hot".to_string()),
+                (coin(3), "settlement".to_string()),
+            ]))
+        }
+
+        #[test]
+        fn excludes_coins_without_allowed_tags() {
+            // Given
+            let mut exclude = Exclude::default();
+
+            // When
+            apply_coin_tags(coin_tags(), tags(&["hot"]), tags(&[]), &mut exclude);
+
+            // Then
+            assert!(!exclude.contains(&coin(1)));
+            assert!(exclude.contains(&coin(2)));
+            assert!(!exclude.contains(&coin(3)));
+            assert!(exclude.contains(&coin(4)));
+        }
+
+        #[test]
+        fn excludes_coins_with_denied_tags() {
+            // Given
+            let mut exclude = Exclude::default();
+
+            // When
+            let (allowed, denied) = (tags(&["hot"]), tags(&["settlement"]));
+            apply_coin_tags(coin_tags(), allowed, denied, &mut exclude);
+
+            // Then
+            assert!(!exclude.contains(&coin(1)));
+            assert!(exclude.contains(&coin(2)));
+            assert!(exclude.contains(&coin(3)));
+        }
+
+        #[test]
+        fn does_nothing_if_tagging_is_not_configured() {
+            // Given
+            let coin_tags = Arc::new(CoinsToSpendTags::default());
+            let mut exclude = Exclude::default();
+
+            // When
+            apply_coin_tags(coin_tags, tags(&["hot"]), tags(&["cold"]), &mut exclude);
+
+            // Then
+            assert!(exclude.filter.is_none());
+            assert!(!exclude.contains(&coin(1)));
+        }
+    }
+
+    mod selection_diagnosis {
+        use super::*;
+        use crate::coins_query::{
+            BindingConstraint,
+            SelectionDiagnosis,
+        };
+        use async_graphql::{
+            ErrorExtensions,
+            Value,
+        };
+
+        async fn select(target: u128, max: u16) -> Result<Vec<Word>, CoinsQueryError> {
+            let (owner, asset_ids, base_asset_id, db) = setup_coins();
+            let asset = AssetSpendTarget::new(asset_ids[0], target, max);
+            let db = db.service_database();
+            let coins = largest_first(AssetQuery::new(
+                &owner,
+                &asset,
+                &base_asset_id,
+                None,
+                &db.test_view(),
+            ))
+            .await?;
+
+            Ok(coins.iter().map(|coin| coin.amount()).collect())
         }
 
         #[tokio::test]
-        async fn multiple_assets_coins() {
-            // Setup coins
-            let (owner, asset_ids, base_asset_id, db) = setup_coins();
-            multiple_assets_helper(owner, &asset_ids, &base_asset_id, db).await;
+        async fn max_is_the_binding_constraint_when_balance_covers_the_target() {
+            // When
+            let result = select(10, 2).await;
+
+            // Then
+            let expected = SelectionDiagnosis {
+                target: 10,
+                available_amount: 15,
+                available_count: 5,
+                binding_constraint: BindingConstraint::Max,
+                complete: true,
+            };
+            assert_matches!(
+                result,
+                Err(CoinsQueryError::InsufficientCoinsForTheMax { diagnosis, .. })
+                    if diagnosis == expected
+            );
         }
 
         #[tokio::test]
-        async fn multiple_assets_coins_and_messages() {
-            // Setup coins and messages
-            let (owner, asset_ids, base_asset_id, db) = setup_coins_and_messages();
-            multiple_assets_helper(owner, &asset_ids, &base_asset_id, db).await;
+        async fn balance_is_the_binding_constraint_when_it_is_below_the_target() {
+            // When
+            let result = select(16, u16::MAX).await;
+
+            // Then
+            let expected = SelectionDiagnosis {
+                target: 16,
+                available_amount: 15,
+                available_count: 5,
+                binding_constraint: BindingConstraint::Balance,
+                complete: true,
+            };
+            assert_matches!(
+                result,
+                Err(CoinsQueryError::InsufficientCoinsForTheMax { diagnosis, .. })
+                    if diagnosis == expected
+            );
+        }
+
+        #[tokio::test]
+        async fn diagnosis_is_attached_to_the_error_extensions() {
+            // Given
+            let error = select(10, 2).await.unwrap_err();
+
+            // When
+            let error = error.extend();
+
+            // Then
+            let extensions = error.extensions.expect("The extensions are set");
+            assert_eq!(extensions.get("target"), Some(&Value::from("10")));
+            assert_eq!(extensions.get("availableAmount"), Some(&Value::from("15")));
+            assert_eq!(extensions.get("availableCount"), Some(&Value::from(5)));
+            assert_eq!(extensions.get("bindingConstraint"), Some(&Value::from("MAX")));
+            assert_eq!(extensions.get("diagnosisComplete"), Some(&Value::from(true)));
         }
     }
 
@@ -806,7 +2137,8 @@ mod tests {
                             Err(CoinsQueryError::InsufficientCoinsForTheMax {
                                 asset_id: _,
                                 collected_amount: 15,
-                                max: u16::MAX
+                                max: u16::MAX,
+                                ..
                             })
                         )
                     }
@@ -1007,7 +2339,8 @@ mod tests {
                             Err(CoinsQueryError::InsufficientCoinsForTheMax {
                                 asset_id: _,
                                 collected_amount: 10,
-                                max: u16::MAX
+                                max: u16::MAX,
+                                ..
                             })
                         )
                     }
@@ -1084,10 +2417,17 @@ mod tests {
 
         use crate::{
             coins_query::{
+                coins_feasibility,
+                largest_from_index,
                 select_coins_to_spend,
                 select_coins_until,
+                smallest_from_index,
+                BindingConstraint,
+                CoinsFeasibility,
                 CoinsQueryError,
                 CoinsToSpendIndexKey,
+                SelectionDiagnosis,
+                MAX_DIAGNOSED_COINS,
             },
             graphql_api::ports::CoinsToSpendIndexIter,
             query::asset_query::Exclude,
@@ -1244,6 +2584,7 @@ mod tests {
                 coins_to_spend_iter,
                 TOTAL,
                 MAX,
+                None,
                 &AssetId::default(),
                 &exclude,
                 BATCH_SIZE,
@@ -1306,6 +2647,7 @@ mod tests {
                 coins_to_spend_iter,
                 TOTAL,
                 MAX,
+                None,
                 &AssetId::default(),
                 &exclude,
                 BATCH_SIZE,
@@ -1318,6 +2660,116 @@ mod tests {
             assert_eq!(results, vec![10, 10]);
         }
 
+        #[tokio::test]
+        async fn selection_is_filled_with_dust_up_to_the_preferred_count() {
+            // Given
+            const MAX: u16 = u16::MAX;
+            const TOTAL: u128 = 10;
+            const PREFERRED_COUNT: u16 = 4;
+
+            let big_coins = setup_test_coins([10, 10, 9, 8, 7])
+                .into_iter()
+                .map(|spec| spec.index_entry);
+            let dust_coins = setup_test_coins([1, 2, 3])
+                .into_iter()
+                .map(|spec| spec.index_entry);
+
+            let exclude = Exclude::default();
+
+            let coins_to_spend_iter = CoinsToSpendIndexIter {
+                big_coins_iter: big_coins.into_boxed(),
+                dust_coins_iter: dust_coins.into_boxed(),
+            };
+
+            // When
+            let result = select_coins_to_spend(
+                coins_to_spend_iter,
+                TOTAL,
+                MAX,
+                Some(PREFERRED_COUNT),
+                &AssetId::default(),
+                &exclude,
+                BATCH_SIZE,
+            )
+            .await
+            .expect("should not error");
+
+            // Then
+            let results: Vec<_> = result.into_iter().map(|key| key.amount()).collect();
+            assert_eq!(results, vec![10, 10, 1, 2]);
+        }
+
+        #[tokio::test]
+        async fn smallest_from_index_scans_the_index_in_reverse() {
+            // Given
+            const MAX: u16 = u16::MAX;
+            const TOTAL: u128 = 3;
+
+            let big_coins = setup_test_coins([10, 9, 3, 2, 1])
+                .into_iter()
+                .map(|spec| spec.index_entry);
+            let small_coins = setup_test_coins([1, 2, 3, 9, 10])
+                .into_iter()
+                .map(|spec| spec.index_entry);
+
+            let exclude = Exclude::default();
+
+            let coins_to_spend_iter = CoinsToSpendIndexIter {
+                big_coins_iter: big_coins.into_boxed(),
+                dust_coins_iter: small_coins.into_boxed(),
+            };
+
+            // When
+            let result = smallest_from_index(
+                coins_to_spend_iter,
+                TOTAL,
+                MAX,
+                &AssetId::default(),
+                &exclude,
+                BATCH_SIZE,
+            )
+            .await
+            .expect("should not error");
+
+            // Then
+            let results: Vec<_> = result.into_iter().map(|key| key.amount()).collect();
+            assert_eq!(results, vec![1, 2]);
+        }
+
+        #[tokio::test]
+        async fn smallest_from_index_respects_max_and_excluded_ids() {
+            // Given
+            const MAX: u16 = 2;
+            const TOTAL: u128 = 5;
+
+            let small_coins = setup_test_coins([1, 2, 3, 9, 10]);
+            let excluded = small_coins[0].utxo_id;
+            let small_coins = small_coins.into_iter().map(|spec| spec.index_entry);
+
+            let exclude = Exclude::new(vec![CoinId::Utxo(excluded)]);
+
+            let coins_to_spend_iter = CoinsToSpendIndexIter {
+                big_coins_iter: std::iter::empty().into_boxed(),
+                dust_coins_iter: small_coins.into_boxed(),
+            };
+
+            // When
+            let result = smallest_from_index(
+                coins_to_spend_iter,
+                TOTAL,
+                MAX,
+                &AssetId::default(),
+                &exclude,
+                BATCH_SIZE,
+            )
+            .await
+            .expect("should not error");
+
+            // Then
+            let results: Vec<_> = result.into_iter().map(|key| key.amount()).collect();
+            assert_eq!(results, vec![2, 3]);
+        }
+
         #[tokio::test]
         async fn selection_algorithm_should_bail_on_storage_error() {
             // Given
@@ -1353,6 +2805,7 @@ mod tests {
                 coins_to_spend_iter,
                 TOTAL,
                 MAX,
+                None,
                 &AssetId::default(),
                 &exclude,
                 BATCH_SIZE,
@@ -1381,6 +2834,7 @@ mod tests {
                 coins_to_spend_iter,
                 TOTAL,
                 MAX,
+                None,
                 &AssetId::default(),
                 &exclude,
                 BATCH_SIZE,
@@ -1408,6 +2862,7 @@ mod tests {
                 coins_to_spend_iter,
                 TOTAL,
                 MAX,
+                None,
                 &AssetId::default(),
                 &exclude,
                 BATCH_SIZE,
@@ -1443,6 +2898,7 @@ mod tests {
                 coins_to_spend_iter,
                 TOTAL,
                 MAX,
+                None,
                 &asset_id,
                 &exclude,
                 BATCH_SIZE,
@@ -1450,10 +2906,235 @@ mod tests {
             .await;
 
             const EXPECTED_COLLECTED_AMOUNT: u128 = 10 + 9 + 8; // Because MAX == 3
+            let diagnosis = SelectionDiagnosis::new(TOTAL, 10 + 9 + 8 + 7, 4, true);
 
             // Then
             assert!(matches!(result, Err(actual_error)
-                if CoinsQueryError::InsufficientCoinsForTheMax { asset_id, collected_amount: EXPECTED_COLLECTED_AMOUNT, max: MAX } == actual_error));
+                if CoinsQueryError::InsufficientCoinsForTheMax { asset_id, collected_amount: EXPECTED_COLLECTED_AMOUNT, max: MAX, diagnosis } == actual_error));
+        }
+
+        async fn select_from_index(
+            total: u128,
+            max: u16,
+        ) -> Result<Vec<CoinsToSpendIndexKey>, CoinsQueryError> {
+            let coins = setup_test_coins([10, 9, 8, 7]);
+            let coins_to_spend_iter = CoinsToSpendIndexIter {
+                big_coins_iter: coins
+                    .into_iter()
+                    .map(|spec| spec.index_entry)
+                    .into_boxed(),
+                dust_coins_iter: std::iter::empty().into_boxed(),
+            };
+
+            select_coins_to_spend(
+                coins_to_spend_iter,
+                total,
+                max,
+                None,
+                &AssetId::default(),
+                &Exclude::default(),
+                BATCH_SIZE,
+            )
+            .await
+        }
+
+        #[tokio::test]
+        async fn selection_diagnosis_is_bound_by_max_when_balance_covers_the_total() {
+            // When
+            let result = select_from_index(30, 2).await;
+
+            // Then
+            let expected = SelectionDiagnosis {
+                target: 30,
+                available_amount: 10 + 9 + 8 + 7,
+                available_count: 4,
+                binding_constraint: BindingConstraint::Max,
+                complete: true,
+            };
+            assert!(matches!(result,
+                Err(CoinsQueryError::InsufficientCoinsForTheMax { diagnosis, .. })
+                    if diagnosis == expected));
+        }
+
+        #[tokio::test]
+        async fn selection_diagnosis_is_bound_by_balance_when_it_is_below_the_total() {
+            // When
+            let result = select_from_index(50, u16::MAX).await;
+
+            // Then
+            let expected = SelectionDiagnosis {
+                target: 50,
+                available_amount: 10 + 9 + 8 + 7,
+                available_count: 4,
+                binding_constraint: BindingConstraint::Balance,
+                complete: true,
+            };
+            assert!(matches!(result,
+                Err(CoinsQueryError::InsufficientCoinsForTheMax { diagnosis, .. })
+                    if diagnosis == expected));
+        }
+
+        #[tokio::test]
+        async fn selection_diagnosis_stops_counting_after_the_limit() {
+            // Given
+            const MAX: u16 = 2;
+            let coin = setup_test_coins([1]).remove(0).index_entry.unwrap();
+            let coins_to_spend_iter = CoinsToSpendIndexIter {
+                big_coins_iter: std::iter::repeat_with(move || Ok(coin.clone()))
+                    .take(MAX as usize + MAX_DIAGNOSED_COINS + 1)
+                    .into_boxed(),
+                dust_coins_iter: std::iter::empty().into_boxed(),
+            };
+
+            // When
+            let result = select_coins_to_spend(
+                coins_to_spend_iter,
+                u128::MAX,
+                MAX,
+                None,
+                &AssetId::default(),
+                &Exclude::default(),
+                BATCH_SIZE,
+            )
+            .await;
+
+            // Then
+            let expected = SelectionDiagnosis {
+                target: u128::MAX,
+                available_amount: MAX as u128 + MAX_DIAGNOSED_COINS as u128,
+                available_count: MAX as usize + MAX_DIAGNOSED_COINS,
+                binding_constraint: BindingConstraint::Balance,
+                complete: false,
+            };
+            assert!(matches!(result,
+                Err(CoinsQueryError::InsufficientCoinsForTheMax { diagnosis, .. })
+                    if diagnosis == expected));
+        }
+
+        #[tokio::test]
+        async fn largest_from_index_selects_biggest_coins_covering_the_total() {
+            // Given
+            let coins = setup_test_coins([7, 10, 9, 8]);
+            let mut index_entries: Vec<_> = coins
+                .into_iter()
+                .map(|spec| spec.index_entry.unwrap())
+                .collect();
+            index_entries.sort_by_key(|key| std::cmp::Reverse(key.amount()));
+            let coins_to_spend_iter = CoinsToSpendIndexIter {
+                big_coins_iter: index_entries.into_iter().map(Ok).into_boxed(),
+                dust_coins_iter: std::iter::empty().into_boxed(),
+            };
+
+            // When
+            let result = largest_from_index(
+                coins_to_spend_iter,
+                18,
+                3,
+                &AssetId::default(),
+                &Exclude::default(),
+                BATCH_SIZE,
+            )
+            .await;
+
+            // Then
+            let amounts: Vec<_> =
+                result.unwrap().iter().map(|key| key.amount()).collect();
+            assert_eq!(amounts, vec![10, 9]);
+        }
+
+        #[tokio::test]
+        async fn largest_from_index_fails_when_more_than_max_coins_are_needed() {
+            // Given
+            let coins = setup_test_coins([10, 9, 8, 7]);
+            let coins_to_spend_iter = CoinsToSpendIndexIter {
+                big_coins_iter: coins
+                    .into_iter()
+                    .map(|spec| spec.index_entry)
+                    .into_boxed(),
+                dust_coins_iter: std::iter::empty().into_boxed(),
+            };
+
+            // When
+            let result = largest_from_index(
+                coins_to_spend_iter,
+                30,
+                2,
+                &AssetId::default(),
+                &Exclude::default(),
+                BATCH_SIZE,
+            )
+            .await;
+
+            // Then
+            let expected = SelectionDiagnosis::new(30, 10 + 9 + 8 + 7, 4, true);
+            assert!(matches!(result,
+                Err(CoinsQueryError::InsufficientCoinsForTheMax { collected_amount: 19, diagnosis, .. })
+                    if diagnosis == expected));
+        }
+
+        async fn feasibility_from_index(
+            total: u128,
+            max: u16,
+        ) -> Result<CoinsFeasibility, CoinsQueryError> {
+            let coins = setup_test_coins([10, 9, 8, 7]);
+            let coins_to_spend_iter = CoinsToSpendIndexIter {
+                big_coins_iter: coins
+                    .into_iter()
+                    .map(|spec| spec.index_entry)
+                    .into_boxed(),
+                dust_coins_iter: std::iter::empty().into_boxed(),
+            };
+
+            coins_feasibility(
+                coins_to_spend_iter,
+                total,
+                max,
+                &Exclude::default(),
+                BATCH_SIZE,
+            )
+            .await
+        }
+
+        #[tokio::test]
+        async fn coins_feasibility_stops_at_the_coin_covering_the_total() {
+            // When
+            let result = feasibility_from_index(18, 2).await;
+
+            // Then
+            let expected = CoinsFeasibility {
+                feasible: true,
+                coins_needed: 2,
+                total_available: 10 + 9,
+            };
+            assert_eq!(result.unwrap(), expected);
+        }
+
+        #[tokio::test]
+        async fn coins_feasibility_is_not_feasible_when_max_is_too_low() {
+            // When
+            let result = feasibility_from_index(25, 2).await;
+
+            // Then
+            let expected = CoinsFeasibility {
+                feasible: false,
+                coins_needed: 3,
+                total_available: 10 + 9 + 8,
+            };
+            assert_eq!(result.unwrap(), expected);
+        }
+
+        #[tokio::test]
+        async fn coins_feasibility_stops_after_max_coins_when_balance_is_below_the_total() {
+            // When
+            let result = feasibility_from_index(50, 2).await;
+
+            // Then
+            let expected = CoinsFeasibility {
+                feasible: false,
+                coins_needed: 3,
+                total_available: 10 + 9 + 8,
+            };
+            assert_eq!(result.unwrap(), expected);
         }
     }
 
@@ -1497,11 +3178,7 @@ mod tests {
             &db.service_database().test_view(),
             &SpendQuery::new(
                 owner,
-                &[AssetSpendTarget {
-                    id: asset_ids[0],
-                    target: target_amount,
-                    max: max_coins,
-                }],
+                &[AssetSpendTarget::new(asset_ids[0], target_amount, max_coins)],
                 Cow::Owned(Exclude::default()),
                 base_asset_id,
             )?,
@@ -1531,7 +3208,8 @@ mod tests {
             Err(CoinsQueryError::InsufficientCoinsForTheMax {
                 asset_id: _base_asset_id,
                 collected_amount: 0,
-                max: u16::MAX
+                max: u16::MAX,
+                ..
             })
         )
     }
@@ -1555,6 +3233,22 @@ mod tests {
             prop_assert!(number_of_big_coins + max_dust_count <= max);
             prop_assert!(max_dust_count <= number_of_big_coins.saturating_mul(factor));
         }
+
+        #[test]
+        fn preferred_dust_count_respects_limits(
+            max in 1u16..255,
+            number_of_big_coins in 1u16..255,
+            factor in 1u16..10,
+            preferred_count in 0u16..512,
+        ) {
+            prop_assume!(number_of_big_coins <= max && number_of_big_coins >= 1);
+
+            let dust_count =
+                preferred_dust_count(max, number_of_big_coins, factor, preferred_count);
+            prop_assert!(number_of_big_coins + dust_count <= max);
+            prop_assert!(dust_count <= number_of_big_coins.saturating_mul(factor));
+            prop_assert!(dust_count <= preferred_count.saturating_sub(number_of_big_coins));
+        }
     }
 
     #[test_case::test_case(
@@ -1637,6 +3331,17 @@ mod tests {
             owner: Address,
             amount: Word,
             asset_id: AssetId,
+        ) -> Coin {
+            self.make_coin_at(owner, amount, asset_id, 0)
+        }
+
+        /// Makes the coin created by the block at the `height`.
+        pub fn make_coin_at(
+            &mut self,
+            owner: Address,
+            amount: Word,
+            asset_id: AssetId,
+            height: u32,
         ) -> Coin {
             let index = self.last_coin_index;
             self.last_coin_index += 1;
@@ -1646,6 +3351,7 @@ mod tests {
             coin.set_owner(owner);
             coin.set_amount(amount);
             coin.set_asset_id(asset_id);
+            coin.set_tx_pointer(TxPointer::new(height.into(), 0));
 
             let db = self.database.on_chain_mut();
             StorageMutate::<Coins>::insert(db, &id, &coin).unwrap();
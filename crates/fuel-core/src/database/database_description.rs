@@ -84,6 +84,13 @@ pub enum IndexationKind {
     Balances,
     CoinsToSpend,
     AssetMetadata,
+    UtxoCount,
+    KnownAssets,
+    SenderMessages,
+    TransactionsBySpender,
+    OutboundMessageTransactions,
+    SortedMessageCoins,
+    Predicates,
 }
 
 impl IndexationKind {
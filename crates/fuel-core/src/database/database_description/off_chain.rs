@@ -27,7 +27,10 @@ impl DatabaseDescription for OffChain {
         match column {
             Self::Column::OwnedCoins
             | Self::Column::TransactionsByOwnerBlockIdx
-            | Self::Column::OwnedMessageIds => {
+            | Self::Column::TransactionsBySpenderBlockIdx
+            | Self::Column::OwnedMessageIds
+            | Self::Column::SenderMessageIds
+            | Self::Column::OwnedSortedMessageCoins => {
                 // prefix is address length
                 Some(32)
             }
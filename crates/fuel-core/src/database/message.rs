@@ -4,8 +4,13 @@ use crate::{
         OnChainIterableKeyValueView,
     },
     fuel_core_graphql_api::storage::messages::{
+        MessageCoinSortOrder,
         OwnedMessageIds,
         OwnedMessageKey,
+        OwnedSortedMessageCoinKey,
+        OwnedSortedMessageCoins,
+        SenderMessageIds,
+        SenderMessageKey,
         SpentMessages,
     },
 };
@@ -44,6 +49,40 @@ impl OffChainIterableKeyValueView {
         .map(|res| res.map(|key| *key.nonce()))
     }
 
+    pub fn sender_message_ids(
+        &self,
+        sender: &Address,
+        start_message_id: Option<Nonce>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = StorageResult<Nonce>> + '_ {
+        let start_message_id =
+            start_message_id.map(|msg_id| SenderMessageKey::new(sender, &msg_id));
+        self.iter_all_filtered_keys::<SenderMessageIds, _>(
+            Some(*sender),
+            start_message_id.as_ref(),
+            direction,
+        )
+        .map(|res| res.map(|key| *key.nonce()))
+    }
+
+    pub fn owned_sorted_message_coin_ids(
+        &self,
+        owner: &Address,
+        order: MessageCoinSortOrder,
+        start: Option<(u64, Nonce)>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = StorageResult<(u64, Nonce)>> + '_ {
+        let start = start.map(|(sort_key, nonce)| {
+            OwnedSortedMessageCoinKey::new(owner, order, sort_key, &nonce)
+        });
+        self.iter_all_filtered_keys::<OwnedSortedMessageCoins, _>(
+            Some(OwnedSortedMessageCoinKey::prefix(owner, order)),
+            start.as_ref(),
+            direction,
+        )
+        .map(|res| res.map(|key| (key.sort_key, key.nonce)))
+    }
+
     pub fn message_is_spent(&self, id: &Nonce) -> StorageResult<bool> {
         fuel_core_storage::StorageAsRef::storage::<SpentMessages>(&self).contains_key(id)
     }
@@ -5,6 +5,7 @@ use crate::{
         OwnedTransactionIndexKey,
         OwnedTransactions,
         TransactionStatuses,
+        TransactionsBySpender,
     },
 };
 use fuel_core_storage::{
@@ -62,6 +63,28 @@ impl OffChainIterableKeyValueView {
         })
     }
 
+    /// Iterates over a KV mapping of `[address + block height + tx idx] => transaction
+    /// id` of the transactions spending the coins of the address. The cursor tracks
+    /// the `[block height + tx idx]` for pagination purposes.
+    pub fn spent_transactions(
+        &self,
+        owner: Address,
+        start: Option<OwnedTransactionIndexCursor>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = StorageResult<(TxPointer, Bytes32)>> + '_ {
+        let start = start.map(|cursor| {
+            OwnedTransactionIndexKey::new(&owner, cursor.block_height, cursor.tx_idx)
+        });
+        self.iter_all_filtered::<TransactionsBySpender, _>(
+            Some(owner),
+            start.as_ref(),
+            direction,
+        )
+        .map(|res| {
+            res.map(|(key, tx_id)| (TxPointer::new(key.block_height, key.tx_idx), tx_id))
+        })
+    }
+
     pub fn get_tx_status(
         &self,
         id: &Bytes32,
@@ -1,16 +1,27 @@
 use async_graphql::Context;
+use coin_tags::CoinsToSpendTags;
+use coins_blacklist::CoinsToSpendBlacklist;
 use fuel_core_storage::{
     Error as StorageError,
     IsNotFound,
 };
+use fuel_core_types::fuel_types::AssetId;
 use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
     net::SocketAddr,
+    num::NonZeroU64,
     sync::OnceLock,
     time::Duration,
 };
 
 pub mod api_service;
 pub(crate) mod block_height_subscription;
+pub mod coin_reservations;
+pub mod coin_tags;
+pub mod coins_blacklist;
 pub mod da_compression;
 pub mod database;
 pub(crate) mod extensions;
@@ -30,6 +41,21 @@ pub struct Config {
     pub max_size: usize,
     pub max_txpool_dependency_chain_length: usize,
     pub chain_name: String,
+    pub da_compression: worker_service::DaCompressionConfig,
+    pub historical_state: HistoricalStateRetention,
+}
+
+/// The heights for which the node retains the historical state,
+/// derived from the state rewind policy of the database.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HistoricalStateRetention {
+    /// Only the state at the latest height is available.
+    #[default]
+    LatestOnly,
+    /// The state is retained for all heights since the genesis.
+    Full,
+    /// The state is retained for the `blocks` heights before the latest one.
+    Range { blocks: NonZeroU64 },
 }
 
 #[derive(Clone, Debug)]
@@ -39,6 +65,12 @@ pub struct ServiceConfig {
     pub database_batch_size: usize,
     pub max_queries_depth: usize,
     pub max_queries_complexity: usize,
+    /// API keys of the trusted clients. Queries with one of these keys in the
+    /// `x-api-key` header are limited by the `trusted_max_queries_complexity`
+    /// instead of the `max_queries_complexity`.
+    pub trusted_api_keys: HashSet<String>,
+    /// The max complexity of queries from the trusted clients.
+    pub trusted_max_queries_complexity: usize,
     pub max_queries_recursive_depth: usize,
     pub max_queries_resolver_recursive_depth: usize,
     pub max_queries_directives: usize,
@@ -55,6 +87,31 @@ pub struct ServiceConfig {
     pub api_request_timeout: Duration,
     pub assemble_tx_dry_run_limit: usize,
     pub assemble_tx_estimate_predicates_limit: usize,
+    /// Retry the `coins_to_spend` selection without the coins to spend index
+    /// if the index doesn't have enough coins, e.g. because it lags behind.
+    pub coins_to_spend_cache_fallback: bool,
+    /// The coins excluded from the `coinsToSpend` selection on top of the coins
+    /// excluded by the caller, e.g. for the compliance reasons.
+    pub coins_to_spend_blacklist: CoinsToSpendBlacklist,
+    /// The off-chain tags of the coins used by the tag filters of the `coinsToSpend`
+    /// selection. The filters are ignored if no coin is tagged.
+    pub coins_to_spend_tags: CoinsToSpendTags,
+    /// The maximum number of coins returned by one `coinsToSpend` request
+    /// across all assets.
+    pub coins_to_spend_max_total_coins: usize,
+    /// The minimum spendable amount per asset. The coins of the asset below
+    /// the minimum are selected only if the target can't be met without them.
+    pub coins_to_spend_min_amounts: HashMap<AssetId, u64>,
+    /// Enables the `coinSetRoot` query for the trusted clients. The root isn't
+    /// stored, so the query iterates over the whole coin set.
+    pub coin_set_root: bool,
+    /// The maximum number of transactions that can be dry-run in one request.
+    pub dry_run_max_txs: usize,
+    /// The maximum number of dry runs executed concurrently.
+    pub dry_run_max_concurrency: usize,
+    /// The time a dry run waits for a free slot before failing
+    /// when `dry_run_max_concurrency` dry runs are already executed.
+    pub dry_run_queue_timeout: Duration,
     /// Configurable cost parameters to limit graphql queries complexity
     pub costs: Costs,
 }
@@ -82,6 +139,7 @@ pub struct Costs {
     pub bytecode_read: usize,
     pub state_transition_bytecode_read: usize,
     pub da_compressed_block_read: usize,
+    pub coin_set_root: usize,
 }
 
 #[cfg(feature = "test-helpers")]
@@ -116,6 +174,8 @@ pub const DEFAULT_QUERY_COSTS: Costs = Costs {
     bytecode_read: 8000,
     state_transition_bytecode_read: 76_000,
     da_compressed_block_read: 4000,
+    // Iterates over the whole coin set, so only the trusted clients can afford it.
+    coin_set_root: 400_000,
 };
 
 pub fn query_costs() -> &'static Costs {
@@ -1,15 +1,20 @@
 use crate::{
     fuel_core_graphql_api::{
+        coin_reservations::CoinReservations,
         extensions::unify_response,
         ports::{
             BlockProducerPort,
             ChainStateProvider as ChainStateProviderTrait,
+            CoinTags,
+            CoinsBlacklist,
             ConsensusModulePort,
+            DaDecompressionPort,
             GasPriceEstimate,
             OffChainDatabase,
             OffChainDatabaseAt,
             OnChainDatabase,
             P2pPort,
+            RelayerPort,
             TxPoolPort,
             TxStatusManager,
         },
@@ -21,6 +26,10 @@ use crate::{
             chain_state_info::ChainStateInfoExtension,
             metrics::MetricsExtension,
             required_fuel_block_height::RequiredFuelBlockHeightExtension,
+            trusted_client::{
+                TrustedApiKeys,
+                TrustedClientExtension,
+            },
             validation::ValidationExtension,
         },
     },
@@ -49,6 +58,7 @@ use axum::{
             ACCESS_CONTROL_ALLOW_METHODS,
             ACCESS_CONTROL_ALLOW_ORIGIN,
         },
+        HeaderMap,
         HeaderValue,
     },
     response::{
@@ -111,6 +121,11 @@ pub type TxPool = Box<dyn TxPoolPort>;
 pub type DynTxStatusManager = Box<dyn TxStatusManager>;
 pub type ConsensusModule = Box<dyn ConsensusModulePort>;
 pub type P2pService = Box<dyn P2pPort>;
+pub type RelayerProvider = Box<dyn RelayerPort>;
+pub type DaDecompressor = Box<dyn DaDecompressionPort>;
+pub type CoinsBlacklistProvider = Box<dyn CoinsBlacklist>;
+/// Shared, so the selection filter can hold the tags while the coins are streamed.
+pub type CoinTagsProvider = Arc<dyn CoinTags>;
 
 pub type GasPriceProvider = Box<dyn GasPriceEstimate>;
 
@@ -282,8 +297,19 @@ where
     let required_fuel_block_height_timeout =
         config.config.required_fuel_block_height_timeout;
 
+    let max_queries_complexity = config.config.max_queries_complexity;
+    let trusted_api_keys = TrustedApiKeys::new(config.config.trusted_api_keys.clone());
+    // Trusted clients are allowed to send queries with higher complexity, so
+    // the schema uses the highest limit, while the `TrustedClientExtension`
+    // applies the default limit to untrusted clients.
+    let schema_max_queries_complexity = if config.config.trusted_api_keys.is_empty() {
+        max_queries_complexity
+    } else {
+        max_queries_complexity.max(config.config.trusted_max_queries_complexity)
+    };
+
     let schema = schema
-        .limit_complexity(config.config.max_queries_complexity)
+        .limit_complexity(schema_max_queries_complexity)
         .limit_depth(config.config.max_queries_depth)
         .limit_recursive_depth(config.config.max_queries_recursive_depth)
         .limit_directives(config.config.max_queries_directives)
@@ -304,9 +330,11 @@ where
         .data(chain_state_info_provider)
         .data(memory_pool)
         .data(block_height_subscriber.clone())
+        .data(CoinReservations::default())
         .extension(ValidationExtension::new(
             max_queries_resolver_recursive_depth,
         ))
+        .extension(TrustedClientExtension::new(max_queries_complexity))
         .extension(async_graphql::extensions::Tracing)
         .extension(RequiredFuelBlockHeightExtension::new(
             required_fuel_block_height_tolerance,
@@ -337,6 +365,7 @@ where
         .route("/v1/health", get(health))
         .route("/health", get(health))
         .layer(Extension(schema))
+        .layer(Extension(trusted_api_keys))
         .layer(TraceLayer::new_for_http())
         .layer(TimeoutLayer::new(request_timeout))
         .layer(SetResponseHeaderLayer::<_>::overriding(
@@ -387,9 +416,12 @@ async fn health() -> Json<serde_json::Value> {
 
 async fn graphql_handler(
     schema: Extension<CoreSchema>,
+    trusted_api_keys: Extension<TrustedApiKeys>,
+    headers: HeaderMap,
     req: Json<Request>,
 ) -> Json<Response> {
-    let response = schema.execute(req.0).await;
+    let request = trusted_api_keys.authorize(&headers, req.0);
+    let response = schema.execute(request).await;
     let response = unify_response(response);
 
     response.into()
@@ -397,9 +429,12 @@ async fn graphql_handler(
 
 async fn graphql_subscription_handler(
     schema: Extension<CoreSchema>,
+    trusted_api_keys: Extension<TrustedApiKeys>,
+    headers: HeaderMap,
     req: Json<Request>,
 ) -> Sse<impl Stream<Item = anyhow::Result<Event, serde_json::Error>>> {
-    let stream = schema.execute_stream(req.0).map(|response| {
+    let request = trusted_api_keys.authorize(&headers, req.0);
+    let stream = schema.execute_stream(request).map(|response| {
         let response = unify_response(response);
         Event::default().json_data(response)
     });
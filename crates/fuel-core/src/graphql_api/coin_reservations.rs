@@ -0,0 +1,362 @@
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use fuel_core_types::entities::coins::CoinId;
+use parking_lot::RwLock;
+
+/// The maximum time for which coins can be reserved by a session.
+pub const MAX_RESERVATION_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// The maximum number of coins reserved by all sessions together.
+pub const MAX_RESERVED_COINS: usize = 100_000;
+
+/// The maximum number of coins reserved by one session.
+pub const MAX_RESERVED_COINS_PER_SESSION: usize = 1_000;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ReservationError {
+    #[error("The coin {coin:?} is already reserved by another session")]
+    AlreadyReserved { coin: CoinId },
+    #[error("The reservation TTL {ttl:?} exceeds the maximum of {max:?}")]
+    TtlTooLong { ttl: Duration, max: Duration },
+    #[error("The session can't reserve more than {max} coins")]
+    TooManyCoinsInSession { max: usize },
+    #[error("The node can't reserve more than {max} coins")]
+    TooManyCoins { max: usize },
+}
+
+/// The session reserving the coins. Sessions are scoped to the authenticated
+/// client that created them, so clients can't use the sessions of each other.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SessionId {
+    /// The API key of the client.
+    pub client: String,
+    /// The name of the session chosen by the client.
+    pub name: String,
+}
+
+impl SessionId {
+    pub fn new(client: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            client: client.into(),
+            name: name.into(),
+        }
+    }
+}
+
+struct Reservation {
+    session: SessionId,
+    expires_at: Instant,
+}
+
+impl Reservation {
+    fn is_active(&self, now: Instant) -> bool {
+        self.expires_at > now
+    }
+}
+
+/// Coins reserved by sessions. Reserved coins are excluded from the coin
+/// selection of all other sessions until the reservation expires or is released.
+#[derive(Default, Clone)]
+pub struct CoinReservations {
+    inner: Arc<RwLock<HashMap<CoinId, Reservation>>>,
+}
+
+impl CoinReservations {
+    /// Reserves the `coins` under the `session` for the `ttl`.
+    /// Coins already reserved by the same session get their reservation extended.
+    /// Nothing is reserved if any of the coins is reserved by another session
+    /// or the reservation limits are exceeded.
+    pub fn reserve(
+        &self,
+        session: &SessionId,
+        coins: Vec<CoinId>,
+        ttl: Duration,
+    ) -> Result<(), ReservationError> {
+        if ttl > MAX_RESERVATION_TTL {
+            return Err(ReservationError::TtlTooLong {
+                ttl,
+                max: MAX_RESERVATION_TTL,
+            });
+        }
+
+        let now = Instant::now();
+        let mut reservations = self.inner.write();
+        reservations.retain(|_, reservation| reservation.is_active(now));
+
+        if let Some(coin) = coins.iter().find(|coin| {
+            reservations
+                .get(coin)
+                .is_some_and(|reservation| reservation.session != *session)
+        }) {
+            return Err(ReservationError::AlreadyReserved { coin: *coin });
+        }
+
+        let session_coins = reservations
+            .values()
+            .filter(|reservation| reservation.session == *session)
+            .count();
+        let new_coins = coins
+            .iter()
+            .filter(|coin| !reservations.contains_key(coin))
+            .count();
+        if session_coins.saturating_add(new_coins) > MAX_RESERVED_COINS_PER_SESSION {
+            return Err(ReservationError::TooManyCoinsInSession {
+                max: MAX_RESERVED_COINS_PER_SESSION,
+            });
+        }
+        if reservations.len().saturating_add(new_coins) > MAX_RESERVED_COINS {
+            return Err(ReservationError::TooManyCoins {
+                max: MAX_RESERVED_COINS,
+            });
+        }
+
+        let expires_at = now.checked_add(ttl).unwrap_or(now);
+        for coin in coins {
+            reservations.insert(
+                coin,
+                Reservation {
+                    session: session.clone(),
+                    expires_at,
+                },
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Releases all coins reserved by the `session`.
+    /// Returns the number of released coins.
+    pub fn release(&self, session: &SessionId) -> usize {
+        let now = Instant::now();
+        let mut reservations = self.inner.write();
+        reservations.retain(|_, reservation| reservation.is_active(now));
+
+        let before = reservations.len();
+        reservations.retain(|_, reservation| reservation.session != *session);
+        before.saturating_sub(reservations.len())
+    }
+
+    /// Returns `true` if the `coin` is reserved by a session other than the `session`.
+    pub fn is_reserved_by_others(
+        &self,
+        coin: &CoinId,
+        session: Option<&SessionId>,
+    ) -> bool {
+        let now = Instant::now();
+        self.inner.read().get(coin).is_some_and(|reservation| {
+            reservation.is_active(now) && Some(&reservation.session) != session
+        })
+    }
+
+    #[cfg(test)]
+    fn reserved(&self) -> Vec<CoinId> {
+        let now = Instant::now();
+        self.inner
+            .read()
+            .iter()
+            .filter(|(_, reservation)| reservation.is_active(now))
+            .map(|(coin, _)| *coin)
+            .collect()
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.inner.read().len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use fuel_core_types::fuel_tx::UtxoId;
+
+    fn coin(index: u16) -> CoinId {
+        CoinId::Utxo(UtxoId::new(Default::default(), index))
+    }
+
+    fn session(name: &str) -> SessionId {
+        SessionId::new("key", name)
+    }
+
+    #[test]
+    fn reserve__coins_are_hidden_only_from_other_sessions() {
+        // Given
+        let reservations = CoinReservations::default();
+
+        // When
+        reservations
+            .reserve(
+                &session("alice"),
+                vec![coin(0), coin(1)],
+                Duration::from_secs(60),
+            )
+            .unwrap();
+
+        // Then
+        let alice = session("alice");
+        let bob = session("bob");
+        assert!(!reservations.is_reserved_by_others(&coin(0), Some(&alice)));
+        assert!(reservations.is_reserved_by_others(&coin(0), Some(&bob)));
+        assert!(reservations.is_reserved_by_others(&coin(1), None));
+        assert!(!reservations.is_reserved_by_others(&coin(2), None));
+    }
+
+    #[test]
+    fn reserve__session_of_another_client_is_a_separate_session() {
+        // Given
+        let reservations = CoinReservations::default();
+        let alice = SessionId::new("key", "alice");
+        let other_alice = SessionId::new("other key", "alice");
+        reservations
+            .reserve(&alice, vec![coin(0)], Duration::from_secs(60))
+            .unwrap();
+
+        // When
+        let result =
+            reservations.reserve(&other_alice, vec![coin(0)], Duration::from_secs(60));
+
+        // Then
+        assert_eq!(
+            result,
+            Err(ReservationError::AlreadyReserved { coin: coin(0) })
+        );
+        assert!(reservations.is_reserved_by_others(&coin(0), Some(&other_alice)));
+        assert!(!reservations.is_reserved_by_others(&coin(0), Some(&alice)));
+    }
+
+    #[test]
+    fn reserve__fails_when_coin_is_reserved_by_another_session() {
+        // Given
+        let reservations = CoinReservations::default();
+        reservations
+            .reserve(&session("alice"), vec![coin(0)], Duration::from_secs(60))
+            .unwrap();
+
+        // When
+        let result = reservations.reserve(
+            &session("bob"),
+            vec![coin(1), coin(0)],
+            Duration::from_secs(60),
+        );
+
+        // Then
+        assert_eq!(
+            result,
+            Err(ReservationError::AlreadyReserved { coin: coin(0) })
+        );
+        assert_eq!(reservations.reserved(), vec![coin(0)]);
+    }
+
+    #[test]
+    fn reserve__fails_when_session_exceeds_the_limit() {
+        // Given
+        let reservations = CoinReservations::default();
+        let coins = (0..MAX_RESERVED_COINS_PER_SESSION as u16).map(coin).collect();
+        reservations
+            .reserve(&session("alice"), coins, Duration::from_secs(60))
+            .unwrap();
+
+        // When
+        let extended = reservations.reserve(
+            &session("alice"),
+            vec![coin(0)],
+            Duration::from_secs(60),
+        );
+        let exceeded = reservations.reserve(
+            &session("alice"),
+            vec![coin(MAX_RESERVED_COINS_PER_SESSION as u16)],
+            Duration::from_secs(60),
+        );
+
+        // Then
+        assert_eq!(extended, Ok(()));
+        assert_eq!(
+            exceeded,
+            Err(ReservationError::TooManyCoinsInSession {
+                max: MAX_RESERVED_COINS_PER_SESSION
+            })
+        );
+    }
+
+    #[test]
+    fn reserve__expired_reservations_are_ignored() {
+        // Given
+        let reservations = CoinReservations::default();
+        reservations
+            .reserve(&session("alice"), vec![coin(0)], Duration::ZERO)
+            .unwrap();
+
+        // When
+        let result =
+            reservations.reserve(&session("bob"), vec![coin(0)], Duration::from_secs(60));
+
+        // Then
+        assert_eq!(result, Ok(()));
+        assert!(!reservations.is_reserved_by_others(&coin(0), Some(&session("bob"))));
+    }
+
+    #[test]
+    fn is_reserved_by_others__ignores_expired_reservations() {
+        // Given
+        let reservations = CoinReservations::default();
+        reservations
+            .reserve(&session("alice"), vec![coin(0), coin(1)], Duration::ZERO)
+            .unwrap();
+
+        // When
+        let reserved = reservations.is_reserved_by_others(&coin(0), None);
+
+        // Then
+        assert!(!reserved);
+        assert!(reservations.reserved().is_empty());
+    }
+
+    #[test]
+    fn release__removes_only_coins_of_the_session() {
+        // Given
+        let reservations = CoinReservations::default();
+        reservations
+            .reserve(
+                &session("alice"),
+                vec![coin(0), coin(1)],
+                Duration::from_secs(60),
+            )
+            .unwrap();
+        reservations
+            .reserve(&session("bob"), vec![coin(2)], Duration::from_secs(60))
+            .unwrap();
+
+        // When
+        let released = reservations.release(&session("alice"));
+
+        // Then
+        assert_eq!(released, 2);
+        assert_eq!(reservations.reserved(), vec![coin(2)]);
+    }
+
+    #[test]
+    fn release__keeps_the_session_with_the_same_name_of_another_client() {
+        // Given
+        let reservations = CoinReservations::default();
+        reservations
+            .reserve(&session("alice"), vec![coin(0)], Duration::from_secs(60))
+            .unwrap();
+
+        // When
+        let released = reservations.release(&SessionId::new("other key", "alice"));
+
+        // Then
+        assert_eq!(released, 0);
+        assert_eq!(reservations.reserved(), vec![coin(0)]);
+        assert_eq!(reservations.len(), 1);
+    }
+}
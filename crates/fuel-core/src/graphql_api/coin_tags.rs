@@ -0,0 +1,37 @@
+use crate::fuel_core_graphql_api::ports::CoinTags;
+use fuel_core_types::entities::coins::CoinId;
+use std::collections::{
+    HashMap,
+    HashSet,
+};
+
+/// The tags of the coins loaded from the configuration.
+/// The tagging is configured if at least one coin is tagged.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct CoinsToSpendTags {
+    /// The tags of the coins and messages.
+    pub coins: HashMap<CoinId, HashSet<String>>,
+}
+
+impl CoinsToSpendTags {
+    pub fn new(tags: Vec<(CoinId, String)>) -> Self {
+        let mut instance = Self::default();
+        for (coin_id, tag) in tags {
+            instance.coins.entry(coin_id).or_default().insert(tag);
+        }
+        instance
+    }
+}
+
+impl CoinTags for CoinsToSpendTags {
+    fn is_configured(&self) -> bool {
+        !self.coins.is_empty()
+    }
+
+    fn tags(&self, coin_id: &CoinId) -> Vec<String> {
+        self.coins
+            .get(coin_id)
+            .map(|tags| tags.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
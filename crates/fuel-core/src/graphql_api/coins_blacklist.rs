@@ -0,0 +1,43 @@
+use crate::fuel_core_graphql_api::ports::CoinsBlacklist;
+use fuel_core_types::{
+    entities::coins::CoinId,
+    fuel_tx::UtxoId,
+    fuel_types::{
+        Address,
+        Nonce,
+    },
+};
+use std::collections::HashSet;
+
+/// The blacklist of the coin selection loaded from the configuration.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct CoinsToSpendBlacklist {
+    /// The owners whose coins are never selected.
+    pub owners: HashSet<Address>,
+    /// The coins that are never selected.
+    pub coins: HashSet<UtxoId>,
+    /// The messages that are never selected.
+    pub messages: HashSet<Nonce>,
+}
+
+impl CoinsToSpendBlacklist {
+    pub fn new(owners: Vec<Address>, coins: Vec<UtxoId>, messages: Vec<Nonce>) -> Self {
+        Self {
+            owners: owners.into_iter().collect(),
+            coins: coins.into_iter().collect(),
+            messages: messages.into_iter().collect(),
+        }
+    }
+}
+
+impl CoinsBlacklist for CoinsToSpendBlacklist {
+    fn contains_owner(&self, owner: &Address) -> bool {
+        self.owners.contains(owner)
+    }
+
+    fn coins(&self) -> Vec<CoinId> {
+        let coins = self.coins.iter().copied().map(CoinId::Utxo);
+        let messages = self.messages.iter().copied().map(CoinId::Message);
+        coins.chain(messages).collect()
+    }
+}
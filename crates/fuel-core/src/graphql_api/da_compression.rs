@@ -72,6 +72,47 @@ where
     Ok(())
 }
 
+/// Removes the entries of the temporal registry with the `stale_keys`.
+pub fn da_compact_temporal_registry<T>(
+    stale_keys: &[TimestampKey],
+    db_tx: &mut T,
+) -> anyhow::Result<()>
+where
+    T: OffChainDatabaseTransaction,
+{
+    let mut db_tx = DbTx { db_tx };
+    for TimestampKey { keyspace, key } in stale_keys {
+        match keyspace {
+            TimestampKeyspace::Address => {
+                RegistryCompaction::<Address>::remove_registry(&mut db_tx, key)?
+            }
+            TimestampKeyspace::AssetId => {
+                RegistryCompaction::<AssetId>::remove_registry(&mut db_tx, key)?
+            }
+            TimestampKeyspace::ContractId => {
+                RegistryCompaction::<ContractId>::remove_registry(&mut db_tx, key)?
+            }
+            TimestampKeyspace::ScriptCode => {
+                RegistryCompaction::<ScriptCode>::remove_registry(&mut db_tx, key)?
+            }
+            TimestampKeyspace::PredicateCode => {
+                RegistryCompaction::<PredicateCode>::remove_registry(&mut db_tx, key)?
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Removes the stale entries of the temporal registry.
+trait RegistryCompaction<T> {
+    /// Removes the value of the `key` with its timestamp and its index entry.
+    fn remove_registry(
+        &mut self,
+        key: &fuel_core_types::fuel_compression::RegistryKey,
+    ) -> anyhow::Result<()>;
+}
+
 pub struct DbTx<'a, Tx> {
     pub db_tx: &'a mut Tx,
 }
@@ -92,6 +133,41 @@ mod v1_impl_temporal_registry {
 
     macro_rules! impl_temporal_registry {
         ($type:ty) => { paste::paste! {
+            impl<'a, Tx> RegistryCompaction<$type> for DbTx<'a, Tx>
+            where
+                Tx: OffChainDatabaseTransaction,
+            {
+                fn remove_registry(
+                    &mut self,
+                    key: &fuel_core_types::fuel_compression::RegistryKey,
+                ) -> anyhow::Result<()> {
+                    let value = self.db_tx
+                        .storage_as_mut::<[< DaCompressionTemporalRegistry $type>]>()
+                        .take(key)?;
+
+                    // Remove the value from the index, unless it was registered again
+                    // with another key
+                    if let Some(value) = value {
+                        let reverse_key = (&value).into();
+                        let indexed_key = self.db_tx
+                            .storage_as_ref::<DaCompressionTemporalRegistryIndex>()
+                            .get(&reverse_key)?
+                            .map(|v| v.into_owned());
+                        if indexed_key == Some(*key) {
+                            self.db_tx
+                                .storage_as_mut::<DaCompressionTemporalRegistryIndex>()
+                                .remove(&reverse_key)?;
+                        }
+                    }
+
+                    self.db_tx
+                        .storage_as_mut::<DaCompressionTemporalRegistryTimestamps>()
+                        .remove(&TimestampKey { keyspace: TimestampKeyspace::$type, key: *key })?;
+
+                    Ok(())
+                }
+            }
+
             impl<'a, Tx> TemporalRegistry<$type> for DbTx<'a, Tx>
             where
                 Tx: OffChainDatabaseTransaction,
@@ -295,6 +371,41 @@ mod v2_impl_temporal_registry {
 
     macro_rules! impl_temporal_registry_v2 {
         ($type:ty) => { paste::paste! {
+            impl<'a, Tx> RegistryCompaction<$type> for DbTx<'a, Tx>
+            where
+                Tx: OffChainDatabaseTransaction,
+            {
+                fn remove_registry(
+                    &mut self,
+                    key: &fuel_core_types::fuel_compression::RegistryKey,
+                ) -> anyhow::Result<()> {
+                    let value = self.db_tx
+                        .storage_as_mut::<[< DaCompressionTemporalRegistry $type V2>]>()
+                        .take(key)?;
+
+                    // Remove the value from the index, unless it was registered again
+                    // with another key
+                    if let Some(value) = value {
+                        let reverse_key = (&value).into();
+                        let indexed_key = self.db_tx
+                            .storage_as_ref::<DaCompressionTemporalRegistryIndexV2>()
+                            .get(&reverse_key)?
+                            .map(|v| v.into_owned());
+                        if indexed_key == Some(*key) {
+                            self.db_tx
+                                .storage_as_mut::<DaCompressionTemporalRegistryIndexV2>()
+                                .remove(&reverse_key)?;
+                        }
+                    }
+
+                    self.db_tx
+                        .storage_as_mut::<DaCompressionTemporalRegistryTimestampsV2>()
+                        .remove(&TimestampKey { keyspace: TimestampKeyspace::$type, key: *key })?;
+
+                    Ok(())
+                }
+            }
+
             impl<'a, Tx> TemporalRegistry<$type> for DbTx<'a, Tx>
             where
                 Tx: OffChainDatabaseTransaction,
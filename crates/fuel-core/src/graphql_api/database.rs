@@ -8,6 +8,7 @@ use crate::{
             OnChainDatabase,
             OnChainDatabaseAt,
         },
+        storage::messages::MessageCoinSortOrder,
     },
 };
 use fuel_core_services::yield_stream::StreamYieldExt;
@@ -38,12 +39,15 @@ use fuel_core_types::{
             DaBlockHeight,
         },
     },
-    entities::relayer::{
-        message::{
-            MerkleProof,
-            Message,
+    entities::{
+        coins::coin::Coin,
+        relayer::{
+            message::{
+                MerkleProof,
+                Message,
+            },
+            transaction::RelayedTransactionStatus,
         },
-        transaction::RelayedTransactionStatus,
     },
     fuel_tx::{
         Address,
@@ -90,13 +94,14 @@ pub type OffChainViewAt = Arc<dyn OffChainDatabaseAt>;
 
 /// The container of the on-chain and off-chain database view provides.
 /// It is used only by `ViewExtension` to create a [`ReadView`].
+#[derive(Clone)]
 pub struct ReadDatabase {
     /// The size of the batch during fetching from the database.
     batch_size: usize,
     /// The height of the genesis block.
     genesis_height: BlockHeight,
     /// The on-chain database view provider.
-    on_chain: Box<
+    on_chain: Arc<
         dyn HistoricalView<
             LatestView = OnChainView,
             Height = BlockHeight,
@@ -104,7 +109,7 @@ pub struct ReadDatabase {
         >,
     >,
     /// The off-chain database view provider.
-    off_chain: Box<
+    off_chain: Arc<
         dyn HistoricalView<
             LatestView = OffChainView,
             Height = BlockHeight,
@@ -116,7 +121,7 @@ pub struct ReadDatabase {
 }
 
 #[derive(Clone)]
-pub struct IndexationFlags(u8);
+pub struct IndexationFlags(u16);
 
 impl IndexationFlags {
     pub fn new() -> Self {
@@ -124,11 +129,15 @@ impl IndexationFlags {
     }
 
     pub fn contains(&self, kind: &IndexationKind) -> bool {
-        self.0 & (1 << *kind as u8) != 0
+        self.0 & (1 << *kind as u16) != 0
     }
 
     pub fn insert(&mut self, kind: IndexationKind) {
-        self.0 |= 1 << kind as u8;
+        self.0 |= 1 << kind as u16;
+    }
+
+    pub fn remove(&mut self, kind: IndexationKind) {
+        self.0 &= !(1 << kind as u16);
     }
 }
 
@@ -173,17 +182,58 @@ impl ReadDatabase {
                         indexation_flags.insert(kind);
                     }
                 }
+                IndexationKind::UtxoCount => {
+                    if off_chain.utxo_count_indexation_enabled()? {
+                        indexation_flags.insert(kind);
+                    }
+                }
+                IndexationKind::KnownAssets => {
+                    if off_chain.known_assets_indexation_enabled()? {
+                        indexation_flags.insert(kind);
+                    }
+                }
+                IndexationKind::SenderMessages => {
+                    if off_chain.sender_messages_indexation_enabled()? {
+                        indexation_flags.insert(kind);
+                    }
+                }
+                IndexationKind::TransactionsBySpender => {
+                    if off_chain.transactions_by_spender_indexation_enabled()? {
+                        indexation_flags.insert(kind);
+                    }
+                }
+                IndexationKind::OutboundMessageTransactions => {
+                    if off_chain.outbound_message_transactions_indexation_enabled()? {
+                        indexation_flags.insert(kind);
+                    }
+                }
+                IndexationKind::SortedMessageCoins => {
+                    if off_chain.sorted_message_coins_indexation_enabled()? {
+                        indexation_flags.insert(kind);
+                    }
+                }
+                IndexationKind::Predicates => {
+                    if off_chain.predicates_indexation_enabled()? {
+                        indexation_flags.insert(kind);
+                    }
+                }
             }
         }
         Ok(Self {
             batch_size,
             genesis_height,
-            on_chain: Box::new(ArcWrapper::new(on_chain)),
-            off_chain: Box::new(ArcWrapper::new(off_chain)),
+            on_chain: Arc::new(ArcWrapper::new(on_chain)),
+            off_chain: Arc::new(ArcWrapper::new(off_chain)),
             indexation_flags,
         })
     }
 
+    /// Returns the heights of the latest blocks processed by the on-chain
+    /// and the off-chain databases.
+    pub fn latest_heights(&self) -> (Option<BlockHeight>, Option<BlockHeight>) {
+        (self.on_chain.latest_height(), self.off_chain.latest_height())
+    }
+
     /// Creates a consistent view of the database.
     pub fn view(&self) -> StorageResult<ReadView> {
         // TODO: Use the same height for both views to guarantee consistency.
@@ -370,6 +420,10 @@ impl ReadView {
             .yield_each(self.batch_size)
     }
 
+    pub fn all_coins(&self) -> impl Stream<Item = StorageResult<Coin>> + '_ {
+        futures::stream::iter(self.on_chain.all_coins()).yield_each(self.batch_size)
+    }
+
     pub fn message_exists(&self, nonce: &Nonce) -> StorageResult<bool> {
         self.on_chain.message_exists(nonce)
     }
@@ -462,6 +516,32 @@ impl ReadView {
         ))
     }
 
+    pub fn sender_message_ids(
+        &self,
+        sender: &Address,
+        start_message_id: Option<Nonce>,
+        direction: IterDirection,
+    ) -> impl Stream<Item = StorageResult<Nonce>> + '_ {
+        futures::stream::iter(self.off_chain.sender_message_ids(
+            sender,
+            start_message_id,
+            direction,
+        ))
+    }
+
+    pub fn owned_sorted_message_coin_ids(
+        &self,
+        owner: &Address,
+        order: MessageCoinSortOrder,
+        start: Option<(u64, Nonce)>,
+        direction: IterDirection,
+    ) -> impl Stream<Item = StorageResult<(u64, Nonce)>> + '_ {
+        futures::stream::iter(
+            self.off_chain
+                .owned_sorted_message_coin_ids(owner, order, start, direction),
+        )
+    }
+
     pub fn owned_transactions_ids(
         &self,
         owner: Address,
@@ -474,6 +554,18 @@ impl ReadView {
         )
     }
 
+    pub fn spent_transactions_ids(
+        &self,
+        owner: Address,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> impl Stream<Item = StorageResult<(TxPointer, TxId)>> + '_ {
+        futures::stream::iter(
+            self.off_chain
+                .spent_transactions_ids(owner, start, direction),
+        )
+    }
+
     pub fn contract_salt(&self, contract_id: &ContractId) -> StorageResult<Salt> {
         self.off_chain.contract_salt(contract_id)
     }
@@ -488,6 +580,20 @@ impl ReadView {
     pub fn message_is_spent(&self, nonce: &Nonce) -> StorageResult<bool> {
         self.off_chain.message_is_spent(nonce)
     }
+
+    pub fn outbound_message_tx_id(&self, nonce: &Nonce) -> StorageResult<Option<TxId>> {
+        if !self
+            .indexation_flags
+            .contains(&IndexationKind::OutboundMessageTransactions)
+        {
+            return Err(anyhow::anyhow!(
+                "Outbound message transactions index is not available"
+            )
+            .into())
+        }
+
+        self.off_chain.outbound_message_tx_id(nonce)
+    }
 }
 
 #[derive(Clone)]
@@ -522,6 +628,10 @@ impl ReadViewAt {
         futures::stream::iter(self.on_chain.contract_balance_values(contract_id, assets))
             .yield_each(self.batch_size)
     }
+
+    pub fn coin(&self, utxo_id: &UtxoId) -> StorageResult<Option<Coin>> {
+        self.on_chain.coin(utxo_id)
+    }
 }
 
 #[test]
@@ -11,6 +11,7 @@ use async_graphql::Response;
 pub(crate) mod chain_state_info;
 pub(crate) mod metrics;
 pub(crate) mod required_fuel_block_height;
+pub(crate) mod trusted_client;
 pub(crate) mod validation;
 
 // In the case of a successful query, we return the information below on
@@ -0,0 +1,92 @@
+use async_graphql::{
+    extensions::{
+        Extension,
+        ExtensionContext,
+        ExtensionFactory,
+        NextValidation,
+    },
+    Request,
+    ServerError,
+    ValidationResult,
+};
+use axum::http::HeaderMap;
+use std::{
+    collections::HashSet,
+    sync::Arc,
+};
+
+/// The header with the API key of the client.
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// The marker of the request from a trusted client with its API key.
+pub(crate) struct TrustedClient {
+    pub api_key: String,
+}
+
+/// The API keys of the trusted clients.
+#[derive(Clone, Default)]
+pub(crate) struct TrustedApiKeys(Arc<HashSet<String>>);
+
+impl TrustedApiKeys {
+    pub fn new(keys: HashSet<String>) -> Self {
+        Self(Arc::new(keys))
+    }
+
+    /// Marks the `request` as trusted if the `headers` contain a trusted API key.
+    pub fn authorize(&self, headers: &HeaderMap, request: Request) -> Request {
+        let trusted_key = headers
+            .get(API_KEY_HEADER)
+            .and_then(|key| key.to_str().ok())
+            .filter(|key| self.0.contains(*key));
+
+        match trusted_key {
+            Some(api_key) => request.data(TrustedClient {
+                api_key: api_key.to_string(),
+            }),
+            None => request,
+        }
+    }
+}
+
+/// The extension applies the default complexity limit to the queries from untrusted
+/// clients. The schema itself is limited by the higher complexity of trusted clients.
+pub(crate) struct TrustedClientExtension {
+    max_complexity: usize,
+}
+
+impl TrustedClientExtension {
+    pub fn new(max_complexity: usize) -> Self {
+        Self { max_complexity }
+    }
+}
+
+impl ExtensionFactory for TrustedClientExtension {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(TrustedClientInner {
+            max_complexity: self.max_complexity,
+        })
+    }
+}
+
+struct TrustedClientInner {
+    max_complexity: usize,
+}
+
+#[async_trait::async_trait]
+impl Extension for TrustedClientInner {
+    async fn validation(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        next: NextValidation<'_>,
+    ) -> async_graphql::Result<ValidationResult, Vec<ServerError>> {
+        let result = next.run(ctx).await?;
+
+        if result.complexity > self.max_complexity
+            && ctx.data_opt::<TrustedClient>().is_none()
+        {
+            return Err(vec![ServerError::new("Query is too complex.", None)])
+        }
+
+        Ok(result)
+    }
+}
@@ -1,9 +1,13 @@
 use super::storage::{
     assets::AssetDetails,
     balances::TotalBalanceAmount,
+    messages::MessageCoinSortOrder,
+    predicates::PredicateDetails,
+    statistic::UtxoCount,
 };
 use crate::fuel_core_graphql_api::storage::coins::CoinsToSpendIndexKey;
 use async_trait::async_trait;
+use fuel_core_compression::VersionedCompressedBlock;
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::{
     iter::{
@@ -39,12 +43,18 @@ use fuel_core_types::{
             DaBlockHeight,
         },
     },
-    entities::relayer::{
-        message::{
-            MerkleProof,
-            Message,
+    entities::{
+        coins::{
+            coin::Coin,
+            CoinId,
+        },
+        relayer::{
+            message::{
+                MerkleProof,
+                Message,
+            },
+            transaction::RelayedTransactionStatus,
         },
-        transaction::RelayedTransactionStatus,
     },
     fuel_tx::{
         Bytes32,
@@ -123,6 +133,24 @@ pub trait OffChainDatabase: Send + Sync {
         direction: IterDirection,
     ) -> BoxedIter<'_, StorageResult<Nonce>>;
 
+    /// Returns the ids of the unspent messages sent by the `sender` on the DA layer.
+    fn sender_message_ids(
+        &self,
+        sender: &Address,
+        start_message_id: Option<Nonce>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<Nonce>>;
+
+    /// Returns the sort keys and the ids of the message coins of the `owner`
+    /// sorted by the `order`, starting from the `start` position.
+    fn owned_sorted_message_coin_ids(
+        &self,
+        owner: &Address,
+        order: MessageCoinSortOrder,
+        start: Option<(u64, Nonce)>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(u64, Nonce)>>;
+
     fn owned_transactions_ids(
         &self,
         owner: Address,
@@ -130,12 +158,31 @@ pub trait OffChainDatabase: Send + Sync {
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, TxId)>>;
 
+    /// Returns the ids of the transactions spending the coins of the `owner`.
+    fn spent_transactions_ids(
+        &self,
+        owner: Address,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, TxId)>>;
+
     fn coins_to_spend_index(
         &self,
         owner: &Address,
         asset_id: &AssetId,
     ) -> CoinsToSpendIndexIter;
 
+    /// Returns the keys of the coins to spend index of the `owner` with an amount
+    /// below the `threshold`, ordered by the asset id and the amount.
+    fn owned_dust_coins_to_spend_index_keys(
+        &self,
+        owner: &Address,
+        threshold: u64,
+    ) -> BoxedIter<'_, StorageResult<CoinsToSpendIndexKey>>;
+
+    /// Returns the number of unspent coins in the UTXO set.
+    fn utxo_count(&self) -> StorageResult<UtxoCount>;
+
     fn contract_salt(&self, contract_id: &ContractId) -> StorageResult<Salt>;
 
     fn old_block(&self, height: &BlockHeight) -> StorageResult<CompressedBlock>;
@@ -157,7 +204,21 @@ pub trait OffChainDatabase: Send + Sync {
 
     fn message_is_spent(&self, nonce: &Nonce) -> StorageResult<bool>;
 
+    /// Returns the id of the transaction that emitted the outbound message with the `nonce`.
+    fn outbound_message_tx_id(&self, nonce: &Nonce) -> StorageResult<Option<TxId>>;
+
     fn asset_info(&self, asset_id: &AssetId) -> StorageResult<Option<AssetDetails>>;
+
+    /// Returns the distinct asset ids seen on the chain, ordered by the asset id.
+    fn known_assets(
+        &self,
+        start: Option<AssetId>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<AssetId>>;
+
+    /// Returns the bytecode and the predicate data length hint of the predicate
+    /// with the `address`, if it was seen on the chain.
+    fn predicate(&self, address: &Address) -> StorageResult<Option<PredicateDetails>>;
 }
 
 /// The on chain database port expected by GraphQL API service.
@@ -172,6 +233,7 @@ pub trait OnChainDatabase:
     + StorageInspect<UploadedBytecodes, Error = StorageError>
     + DatabaseContracts
     + DatabaseChain
+    + DatabaseCoins
     + DatabaseMessageProof
 {
 }
@@ -215,6 +277,12 @@ pub trait DatabaseMessages: StorageInspect<Messages, Error = StorageError> {
     fn message_exists(&self, nonce: &Nonce) -> StorageResult<bool>;
 }
 
+/// Trait that specifies all the getters required for coins.
+pub trait DatabaseCoins {
+    /// Returns all unspent coins ordered by the `UtxoId`.
+    fn all_coins(&self) -> BoxedIter<'_, StorageResult<Coin>>;
+}
+
 pub trait DatabaseRelayedTransactions {
     fn transaction_status(
         &self,
@@ -256,6 +324,17 @@ pub trait TxPoolPort: Send + Sync {
 
     async fn insert(&self, txs: Transaction) -> anyhow::Result<()>;
 
+    /// Inserts the `tx` unless a transaction was already inserted with the
+    /// `idempotency_key`. Returns the transaction inserted with the key.
+    async fn insert_idempotent(
+        &self,
+        idempotency_key: String,
+        tx: Transaction,
+    ) -> anyhow::Result<Transaction>;
+
+    /// Returns the tip and the max gas of up to `max_txs` transactions in the pool.
+    async fn tips_and_max_gas(&self, max_txs: usize) -> anyhow::Result<Vec<(u64, u64)>>;
+
     fn latest_pool_stats(&self) -> TxPoolStats;
 }
 
@@ -311,10 +390,117 @@ pub trait P2pPort: Send + Sync {
     async fn all_peer_info(&self) -> anyhow::Result<Vec<PeerInfo>>;
 }
 
+/// Trait that specifies the decompression of the DA compressed blocks.
+#[async_trait::async_trait]
+pub trait DaDecompressionPort: Send + Sync {
+    /// Decompresses the `block` against the temporal registry of the node and
+    /// returns its transactions. The registrations of the `block` are not persisted.
+    async fn decompress(
+        &self,
+        block: VersionedCompressedBlock,
+    ) -> anyhow::Result<Vec<Transaction>>;
+}
+
+/// Trait that specifies queries supported by the relayer.
+///
+/// The queries scan the events synced by the relayer and fail if they
+/// have to visit more than `limit` DA heights with events.
+#[async_trait::async_trait]
+pub trait RelayerPort: Send + Sync {
+    /// Returns `true` if the relayer has synced the message with the `nonce`
+    /// at a DA height above the `after_da_height`.
+    /// Always returns `false` if the relayer is disabled.
+    async fn contains_message(
+        &self,
+        nonce: &Nonce,
+        after_da_height: DaBlockHeight,
+        limit: usize,
+    ) -> anyhow::Result<bool>;
+
+    /// Returns the progress of the relayer with the number of messages synced
+    /// at a DA height above the `after_da_height`.
+    /// Returns `None` if the relayer is disabled.
+    async fn message_backlog(
+        &self,
+        after_da_height: DaBlockHeight,
+        limit: usize,
+    ) -> anyhow::Result<Option<RelayerBacklog>>;
+
+    /// Returns the number and the total amount of the messages synced by the relayer
+    /// at the DA heights above the `after_da_height` up to the `to_da_height`.
+    /// Returns `None` if the relayer is disabled.
+    async fn relayed_messages(
+        &self,
+        after_da_height: DaBlockHeight,
+        to_da_height: DaBlockHeight,
+        limit: usize,
+    ) -> anyhow::Result<Option<RelayedMessages>>;
+}
+
+/// The messages known to the relayer, but not yet included into the chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelayerBacklog {
+    /// The DA height up to which the relayer synced the events.
+    pub synced_da_height: DaBlockHeight,
+    /// Whether the relayer reached the finalized tip of the DA layer.
+    pub synced_to_tip: bool,
+    /// The number of messages synced by the relayer, but not yet included.
+    pub pending_messages: u64,
+}
+
+/// The messages synced by the relayer within a range of DA heights.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RelayedMessages {
+    /// The number of the messages.
+    pub count: u64,
+    /// The total amount of the messages.
+    pub amount: u128,
+}
+
+/// Trait that specifies the coins that must not be selected for spending,
+/// e.g. the coins of the sanctioned addresses. The blacklist is applied
+/// on top of the coins excluded by the caller of the selection.
+pub trait CoinsBlacklist: Send + Sync {
+    /// Returns `true` if the coins of the `owner` must not be selected.
+    fn contains_owner(&self, owner: &Address) -> bool;
+
+    /// Returns the coins and messages that must not be selected.
+    fn coins(&self) -> Vec<CoinId>;
+}
+
+/// Trait that specifies the off-chain tags of the coins, e.g. the business
+/// purpose of the funds assigned by a custodian, used to filter the selection.
+pub trait CoinTags: Send + Sync {
+    /// Returns `false` if the coins aren't tagged, so the tag filters are ignored.
+    fn is_configured(&self) -> bool;
+
+    /// Returns the tags of the coin or message.
+    fn tags(&self, coin_id: &CoinId) -> Vec<String>;
+}
+
+/// The gas price split into the execution and DA components.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GasPriceComponents {
+    /// The part of the gas price that covers the execution.
+    pub execution: u64,
+    /// The part of the gas price that covers the DA commitment.
+    pub da: u64,
+}
+
 /// Trait for defining how to estimate gas price for future blocks
 pub trait GasPriceEstimate: Send + Sync {
     /// The worst case scenario for gas price at a given horizon
     fn worst_case_gas_price(&self, height: BlockHeight) -> Option<u64>;
+
+    /// The worst case scenario for gas price at a given horizon split into
+    /// the execution and DA components. Returns `None` if the estimator
+    /// doesn't track the components separately.
+    fn worst_case_gas_price_components(
+        &self,
+        _height: BlockHeight,
+    ) -> Option<GasPriceComponents> {
+        None
+    }
 }
 
 /// Trait for getting VM memory.
@@ -333,24 +519,39 @@ pub mod worker {
             coins::OwnedCoins,
             contracts::ContractsInfo,
             messages::{
+                OutboundMessageTransactions,
                 OwnedMessageIds,
+                OwnedSortedMessageCoins,
+                SenderMessageIds,
                 SpentMessages,
             },
         },
         graphql_api::storage::{
-            assets::AssetsInfo,
+            assets::{
+                AssetsInfo,
+                KnownAssets,
+            },
             balances::{
                 CoinBalances,
                 MessageBalances,
             },
-            coins::CoinsToSpendIndex,
-            da_compression::*,
+            coins::{
+                CoinsToSpendIndex,
+                CoinsToSpendIndexKey,
+            },
+            da_compression::{
+                timestamps::TimestampKey,
+                *,
+            },
             old::{
                 OldFuelBlockConsensus,
                 OldFuelBlocks,
                 OldTransactions,
             },
+            predicates::Predicates,
             relayed_transactions::RelayedTransactionStatuses,
+            statistic::UtxoCount,
+            transactions::TransactionsBySpender,
         },
     };
     use derive_more::Display;
@@ -363,6 +564,7 @@ pub mod worker {
     use fuel_core_types::{
         fuel_tx::{
             Address,
+            AssetId,
             Bytes32,
         },
         fuel_types::BlockHeight,
@@ -373,6 +575,7 @@ pub mod worker {
                 TransactionStatus,
             },
         },
+        tai64::Tai64,
     };
 
     pub trait OnChainDatabase: Send + Sync {
@@ -397,8 +600,44 @@ pub mod worker {
         /// Checks if CoinsToSpend indexation functionality is available.
         fn coins_to_spend_indexation_enabled(&self) -> StorageResult<bool>;
 
+        /// Returns the keys of the coins to spend index of the `owner`,
+        /// optionally limited to the `asset_id`.
+        fn owned_coins_to_spend_index_keys(
+            &self,
+            owner: &Address,
+            asset_id: Option<&AssetId>,
+        ) -> StorageResult<Vec<CoinsToSpendIndexKey>>;
+
         /// Checks if AssetMetadata indexation functionality is available.
         fn asset_metadata_indexation_enabled(&self) -> StorageResult<bool>;
+
+        /// Checks if the UTXO count statistic is available.
+        fn utxo_count_indexation_enabled(&self) -> StorageResult<bool>;
+
+        /// Checks if the known assets index is available.
+        fn known_assets_indexation_enabled(&self) -> StorageResult<bool>;
+
+        /// Checks if the index of the messages by the sender is available.
+        fn sender_messages_indexation_enabled(&self) -> StorageResult<bool>;
+
+        /// Checks if the index of the transactions by the spender is available.
+        fn transactions_by_spender_indexation_enabled(&self) -> StorageResult<bool>;
+
+        /// Checks if the index of the transactions by the nonces of their outbound messages is available.
+        fn outbound_message_transactions_indexation_enabled(&self) -> StorageResult<bool>;
+
+        /// Returns  if the index of the message coins sorted by the amount and the DA height is available.
+        fn sorted_message_coins_indexation_enabled(&self) -> StorageResult<bool>;
+
+        /// Checks if the index of the predicates by their address is available.
+        fn predicates_indexation_enabled(&self) -> StorageResult<bool>;
+
+        /// Returns the keys of the DA compression temporal registry
+        /// with a timestamp older than the `oldest_timestamp`.
+        fn stale_da_compression_registry_keys(
+            &self,
+            oldest_timestamp: Tai64,
+        ) -> StorageResult<Vec<TimestampKey>>;
     }
 
     /// Represents either the Genesis Block or a block at a specific height
@@ -412,13 +651,17 @@ pub mod worker {
 
     pub trait OffChainDatabaseTransaction:
         StorageMutate<OwnedMessageIds, Error = StorageError>
+        + StorageMutate<SenderMessageIds, Error = StorageError>
+        + StorageMutate<OwnedSortedMessageCoins, Error = StorageError>
         + StorageMutate<OwnedCoins, Error = StorageError>
+        + StorageMutate<TransactionsBySpender, Error = StorageError>
         + StorageMutate<FuelBlockIdsToHeights, Error = StorageError>
         + StorageMutate<ContractsInfo, Error = StorageError>
         + StorageMutate<OldFuelBlocks, Error = StorageError>
         + StorageMutate<OldFuelBlockConsensus, Error = StorageError>
         + StorageMutate<OldTransactions, Error = StorageError>
         + StorageMutate<SpentMessages, Error = StorageError>
+        + StorageMutate<OutboundMessageTransactions, Error = StorageError>
         + StorageMutate<RelayedTransactionStatuses, Error = StorageError>
         + StorageMutate<CoinBalances, Error = StorageError>
         + StorageMutate<MessageBalances, Error = StorageError>
@@ -433,6 +676,8 @@ pub mod worker {
         + StorageMutate<DaCompressionTemporalRegistryTimestamps, Error = StorageError>
         + StorageMutate<DaCompressionTemporalRegistryEvictorCache, Error = StorageError>
         + StorageMutate<AssetsInfo, Error = StorageError>
+        + StorageMutate<KnownAssets, Error = StorageError>
+        + StorageMutate<Predicates, Error = StorageError>
         + MaybeTemporalRegistryV2Bounds
     {
         fn record_tx_id_owner(
@@ -456,6 +701,16 @@ pub mod worker {
         /// Gets the total number of transactions on the chain from metadata.
         fn get_tx_count(&self) -> StorageResult<u64>;
 
+        /// Updates the number of unspent coins with the `created` and `consumed`
+        /// coins. The `block_height` is recorded as the last update height if set.
+        /// Returns the count after the update.
+        fn update_utxo_count(
+            &mut self,
+            created: u64,
+            consumed: u64,
+            block_height: Option<BlockHeight>,
+        ) -> StorageResult<UtxoCount>;
+
         /// Commits the underlying changes into the database.
         fn commit(self) -> StorageResult<()>;
     }
@@ -581,6 +836,9 @@ pub trait OnChainDatabaseAt: Send + Sync {
         contract_id: ContractId,
         assets: Vec<AssetId>,
     ) -> BoxedIter<StorageResult<ContractBalance>>;
+
+    /// Returns the coin if it is unspent at the height of the view.
+    fn coin(&self, utxo_id: &UtxoId) -> StorageResult<Option<Coin>>;
 }
 
 pub trait OffChainDatabaseAt: Send + Sync {}
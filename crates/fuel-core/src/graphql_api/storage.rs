@@ -34,7 +34,10 @@ use fuel_core_types::{
     fuel_types::BlockHeight,
     services::txpool::TransactionExecutionStatus,
 };
-use statistic::StatisticTable;
+use statistic::{
+    StatisticTable,
+    UtxoCount,
+};
 
 pub mod assets;
 pub mod balances;
@@ -44,6 +47,7 @@ pub mod contracts;
 pub mod da_compression;
 pub mod messages;
 pub mod old;
+pub mod predicates;
 pub mod statistic;
 pub mod transactions;
 
@@ -51,6 +55,8 @@ pub mod relayed_transactions;
 /// Tracks the total number of transactions written to the chain
 /// It's useful for analyzing TPS or other metrics.
 const TX_COUNT: &str = "total_tx_count";
+/// Tracks the total number of unspent coins and the height at which it was updated.
+pub(crate) const UTXO_COUNT: &str = "total_utxo_count";
 
 /// GraphQL database tables column ids to the corresponding [`fuel_core_storage::Mappable`] table.
 #[repr(u32)]
@@ -176,6 +182,18 @@ pub enum Column {
     DaCompressionTemporalRegistryEvictorCacheMerkleData = 49,
     #[cfg(feature = "fault-proving")]
     DaCompressionTemporalRegistryEvictorCacheMerkleMetadata = 50,
+    /// See [`OutboundMessageTransactions`](messages::OutboundMessageTransactions)
+    OutboundMessageTransactions = 51,
+    /// See [`KnownAssets`](assets::KnownAssets)
+    KnownAssets = 52,
+    /// See [`SenderMessageIds`](messages::SenderMessageIds)
+    SenderMessageIds = 53,
+    /// See [`TransactionsBySpender`](transactions::TransactionsBySpender)
+    TransactionsBySpenderBlockIdx = 54,
+    /// See [`OwnedSortedMessageCoins`](messages::OwnedSortedMessageCoins)
+    OwnedSortedMessageCoins = 55,
+    /// See [`Predicates`](predicates::Predicates)
+    Predicates = 56,
 }
 
 impl Column {
@@ -245,6 +263,39 @@ where
         Ok(tx_count)
     }
 
+    fn update_utxo_count(
+        &mut self,
+        created: u64,
+        consumed: u64,
+        block_height: Option<BlockHeight>,
+    ) -> StorageResult<UtxoCount> {
+        let current = self
+            .storage::<StatisticTable<UtxoCount>>()
+            .get(UTXO_COUNT)?
+            .unwrap_or_default()
+            .into_owned();
+        let created_count = current.count.saturating_add(created);
+        let count = created_count.checked_sub(consumed).unwrap_or_else(|| {
+            // The count is only a statistic, so the block processing continues.
+            tracing::error!(
+                "The UTXO count {} underflows with {created} created \
+                and {consumed} consumed coins, resetting it to zero",
+                current.count
+            );
+            0
+        });
+        let new_utxo_count = UtxoCount {
+            count,
+            block_height: block_height.or(current.block_height),
+        };
+        <_ as StorageMutate<StatisticTable<UtxoCount>>>::insert(
+            self,
+            UTXO_COUNT,
+            &new_utxo_count,
+        )?;
+        Ok(new_utxo_count)
+    }
+
     fn commit(self) -> StorageResult<()> {
         self.commit()?;
         Ok(())
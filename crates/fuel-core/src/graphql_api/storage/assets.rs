@@ -40,6 +40,25 @@ impl TableWithBlueprint for AssetsInfo {
     }
 }
 
+/// The table of the distinct asset ids seen on the chain in coins and contract mints.
+pub struct KnownAssets;
+
+impl Mappable for KnownAssets {
+    type Key = AssetId;
+    type OwnedKey = Self::Key;
+    type Value = Self::OwnedValue;
+    type OwnedValue = ();
+}
+
+impl TableWithBlueprint for KnownAssets {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::KnownAssets
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -49,4 +68,10 @@ mod test {
         <AssetsInfo as Mappable>::Key::default(),
         <AssetsInfo as Mappable>::Value::default()
     );
+
+    fuel_core_storage::basic_storage_tests!(
+        KnownAssets,
+        <KnownAssets as Mappable>::Key::default(),
+        <KnownAssets as Mappable>::Value::default()
+    );
 }
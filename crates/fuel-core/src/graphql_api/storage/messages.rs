@@ -8,15 +8,22 @@ use fuel_core_chain_config::{
 use fuel_core_storage::{
     blueprint::plain::Plain,
     codec::{
+        manual::Manual,
         postcard::Postcard,
         raw::Raw,
+        Decode,
+        Encode,
     },
     structured_storage::TableWithBlueprint,
     Mappable,
 };
-use fuel_core_types::fuel_types::{
-    Address,
-    Nonce,
+use fuel_core_types::{
+    entities::relayer::message::Message,
+    fuel_tx::TxId,
+    fuel_types::{
+        Address,
+        Nonce,
+    },
 };
 use rand::{
     distributions::{
@@ -25,6 +32,10 @@ use rand::{
     },
     Rng,
 };
+use std::{
+    array::TryFromSliceError,
+    mem::size_of,
+};
 
 fuel_core_types::fuel_vm::double_key!(OwnedMessageKey, Address, address, Nonce, nonce);
 
@@ -64,6 +75,184 @@ fuel_core_storage::basic_storage_tests!(
     <OwnedMessageIds as Mappable>::Value::default()
 );
 
+fuel_core_types::fuel_vm::double_key!(SenderMessageKey, Address, sender, Nonce, nonce);
+
+/// The table that stores all unspent messages per sender on the DA layer.
+pub struct SenderMessageIds;
+
+impl Mappable for SenderMessageIds {
+    type Key = SenderMessageKey;
+    type OwnedKey = Self::Key;
+    type Value = ();
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for SenderMessageIds {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::SenderMessageIds
+    }
+}
+
+#[cfg(test)]
+fuel_core_storage::basic_storage_tests!(
+    SenderMessageIds,
+    <SenderMessageIds as Mappable>::Key::default(),
+    <SenderMessageIds as Mappable>::Value::default()
+);
+
+/// The order of the message coins in the [`OwnedSortedMessageCoins`] table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum MessageCoinSortOrder {
+    Amount = 0,
+    DaHeight = 1,
+}
+
+const SORTED_MESSAGE_COIN_KEY_SIZE: usize =
+    Address::LEN + size_of::<u8>() + size_of::<u64>() + Nonce::LEN;
+
+/// The key of the [`OwnedSortedMessageCoins`] table:
+/// `owner + order + sort key + nonce`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct OwnedSortedMessageCoinKey {
+    pub owner: Address,
+    pub order: u8,
+    pub sort_key: u64,
+    pub nonce: Nonce,
+}
+
+impl OwnedSortedMessageCoinKey {
+    pub fn new(
+        owner: &Address,
+        order: MessageCoinSortOrder,
+        sort_key: u64,
+        nonce: &Nonce,
+    ) -> Self {
+        Self {
+            owner: *owner,
+            order: order as u8,
+            sort_key,
+            nonce: *nonce,
+        }
+    }
+
+    /// Returns the keys of the `message` in all orders.
+    pub fn from_message(message: &Message) -> [Self; 2] {
+        [
+            Self::new(
+                message.recipient(),
+                MessageCoinSortOrder::Amount,
+                message.amount(),
+                message.nonce(),
+            ),
+            Self::new(
+                message.recipient(),
+                MessageCoinSortOrder::DaHeight,
+                message.da_height().0,
+                message.nonce(),
+            ),
+        ]
+    }
+
+    /// The keys of the message coins of the `owner` sorted by the `order`
+    /// start with the prefix.
+    pub fn prefix(owner: &Address, order: MessageCoinSortOrder) -> [u8; Address::LEN + 1] {
+        let mut prefix = [0u8; Address::LEN + 1];
+        prefix[..Address::LEN].copy_from_slice(owner.as_ref());
+        prefix[Address::LEN] = order as u8;
+        prefix
+    }
+}
+
+impl From<[u8; SORTED_MESSAGE_COIN_KEY_SIZE]> for OwnedSortedMessageCoinKey {
+    fn from(bytes: [u8; SORTED_MESSAGE_COIN_KEY_SIZE]) -> Self {
+        let owner: [u8; 32] = bytes[..32].try_into().expect("It's an array of 32 bytes");
+        let sort_key: [u8; 8] = bytes[33..41].try_into().expect("It's an array of 8 bytes");
+        let nonce: [u8; 32] = bytes[41..].try_into().expect("It's an array of 32 bytes");
+
+        Self {
+            owner: owner.into(),
+            order: bytes[32],
+            sort_key: u64::from_be_bytes(sort_key),
+            nonce: nonce.into(),
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for OwnedSortedMessageCoinKey {
+    type Error = TryFromSliceError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; SORTED_MESSAGE_COIN_KEY_SIZE] = bytes.try_into()?;
+        Ok(Self::from(bytes))
+    }
+}
+
+impl Encode<OwnedSortedMessageCoinKey> for Manual<OwnedSortedMessageCoinKey> {
+    type Encoder<'a> = [u8; SORTED_MESSAGE_COIN_KEY_SIZE];
+
+    fn encode(t: &OwnedSortedMessageCoinKey) -> Self::Encoder<'_> {
+        let mut bytes = [0u8; SORTED_MESSAGE_COIN_KEY_SIZE];
+        bytes[..32].copy_from_slice(t.owner.as_ref());
+        bytes[32] = t.order;
+        bytes[33..41].copy_from_slice(&t.sort_key.to_be_bytes());
+        bytes[41..].copy_from_slice(t.nonce.as_ref());
+        bytes
+    }
+}
+
+impl Decode<OwnedSortedMessageCoinKey> for Manual<OwnedSortedMessageCoinKey> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<OwnedSortedMessageCoinKey> {
+        OwnedSortedMessageCoinKey::try_from(bytes)
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))
+    }
+}
+
+/// The table that stores the message coins, i.e. the messages without data,
+/// per owner sorted by the amount and by the DA height, which allows paginating
+/// them in these orders.
+pub struct OwnedSortedMessageCoins;
+
+impl Mappable for OwnedSortedMessageCoins {
+    type Key = OwnedSortedMessageCoinKey;
+    type OwnedKey = Self::Key;
+    type Value = ();
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for OwnedSortedMessageCoins {
+    type Blueprint = Plain<Manual<OwnedSortedMessageCoinKey>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::OwnedSortedMessageCoins
+    }
+}
+
+#[cfg(test)]
+mod sorted_message_coins_test {
+    use super::*;
+
+    fn generate_key(
+        rng: &mut impl rand::Rng,
+    ) -> <OwnedSortedMessageCoins as Mappable>::Key {
+        let mut bytes = [0u8; SORTED_MESSAGE_COIN_KEY_SIZE];
+        rng.fill(bytes.as_mut());
+        bytes.into()
+    }
+
+    fuel_core_storage::basic_storage_tests!(
+        OwnedSortedMessageCoins,
+        [1u8; SORTED_MESSAGE_COIN_KEY_SIZE].into(),
+        <OwnedSortedMessageCoins as Mappable>::Value::default(),
+        <OwnedSortedMessageCoins as Mappable>::Value::default(),
+        generate_key
+    );
+}
+
 /// The storage table that indicates if the message is spent or not.
 pub struct SpentMessages;
 
@@ -101,3 +290,30 @@ fuel_core_storage::basic_storage_tests!(
     <SpentMessages as Mappable>::Key::default(),
     <SpentMessages as Mappable>::Value::default()
 );
+
+/// The table that maps the nonce of an outbound message
+/// (`Receipt::MessageOut`) to the transaction that emitted it.
+pub struct OutboundMessageTransactions;
+
+impl Mappable for OutboundMessageTransactions {
+    type Key = Self::OwnedKey;
+    type OwnedKey = Nonce;
+    type Value = Self::OwnedValue;
+    type OwnedValue = TxId;
+}
+
+impl TableWithBlueprint for OutboundMessageTransactions {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::OutboundMessageTransactions
+    }
+}
+
+#[cfg(test)]
+fuel_core_storage::basic_storage_tests!(
+    OutboundMessageTransactions,
+    <OutboundMessageTransactions as Mappable>::Key::default(),
+    <OutboundMessageTransactions as Mappable>::Value::default()
+);
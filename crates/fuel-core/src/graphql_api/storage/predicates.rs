@@ -0,0 +1,49 @@
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        postcard::Postcard,
+        raw::Raw,
+    },
+    structured_storage::TableWithBlueprint,
+    Mappable,
+};
+use fuel_core_types::fuel_tx::Address;
+
+/// The table of the predicates seen on the chain by their address,
+/// used to assemble the predicate inputs of the selected coins.
+pub struct Predicates;
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PredicateDetails {
+    /// The bytecode of the predicate.
+    pub bytecode: Vec<u8>,
+    /// The length of the predicate data of the last spent input with this predicate.
+    pub data_length: u64,
+}
+
+impl Mappable for Predicates {
+    type Key = Address;
+    type OwnedKey = Self::Key;
+    type Value = Self::OwnedValue;
+    type OwnedValue = PredicateDetails;
+}
+
+impl TableWithBlueprint for Predicates {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::Predicates
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fuel_core_storage::basic_storage_tests!(
+        Predicates,
+        <Predicates as Mappable>::Key::default(),
+        <Predicates as Mappable>::Value::default()
+    );
+}
@@ -4,6 +4,7 @@ use fuel_core_storage::{
     structured_storage::TableWithBlueprint,
     Mappable,
 };
+use fuel_core_types::fuel_types::BlockHeight;
 
 /// The table that stores all statistic about blockchain. Each key is a string, while the value
 /// depends on the context.
@@ -30,3 +31,15 @@ where
         Self::Column::Statistic
     }
 }
+
+/// The number of unspent coins in the UTXO set.
+#[derive(
+    Default, Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+)]
+pub struct UtxoCount {
+    /// The total number of unspent coins.
+    pub count: u64,
+    /// The height of the last block that updated the count.
+    /// It is `None` if only the genesis state was imported.
+    pub block_height: Option<BlockHeight>,
+}
@@ -61,6 +61,37 @@ impl AddTable<OwnedTransactions> for StateConfigBuilder {
     }
 }
 
+/// These tables allow iteration over all transactions spending the coins of an address.
+pub struct TransactionsBySpender;
+
+impl Mappable for TransactionsBySpender {
+    type Key = OwnedTransactionIndexKey;
+    type OwnedKey = Self::Key;
+    type Value = Bytes32;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for TransactionsBySpender {
+    type Blueprint = Plain<Manual<OwnedTransactionIndexKey>, Raw>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::TransactionsBySpenderBlockIdx
+    }
+}
+
+impl AsTable<TransactionsBySpender> for StateConfig {
+    fn as_table(&self) -> Vec<TableEntry<TransactionsBySpender>> {
+        Vec::new() // Do not include these for now
+    }
+}
+
+impl AddTable<TransactionsBySpender> for StateConfigBuilder {
+    fn add(&mut self, _entries: Vec<TableEntry<TransactionsBySpender>>) {
+        // Do not include these for now
+    }
+}
+
 /// The table stores the status of each transaction.
 pub struct TransactionStatuses;
 
@@ -235,6 +266,14 @@ mod test {
         generate_key
     );
 
+    fuel_core_storage::basic_storage_tests!(
+        TransactionsBySpender,
+        [1u8; INDEX_SIZE].into(),
+        <TransactionsBySpender as Mappable>::Value::default(),
+        <TransactionsBySpender as Mappable>::Value::default(),
+        generate_key
+    );
+
     fuel_core_storage::basic_storage_tests!(
         TransactionStatuses,
         <TransactionStatuses as Mappable>::Key::default(),
@@ -2,7 +2,14 @@ use self::indexation::error::IndexationError;
 
 use super::{
     block_height_subscription,
-    da_compression::da_compress_block,
+    da_compression::{
+        da_compact_temporal_registry,
+        da_compress_block,
+    },
+    database::{
+        ReadDatabase,
+        ReadView,
+    },
     indexation,
     storage::old::{
         OldFuelBlockConsensus,
@@ -20,17 +27,33 @@ use crate::{
             },
         },
         storage::{
+            assets::KnownAssets,
             blocks::FuelBlockIdsToHeights,
             coins::{
                 owner_coin_id_key,
+                CoinsToSpendIndex,
+                CoinsToSpendIndexKey,
                 OwnedCoins,
             },
             contracts::ContractsInfo,
             messages::{
+                OutboundMessageTransactions,
                 OwnedMessageIds,
                 OwnedMessageKey,
+                OwnedSortedMessageCoinKey,
+                OwnedSortedMessageCoins,
+                SenderMessageIds,
+                SenderMessageKey,
                 SpentMessages,
             },
+            predicates::{
+                PredicateDetails,
+                Predicates,
+            },
+            transactions::{
+                OwnedTransactionIndexKey,
+                TransactionsBySpender,
+            },
         },
     },
     graphql_api::{
@@ -48,9 +71,15 @@ use fuel_core_services::{
     TaskNextAction,
 };
 use fuel_core_storage::{
+    iter::IterDirection,
+    tables::{
+        Coins,
+        Messages,
+    },
     Error as StorageError,
     Result as StorageResult,
     StorageAsMut,
+    StorageAsRef,
 };
 use fuel_core_tx_status_manager::from_executor_to_status;
 use fuel_core_types::{
@@ -73,9 +102,11 @@ use fuel_core_types::{
             CoinPredicate,
             CoinSigned,
         },
+        Address,
         AssetId,
         ConsensusParameters,
         Contract,
+        ContractIdExt,
         Input,
         Output,
         Receipt,
@@ -99,14 +130,25 @@ use fuel_core_types::{
             TransactionExecutionStatus,
         },
     },
+    tai64::Tai64,
 };
 use futures::{
     FutureExt,
     StreamExt,
 };
+use parking_lot::Mutex;
 use std::{
     borrow::Cow,
+    collections::{
+        HashMap,
+        VecDeque,
+    },
     ops::Deref,
+    sync::Arc,
+};
+use tokio::sync::{
+    mpsc,
+    oneshot,
 };
 #[cfg(test)]
 mod tests;
@@ -119,6 +161,7 @@ pub(crate) struct Context<'a, TxStatusManager, BlockImporter, OnChain, OffChain>
     pub(crate) da_compression_config: DaCompressionConfig,
     pub(crate) continue_on_error: bool,
     pub(crate) consensus_parameters: &'a ConsensusParameters,
+    pub(crate) coins_to_spend_index_rebuilds: mpsc::Receiver<CoinsToSpendIndexRebuild>,
 }
 
 #[derive(Debug, Clone)]
@@ -127,6 +170,238 @@ pub enum DaCompressionConfig {
     Enabled(fuel_core_compression::config::Config),
 }
 
+/// The maximum number of the coins to spend index rebuilds whose status is remembered.
+pub const MAX_COINS_TO_SPEND_INDEX_REBUILDS: usize = 64;
+
+/// The number of times the rebuild recomputes the index entries if the off-chain
+/// database processed new blocks before the entries were applied.
+const MAX_COINS_TO_SPEND_INDEX_REBUILD_ATTEMPTS: usize = 5;
+
+/// The request to replace the coins to spend index entries of the `owner`,
+/// optionally only for the `asset_id`, with the `keys` computed at the `height`
+/// of the off-chain database.
+pub struct CoinsToSpendIndexRebuild {
+    owner: Address,
+    asset_id: Option<AssetId>,
+    height: Option<BlockHeight>,
+    keys: Vec<CoinsToSpendIndexKey>,
+    /// Receives `false` if the off-chain database is no longer at the `height`,
+    /// and the keys must be recomputed.
+    response: oneshot::Sender<anyhow::Result<bool>>,
+}
+
+/// The status of the coins to spend index rebuild.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CoinsToSpendIndexRebuildStatus {
+    Pending,
+    Completed { entries: usize },
+    Failed { error: String },
+}
+
+#[derive(Default)]
+struct CoinsToSpendIndexRebuilds {
+    next_id: u64,
+    statuses: HashMap<u64, CoinsToSpendIndexRebuildStatus>,
+    /// The ids of the rebuilds in the order they were started.
+    order: VecDeque<u64>,
+}
+
+impl CoinsToSpendIndexRebuilds {
+    fn start(&mut self) -> anyhow::Result<u64> {
+        if self.order.len() >= MAX_COINS_TO_SPEND_INDEX_REBUILDS {
+            // Forget the oldest finished rebuild to remember the new one.
+            let finished = self.order.iter().position(|id| {
+                self.statuses.get(id) != Some(&CoinsToSpendIndexRebuildStatus::Pending)
+            });
+            let Some(position) = finished else {
+                return Err(anyhow::anyhow!(
+                    "The node can't run more than {MAX_COINS_TO_SPEND_INDEX_REBUILDS} \
+                    rebuilds of the coins to spend index at once"
+                ));
+            };
+            if let Some(id) = self.order.remove(position) {
+                self.statuses.remove(&id);
+            }
+        }
+
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1);
+        self.statuses
+            .insert(id, CoinsToSpendIndexRebuildStatus::Pending);
+        self.order.push_back(id);
+        Ok(id)
+    }
+
+    fn finish(&mut self, id: u64, result: anyhow::Result<usize>) {
+        if let Some(status) = self.statuses.get_mut(&id) {
+            *status = match result {
+                Ok(entries) => CoinsToSpendIndexRebuildStatus::Completed { entries },
+                Err(err) => CoinsToSpendIndexRebuildStatus::Failed {
+                    error: err.to_string(),
+                },
+            };
+        }
+    }
+}
+
+/// Runs the rebuilds of the coins to spend index in the background and remembers
+/// their status. Each rebuild computes the entries of the owner on its own task
+/// and sends them to the off-chain worker, which applies them between blocks.
+#[derive(Clone)]
+pub struct CoinsToSpendIndexRebuilder {
+    sender: mpsc::Sender<CoinsToSpendIndexRebuild>,
+    rebuilds: Arc<Mutex<CoinsToSpendIndexRebuilds>>,
+}
+
+impl CoinsToSpendIndexRebuilder {
+    /// Creates the rebuilder and the receiver of its requests
+    /// that should be passed to the off-chain worker.
+    pub fn new() -> (Self, mpsc::Receiver<CoinsToSpendIndexRebuild>) {
+        let (sender, receiver) = mpsc::channel(16);
+        let rebuilder = Self {
+            sender,
+            rebuilds: Default::default(),
+        };
+        (rebuilder, receiver)
+    }
+
+    /// Starts the rebuild of the coins to spend index entries of the `owner`,
+    /// optionally only for the `asset_id`, and returns its id.
+    /// The entries are computed from the coins and messages of the owner
+    /// in the owner-indexed off-chain tables.
+    pub fn start(
+        &self,
+        database: ReadDatabase,
+        owner: Address,
+        asset_id: Option<AssetId>,
+        base_asset_id: AssetId,
+    ) -> anyhow::Result<u64> {
+        let id = self.rebuilds.lock().start()?;
+
+        let rebuilder = self.clone();
+        tokio::spawn(async move {
+            let result = rebuilder
+                .rebuild(&database, owner, asset_id, base_asset_id)
+                .await;
+            match &result {
+                Ok(entries) => tracing::info!(
+                    %owner,
+                    entries,
+                    "Rebuilt the coins to spend index"
+                ),
+                Err(err) => tracing::error!(
+                    %owner,
+                    "Failed to rebuild the coins to spend index: {err:?}"
+                ),
+            }
+            rebuilder.rebuilds.lock().finish(id, result);
+        });
+
+        Ok(id)
+    }
+
+    /// Returns the status of the rebuild with the `id`,
+    /// or `None` if it is unknown or was forgotten.
+    pub fn status(&self, id: u64) -> Option<CoinsToSpendIndexRebuildStatus> {
+        self.rebuilds.lock().statuses.get(&id).cloned()
+    }
+
+    async fn rebuild(
+        &self,
+        database: &ReadDatabase,
+        owner: Address,
+        asset_id: Option<AssetId>,
+        base_asset_id: AssetId,
+    ) -> anyhow::Result<usize> {
+        for _ in 0..MAX_COINS_TO_SPEND_INDEX_REBUILD_ATTEMPTS {
+            // The height is taken before the view, so the view is at this height
+            // if the off-chain database is still at it when the keys are applied.
+            let (_, height) = database.latest_heights();
+            let view = database.view()?;
+            let keys =
+                coins_to_spend_index_keys(&view, &owner, asset_id.as_ref(), &base_asset_id)
+                    .await?;
+            let entries = keys.len();
+
+            let (response, receiver) = oneshot::channel();
+            self.sender
+                .send(CoinsToSpendIndexRebuild {
+                    owner,
+                    asset_id,
+                    height,
+                    keys,
+                    response,
+                })
+                .await
+                .map_err(|_| anyhow::anyhow!("The off-chain worker is not running"))?;
+            let applied = receiver
+                .await
+                .map_err(|_| anyhow::anyhow!("The off-chain worker dropped the rebuild"))??;
+
+            if applied {
+                return Ok(entries);
+            }
+        }
+
+        Err(anyhow::anyhow!(
+            "The off-chain database processed new blocks during each of \
+            {MAX_COINS_TO_SPEND_INDEX_REBUILD_ATTEMPTS} attempts to rebuild the index"
+        ))
+    }
+}
+
+/// Computes the coins to spend index entries of the `owner` from its coins and
+/// messages in the off-chain owner indexes. The coins and messages that the on-chain
+/// database has already spent are skipped, the worker removes their entries anyway.
+async fn coins_to_spend_index_keys(
+    view: &ReadView,
+    owner: &Address,
+    asset_id: Option<&AssetId>,
+    base_asset_id: &AssetId,
+) -> StorageResult<Vec<CoinsToSpendIndexKey>> {
+    let mut keys = vec![];
+
+    let mut coin_ids = view
+        .owned_coins_ids(owner, None, IterDirection::Forward)
+        .chunks(view.batch_size);
+    while let Some(chunk) = coin_ids.next().await {
+        for utxo_id in chunk {
+            let utxo_id = utxo_id?;
+            let coin = view
+                .on_chain
+                .as_ref()
+                .storage::<Coins>()
+                .get(&utxo_id)?
+                .map(Cow::into_owned);
+            let Some(coin) = coin else { continue };
+            keys.push(CoinsToSpendIndexKey::from_coin(&coin.uncompress(utxo_id)));
+        }
+        // Give a chance to other tasks to run.
+        tokio::task::yield_now().await;
+    }
+
+    let mut nonces = view
+        .owned_message_ids(owner, None, IterDirection::Forward)
+        .chunks(view.batch_size);
+    while let Some(chunk) = nonces.next().await {
+        for nonce in chunk {
+            let nonce = nonce?;
+            let message = view
+                .on_chain
+                .as_ref()
+                .storage::<Messages>()
+                .get(&nonce)?
+                .map(Cow::into_owned);
+            let Some(message) = message else { continue };
+            keys.push(CoinsToSpendIndexKey::from_message(&message, base_asset_id));
+        }
+        tokio::task::yield_now().await;
+    }
+
+    keys.retain(|key| asset_id.map_or(true, |asset_id| key.asset_id() == asset_id));
+    Ok(keys)
+}
+
 /// The initialization task recovers the state of the GraphQL service database on startup.
 pub struct InitializeTask<TxStatusManager, BlockImporter, OnChain, OffChain> {
     chain_id: ChainId,
@@ -139,44 +414,81 @@ pub struct InitializeTask<TxStatusManager, BlockImporter, OnChain, OffChain> {
     off_chain_database: OffChain,
     base_asset_id: AssetId,
     block_height_subscription_handler: block_height_subscription::Handler,
+    coins_to_spend_index_rebuilds: mpsc::Receiver<CoinsToSpendIndexRebuild>,
 }
 
 /// The off-chain GraphQL API worker task processes the imported blocks
 /// and actualize the information used by the GraphQL service.
-pub struct Task<TxStatusManager, D> {
+pub struct Task<TxStatusManager, OnChain, D> {
     tx_status_manager: TxStatusManager,
     block_importer: BoxStream<SharedImportResult>,
+    on_chain_database: OnChain,
     database: D,
     chain_id: ChainId,
     da_compression_config: DaCompressionConfig,
+    /// The block time of the last compaction of the DA compression temporal registry.
+    last_da_compaction: Option<Tai64>,
     continue_on_error: bool,
     balances_indexation_enabled: bool,
     coins_to_spend_indexation_enabled: bool,
     asset_metadata_indexation_enabled: bool,
+    utxo_count_indexation_enabled: bool,
+    known_assets_indexation_enabled: bool,
+    sender_messages_indexation_enabled: bool,
+    sorted_message_coins_indexation_enabled: bool,
+    transactions_by_spender_indexation_enabled: bool,
+    outbound_message_transactions_indexation_enabled: bool,
+    predicates_indexation_enabled: bool,
     base_asset_id: AssetId,
     block_height_subscription_handler: block_height_subscription::Handler,
+    coins_to_spend_index_rebuilds: mpsc::Receiver<CoinsToSpendIndexRebuild>,
 }
 
-impl<TxStatusManager, D> Task<TxStatusManager, D>
+impl<TxStatusManager, OnChain, D> Task<TxStatusManager, OnChain, D>
 where
     TxStatusManager: ports::worker::TxStatusCompletion,
+    OnChain: ports::worker::OnChainDatabase,
     D: ports::worker::OffChainDatabase,
 {
     fn process_block(&mut self, result: SharedImportResult) -> anyhow::Result<()> {
         let block = &result.sealed_block.entity;
+        let block_time = block.header().time();
+        let stale_da_registry_keys = match self.da_compression_config {
+            DaCompressionConfig::Enabled(config)
+                if config.is_compaction_due(block_time, self.last_da_compaction) =>
+            {
+                let oldest_timestamp = config.oldest_accessible_timestamp(block_time);
+                Some(
+                    self.database
+                        .stale_da_compression_registry_keys(oldest_timestamp)?,
+                )
+            }
+            _ => None,
+        };
         let mut transaction = self.database.transaction();
         // save the status for every transaction using the finalized block id
         persist_transaction_status(
             &result,
             self.asset_metadata_indexation_enabled,
+            self.known_assets_indexation_enabled,
+            self.outbound_message_transactions_indexation_enabled,
             &mut transaction,
         )?;
 
         // save the associated owner for each transaction in the block
-        index_tx_owners_for_block(block, &mut transaction, &self.chain_id)?;
+        index_tx_owners_for_block(
+            block,
+            &mut transaction,
+            &self.chain_id,
+            self.transactions_by_spender_indexation_enabled,
+        )?;
 
         // save the transaction related information
-        process_transactions(block.transactions().iter(), &mut transaction)?;
+        process_transactions(
+            block.transactions().iter(),
+            &mut transaction,
+            self.predicates_indexation_enabled,
+        )?;
 
         let height = block.header().height();
         let block_id = block.id();
@@ -193,18 +505,46 @@ where
             &mut transaction,
             self.balances_indexation_enabled,
             self.coins_to_spend_indexation_enabled,
+            self.known_assets_indexation_enabled,
+            self.sender_messages_indexation_enabled,
+            self.sorted_message_coins_indexation_enabled,
             &self.base_asset_id,
         )?;
 
+        if self.utxo_count_indexation_enabled {
+            let (created_coins, consumed_coins) = result.events.iter().fold(
+                (0u64, 0u64),
+                |(created, consumed), event| match event {
+                    Event::CoinCreated(_) => (created.saturating_add(1), consumed),
+                    Event::CoinConsumed(_) => (created, consumed.saturating_add(1)),
+                    _ => (created, consumed),
+                },
+            );
+            transaction.update_utxo_count(
+                created_coins,
+                consumed_coins,
+                Some(*height),
+            )?;
+        }
+
         match self.da_compression_config {
             DaCompressionConfig::Disabled => {}
             DaCompressionConfig::Enabled(config) => {
+                // The stale entries are removed before the compression,
+                // so the block registers the values it uses again.
+                if let Some(stale_keys) = &stale_da_registry_keys {
+                    da_compact_temporal_registry(stale_keys, &mut transaction)?;
+                }
                 da_compress_block(config, block, &result.events, &mut transaction)?;
             }
         }
 
         transaction.commit()?;
 
+        if stale_da_registry_keys.is_some() {
+            self.last_da_compaction = Some(block_time);
+        }
+
         for status in result.tx_status.iter() {
             let tx_id = status.id;
             let status = from_executor_to_status(block, status.result.clone());
@@ -223,14 +563,53 @@ where
 
         Ok(())
     }
+
+    /// Replaces the coins to spend index entries of the owner with the rebuilt ones.
+    /// Returns `false` without changes if the off-chain database is no longer
+    /// at the height at which the entries were computed.
+    fn apply_coins_to_spend_index_rebuild(
+        &mut self,
+        rebuild: &CoinsToSpendIndexRebuild,
+    ) -> anyhow::Result<bool> {
+        if !self.coins_to_spend_indexation_enabled {
+            return Err(anyhow::anyhow!(
+                "The coins to spend indexation is not available"
+            ));
+        }
+
+        if self.database.latest_height()? != rebuild.height {
+            return Ok(false);
+        }
+
+        let stale_keys = self
+            .database
+            .owned_coins_to_spend_index_keys(&rebuild.owner, rebuild.asset_id.as_ref())?;
+
+        let mut transaction = self.database.transaction();
+        for key in stale_keys.iter() {
+            transaction.storage_as_mut::<CoinsToSpendIndex>().remove(key)?;
+        }
+        for key in rebuild.keys.iter() {
+            transaction
+                .storage_as_mut::<CoinsToSpendIndex>()
+                .insert(key, &())?;
+        }
+        transaction.commit()?;
+
+        Ok(true)
+    }
 }
 
 /// Process the executor events and update the indexes for the messages and coins.
+#[allow(clippy::too_many_arguments)]
 pub fn process_executor_events<'a, Iter, T>(
     events: Iter,
     block_st_transaction: &mut T,
     balances_indexation_enabled: bool,
     coins_to_spend_indexation_enabled: bool,
+    known_assets_indexation_enabled: bool,
+    sender_messages_indexation_enabled: bool,
+    sorted_message_coins_indexation_enabled: bool,
     base_asset_id: &AssetId,
 ) -> anyhow::Result<()>
 where
@@ -262,6 +641,28 @@ where
                         &OwnedMessageKey::new(message.recipient(), message.nonce()),
                         &(),
                     )?;
+                if sender_messages_indexation_enabled {
+                    block_st_transaction
+                        .storage_as_mut::<SenderMessageIds>()
+                        .insert(
+                            &SenderMessageKey::new(message.sender(), message.nonce()),
+                            &(),
+                        )?;
+                }
+                if sorted_message_coins_indexation_enabled
+                    && message.is_non_retryable_message()
+                {
+                    for key in OwnedSortedMessageCoinKey::from_message(message) {
+                        block_st_transaction
+                            .storage_as_mut::<OwnedSortedMessageCoins>()
+                            .insert(&key, &())?;
+                    }
+                }
+                if known_assets_indexation_enabled {
+                    block_st_transaction
+                        .storage_as_mut::<KnownAssets>()
+                        .insert(base_asset_id, &())?;
+                }
             }
             Event::MessageConsumed(message) => {
                 block_st_transaction
@@ -270,6 +671,23 @@ where
                         message.recipient(),
                         message.nonce(),
                     ))?;
+                if sender_messages_indexation_enabled {
+                    block_st_transaction
+                        .storage_as_mut::<SenderMessageIds>()
+                        .remove(&SenderMessageKey::new(
+                            message.sender(),
+                            message.nonce(),
+                        ))?;
+                }
+                if sorted_message_coins_indexation_enabled
+                    && message.is_non_retryable_message()
+                {
+                    for key in OwnedSortedMessageCoinKey::from_message(message) {
+                        block_st_transaction
+                            .storage_as_mut::<OwnedSortedMessageCoins>()
+                            .remove(&key)?;
+                    }
+                }
                 block_st_transaction
                     .storage::<SpentMessages>()
                     .insert(message.nonce(), &())?;
@@ -279,6 +697,11 @@ where
                 block_st_transaction
                     .storage_as_mut::<OwnedCoins>()
                     .insert(&coin_by_owner, &())?;
+                if known_assets_indexation_enabled {
+                    block_st_transaction
+                        .storage_as_mut::<KnownAssets>()
+                        .insert(&coin.asset_id, &())?;
+                }
             }
             Event::CoinConsumed(coin) => {
                 let key = owner_coin_id_key(&coin.owner, &coin.utxo_id);
@@ -353,6 +776,7 @@ fn index_tx_owners_for_block<T>(
     block: &Block,
     block_st_transaction: &mut T,
     chain_id: &ChainId,
+    transactions_by_spender_indexation_enabled: bool,
 ) -> anyhow::Result<()>
 where
     T: OffChainDatabaseTransaction,
@@ -394,30 +818,35 @@ where
             outputs,
             &tx_id,
             tx_idx,
+            transactions_by_spender_indexation_enabled,
             block_st_transaction,
         )?;
     }
     Ok(())
 }
 
-/// Index the tx id by owner for all of the inputs and outputs
+/// Index the tx id by owner for all of the inputs and outputs,
+/// and by spender for the owners of the coin inputs
 fn persist_owners_index<T>(
     block_height: BlockHeight,
     inputs: &[Input],
     outputs: &[Output],
     tx_id: &Bytes32,
     tx_idx: u16,
+    transactions_by_spender_indexation_enabled: bool,
     db: &mut T,
 ) -> StorageResult<()>
 where
     T: OffChainDatabaseTransaction,
 {
     let mut owners = vec![];
+    let mut spenders = vec![];
     for input in inputs {
         if let Input::CoinSigned(CoinSigned { owner, .. })
         | Input::CoinPredicate(CoinPredicate { owner, .. }) = input
         {
             owners.push(owner);
+            spenders.push(owner);
         }
     }
 
@@ -440,12 +869,28 @@ where
         db.record_tx_id_owner(owner, block_height, tx_idx, tx_id)?;
     }
 
+    if !transactions_by_spender_indexation_enabled {
+        return Ok(());
+    }
+
+    spenders.sort();
+    spenders.dedup();
+
+    for spender in spenders {
+        db.storage_as_mut::<TransactionsBySpender>().insert(
+            &OwnedTransactionIndexKey::new(spender, block_height, tx_idx),
+            tx_id,
+        )?;
+    }
+
     Ok(())
 }
 
 fn persist_transaction_status<T>(
     import_result: &ImportResult,
     asset_metadata_indexation_enabled: bool,
+    known_assets_indexation_enabled: bool,
+    outbound_message_transactions_indexation_enabled: bool,
     db: &mut T,
 ) -> StorageResult<()>
 where
@@ -467,17 +912,68 @@ where
             continue
         };
 
+        if outbound_message_transactions_indexation_enabled {
+            persist_outbound_messages(id, receipts, db)?;
+        }
+        if known_assets_indexation_enabled {
+            persist_minted_assets(receipts, db)?;
+        }
         update_receipt_based_indexation(receipts, db, asset_metadata_indexation_enabled)?;
     }
     Ok(())
 }
 
-pub fn process_transactions<'a, I, T>(transactions: I, db: &mut T) -> StorageResult<()>
+/// Index the transaction id by the nonce of each outbound message it emitted
+fn persist_outbound_messages<T>(
+    tx_id: &TxId,
+    receipts: &[Receipt],
+    db: &mut T,
+) -> StorageResult<()>
+where
+    T: OffChainDatabaseTransaction,
+{
+    for receipt in receipts {
+        if let Receipt::MessageOut { nonce, .. } = receipt {
+            db.storage_as_mut::<OutboundMessageTransactions>()
+                .insert(nonce, tx_id)?;
+        }
+    }
+    Ok(())
+}
+
+/// Remember the asset ids minted by the contracts
+fn persist_minted_assets<T>(receipts: &[Receipt], db: &mut T) -> StorageResult<()>
+where
+    T: OffChainDatabaseTransaction,
+{
+    for receipt in receipts {
+        if let Receipt::Mint {
+            sub_id,
+            contract_id,
+            ..
+        } = receipt
+        {
+            db.storage_as_mut::<KnownAssets>()
+                .insert(&contract_id.asset_id(sub_id), &())?;
+        }
+    }
+    Ok(())
+}
+
+pub fn process_transactions<'a, I, T>(
+    transactions: I,
+    db: &mut T,
+    predicates_indexation_enabled: bool,
+) -> StorageResult<()>
 where
     I: Iterator<Item = &'a Transaction>,
     T: OffChainDatabaseTransaction,
 {
     for tx in transactions {
+        if predicates_indexation_enabled {
+            index_predicates(tx, db)?;
+        }
+
         match tx {
             Transaction::Create(tx) => {
                 let contract_id = tx
@@ -520,6 +1016,35 @@ where
     Ok(())
 }
 
+/// Index the bytecode of the predicates of the transaction inputs by the predicate address.
+fn index_predicates<T>(tx: &Transaction, db: &mut T) -> StorageResult<()>
+where
+    T: OffChainDatabaseTransaction,
+{
+    let inputs = match tx {
+        Transaction::Script(tx) => tx.inputs().as_slice(),
+        Transaction::Create(tx) => tx.inputs().as_slice(),
+        Transaction::Mint(_) => return Ok(()),
+        Transaction::Upgrade(tx) => tx.inputs().as_slice(),
+        Transaction::Upload(tx) => tx.inputs().as_slice(),
+        Transaction::Blob(tx) => tx.inputs().as_slice(),
+    };
+
+    for input in inputs {
+        let (Some(owner), Some((predicate, predicate_data, _))) =
+            (input.input_owner(), input.predicate())
+        else {
+            continue
+        };
+        let details = PredicateDetails {
+            bytecode: predicate.to_vec(),
+            data_length: predicate_data.len() as u64,
+        };
+        db.storage_as_mut::<Predicates>().insert(owner, &details)?;
+    }
+    Ok(())
+}
+
 pub fn copy_to_old_blocks<'a, I, T>(blocks: I, db: &mut T) -> StorageResult<()>
 where
     I: Iterator<Item = (&'a BlockHeight, &'a CompressedBlock)>,
@@ -568,7 +1093,7 @@ where
 {
     const NAME: &'static str = "GraphQL_Off_Chain_Worker";
     type SharedData = block_height_subscription::Subscriber;
-    type Task = Task<TxStatusManager, OffChain>;
+    type Task = Task<TxStatusManager, OnChain, OffChain>;
     type TaskParams = ();
 
     fn shared_data(&self) -> Self::SharedData {
@@ -594,10 +1119,35 @@ where
         let asset_metadata_indexation_enabled = self
             .off_chain_database
             .asset_metadata_indexation_enabled()?;
+        let utxo_count_indexation_enabled =
+            self.off_chain_database.utxo_count_indexation_enabled()?;
+        let known_assets_indexation_enabled =
+            self.off_chain_database.known_assets_indexation_enabled()?;
+        let sender_messages_indexation_enabled = self
+            .off_chain_database
+            .sender_messages_indexation_enabled()?;
+        let sorted_message_coins_indexation_enabled = self
+            .off_chain_database
+            .sorted_message_coins_indexation_enabled()?;
+        let transactions_by_spender_indexation_enabled = self
+            .off_chain_database
+            .transactions_by_spender_indexation_enabled()?;
+        let outbound_message_transactions_indexation_enabled = self
+            .off_chain_database
+            .outbound_message_transactions_indexation_enabled()?;
+        let predicates_indexation_enabled =
+            self.off_chain_database.predicates_indexation_enabled()?;
         tracing::info!(
             balances_indexation_enabled,
             coins_to_spend_indexation_enabled,
             asset_metadata_indexation_enabled,
+            utxo_count_indexation_enabled,
+            known_assets_indexation_enabled,
+            sender_messages_indexation_enabled,
+            sorted_message_coins_indexation_enabled,
+            transactions_by_spender_indexation_enabled,
+            outbound_message_transactions_indexation_enabled,
+            predicates_indexation_enabled,
             "Indexation availability status"
         );
         tracing::debug!(
@@ -616,23 +1166,34 @@ where
             continue_on_error,
             base_asset_id,
             block_height_subscription_handler,
+            coins_to_spend_index_rebuilds,
         } = self;
 
         let mut task = Task {
             tx_status_manager,
             block_importer: blocks_events,
+            on_chain_database,
             database: off_chain_database,
             chain_id,
             da_compression_config,
+            last_da_compaction: None,
             continue_on_error,
             balances_indexation_enabled,
             coins_to_spend_indexation_enabled,
             asset_metadata_indexation_enabled,
+            utxo_count_indexation_enabled,
+            known_assets_indexation_enabled,
+            sender_messages_indexation_enabled,
+            sorted_message_coins_indexation_enabled,
+            transactions_by_spender_indexation_enabled,
+            outbound_message_transactions_indexation_enabled,
+            predicates_indexation_enabled,
             base_asset_id,
             block_height_subscription_handler,
+            coins_to_spend_index_rebuilds,
         };
 
-        let mut target_chain_height = on_chain_database.latest_height()?;
+        let mut target_chain_height = task.on_chain_database.latest_height()?;
         // Process all blocks that were imported before the service started.
         // The block importer may produce some blocks on start-up during the
         // genesis stage or the recovery process. In this case, we need to
@@ -649,13 +1210,14 @@ where
     }
 }
 
-fn sync_databases<TxStatusManager, BlockImporter, OffChain>(
-    task: &mut Task<TxStatusManager, OffChain>,
+fn sync_databases<TxStatusManager, BlockImporter, OnChain, OffChain>(
+    task: &mut Task<TxStatusManager, OnChain, OffChain>,
     target_chain_height: Option<BlockHeight>,
     import_result_provider: &BlockImporter,
 ) -> anyhow::Result<()>
 where
     BlockImporter: ports::worker::BlockImporter,
+    OnChain: ports::worker::OnChainDatabase,
     OffChain: ports::worker::OffChainDatabase,
     TxStatusManager: ports::worker::TxStatusCompletion,
 {
@@ -689,8 +1251,9 @@ where
     Ok(())
 }
 
-impl<TxStatusManager, D> RunnableTask for Task<TxStatusManager, D>
+impl<TxStatusManager, OnChain, D> RunnableTask for Task<TxStatusManager, OnChain, D>
 where
+    OnChain: ports::worker::OnChainDatabase,
     D: ports::worker::OffChainDatabase,
     TxStatusManager: ports::worker::TxStatusCompletion,
 {
@@ -721,6 +1284,12 @@ where
                     TaskNextAction::Stop
                 }
             }
+
+            Some(rebuild) = self.coins_to_spend_index_rebuilds.recv() => {
+                let result = self.apply_coins_to_spend_index_rebuild(&rebuild);
+                let _ = rebuild.response.send(result);
+                TaskNextAction::Continue
+            }
         }
     }
 
@@ -759,6 +1328,7 @@ where
         da_compression_config,
         continue_on_error,
         consensus_parameters,
+        coins_to_spend_index_rebuilds,
     } = context;
 
     let off_chain_block_height = off_chain_database.latest_height()?.unwrap_or_default();
@@ -776,6 +1346,7 @@ where
         block_height_subscription_handler: block_height_subscription::Handler::new(
             off_chain_block_height,
         ),
+        coins_to_spend_index_rebuilds,
     });
 
     Ok(service)
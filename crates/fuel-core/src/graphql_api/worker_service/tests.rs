@@ -2,8 +2,17 @@
 
 use super::*;
 use crate::{
-    database::Database,
-    graphql_api::storage::relayed_transactions::RelayedTransactionStatuses,
+    database::{
+        database_description::{
+            off_chain::OffChain,
+            on_chain::OnChain,
+        },
+        Database,
+    },
+    graphql_api::storage::{
+        relayed_transactions::RelayedTransactionStatuses,
+        statistic::UtxoCount,
+    },
 };
 use fuel_core_services::stream::IntoBoxStream;
 use fuel_core_storage::StorageAsRef;
@@ -59,6 +68,69 @@ async fn run__relayed_transaction_events_are_added_to_storage() {
     assert_eq!(*actual, expected);
 }
 
+#[test]
+fn update_utxo_count__saturates_at_zero_when_more_coins_are_consumed() {
+    // Given
+    let mut database: Database<OffChain> = Database::in_memory();
+    let mut transaction = ports::worker::OffChainDatabase::transaction(&mut database);
+    transaction.update_utxo_count(1, 0, None).unwrap();
+
+    // When
+    let result = transaction.update_utxo_count(0, 2, Some(5.into()));
+
+    // Then
+    assert_eq!(
+        result.unwrap(),
+        UtxoCount {
+            count: 0,
+            block_height: Some(5.into()),
+        }
+    );
+}
+
+#[test]
+fn coins_to_spend_index_rebuilds__forget_the_oldest_finished_rebuild() {
+    // Given
+    let mut rebuilds = CoinsToSpendIndexRebuilds::default();
+    let first = rebuilds.start().unwrap();
+    rebuilds.finish(first, Ok(1));
+    let second = rebuilds.start().unwrap();
+    rebuilds.finish(second, Ok(2));
+    for _ in 2..MAX_COINS_TO_SPEND_INDEX_REBUILDS {
+        rebuilds.start().unwrap();
+    }
+
+    // When
+    let new = rebuilds.start().unwrap();
+
+    // Then
+    assert_eq!(rebuilds.statuses.get(&first), None);
+    assert_eq!(
+        rebuilds.statuses.get(&second),
+        Some(&CoinsToSpendIndexRebuildStatus::Completed { entries: 2 })
+    );
+    assert_eq!(
+        rebuilds.statuses.get(&new),
+        Some(&CoinsToSpendIndexRebuildStatus::Pending)
+    );
+}
+
+#[test]
+fn coins_to_spend_index_rebuilds__fail_when_all_rebuilds_are_pending() {
+    // Given
+    let mut rebuilds = CoinsToSpendIndexRebuilds::default();
+    for _ in 0..MAX_COINS_TO_SPEND_INDEX_REBUILDS {
+        rebuilds.start().unwrap();
+    }
+
+    // When
+    let result = rebuilds.start();
+
+    // Then
+    assert!(result.is_err());
+    assert_eq!(rebuilds.statuses.len(), MAX_COINS_TO_SPEND_INDEX_REBUILDS);
+}
+
 fn block_importer_for_event(event: Event) -> BoxStream<SharedImportResult> {
     let block = Arc::new(ImportResult {
         sealed_block: Default::default(),
@@ -73,20 +145,31 @@ fn block_importer_for_event(event: Event) -> BoxStream<SharedImportResult> {
 fn worker_task_with_block_importer_and_db<D: ports::worker::OffChainDatabase>(
     block_importer: BoxStream<SharedImportResult>,
     database: D,
-) -> Task<MockTxStatusManager, D> {
+) -> Task<MockTxStatusManager, Database<OnChain>, D> {
     let tx_status_manager = MockTxStatusManager;
     let chain_id = Default::default();
+    let (_, coins_to_spend_index_rebuilds) = CoinsToSpendIndexRebuilder::new();
     Task {
         tx_status_manager,
         block_importer,
+        on_chain_database: Database::in_memory(),
         database,
         chain_id,
         da_compression_config: DaCompressionConfig::Disabled,
+        last_da_compaction: None,
         continue_on_error: false,
         balances_indexation_enabled: true,
         coins_to_spend_indexation_enabled: true,
         asset_metadata_indexation_enabled: true,
+        utxo_count_indexation_enabled: true,
+        known_assets_indexation_enabled: true,
+        sender_messages_indexation_enabled: true,
+        sorted_message_coins_indexation_enabled: true,
+        transactions_by_spender_indexation_enabled: true,
+        outbound_message_transactions_indexation_enabled: true,
+        predicates_indexation_enabled: true,
         base_asset_id: Default::default(),
         block_height_subscription_handler: Default::default(),
+        coins_to_spend_index_rebuilds,
     }
 }
@@ -3,11 +3,14 @@ use crate::{
     fuel_core_graphql_api::database::ReadView,
     graphql_api::storage::assets::AssetDetails,
 };
+use fuel_core_services::yield_stream::StreamYieldExt;
 use fuel_core_storage::{
+    iter::IterDirection,
     not_found,
     Result as StorageResult,
 };
 use fuel_core_types::fuel_tx::AssetId;
+use futures::Stream;
 
 impl ReadView {
     pub fn get_asset_details(&self, id: &AssetId) -> StorageResult<AssetDetails> {
@@ -23,4 +26,35 @@ impl ReadView {
             Err(anyhow::anyhow!("Asset metadata index is not available").into())
         }
     }
+
+    /// Returns the total supply of the asset minted by a contract. Returns `None` if the
+    /// asset wasn't minted by a contract or the asset metadata index is not available.
+    pub fn asset_total_supply(&self, id: &AssetId) -> StorageResult<Option<u128>> {
+        if !self
+            .indexation_flags
+            .contains(&IndexationKind::AssetMetadata)
+        {
+            return Ok(None)
+        }
+
+        Ok(self
+            .off_chain
+            .asset_info(id)?
+            .map(|details| details.total_supply))
+    }
+
+    pub fn known_assets(
+        &self,
+        start: Option<AssetId>,
+        direction: IterDirection,
+    ) -> StorageResult<impl Stream<Item = StorageResult<AssetId>> + '_> {
+        if !self.indexation_flags.contains(&IndexationKind::KnownAssets) {
+            return Err(anyhow::anyhow!("Known assets index is not available").into())
+        }
+
+        Ok(
+            futures::stream::iter(self.off_chain.known_assets(start, direction))
+                .yield_each(self.batch_size),
+        )
+    }
 }
@@ -21,7 +21,13 @@ use futures::{
     Stream,
     TryStreamExt,
 };
-use std::collections::HashSet;
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    sync::Arc,
+};
 use tokio_stream::StreamExt;
 
 /// At least required `target` of the query per asset's `id` with `max` coins.
@@ -30,17 +36,84 @@ pub struct AssetSpendTarget {
     pub id: AssetId,
     pub target: u128,
     pub max: u16,
+    /// The number of coins the selection is biased towards.
+    pub preferred_count: Option<u16>,
+    /// The maximum amount by which the selection may exceed the `target`.
+    pub max_overshoot: Option<u128>,
+    /// The number of coins of the asset that must stay unspent after the selection.
+    pub reserve_count: Option<u16>,
+    /// Whether the largest coin of the asset is used only if the `target`
+    /// can't be met within `max` without it.
+    pub avoid_largest_coin: bool,
+    /// Whether the selection prefers the coins created at lower heights, which are
+    /// deeper below the tip and safer against reorgs. The shallower coins are used
+    /// only if the target can't be met within `max` without them.
+    pub prefer_deep_coins: bool,
+    /// The number of change outputs the selection is biased towards. Each asset
+    /// produces at most one change output, so `0` prefers the coins matching
+    /// the `target` exactly, and any other number prefers the coins exceeding it.
+    pub preferred_change_outputs: Option<u16>,
 }
 
 impl AssetSpendTarget {
     pub fn new(id: AssetId, target: u128, max: u16) -> Self {
-        Self { id, target, max }
+        Self {
+            id,
+            target,
+            max,
+            preferred_count: None,
+            max_overshoot: None,
+            reserve_count: None,
+            avoid_largest_coin: false,
+            prefer_deep_coins: false,
+            preferred_change_outputs: None,
+        }
+    }
+
+    pub fn with_preferred_count(mut self, preferred_count: Option<u16>) -> Self {
+        self.preferred_count = preferred_count;
+        self
+    }
+
+    pub fn with_max_overshoot(mut self, max_overshoot: Option<u128>) -> Self {
+        self.max_overshoot = max_overshoot;
+        self
+    }
+
+    pub fn with_reserve_count(mut self, reserve_count: Option<u16>) -> Self {
+        self.reserve_count = reserve_count;
+        self
+    }
+
+    pub fn with_avoid_largest_coin(mut self, avoid_largest_coin: bool) -> Self {
+        self.avoid_largest_coin = avoid_largest_coin;
+        self
+    }
+
+    pub fn with_prefer_deep_coins(mut self, prefer_deep_coins: bool) -> Self {
+        self.prefer_deep_coins = prefer_deep_coins;
+        self
+    }
+
+    pub fn with_preferred_change_outputs(
+        mut self,
+        preferred_change_outputs: Option<u16>,
+    ) -> Self {
+        self.preferred_change_outputs = preferred_change_outputs;
+        self
     }
 }
 
+/// The predicate that returns `true` if the coin is available for the selection.
+pub type CoinFilter = Arc<dyn Fn(&CoinId) -> bool + Send + Sync>;
+
 #[derive(Default, Clone)]
 pub struct Exclude {
     pub coin_ids: HashSet<CoinId>,
+    /// The minimum amount of the coins of the asset available for the selection.
+    pub min_amounts: HashMap<AssetId, u64>,
+    /// The filter that the coins must pass to be available for the selection.
+    pub filter: Option<CoinFilter>,
 }
 
 impl Exclude {
@@ -58,12 +131,45 @@ impl Exclude {
         self.coin_ids.insert(coin);
     }
 
+    /// Excludes the coins that don't pass the `filter`, on top of the previous filter.
+    pub fn retain(&mut self, filter: CoinFilter) {
+        let filter: CoinFilter = match self.filter.take() {
+            Some(previous) => Arc::new(move |id: &CoinId| previous(id) && filter(id)),
+            None => filter,
+        };
+        self.filter = Some(filter);
+    }
+
+    pub fn contains(&self, id: &CoinId) -> bool {
+        self.coin_ids.contains(id)
+            || self.filter.as_ref().is_some_and(|filter| !filter(id))
+    }
+
     pub fn contains_coin(&self, id: &UtxoId) -> bool {
-        self.coin_ids.contains(&CoinId::Utxo(*id))
+        self.contains(&CoinId::Utxo(*id))
     }
 
     pub fn contains_message(&self, id: &Nonce) -> bool {
-        self.coin_ids.contains(&CoinId::Message(*id))
+        self.contains(&CoinId::Message(*id))
+    }
+
+    /// Excludes the coins of the asset with the amount below the `min_amount`.
+    pub fn exclude_below(&mut self, asset_id: AssetId, min_amount: u64) {
+        self.min_amounts.insert(asset_id, min_amount);
+    }
+
+    /// Allows the coins of the asset with any amount again.
+    pub fn allow_below_min(&mut self, asset_id: &AssetId) {
+        self.min_amounts.remove(asset_id);
+    }
+
+    pub fn min_amount(&self, asset_id: &AssetId) -> Option<u64> {
+        self.min_amounts.get(asset_id).copied()
+    }
+
+    pub fn is_below_min(&self, asset_id: &AssetId, amount: u64) -> bool {
+        self.min_amount(asset_id)
+            .is_some_and(|min_amount| amount < min_amount)
     }
 }
 
@@ -103,7 +209,7 @@ impl<'a> AssetsQuery<'a> {
             .filter(move |result| {
                 if let Ok(id) = result {
                     if let Some(exclude) = self.exclude {
-                        !exclude.coin_ids.contains(id)
+                        !exclude.contains(id)
                     } else {
                         true
                     }
@@ -156,7 +262,7 @@ impl<'a> AssetsQuery<'a> {
             .filter(move |result| {
                 if let Ok(id) = result {
                     if let Some(e) = exclude {
-                        !e.coin_ids.contains(id)
+                        !e.contains(id)
                     } else {
                         true
                     }
@@ -258,7 +364,15 @@ impl<'a> AssetQuery<'a> {
     /// Returns the iterator over all valid(spendable, allowed by `exclude`) coins of the `owner`
     /// for the `asset_id`.
     pub fn coins(self) -> impl Stream<Item = StorageResult<CoinType>> + 'a {
-        self.query.coins()
+        let exclude = self.exclude;
+        let asset_id = self.asset.id;
+        self.query.coins().filter(move |result| {
+            if let (Ok(coin), Some(exclude)) = (result, exclude) {
+                !exclude.is_below_min(&asset_id, coin.amount())
+            } else {
+                true
+            }
+        })
     }
 }
 
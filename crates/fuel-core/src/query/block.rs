@@ -7,6 +7,7 @@ use fuel_core_storage::{
 use fuel_core_types::{
     blockchain::block::CompressedBlock,
     fuel_types::BlockHeight,
+    services::txpool::TransactionExecutionStatus,
 };
 use futures::Stream;
 
@@ -26,4 +27,60 @@ impl ReadView {
     ) -> impl Stream<Item = StorageResult<CompressedBlock>> + '_ {
         futures::stream::iter(self.blocks(height, direction)).yield_each(self.batch_size)
     }
+
+    /// Returns the gas used by the block, summed over the execution
+    /// statuses of its transactions.
+    pub fn block_gas_used(&self, block: &CompressedBlock) -> StorageResult<u64> {
+        block.transactions().iter().try_fold(0u64, |gas_used, tx_id| {
+            let total_gas = match self.tx_status(tx_id)? {
+                TransactionExecutionStatus::Success { total_gas, .. }
+                | TransactionExecutionStatus::Failed { total_gas, .. } => total_gas,
+                _ => 0,
+            };
+            Ok(gas_used.saturating_add(total_gas))
+        })
+    }
+
+    /// Returns the fees paid by the transactions of the block, summed over
+    /// the execution statuses of its transactions.
+    pub fn block_fees(&self, block: &CompressedBlock) -> StorageResult<u64> {
+        block.transactions().iter().try_fold(0u64, |fees, tx_id| {
+            let total_fee = match self.tx_status(tx_id)? {
+                TransactionExecutionStatus::Success { total_fee, .. }
+                | TransactionExecutionStatus::Failed { total_fee, .. } => total_fee,
+                _ => 0,
+            };
+            Ok(fees.saturating_add(total_fee))
+        })
+    }
+
+    /// Returns the gas used and the fees paid by the transactions of the block,
+    /// reading the execution status of each transaction once.
+    pub fn block_gas_used_and_fees(
+        &self,
+        block: &CompressedBlock,
+    ) -> StorageResult<(u64, u64)> {
+        block
+            .transactions()
+            .iter()
+            .try_fold((0u64, 0u64), |(gas_used, fees), tx_id| {
+                let (total_gas, total_fee) = match self.tx_status(tx_id)? {
+                    TransactionExecutionStatus::Success {
+                        total_gas,
+                        total_fee,
+                        ..
+                    }
+                    | TransactionExecutionStatus::Failed {
+                        total_gas,
+                        total_fee,
+                        ..
+                    } => (total_gas, total_fee),
+                    _ => (0, 0),
+                };
+                Ok((
+                    gas_used.saturating_add(total_gas),
+                    fees.saturating_add(total_fee),
+                ))
+            })
+    }
 }
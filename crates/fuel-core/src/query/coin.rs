@@ -1,4 +1,8 @@
-use crate::fuel_core_graphql_api::database::ReadView;
+use crate::{
+    database::database_description::IndexationKind,
+    fuel_core_graphql_api::database::ReadView,
+    graphql_api::storage::predicates::PredicateDetails,
+};
 use fuel_core_storage::{
     iter::IterDirection,
     not_found,
@@ -9,8 +13,12 @@ use fuel_core_storage::{
 };
 use fuel_core_types::{
     entities::coins::coin::Coin,
+    fuel_merkle::binary::root_calculator::MerkleRootCalculator,
     fuel_tx::UtxoId,
-    fuel_types::Address,
+    fuel_types::{
+        Address,
+        Bytes32,
+    },
 };
 use futures::{
     Stream,
@@ -63,4 +71,55 @@ impl ReadView {
             })
             .try_flatten()
     }
+
+    /// Returns the Merkle root of the unspent coins and the number of the coins.
+    ///
+    /// The root is the binary Merkle tree root as defined by the Fuel specification,
+    /// the same as the transactions root of the block header: the leaf is hashed as
+    /// `sha256(0x00 ++ leaf)`, the node as `sha256(0x01 ++ left ++ right)`, and the
+    /// root of the empty tree is `sha256("")`. The leaves are the coins ordered by
+    /// the `UtxoId`, i.e. by the transaction id and then by the output index,
+    /// encoded by [`coin_set_leaf`].
+    pub async fn coin_set_root(&self) -> StorageResult<(Bytes32, u64)> {
+        let mut calculator = MerkleRootCalculator::new();
+        let mut count = 0u64;
+
+        let coins = self.all_coins();
+        futures::pin_mut!(coins);
+        while let Some(coin) = coins.next().await {
+            calculator.push(&coin_set_leaf(&coin?));
+            count = count.saturating_add(1);
+        }
+
+        Ok((calculator.root().into(), count))
+    }
+
+    /// Returns the predicate with the `address` seen on the chain. Returns `None` if
+    /// the predicate wasn't seen or the predicates index is not available.
+    pub fn predicate(
+        &self,
+        address: &Address,
+    ) -> StorageResult<Option<PredicateDetails>> {
+        if !self.indexation_flags.contains(&IndexationKind::Predicates) {
+            return Ok(None)
+        }
+
+        self.off_chain.predicate(address)
+    }
+}
+
+/// Encodes the `coin` as a leaf of the coin set Merkle tree:
+/// `tx_id (32 bytes) ++ output_index (u16) ++ owner (32 bytes) ++ amount (u64)
+/// ++ asset_id (32 bytes) ++ tx_pointer.block_height (u32) ++ tx_pointer.tx_index (u16)`,
+/// where the integers are big-endian.
+fn coin_set_leaf(coin: &Coin) -> Vec<u8> {
+    let mut leaf = vec![];
+    leaf.extend_from_slice(coin.utxo_id.tx_id().as_ref());
+    leaf.extend_from_slice(&coin.utxo_id.output_index().to_be_bytes());
+    leaf.extend_from_slice(coin.owner.as_ref());
+    leaf.extend_from_slice(&coin.amount.to_be_bytes());
+    leaf.extend_from_slice(coin.asset_id.as_ref());
+    leaf.extend_from_slice(&u32::from(coin.tx_pointer.block_height()).to_be_bytes());
+    leaf.extend_from_slice(&coin.tx_pointer.tx_index().to_be_bytes());
+    leaf
 }
@@ -1,4 +1,10 @@
-use crate::fuel_core_graphql_api::database::ReadView;
+use crate::{
+    database::database_description::IndexationKind,
+    fuel_core_graphql_api::{
+        database::ReadView,
+        storage::messages::MessageCoinSortOrder,
+    },
+};
 use fuel_core_storage::{
     iter::{
         BoxedIter,
@@ -11,7 +17,11 @@ use fuel_core_storage::{
     StorageAsRef,
 };
 use fuel_core_types::{
-    blockchain::block::CompressedBlock,
+    blockchain::{
+        block::CompressedBlock,
+        header::BlockHeader,
+        primitives::DaBlockHeight,
+    },
     entities::relayer::message::{
         MerkleProof,
         Message,
@@ -108,6 +118,131 @@ impl ReadView {
             })
             .try_flatten()
     }
+
+    pub fn sender_messages<'a>(
+        &'a self,
+        sender: &'a Address,
+        start_message_id: Option<Nonce>,
+        direction: IterDirection,
+    ) -> StorageResult<impl Stream<Item = StorageResult<Message>> + 'a> {
+        if !self.indexation_flags.contains(&IndexationKind::SenderMessages) {
+            return Err(
+                anyhow::anyhow!("Messages by sender index is not available").into()
+            )
+        }
+
+        let messages = self
+            .sender_message_ids(sender, start_message_id, direction)
+            .chunks(self.batch_size)
+            .map(|chunk| {
+                let chunk = chunk.into_iter().try_collect::<_, Vec<_>, _>()?;
+                Ok(chunk)
+            })
+            .try_filter_map(move |chunk| async move {
+                let chunk = self.messages(chunk).await;
+                Ok::<_, StorageError>(Some(futures::stream::iter(chunk)))
+            })
+            .try_flatten();
+        Ok(messages)
+    }
+
+    /// Returns the message coins of the `owner` with their sort keys,
+    /// sorted by the `order`, starting from the `start` position.
+    pub fn owned_sorted_message_coins<'a>(
+        &'a self,
+        owner: &'a Address,
+        order: MessageCoinSortOrder,
+        start: Option<(u64, Nonce)>,
+        direction: IterDirection,
+    ) -> StorageResult<impl Stream<Item = StorageResult<(u64, Message)>> + 'a> {
+        if !self
+            .indexation_flags
+            .contains(&IndexationKind::SortedMessageCoins)
+        {
+            return Err(anyhow::anyhow!("Sorted message coins index is not available").into())
+        }
+
+        let messages = self
+            .owned_sorted_message_coin_ids(owner, order, start, direction)
+            .chunks(self.batch_size)
+            .map(|chunk| {
+                let chunk = chunk.into_iter().try_collect::<_, Vec<_>, _>()?;
+                Ok(chunk)
+            })
+            .try_filter_map(move |chunk| async move {
+                let (keys, ids): (Vec<_>, Vec<_>) = chunk.into_iter().unzip();
+                let chunk = self
+                    .messages(ids)
+                    .await
+                    .zip(keys)
+                    .map(|(message, key)| message.map(|message| (key, message)));
+                Ok::<_, StorageError>(Some(futures::stream::iter(chunk)))
+            })
+            .try_flatten();
+        Ok(messages)
+    }
+
+    /// Returns the height of the first block that could include the messages
+    /// from the `da_height`, i.e. the first block with the DA height greater than
+    /// or equal to the `da_height`. Returns `None` if the relayer hasn't synced
+    /// that far yet.
+    pub fn first_block_with_da_height(
+        &self,
+        da_height: DaBlockHeight,
+    ) -> StorageResult<Option<BlockHeight>> {
+        let latest_height = self.latest_height()?;
+        if self.block(&latest_height)?.header().da_height() < da_height {
+            return Ok(None)
+        }
+
+        // The DA height of blocks never decreases, so the first block can be found
+        // with the binary search.
+        let mut low = u32::from(self.genesis_height);
+        let mut high = u32::from(latest_height);
+        while low < high {
+            let mid = low.saturating_add(high.saturating_sub(low) / 2);
+            if self.block(&mid.into())?.header().da_height() >= da_height {
+                high = mid;
+            } else {
+                low = mid.saturating_add(1);
+            }
+        }
+
+        Ok(Some(low.into()))
+    }
+
+    /// Returns the height of the last block with the DA height less than or equal to
+    /// the `da_height`, i.e. the latest block anchored at or below the `da_height`.
+    /// Returns `None` if even the genesis block is above the `da_height`.
+    pub fn last_block_at_or_below_da_height(
+        &self,
+        da_height: DaBlockHeight,
+    ) -> StorageResult<Option<BlockHeight>> {
+        let latest_height = self.latest_height()?;
+        if self.block(&latest_height)?.header().da_height() <= da_height {
+            return Ok(Some(latest_height))
+        }
+
+        let mut low = u32::from(self.genesis_height);
+        if self.block(&low.into())?.header().da_height() > da_height {
+            return Ok(None)
+        }
+
+        // The DA height of blocks never decreases, so the last block can be found
+        // with the binary search. The block at `low` is always at or below
+        // the `da_height`, and the block at `high` is always above it.
+        let mut high = u32::from(latest_height);
+        while high.saturating_sub(low) > 1 {
+            let mid = low.saturating_add(high.saturating_sub(low) / 2);
+            if self.block(&mid.into())?.header().da_height() <= da_height {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+
+        Ok(Some(low.into()))
+    }
 }
 
 /// Trait that specifies all the data required by the output message query.
@@ -157,6 +292,42 @@ pub fn message_proof<T: MessageProofData + ?Sized>(
     transaction_id: Bytes32,
     desired_nonce: Nonce,
     commit_block_height: BlockHeight,
+) -> StorageResult<MessageProof> {
+    let commit_block_header = commit_block_header(database, commit_block_height)?;
+    message_proof_for_commit_block(
+        database,
+        transaction_id,
+        desired_nonce,
+        commit_block_header,
+        true,
+    )
+}
+
+/// Returns the header of the commit block at the `commit_block_height`.
+pub fn commit_block_header<T: MessageProofData + ?Sized>(
+    database: &T,
+    commit_block_height: BlockHeight,
+) -> StorageResult<BlockHeader> {
+    match database.block(&commit_block_height) {
+        Ok(commit_block) => Ok(commit_block.into_inner().0),
+        Err(err) => Err(anyhow::anyhow!(
+            "Unable to get commit block header from database: {err}"
+        )
+        .into()),
+    }
+}
+
+/// Generates the proof of the message relatively to the already loaded commit block,
+/// so the proofs of several messages can share it.
+///
+/// If `include_message_path` is `false`, the Merkle path of the message inside of
+/// the message block is not built, and the `message_proof` is left empty.
+pub fn message_proof_for_commit_block<T: MessageProofData + ?Sized>(
+    database: &T,
+    transaction_id: Bytes32,
+    desired_nonce: Nonce,
+    commit_block_header: BlockHeader,
+    include_message_path: bool,
 ) -> StorageResult<MessageProof> {
     // Get the block id from the transaction status if it's ready.
     let (message_block_height, (sender, recipient, nonce, amount, data)) = match database.transaction_status(&transaction_id) {
@@ -221,19 +392,19 @@ pub fn message_proof<T: MessageProofData + ?Sized>(
             }
         };
 
-    let message_id = compute_message_id(&sender, &recipient, &nonce, amount, &data);
-
-    let message_proof = message_receipts_proof(database, message_id, &message_block_txs)?;
+    // The transaction status may still point to a block orphaned by a reorg.
+    if !message_block_txs.contains(&transaction_id) {
+        return Err(anyhow::anyhow!(
+            "The message block at height {message_block_height} is not on the canonical chain"
+        )
+        .into())
+    }
 
-    // Get the commit fuel block header.
-    let (commit_block_header, _) = match database.block(&commit_block_height) {
-        Ok(commit_block_header) => commit_block_header.into_inner(),
-        Err(err) => {
-            return Err(anyhow::anyhow!(
-                "Unable to get commit block header from database: {err}"
-            )
-            .into())
-        }
+    let message_proof = if include_message_path {
+        let message_id = compute_message_id(&sender, &recipient, &nonce, amount, &data);
+        message_receipts_proof(database, message_id, &message_block_txs)?
+    } else {
+        MerkleProof::default()
     };
 
     let Some(verifiable_commit_block_height) = commit_block_header.height().pred() else {
@@ -338,6 +509,8 @@ pub fn message_status(
 
 #[cfg(test)]
 mod tests {
+    #![allow(non_snake_case)]
+
     use std::collections::HashMap;
 
     use fuel_core_storage::not_found;
@@ -504,4 +677,46 @@ mod tests {
         // The proof should be the same because the invalid transaction was ignored
         assert_eq!(message_proof_valid_tx, message_proof_invalid_tx);
     }
+
+    #[test]
+    fn message_proof__fails_when_message_block_is_orphaned() {
+        let mut database = FakeDB::new();
+
+        // Given
+        // The transaction status points to the block at height 1, but the canonical
+        // block at this height doesn't include the transaction anymore.
+        let block_height: BlockHeight = BlockHeight::new(1);
+        let mut block = CompressedBlock::default();
+        block.header_mut().set_block_height(block_height);
+        block.transactions_mut().push(Bytes32::new([2; 32]));
+        database.insert_block(block_height, block);
+        let orphaned_tx_id = Bytes32::new([1; 32]);
+        database.insert_transaction_status(
+            orphaned_tx_id,
+            TransactionExecutionStatus::Success {
+                time: Tai64::UNIX_EPOCH,
+                block_height,
+                receipts: vec![Receipt::MessageOut {
+                    sender: Address::default(),
+                    recipient: Address::default(),
+                    amount: 0,
+                    nonce: 0.into(),
+                    len: 32,
+                    digest: Bytes32::default(),
+                    data: Some(vec![0; 32]),
+                }],
+                total_fee: 0,
+                total_gas: 0,
+                result: None,
+            },
+        );
+
+        // When
+        let result =
+            message_proof(&database, orphaned_tx_id, Nonce::default(), block_height);
+
+        // Then
+        let err = result.expect_err("The proof for an orphaned block should fail");
+        assert!(err.to_string().contains("is not on the canonical chain"));
+    }
 }
@@ -1,4 +1,7 @@
-use crate::fuel_core_graphql_api::database::ReadView;
+use crate::{
+    database::database_description::IndexationKind,
+    fuel_core_graphql_api::database::ReadView,
+};
 use fuel_core_storage::{
     iter::IterDirection,
     not_found,
@@ -13,14 +16,19 @@ use fuel_core_types::{
         TxId,
         TxPointer,
     },
-    fuel_types::Address,
+    fuel_types::{
+        Address,
+        BlockHeight,
+    },
     services::txpool::TransactionExecutionStatus,
 };
 use futures::{
+    future,
     Stream,
     StreamExt,
     TryStreamExt,
 };
+use std::ops::RangeInclusive;
 
 impl ReadView {
     pub fn receipts(&self, tx_id: &TxId) -> StorageResult<Vec<Receipt>> {
@@ -42,8 +50,81 @@ impl ReadView {
         start: Option<TxPointer>,
         direction: IterDirection,
     ) -> impl Stream<Item = StorageResult<(TxPointer, Transaction)>> + '_ {
-        self.owned_transactions_ids(owner, start, direction)
-            .chunks(self.batch_size)
+        let ids = self.owned_transactions_ids(owner, start, direction);
+        self.transactions_by_pointers(ids)
+    }
+
+    /// Returns up to `limit` transactions of the `owner` included in the blocks
+    /// within the `heights` after the `after` transaction, in the order of inclusion.
+    pub fn owned_transactions_within(
+        &self,
+        owner: Address,
+        heights: RangeInclusive<BlockHeight>,
+        after: Option<TxPointer>,
+        limit: usize,
+    ) -> impl Stream<Item = StorageResult<(TxPointer, Transaction)>> + '_ {
+        let start = after
+            .filter(|after| after.block_height() >= *heights.start())
+            .unwrap_or_else(|| TxPointer::new(*heights.start(), 0));
+        let ids = self
+            .owned_transactions_ids(owner, Some(start), IterDirection::Forward)
+            .try_filter(move |(tx_pointer, _)| future::ready(Some(*tx_pointer) != after))
+            .try_take_while(move |(tx_pointer, _)| {
+                future::ready(Ok(heights.contains(&tx_pointer.block_height())))
+            })
+            .take(limit);
+        self.transactions_by_pointers(ids)
+    }
+
+    /// Returns the transactions spending the coins of the `owner` within the `heights`.
+    /// The iteration seeks to the `start` or, if it is missing or lies before
+    /// the `heights` in the `direction`, to the bound of the `heights`, and stops
+    /// at the opposite bound.
+    pub fn spent_transactions(
+        &self,
+        owner: Address,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+        heights: RangeInclusive<BlockHeight>,
+    ) -> StorageResult<impl Stream<Item = StorageResult<(TxPointer, Transaction)>> + '_>
+    {
+        if !self
+            .indexation_flags
+            .contains(&IndexationKind::TransactionsBySpender)
+        {
+            return Err(anyhow::anyhow!(
+                "Transactions by spender index is not available"
+            )
+            .into())
+        }
+
+        let (first_height, last_height) = (*heights.start(), *heights.end());
+        let start = match direction {
+            IterDirection::Forward => start
+                .filter(|start| start.block_height() >= first_height)
+                .unwrap_or_else(|| TxPointer::new(first_height, 0)),
+            IterDirection::Reverse => start
+                .filter(|start| start.block_height() <= last_height)
+                .unwrap_or_else(|| TxPointer::new(last_height, u16::MAX)),
+        };
+        let ids = self
+            .spent_transactions_ids(owner, Some(start), direction)
+            .try_take_while(move |(tx_pointer, _)| {
+                let height = tx_pointer.block_height();
+                let within = match direction {
+                    IterDirection::Forward => height <= last_height,
+                    IterDirection::Reverse => height >= first_height,
+                };
+                future::ready(Ok(within))
+            });
+        Ok(self.transactions_by_pointers(ids))
+    }
+
+    fn transactions_by_pointers<'a>(
+        &'a self,
+        ids: impl Stream<Item = StorageResult<(TxPointer, TxId)>> + 'a,
+    ) -> impl Stream<Item = StorageResult<(TxPointer, Transaction)>> + 'a {
+        ids.chunks(self.batch_size)
             .map(|chunk| {
                 use itertools::Itertools;
 
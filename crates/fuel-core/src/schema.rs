@@ -7,8 +7,10 @@ use async_graphql::{
     connection::{
         query,
         Connection,
+        ConnectionNameType,
         CursorType,
         Edge,
+        EdgeNameType,
         EmptyFields,
     },
     parser::types::OperationType,
@@ -75,10 +77,19 @@ pub struct Query(
 );
 
 #[derive(MergedObject, Default)]
-pub struct Mutation(dap::DapMutation, tx::TxMutation, block::BlockMutation);
+pub struct Mutation(
+    dap::DapMutation,
+    tx::TxMutation,
+    block::BlockMutation,
+    coins::CoinMutation,
+);
 
 #[derive(MergedSubscription, Default)]
-pub struct Subscription(tx::TxStatusSubscription, storage::StorageSubscription);
+pub struct Subscription(
+    tx::TxStatusSubscription,
+    storage::StorageSubscription,
+    da_compressed::DaCompressedBlockSubscription,
+);
 
 pub type CoreSchema = Schema<Query, Mutation, Subscription>;
 pub type CoreSchemaBuilder = SchemaBuilder<Query, Mutation, Subscription>;
@@ -92,14 +103,18 @@ pub fn build_schema() -> CoreSchemaBuilder {
     )
 }
 
-async fn query_pagination<F, Entries, SchemaKey, SchemaValue>(
+async fn query_pagination<F, Entries, SchemaKey, SchemaValue, Name, EdgeName>(
     after: Option<String>,
     before: Option<String>,
     first: Option<i32>,
     last: Option<i32>,
     entries: F,
-) -> async_graphql::Result<Connection<SchemaKey, SchemaValue, EmptyFields, EmptyFields>>
+) -> async_graphql::Result<
+    Connection<SchemaKey, SchemaValue, EmptyFields, EmptyFields, Name, EdgeName>,
+>
 where
+    Name: ConnectionNameType,
+    EdgeName: EdgeNameType,
     SchemaKey: CursorType + Send + Sync,
     <SchemaKey as CursorType>::Error: core::fmt::Display + Send + Sync + 'static,
     SchemaValue: OutputType,
@@ -211,7 +226,10 @@ where
                     .map(|(key, value)| Edge::new(key, value)),
             );
 
-            Ok::<Connection<SchemaKey, SchemaValue>, anyhow::Error>(connection)
+            Ok::<
+                Connection<SchemaKey, SchemaValue, EmptyFields, EmptyFields, Name, EdgeName>,
+                anyhow::Error,
+            >(connection)
         },
     )
     .await
@@ -1,7 +1,12 @@
 use async_graphql::{
+    connection::{
+        Connection,
+        EmptyFields,
+    },
     Context,
     Object,
 };
+use futures::StreamExt;
 
 use crate::{
     fuel_core_graphql_api::query_costs,
@@ -34,6 +39,63 @@ impl AssetInfoQuery {
             .map(|details| details.into())
             .map_err(async_graphql::Error::from)
     }
+
+    /// The distinct asset ids seen on the chain in the coins and the contract mints,
+    /// ordered by the asset id. It is only available on nodes whose off-chain
+    /// database was indexed from the genesis.
+    #[graphql(complexity = "{\
+        query_costs().storage_iterator\
+        + (query_costs().storage_read + first.unwrap_or_default() as usize) * child_complexity \
+        + (query_costs().storage_read + last.unwrap_or_default() as usize) * child_complexity\
+    }")]
+    async fn known_assets(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<Connection<AssetId, KnownAsset, EmptyFields, EmptyFields>>
+    {
+        let query = ctx.read_view()?;
+
+        crate::schema::query_pagination(after, before, first, last, |start, direction| {
+            let assets = query
+                .known_assets((*start).map(Into::into), direction)?
+                .map(|asset_id| {
+                    let asset_id: AssetId = asset_id?.into();
+
+                    Ok((asset_id, KnownAsset { asset_id }))
+                });
+
+            Ok(assets)
+        })
+        .await
+    }
+}
+
+pub struct KnownAsset {
+    asset_id: AssetId,
+}
+
+#[Object]
+impl KnownAsset {
+    async fn asset_id(&self) -> &AssetId {
+        &self.asset_id
+    }
+
+    /// The total supply of the asset minted by a contract. Empty if the asset
+    /// wasn't minted by a contract or the asset metadata index is not available.
+    #[graphql(complexity = "query_costs().storage_read")]
+    async fn total_supply(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<U128>> {
+        let query = ctx.read_view()?;
+        let total_supply = query.asset_total_supply(&self.asset_id.into())?;
+
+        Ok(total_supply.map(Into::into))
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -5,6 +5,7 @@ use crate::{
         query_costs,
     },
     schema::{
+        coins::count_at_most,
         scalars::{
             Address,
             AssetId,
@@ -22,12 +23,26 @@ use async_graphql::{
     Context,
     InputObject,
     Object,
+    SimpleObject,
 };
+use fuel_core_storage::iter::IterDirection;
 use fuel_core_types::services::graphql_api;
-use futures::StreamExt;
+use futures::{
+    StreamExt,
+    TryStreamExt,
+};
 
 use super::scalars::U64;
 
+/// The maximum number of owners in one `portfolio` query.
+pub const MAX_PORTFOLIO_OWNERS: usize = 100;
+
+/// The maximum number of assets per owner returned by the `portfolio` query.
+const MAX_PORTFOLIO_ASSETS: usize = 100;
+
+/// The maximum number of coins counted per asset by the `portfolio` query.
+const MAX_PORTFOLIO_COINS: usize = 1_000;
+
 pub struct Balance(graphql_api::AddressBalance);
 
 #[Object]
@@ -50,6 +65,55 @@ impl Balance {
     }
 }
 
+/// The balance of the owner projected after the planned spend.
+#[derive(SimpleObject)]
+pub struct ProjectedBalance {
+    owner: Address,
+    asset_id: AssetId,
+    /// The current balance of the owner for the asset.
+    current_balance: U128,
+    /// The planned outflow of the asset, including the fee for the base asset.
+    outflow: U128,
+    /// The balance remaining after the outflow, or zero if the outflow exceeds it.
+    remaining_balance: U128,
+    /// Whether the current balance covers the outflow.
+    sufficient: bool,
+}
+
+impl ProjectedBalance {
+    fn new(balance: graphql_api::AddressBalance, outflow: u128) -> Self {
+        Self {
+            owner: balance.owner.into(),
+            asset_id: balance.asset_id.into(),
+            current_balance: balance.amount.into(),
+            outflow: outflow.into(),
+            remaining_balance: balance.amount.saturating_sub(outflow).into(),
+            sufficient: balance.amount >= outflow,
+        }
+    }
+}
+
+/// The balance and the number of the spendable coins of the owner for one asset.
+#[derive(SimpleObject)]
+pub struct AssetPortfolioEntry {
+    asset_id: AssetId,
+    /// The balance of the owner for the asset.
+    amount: U128,
+    /// The number of the spendable coins of the asset.
+    coin_count: U64,
+}
+
+/// The summary of the balances of one owner.
+#[derive(SimpleObject)]
+pub struct OwnerPortfolio {
+    owner: Address,
+    /// The balance and the number of the coins per asset, ordered by the asset id.
+    assets: Vec<AssetPortfolioEntry>,
+    /// Whether all assets and coins of the owner are counted. `false` if the owner
+    /// has more assets or coins of one asset than the query can count.
+    complete: bool,
+}
+
 #[derive(InputObject)]
 struct BalanceFilterInput {
     /// Filter coins based on the `owner` field
@@ -136,6 +200,111 @@ impl BalanceQuery {
         })
         .await
     }
+
+    /// Projects the balance of the `owner` for the asset after spending the `amount`.
+    /// The fee is paid in the base asset, so it is a part of the outflow
+    /// only for the base asset.
+    #[graphql(complexity = "query_costs().balance_query")]
+    async fn projected_balance(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "address of the owner")] owner: Address,
+        #[graphql(desc = "asset_id of the coin")] asset_id: AssetId,
+        #[graphql(desc = "The amount of the asset planned to be spent.")] amount: U128,
+        #[graphql(desc = "The estimated fee of the transaction in the base asset.")]
+        fee: Option<U64>,
+    ) -> async_graphql::Result<ProjectedBalance> {
+        let query = ctx.read_view()?;
+        let base_asset_id = *ctx
+            .data_unchecked::<ChainInfoProvider>()
+            .current_consensus_params()
+            .base_asset_id();
+        let balance = query.balance(owner.0, asset_id.0, base_asset_id).await?;
+
+        let fee = if asset_id.0 == base_asset_id {
+            fee.map(|fee| fee.0 as u128).unwrap_or_default()
+        } else {
+            0
+        };
+        let outflow = amount.0.saturating_add(fee);
+
+        Ok(ProjectedBalance::new(balance, outflow))
+    }
+
+    /// Returns the balance and the number of the spendable coins per asset for each
+    /// of the `owners`, in the order of the `owners`. The coins are counted using
+    /// the coins to spend index, and at most `MAX_PORTFOLIO_COINS` of them are
+    /// counted per asset.
+    #[graphql(
+        complexity = "(query_costs().balance_query + query_costs().storage_iterator \
+            + child_complexity) * owners.len()"
+    )]
+    async fn portfolio(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The addresses of the owners, at most 100.")] owners: Vec<
+            Address,
+        >,
+    ) -> async_graphql::Result<Vec<OwnerPortfolio>> {
+        if owners.len() > MAX_PORTFOLIO_OWNERS {
+            return Err(anyhow!(
+                "The number of owners ({}) exceeds the limit of {MAX_PORTFOLIO_OWNERS}",
+                owners.len()
+            )
+            .into())
+        }
+
+        let query = ctx.read_view()?;
+        if !query
+            .indexation_flags
+            .contains(&IndexationKind::CoinsToSpend)
+        {
+            return Err(anyhow!(
+                "The coins to spend indexation is required to count the coins"
+            )
+            .into())
+        }
+        let base_asset_id = *ctx
+            .data_unchecked::<ChainInfoProvider>()
+            .current_consensus_params()
+            .base_asset_id();
+
+        let mut portfolios = Vec::with_capacity(owners.len());
+        for owner in owners {
+            let mut balances: Vec<graphql_api::AddressBalance> = query
+                .balances(&owner.0, None, IterDirection::Forward, &base_asset_id)
+                .take(MAX_PORTFOLIO_ASSETS.saturating_add(1))
+                .try_collect()
+                .await?;
+            let mut complete = balances.len() <= MAX_PORTFOLIO_ASSETS;
+            balances.truncate(MAX_PORTFOLIO_ASSETS);
+
+            let mut assets = Vec::with_capacity(balances.len());
+            for balance in balances {
+                let (coin_count, counted_all) = count_at_most(
+                    query
+                        .off_chain
+                        .coins_to_spend_index(&owner.0, &balance.asset_id)
+                        .big_coins_iter,
+                    MAX_PORTFOLIO_COINS,
+                )?;
+                complete &= counted_all;
+                assets.push(AssetPortfolioEntry {
+                    asset_id: balance.asset_id.into(),
+                    amount: balance.amount.into(),
+                    coin_count: coin_count.into(),
+                });
+            }
+
+            portfolios.push(OwnerPortfolio {
+                owner,
+                assets,
+                complete,
+            });
+        }
+
+        Ok(portfolios)
+    }
 }
 
 impl From<graphql_api::AddressBalance> for Balance {
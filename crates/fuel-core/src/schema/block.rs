@@ -5,7 +5,10 @@ use super::scalars::{
 };
 use crate::{
     fuel_core_graphql_api::{
-        api_service::ConsensusModule,
+        api_service::{
+            ChainInfoProvider,
+            ConsensusModule,
+        },
         block_height_subscription,
         database::ReadView,
         query_costs,
@@ -57,6 +60,12 @@ use futures::{
     TryStreamExt,
 };
 
+/// The maximum number of headers in the response of the `blockHeaders` query.
+pub const MAX_BLOCK_HEADERS_RANGE: usize = 100;
+
+/// The maximum number of blocks in the response of the `messageOutboxRoots` query.
+pub const MAX_MESSAGE_OUTBOX_ROOTS_RANGE: usize = 100;
+
 pub struct Block(pub(crate) CompressedBlock);
 
 pub struct Header(pub(crate) BlockHeader);
@@ -90,6 +99,52 @@ pub struct PoAConsensus {
     signature: Signature,
 }
 
+#[derive(SimpleObject)]
+pub struct BlockGasUsage {
+    /// The height of the block.
+    pub height: U32,
+    /// The gas used by all transactions of the block.
+    pub gas_used: U64,
+}
+
+#[derive(SimpleObject)]
+pub struct BlockSummary {
+    /// The height of the block.
+    pub height: U32,
+    /// The time of the block.
+    pub time: Tai64Timestamp,
+    /// The number of transactions in the block, including the mint transaction.
+    pub transaction_count: U16,
+    /// The gas used by all transactions of the block.
+    pub gas_used: U64,
+    /// The fees paid by all transactions of the block.
+    pub fees: U64,
+}
+
+#[derive(SimpleObject)]
+pub struct ChainTip {
+    /// The height of the latest block.
+    pub height: U32,
+    /// The id of the latest block.
+    pub id: BlockId,
+}
+
+#[derive(SimpleObject)]
+pub struct BlockMessageOutboxRoot {
+    /// The height of the block.
+    pub height: U32,
+    /// The merkle root of the messages sent from the block.
+    pub message_outbox_root: Bytes32,
+}
+
+#[derive(SimpleObject)]
+pub struct BlockGasUtilization {
+    /// The block gas limit from the current consensus parameters.
+    pub block_gas_limit: U64,
+    /// The gas used by the latest blocks, starting from the most recent one.
+    pub blocks: Vec<BlockGasUsage>,
+}
+
 #[derive(Clone, Copy, Debug, Enum, Eq, PartialEq)]
 pub enum BlockVersion {
     V1,
@@ -317,6 +372,199 @@ impl BlockQuery {
         })
         .await
     }
+
+    /// Returns the current block gas limit and the gas used by the latest `last` blocks.
+    #[graphql(complexity = "{\
+        (query_costs().block_header + query_costs().block_transactions) * last as usize \
+        + child_complexity \
+    }")]
+    async fn block_gas_utilization(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Number of the latest blocks to include")] last: i32,
+    ) -> async_graphql::Result<BlockGasUtilization> {
+        let last = usize::try_from(last)
+            .map_err(|_| anyhow!("The number of blocks can't be negative"))?;
+        let block_gas_limit = ctx
+            .data_unchecked::<ChainInfoProvider>()
+            .current_consensus_params()
+            .block_gas_limit();
+
+        let query = ctx.read_view()?;
+        let query = query.as_ref();
+        let blocks = query
+            .compressed_blocks(None, IterDirection::Reverse)
+            .take(last)
+            .map(|block| {
+                let block = block?;
+                let gas_used = query.block_gas_used(&block)?;
+                let height: u32 = (*block.header().height()).into();
+                Ok::<_, fuel_core_storage::Error>(BlockGasUsage {
+                    height: height.into(),
+                    gas_used: gas_used.into(),
+                })
+            })
+            .try_collect()
+            .await?;
+
+        Ok(BlockGasUtilization {
+            block_gas_limit: block_gas_limit.into(),
+            blocks,
+        })
+    }
+
+    /// Returns the total fee paid by the transactions of the block at the `height`.
+    /// The fee of an empty block is zero.
+    #[graphql(complexity = "query_costs().block_header + query_costs().block_transactions")]
+    async fn block_fees(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Height of the block")] height: U32,
+    ) -> async_graphql::Result<Option<U64>> {
+        let query = ctx.read_view()?;
+        let height: u32 = height.into();
+        let block: Option<CompressedBlock> = query
+            .block(&height.into())
+            .into_api_result::<_, fuel_core_storage::Error>()?;
+
+        let fees = match block {
+            Some(block) => Some(query.block_fees(&block)?.into()),
+            None => None,
+        };
+
+        Ok(fees)
+    }
+
+    /// Returns the number of transactions, the gas used, the fees and the time
+    /// of the block at the `height` in one response.
+    #[graphql(complexity = "query_costs().block_header + query_costs().block_transactions")]
+    async fn block_summary(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Height of the block")] height: U32,
+    ) -> async_graphql::Result<Option<BlockSummary>> {
+        let query = ctx.read_view()?;
+        let height: u32 = height.into();
+        let block: Option<CompressedBlock> = query
+            .block(&height.into())
+            .into_api_result::<_, fuel_core_storage::Error>()?;
+
+        let Some(block) = block else {
+            return Ok(None)
+        };
+
+        let (gas_used, fees) = query.block_gas_used_and_fees(&block)?;
+        let header = block.header();
+
+        Ok(Some(BlockSummary {
+            height: height.into(),
+            time: Tai64Timestamp(header.time()),
+            transaction_count: header.transactions_count().into(),
+            gas_used: gas_used.into(),
+            fees: fees.into(),
+        }))
+    }
+
+    /// Returns the height and the id of the latest block. Both are read from
+    /// the same view of the database, so they always belong to the same block.
+    #[graphql(complexity = "query_costs().block_header")]
+    async fn chain_tip(&self, ctx: &Context<'_>) -> async_graphql::Result<ChainTip> {
+        let query = ctx.read_view()?;
+        let block = query.latest_block()?;
+        let header = block.header();
+        let height: u32 = (*header.height()).into();
+        let id: fuel_types::Bytes32 = header.id().into();
+
+        Ok(ChainTip {
+            height: height.into(),
+            id: id.into(),
+        })
+    }
+
+    /// Returns the headers of the blocks from the `start` up to the `end` height
+    /// inclusive, in ascending order. The headers contain everything required to
+    /// verify the chain, so light clients can sync headers before the blocks.
+    #[graphql(complexity = "{\
+        (query_costs().block_header + child_complexity) \
+        * usize::from(end).saturating_sub(usize::from(start)).saturating_add(1) \
+    }")]
+    async fn block_headers(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Height of the first block")] start: U32,
+        #[graphql(desc = "Height of the last block, at most 99 above the start")]
+        end: U32,
+    ) -> async_graphql::Result<Vec<Header>> {
+        let start: u32 = start.into();
+        let end: u32 = end.into();
+        if end < start {
+            return Err(anyhow!("The end height can't be below the start height").into())
+        }
+        let count = usize::try_from(end.saturating_sub(start))?.saturating_add(1);
+        let limit = MAX_BLOCK_HEADERS_RANGE;
+        if count > limit {
+            return Err(
+                anyhow!("The number of blocks exceeds the limit of {limit}").into()
+            )
+        }
+
+        let query = ctx.read_view()?;
+        let headers = query
+            .compressed_blocks(Some(start.into()), IterDirection::Forward)
+            .take(count)
+            .map(|block| block.map(Header::from))
+            .try_collect()
+            .await?;
+
+        Ok(headers)
+    }
+
+    /// Returns the message outbox roots of the blocks from the `start` up to the `end`
+    /// height inclusive, in ascending order. The roots are read from the block headers,
+    /// so the message inclusion can be verified without fetching the blocks.
+    #[graphql(complexity = "{\
+        query_costs().block_header \
+        * usize::from(end).saturating_sub(usize::from(start)).saturating_add(1) \
+    }")]
+    async fn message_outbox_roots(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Height of the first block")] start: U32,
+        #[graphql(desc = "Height of the last block, at most 99 above the start")]
+        end: U32,
+    ) -> async_graphql::Result<Vec<BlockMessageOutboxRoot>> {
+        let start: u32 = start.into();
+        let end: u32 = end.into();
+        if end < start {
+            return Err(anyhow!("The end height can't be below the start height").into())
+        }
+        let count = usize::try_from(end.saturating_sub(start))?.saturating_add(1);
+        let limit = MAX_MESSAGE_OUTBOX_ROOTS_RANGE;
+        if count > limit {
+            return Err(
+                anyhow!("The number of blocks exceeds the limit of {limit}").into()
+            )
+        }
+
+        let query = ctx.read_view()?;
+        let roots = query
+            .compressed_blocks(Some(start.into()), IterDirection::Forward)
+            .take(count)
+            .map(|block| {
+                block.map(|block| {
+                    let header = block.header();
+                    let height: u32 = (*header.height()).into();
+                    BlockMessageOutboxRoot {
+                        height: height.into(),
+                        message_outbox_root: header.message_outbox_root().into(),
+                    }
+                })
+            })
+            .try_collect()
+            .await?;
+
+        Ok(roots)
+    }
 }
 
 #[derive(Default)]
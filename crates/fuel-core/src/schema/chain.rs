@@ -20,6 +20,7 @@ use async_graphql::{
     Context,
     Enum,
     Object,
+    SimpleObject,
     Union,
 };
 use fuel_core_types::{
@@ -837,4 +838,37 @@ impl ChainQuery {
     async fn chain(&self) -> ChainInfo {
         ChainInfo
     }
+
+    /// The transaction parameters of the current consensus parameters,
+    /// i.e. the limits that transactions must satisfy to be accepted by the chain.
+    #[graphql(complexity = "query_costs().storage_read + child_complexity")]
+    async fn tx_params(&self, ctx: &Context<'_>) -> TxParameters {
+        let params = ctx
+            .data_unchecked::<ChainInfoProvider>()
+            .current_consensus_params();
+
+        TxParameters(params.tx_params().to_owned())
+    }
+
+    /// The chain id and the human-readable name of the network the node belongs to.
+    #[graphql(complexity = "query_costs().storage_read")]
+    async fn network_info(&self, ctx: &Context<'_>) -> NetworkInfo {
+        let params = ctx
+            .data_unchecked::<ChainInfoProvider>()
+            .current_consensus_params();
+        let config: &Config = ctx.data_unchecked();
+
+        NetworkInfo {
+            chain_id: (*params.chain_id()).into(),
+            name: config.chain_name.clone(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct NetworkInfo {
+    /// The chain id of the current consensus parameters.
+    chain_id: U64,
+    /// The name of the chain from the chain config.
+    name: String,
 }
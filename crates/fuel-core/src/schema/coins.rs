@@ -1,68 +1,170 @@
 use std::{
     borrow::Cow,
     collections::HashSet,
+    sync::Arc,
+    time::Duration,
 };
 
 use crate::{
     coins_query::{
+        apply_blacklist,
+        apply_coin_tags,
+        apply_reservations,
+        candidate_selections,
+        coins_feasibility,
+        largest_first_within_size,
+        largest_from_index,
+        largest_up_to_max,
         random_improve,
         select_coins_to_spend,
+        smallest_from_index,
+        CoinsFeasibility as CoinsFeasibilityModel,
         CoinsQueryError,
         SpendQuery,
     },
     database::database_description::IndexationKind,
     fuel_core_graphql_api::{
+        coin_reservations::{
+            CoinReservations,
+            SessionId,
+        },
         query_costs,
-        storage::coins::CoinsToSpendIndexKey,
+        storage::{
+            coins::CoinsToSpendIndexKey,
+            messages::MessageCoinSortOrder,
+            predicates::PredicateDetails,
+        },
+        worker_service::{
+            CoinsToSpendIndexRebuildStatus,
+            CoinsToSpendIndexRebuilder,
+        },
+        Config as GraphQLConfig,
         IntoApiResult,
     },
     graphql_api::{
-        api_service::ChainInfoProvider,
-        database::ReadView,
+        api_service::{
+            ChainInfoProvider,
+            CoinTagsProvider,
+            CoinsBlacklistProvider,
+        },
+        database::{
+            ReadDatabase,
+            ReadView,
+        },
+        extensions::trusted_client::TrustedClient,
     },
     query::asset_query::{
+        AssetQuery,
         AssetSpendTarget,
+        AssetsQuery,
         Exclude,
     },
     schema::{
         scalars::{
             Address,
             AssetId,
+            Bytes32,
+            HexString,
             Nonce,
+            SortedMessageCoinCursor,
+            TransactionId,
+            TxPointer,
             UtxoId,
             U128,
             U16,
             U32,
             U64,
         },
+        tx::{
+            output::AssetAmount,
+            types::Transaction,
+        },
         ReadViewProvider,
     },
 };
 use async_graphql::{
     connection::{
         Connection,
+        ConnectionNameType,
+        EdgeNameType,
         EmptyFields,
     },
     Context,
+    ErrorExtensions,
+    OutputType,
+};
+use fuel_core_storage::{
+    iter::IterDirection,
+    Result as StorageResult,
 };
 use fuel_core_types::{
-    entities::coins::{
-        self,
-        coin::Coin as CoinModel,
-        message_coin::{
+    blockchain::{
+        block::CompressedBlock,
+        header::ConsensusParametersVersion,
+        transaction::TransactionExt,
+    },
+    entities::{
+        coins::{
             self,
-            MessageCoin as MessageCoinModel,
+            coin::Coin as CoinModel,
+            message_coin::{
+                self,
+                MessageCoin as MessageCoinModel,
+            },
+            CoinId,
         },
-        CoinId,
+        relayer::message::Message,
     },
+    fuel_crypto::Signature,
     fuel_tx::{
         self,
         ConsensusParameters,
+        UniqueIdentifier,
+    },
+    fuel_types::{
+        canonical::Serialize,
+        BlockHeight,
     },
 };
+use futures::TryStreamExt;
 use itertools::Itertools;
 use tokio_stream::StreamExt;
 
+/// The maximum number of dust coins counted by the `dust_coins` query.
+const MAX_DUST_COINS: usize = 10_000;
+
+/// The maximum number of coins counted per asset by the `coins_to_spend_cost` query.
+pub const MAX_SELECTION_COST_COINS: usize = 10_000;
+
+/// The maximum number of asset ids in the filter of the `coins` query.
+pub const MAX_COIN_FILTER_ASSET_IDS: usize = 256;
+
+/// The maximum number of coins and messages checked by the `locked_coins` query.
+pub const MAX_LOCKED_COINS_SCANNED: usize = 10_000;
+
+/// The maximum number of coins returned by the `coins_eligibility` query.
+pub const MAX_COIN_ELIGIBILITY_COINS: usize = 1_000;
+
+/// The maximum number of groups returned by the `coins_to_spend_in_groups` query.
+pub const MAX_COIN_GROUPS: u16 = 16;
+
+/// The maximum number of blocks covered by the `coin_activity` query.
+pub const MAX_COIN_ACTIVITY_BLOCKS: u32 = 10_000;
+
+/// The maximum number of the base asset selections done by the `coins_to_spend_with_fee`
+/// query to cover the fee of its own inputs.
+pub const MAX_FEE_SELECTION_ROUNDS: usize = 4;
+
+/// The maximum number of transactions covered by one page of the `coin_activity` query.
+pub const MAX_COIN_ACTIVITY_TRANSACTIONS: u32 = 100;
+
+/// The maximum number of candidates returned by the `coin_selection_candidates` query.
+pub const MAX_COIN_SELECTION_CANDIDATES: u16 = 16;
+
+/// The maximum number of candidates enumerated by the `coin_selection_candidates`
+/// query before ranking them.
+const MAX_ENUMERATED_COIN_SELECTIONS: usize = 256;
+
 pub struct Coin(pub(crate) CoinModel);
 
 #[async_graphql::Object]
@@ -168,15 +270,213 @@ impl From<coins::CoinType> for CoinType {
     }
 }
 
+/// The fields of the `Input::CoinSigned` or the `Input::CoinPredicate` spending the coin.
+#[derive(async_graphql::SimpleObject)]
+pub struct CoinInputFragment {
+    utxo_id: UtxoId,
+    owner: Address,
+    amount: U64,
+    asset_id: AssetId,
+    tx_pointer: TxPointer,
+    /// The index of the witness with the signature of the owner.
+    /// Not used by the `Input::CoinPredicate`.
+    witness_index: U16,
+    /// The bytecode of the predicate of the owner for the `Input::CoinPredicate`.
+    /// Only returned if requested and the predicate was seen on the chain.
+    predicate: Option<HexString>,
+    /// The length of the predicate data of the last spent input with the predicate.
+    /// Only returned together with the `predicate`.
+    predicate_data_length: Option<U64>,
+}
+
+/// The fields of the `Input::MessageCoinSigned` or the `Input::MessageCoinPredicate`
+/// spending the message coin.
+#[derive(async_graphql::SimpleObject)]
+pub struct MessageCoinInputFragment {
+    sender: Address,
+    recipient: Address,
+    amount: U64,
+    nonce: Nonce,
+    /// The index of the witness with the signature of the recipient.
+    /// Not used by the `Input::MessageCoinPredicate`.
+    witness_index: U16,
+    /// The bytecode of the predicate of the recipient for the
+    /// `Input::MessageCoinPredicate`.
+    /// Only returned if requested and the predicate was seen on the chain.
+    predicate: Option<HexString>,
+    /// The length of the predicate data of the last spent input with the predicate.
+    /// Only returned together with the `predicate`.
+    predicate_data_length: Option<U64>,
+}
+
+/// The fields of the transaction input spending the selected coin.
+#[derive(async_graphql::Union)]
+pub enum InputFragment {
+    Coin(CoinInputFragment),
+    MessageCoin(MessageCoinInputFragment),
+}
+
+impl InputFragment {
+    fn new(
+        coin: CoinType,
+        witness_index: u16,
+        predicate: Option<&PredicateDetails>,
+    ) -> Self {
+        let predicate_data_length = predicate.map(|details| details.data_length.into());
+        let predicate = predicate.map(|details| HexString(details.bytecode.clone()));
+        match coin {
+            CoinType::Coin(Coin(coin)) => InputFragment::Coin(CoinInputFragment {
+                utxo_id: coin.utxo_id.into(),
+                owner: coin.owner.into(),
+                amount: coin.amount.into(),
+                asset_id: coin.asset_id.into(),
+                tx_pointer: coin.tx_pointer.into(),
+                witness_index: witness_index.into(),
+                predicate,
+                predicate_data_length,
+            }),
+            CoinType::MessageCoin(MessageCoin(coin)) => {
+                InputFragment::MessageCoin(MessageCoinInputFragment {
+                    sender: coin.sender.into(),
+                    recipient: coin.recipient.into(),
+                    amount: coin.amount.into(),
+                    nonce: coin.nonce.into(),
+                    witness_index: witness_index.into(),
+                    predicate,
+                    predicate_data_length,
+                })
+            }
+        }
+    }
+}
+
+/// Returns the input spending the `coin` signed by the witness at the `witness_index`.
+fn signed_input(coin: &CoinType, witness_index: u16) -> fuel_tx::Input {
+    match coin {
+        CoinType::Coin(Coin(coin)) => fuel_tx::Input::coin_signed(
+            coin.utxo_id,
+            coin.owner,
+            coin.amount,
+            coin.asset_id,
+            coin.tx_pointer,
+            witness_index,
+        ),
+        CoinType::MessageCoin(MessageCoin(coin)) => fuel_tx::Input::message_coin_signed(
+            coin.sender,
+            coin.recipient,
+            coin.amount,
+            coin.nonce,
+            witness_index,
+        ),
+    }
+}
+
+/// Estimates the gas contributed by the `coins` spent as signed inputs
+/// sharing the same witness.
+fn selection_gas_cost(
+    coins: &[Vec<CoinType>],
+    params: &ConsensusParameters,
+) -> SelectionGasCost {
+    let inputs = coins.iter().flatten().map(|coin| signed_input(coin, 0));
+
+    let (count, input_bytes) = inputs.fold((0u32, 0u64), |(count, bytes), input| {
+        let size = u64::try_from(input.size()).unwrap_or(u64::MAX);
+        (count.saturating_add(1), bytes.saturating_add(size))
+    });
+
+    let (signature_gas, bytes) = if count > 0 {
+        (
+            params.gas_costs().eck1(),
+            input_bytes.saturating_add(signature_witness_size()),
+        )
+    } else {
+        (0, 0)
+    };
+    let bytes_gas = bytes.saturating_mul(params.fee_params().gas_per_byte());
+
+    SelectionGasCost {
+        inputs: count.into(),
+        signature_gas: signature_gas.into(),
+        bytes: bytes.into(),
+        bytes_gas: bytes_gas.into(),
+        total_gas: signature_gas.saturating_add(bytes_gas).into(),
+    }
+}
+
+/// The size of the witness with the signature shared by the signed inputs.
+fn signature_witness_size() -> u64 {
+    let witness: fuel_tx::Witness = vec![0; Signature::LEN].into();
+    u64::try_from(witness.size()).unwrap_or(u64::MAX)
+}
+
+/// Estimates the size of the `coin` spent as a signed input, or as a predicate input
+/// if the `predicate_size` of the predicate with its data is known.
+fn estimated_input_size(coin: &coins::CoinType, predicate_size: Option<u64>) -> u64 {
+    let witness_index = 0;
+    let predicate_gas_used = 0;
+    let input = match (coin, predicate_size) {
+        (coins::CoinType::Coin(coin), None) => fuel_tx::Input::coin_signed(
+            coin.utxo_id,
+            coin.owner,
+            coin.amount,
+            coin.asset_id,
+            coin.tx_pointer,
+            witness_index,
+        ),
+        (coins::CoinType::Coin(coin), Some(_)) => fuel_tx::Input::coin_predicate(
+            coin.utxo_id,
+            coin.owner,
+            coin.amount,
+            coin.asset_id,
+            coin.tx_pointer,
+            predicate_gas_used,
+            vec![],
+            vec![],
+        ),
+        (coins::CoinType::MessageCoin(coin), None) => {
+            fuel_tx::Input::message_coin_signed(
+                coin.sender,
+                coin.recipient,
+                coin.amount,
+                coin.nonce,
+                witness_index,
+            )
+        }
+        (coins::CoinType::MessageCoin(coin), Some(_)) => {
+            fuel_tx::Input::message_coin_predicate(
+                coin.sender,
+                coin.recipient,
+                coin.amount,
+                coin.nonce,
+                predicate_gas_used,
+                vec![],
+                vec![],
+            )
+        }
+    };
+
+    u64::try_from(input.size())
+        .unwrap_or(u64::MAX)
+        .saturating_add(predicate_size.unwrap_or_default())
+}
+
 #[derive(async_graphql::InputObject)]
 struct CoinFilterInput {
     /// Returns coins owned by the `owner`.
     owner: Address,
     /// Returns coins only with `asset_id`.
     asset_id: Option<AssetId>,
+    /// Returns coins with any of the `asset_ids`. Can't be used together with `asset_id`.
+    /// At most 256 asset ids can be specified.
+    asset_ids: Option<Vec<AssetId>>,
+    /// Excludes coins of the base asset from the result.
+    exclude_base_asset: Option<bool>,
+    /// Returns only coins with at least `min_confirmations` blocks
+    /// on top of the block that created them.
+    min_confirmations: Option<U32>,
 }
 
-#[derive(async_graphql::InputObject)]
+#[derive(async_graphql::InputObject, Clone)]
 pub struct SpendQueryElementInput {
     /// Identifier of the asset to spend.
     pub asset_id: AssetId,
@@ -184,6 +484,39 @@ pub struct SpendQueryElementInput {
     pub amount: U128,
     /// The maximum number of currencies for selection.
     pub max: Option<U16>,
+    /// The preferred number of coins in the selection. The selection is biased
+    /// towards this number of coins when the target can be reached with it.
+    pub preferred_count: Option<U16>,
+    /// The maximum amount by which the selection may exceed the target amount.
+    /// The selection fails if the target can't be met within this cap.
+    pub max_overshoot: Option<U128>,
+    /// The number of coins of the asset that must stay unspent after the selection.
+    /// The smallest coins are kept unspent, and the selection uses the rest.
+    pub reserve_count: Option<U16>,
+    /// Whether the selection avoids the largest coin of the asset, which is the most
+    /// linkable one. The largest coin is used only if the target can't be met within
+    /// `max` without it.
+    pub avoid_largest_coin: Option<bool>,
+    /// Whether the selection prefers the coins created at lower heights, which are
+    /// confirmed deeper and are safer against reorgs. The shallower coins are used
+    /// only if the target can't be met within `max` without them.
+    pub prefer_deep_coins: Option<bool>,
+    /// The direction in which the coins to spend index is scanned. It applies only
+    /// to the selection from the index and defaults to the largest coins first.
+    pub scan_direction: Option<IndexScanDirection>,
+    /// The number of change outputs the selection of the asset is biased towards.
+    /// Each asset produces at most one change output, so `0` prefers the coins
+    /// matching the amount exactly, and any other number prefers the coins
+    /// exceeding it. The selection falls back to any coins within `max`.
+    pub preferred_change_outputs: Option<U16>,
+}
+
+#[derive(async_graphql::InputObject)]
+pub struct AssetAmountInput {
+    /// Identifier of the asset.
+    pub asset_id: AssetId,
+    /// The amount of the asset.
+    pub amount: U128,
 }
 
 #[derive(async_graphql::InputObject)]
@@ -192,6 +525,36 @@ pub struct ExcludeInput {
     pub utxos: Vec<UtxoId>,
     /// Messages to exclude from the selection.
     pub messages: Vec<Nonce>,
+    /// The session on behalf of which the coins are selected. Coins reserved by other
+    /// sessions are excluded from the selection. The session of a client without
+    /// a trusted API key is ignored.
+    pub session: Option<String>,
+}
+
+#[derive(async_graphql::InputObject)]
+pub struct CoinsSelectionInput {
+    /// The `Address` of the coins owner.
+    pub owner: Address,
+    /// The list of requested assets` coins with asset ids, `target` amount the user
+    /// wants to reach, and the `max` number of coins in the selection. Several entries
+    /// with the same asset id are not allowed. The result can't contain more coins
+    /// than `max_inputs`.
+    pub query_per_asset: Vec<SpendQueryElementInput>,
+    /// The excluded coins from the selection.
+    pub excluded_ids: Option<ExcludeInput>,
+}
+
+impl CoinsSelectionInput {
+    /// Prepares the selection under the current consensus parameters and returns it
+    /// with the checked requested assets.
+    fn prepare(
+        self,
+        ctx: &Context<'_>,
+    ) -> Result<(SelectionInput, Vec<SpendQueryElementInput>), CoinsQueryError> {
+        let selection = SelectionInput::new(ctx, &self.owner, self.excluded_ids)?;
+        let query_per_asset = selection.query_per_asset(self.query_per_asset)?;
+        Ok((selection, query_per_asset))
+    }
 }
 
 impl From<Option<ExcludeInput>> for Exclude {
@@ -212,6 +575,25 @@ impl From<Option<ExcludeInput>> for Exclude {
     }
 }
 
+/// The name of the `message_coins_by_sender` connection. It is paginated by the nonce,
+/// so it can't share the connection type with `message_coins`.
+pub struct MessageCoinBySenderConnectionName;
+
+impl ConnectionNameType for MessageCoinBySenderConnectionName {
+    fn type_name<T: OutputType>() -> String {
+        "MessageCoinBySenderConnection".to_string()
+    }
+}
+
+/// The name of the edge of the `message_coins_by_sender` connection.
+pub struct MessageCoinBySenderEdgeName;
+
+impl EdgeNameType for MessageCoinBySenderEdgeName {
+    fn type_name<T: OutputType>() -> String {
+        "MessageCoinBySenderEdge".to_string()
+    }
+}
+
 #[derive(Default)]
 pub struct CoinQuery;
 
@@ -228,6 +610,30 @@ impl CoinQuery {
         query.coin(utxo_id.0).into_api_result()
     }
 
+    /// Gets the coin by `utxo_id` together with the transaction that created it.
+    /// The transaction is `null` if it can't be resolved, e.g. because it was pruned.
+    #[graphql(complexity = "2 * query_costs().storage_read \
+        + query_costs().tx_get + child_complexity")]
+    async fn coin_with_transaction(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the coin")] utxo_id: UtxoId,
+    ) -> async_graphql::Result<Option<CoinWithTransaction>> {
+        let query = ctx.read_view()?;
+        let coin: Option<CoinModel> = query
+            .coin(utxo_id.0)
+            .into_api_result::<_, fuel_core_storage::Error>()?;
+        let Some(coin) = coin else {
+            return Ok(None);
+        };
+        let transaction = creating_transaction(&query, &coin)?;
+
+        Ok(Some(CoinWithTransaction {
+            coin: coin.into(),
+            transaction,
+        }))
+    }
+
     /// Gets all unspent coins of some `owner` maybe filtered with by `asset_id` per page.
     #[graphql(complexity = "{\
         query_costs().storage_iterator\
@@ -243,8 +649,41 @@ impl CoinQuery {
         last: Option<i32>,
         before: Option<String>,
     ) -> async_graphql::Result<Connection<UtxoId, Coin, EmptyFields, EmptyFields>> {
+        if filter.asset_id.is_some() && filter.asset_ids.is_some() {
+            return Err(anyhow::anyhow!(
+                "Only one of `asset_id` and `asset_ids` can be specified in the filter"
+            )
+            .into());
+        }
+        if let Some(asset_ids) = &filter.asset_ids {
+            if asset_ids.len() > MAX_COIN_FILTER_ASSET_IDS {
+                return Err(anyhow::anyhow!(
+                    "The filter can't have more than {MAX_COIN_FILTER_ASSET_IDS} asset ids"
+                )
+                .into());
+            }
+        }
+
         let query = ctx.read_view()?;
         let owner: fuel_tx::Address = filter.owner.into();
+        let filter_asset_ids: Option<HashSet<fuel_tx::AssetId>> = filter
+            .asset_ids
+            .map(|asset_ids| asset_ids.into_iter().map(|id| id.0).collect());
+        let excluded_asset_id = if filter.exclude_base_asset.unwrap_or(false) {
+            let params = ctx
+                .data_unchecked::<ChainInfoProvider>()
+                .current_consensus_params();
+            Some(*params.base_asset_id())
+        } else {
+            None
+        };
+        let min_confirmations = match filter.min_confirmations {
+            Some(min_confirmations) => Some((
+                u32::from(query.latest_block_height()?),
+                min_confirmations.0,
+            )),
+            None => None,
+        };
         crate::schema::query_pagination(after, before, first, last, |start, direction| {
             let coins = query
                 .owned_coins(&owner, (*start).map(Into::into), direction)
@@ -256,6 +695,32 @@ impl CoinQuery {
                         }
                     }
 
+                    if let (Ok(coin), Some(filter_asset_ids)) =
+                        (&result, &filter_asset_ids)
+                    {
+                        if !filter_asset_ids.contains(&coin.asset_id) {
+                            return None
+                        }
+                    }
+
+                    if let (Ok(coin), Some(excluded_asset_id)) =
+                        (&result, &excluded_asset_id)
+                    {
+                        if &coin.asset_id == excluded_asset_id {
+                            return None
+                        }
+                    }
+
+                    if let (Ok(coin), Some((latest_height, min_confirmations))) =
+                        (&result, &min_confirmations)
+                    {
+                        let created_height = u32::from(coin.tx_pointer.block_height());
+                        let confirmations = latest_height.saturating_sub(created_height);
+                        if confirmations < *min_confirmations {
+                            return None
+                        }
+                    }
+
                     Some(result)
                 })
                 .map(|res| res.map(|coin| (coin.utxo_id.into(), coin.into())));
@@ -265,6 +730,92 @@ impl CoinQuery {
         .await
     }
 
+    /// Gets the messages of the `owner` that can be spent as message coins,
+    /// i.e. the messages without data, sorted by the `order` per page.
+    /// It is only available on nodes whose off-chain database was indexed
+    /// from the genesis.
+    #[graphql(complexity = "{\
+        query_costs().storage_iterator\
+        + (query_costs().storage_read + first.unwrap_or_default() as usize) * child_complexity \
+        + (query_costs().storage_read + last.unwrap_or_default() as usize) * child_complexity\
+    }")]
+    #[allow(clippy::too_many_arguments)]
+    async fn message_coins(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The recipient of the messages.")] owner: Address,
+        #[graphql(desc = "The order of the message coins. By the amount if not set.")]
+        order: Option<MessageCoinOrder>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<SortedMessageCoinCursor, MessageCoin, EmptyFields, EmptyFields>,
+    > {
+        let query = ctx.read_view()?;
+        let owner: fuel_tx::Address = owner.0;
+        let order = order.unwrap_or_default().into();
+        crate::schema::query_pagination(after, before, first, last, |start, direction| {
+            let start = (*start)
+                .map(|cursor: SortedMessageCoinCursor| (cursor.key, cursor.nonce.into()));
+            let message_coins = query
+                .owned_sorted_message_coins(&owner, order, start, direction)?
+                .try_filter_map(|(key, message)| async move {
+                    Ok(MessageCoinModel::try_from(message).ok().map(|coin| (key, coin)))
+                })
+                .map_ok(|(key, coin)| {
+                    let cursor = SortedMessageCoinCursor::new(key, coin.nonce.into());
+                    (cursor, coin.into())
+                });
+
+            Ok(message_coins)
+        })
+        .await
+    }
+
+    /// Gets the unspent message coins sent by the `sender` on the DA layer,
+    /// e.g. the deposits of the L1 contract, ordered by the nonce per page.
+    /// It is only available on nodes whose off-chain database was indexed
+    /// from the genesis.
+    #[graphql(complexity = "{\
+        query_costs().storage_iterator\
+        + (query_costs().storage_read + first.unwrap_or_default() as usize) * child_complexity \
+        + (query_costs().storage_read + last.unwrap_or_default() as usize) * child_complexity\
+    }")]
+    async fn message_coins_by_sender(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The sender of the messages on the DA layer.")] sender: Address,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<
+            Nonce,
+            MessageCoin,
+            EmptyFields,
+            EmptyFields,
+            MessageCoinBySenderConnectionName,
+            MessageCoinBySenderEdgeName,
+        >,
+    > {
+        let query = ctx.read_view()?;
+        let sender: fuel_tx::Address = sender.0;
+        crate::schema::query_pagination(after, before, first, last, |start, direction| {
+            let message_coins = query
+                .sender_messages(&sender, (*start).map(Into::into), direction)?
+                .try_filter_map(|message| async move {
+                    Ok(MessageCoinModel::try_from(message).ok())
+                })
+                .map_ok(|coin| (coin.nonce.into(), coin.into()));
+
+            Ok(message_coins)
+        })
+        .await
+    }
+
     /// For each `query_per_asset`, get some spendable coins(of asset specified by the query) owned by
     /// `owner` that add up at least the query amount. The returned coins can be spent.
     /// The number of coins is optimized to prevent dust accumulation.
@@ -275,7 +826,26 @@ impl CoinQuery {
     ///     The list of spendable coins per asset from the query. The length of the result is
     ///     the same as the length of `query_per_asset`. The ordering of assets and `query_per_asset`
     ///     is the same.
+    ///
+    /// If the target of an asset can't be reached, the error extensions contain the `target`,
+    /// the `availableAmount` and the `availableCount` of the coins, and the `bindingConstraint`
+    /// that is `MAX` if the coins cover the target above the `max` or `BALANCE` otherwise.
+    /// At most `MAX_DIAGNOSED_COINS` coins are counted. If `diagnosisComplete` is `false`,
+    /// the available amount and count are lower bounds.
+    ///
+    /// The coins can be filtered by the tags from the off-chain source of the node,
+    /// like "hot" or "cold". The tag filters are ignored if the tagging isn't configured.
+    ///
+    /// The `WITHIN_SIZE` strategy selects the biggest coins for each asset while
+    /// the estimated size of the inputs of all assets stays within the `max_size`.
+    /// Unlike the default strategy, which is limited only by the number of inputs,
+    /// it doesn't select many large inputs that don't fit into the transaction.
+    /// The coins that don't fit into the remaining size are skipped in favor of
+    /// the smaller ones. The inputs are estimated as signed inputs sharing one
+    /// signature, or as predicate inputs if the `predicate_size` is known.
+    /// Only the `max` and the `reserve_count` options of the queries are applied.
     #[graphql(complexity = "query_costs().coins_to_spend")]
+    #[allow(clippy::too_many_arguments)]
     async fn coins_to_spend(
         &self,
         ctx: &Context<'_>,
@@ -284,106 +854,2627 @@ impl CoinQuery {
             The list of requested assets` coins with asset ids, `target` amount the user wants \
             to reach, and the `max` number of coins in the selection. Several entries with the \
             same asset id are not allowed. The result can't contain more coins than `max_inputs`.")]
-        mut query_per_asset: Vec<SpendQueryElementInput>,
+        query_per_asset: Vec<SpendQueryElementInput>,
         #[graphql(desc = "The excluded coins from the selection.")] excluded_ids: Option<
             ExcludeInput,
         >,
+        #[graphql(desc = "\
+            The version of the consensus parameters used by the selection, like the base \
+            asset id. The current consensus parameters are used if not set.")]
+        consensus_parameters_version: Option<ConsensusParametersVersion>,
+        #[graphql(desc = "\
+            Only the coins with any of the tags are selected. \
+            All tags are allowed if not set.")]
+        allowed_tags: Option<Vec<String>>,
+        #[graphql(desc = "The coins with any of the tags are not selected.")]
+        denied_tags: Option<Vec<String>>,
+        #[graphql(desc = "\
+            The strategy of the selection. The default strategy is used if not set.")]
+        strategy: Option<SelectionStrategy>,
+        #[graphql(desc = "\
+            The maximum size of the inputs in bytes for the `WITHIN_SIZE` strategy. \
+            The maximum size of the transaction is used if not set.")]
+        max_size: Option<U64>,
+        #[graphql(desc = "\
+            The size in bytes of the predicate with its data that owns the coins for \
+            the `WITHIN_SIZE` strategy. The coins are spent as signed inputs if not set.")]
+        predicate_size: Option<U64>,
     ) -> async_graphql::Result<Vec<Vec<CoinType>>> {
-        let params = ctx
-            .data_unchecked::<ChainInfoProvider>()
-            .current_consensus_params();
-        let max_input = params.tx_params().max_inputs();
-
-        let excluded_id_count = excluded_ids.as_ref().map_or(0, |exclude| {
-            exclude.utxos.len().saturating_add(exclude.messages.len())
-        });
-        if excluded_id_count > max_input as usize {
-            return Err(CoinsQueryError::TooManyExcludedId {
-                provided: excluded_id_count,
-                allowed: max_input,
-            }
-            .into());
+        let strategy = strategy.unwrap_or_default();
+        if strategy != SelectionStrategy::WithinSize
+            && (max_size.is_some() || predicate_size.is_some())
+        {
+            return Err(anyhow::anyhow!(
+                "The `max_size` and `predicate_size` require the `WITHIN_SIZE` strategy"
+            )
+            .into())
         }
 
-        let exclude: Exclude = excluded_ids.into();
 
-        let mut duplicate_checker = HashSet::with_capacity(query_per_asset.len());
-        for query in &query_per_asset {
-            let asset_id: fuel_tx::AssetId = query.asset_id.into();
-            if !duplicate_checker.insert(asset_id) {
-                return Err(CoinsQueryError::DuplicateAssets(asset_id).into());
+        let chain_info = ctx.data_unchecked::<ChainInfoProvider>();
+        let params = match consensus_parameters_version {
+            Some(version) => chain_info.consensus_params_at_version(&version)?,
+            None => chain_info.current_consensus_params(),
+        };
+        let mut selection =
+            SelectionInput::with_params(ctx, params, &owner, excluded_ids)?;
+        apply_coin_tags(
+            ctx.data_unchecked::<CoinTagsProvider>().clone(),
+            allowed_tags.unwrap_or_default().into_iter().collect(),
+            denied_tags.unwrap_or_default().into_iter().collect(),
+            &mut selection.exclude,
+        );
+        let query_per_asset = selection.query_per_asset(query_per_asset)?;
+
+        match strategy {
+            SelectionStrategy::Default => {
+                select_coins(ctx, owner.0, &query_per_asset, &selection).await
+            }
+            SelectionStrategy::WithinSize => {
+                select_coins_within_size(
+                    ctx,
+                    owner.0,
+                    &query_per_asset,
+                    &selection,
+                    max_size.map(|size| size.0),
+                    predicate_size.map(|size| size.0),
+                )
+                .await
             }
         }
+    }
 
-        let owner: fuel_tx::Address = owner.0;
-
-        // `coins_to_spend` exists to help select inputs for the transactions.
-        // It doesn't make sense to allow the user to request more than the maximum number
-        // of inputs.
-        // TODO: To avoid breaking changes, we will truncate request for now.
-        //  In the future, we should return an error if the input is too large.
-        //  https://github.com/FuelLabs/fuel-core/issues/2343
-        query_per_asset.truncate(max_input as usize);
+    /// The same as `coins_to_spend`, but the result is a flat list of coins where each
+    /// coin is tagged with the asset id from the query. The ordering of coins is the same
+    /// as in the nested result of `coins_to_spend`.
+    #[graphql(complexity = "query_costs().coins_to_spend")]
+    async fn coins_to_spend_flat(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The coins to select.")] selection: CoinsSelectionInput,
+    ) -> async_graphql::Result<Vec<AssetCoin>> {
+        let owner = selection.owner.0;
+        let (selection, query_per_asset) = selection.prepare(ctx)?;
+        let result = select_coins(ctx, owner, &query_per_asset, &selection).await?;
+        let asset_ids = query_per_asset.into_iter().map(|query| query.asset_id);
 
-        let read_view = ctx.read_view()?;
-        let result = read_view
-            .coins_to_spend(owner, &query_per_asset, &exclude, &params, max_input)
-            .await?;
+        let coins = asset_ids
+            .zip(result)
+            .flat_map(|(asset_id, coins)| {
+                coins
+                    .into_iter()
+                    .map(move |coin| AssetCoin { asset_id, coin })
+            })
+            .collect();
 
-        Ok(result)
+        Ok(coins)
     }
-}
 
-impl ReadView {
-    pub async fn coins_to_spend(
+    /// The same as `coins_to_spend`, but each coin is returned with the fields required
+    /// to construct the input of the transaction. Coins contain the fields of the
+    /// `Input::CoinSigned` and `Input::CoinPredicate`, and message coins contain
+    /// the fields of the `Input::MessageCoinSigned` and `Input::MessageCoinPredicate`.
+    /// The `witness_index` of all inputs is the `witness_index` from the query,
+    /// because all coins belong to the same owner.
+    ///
+    /// If `include_predicate` is `true` and the owner is a predicate seen on the chain,
+    /// each input also contains the bytecode of the predicate and the length of the
+    /// predicate data of its last spending. They are omitted if the predicates index
+    /// is not available.
+    #[graphql(complexity = "query_costs().coins_to_spend")]
+    async fn coins_to_spend_as_inputs(
         &self,
-        owner: fuel_tx::Address,
-        query_per_asset: &[SpendQueryElementInput],
-        excluded: &Exclude,
-        params: &ConsensusParameters,
-        max_input: u16,
-    ) -> Result<Vec<Vec<CoinType>>, CoinsQueryError> {
-        let indexation_available = self
-            .indexation_flags
-            .contains(&IndexationKind::CoinsToSpend);
-        if indexation_available {
-            coins_to_spend_with_cache(owner, query_per_asset, excluded, max_input, self)
-                .await
+        ctx: &Context<'_>,
+        #[graphql(desc = "The coins to select.")] selection: CoinsSelectionInput,
+        #[graphql(desc = "\
+            The index of the witness with the signature of the owner. \
+            The default value is `0`.")]
+        witness_index: Option<U16>,
+        #[graphql(desc = "\
+            Whether to include the bytecode and the predicate data length hint \
+            of the predicate of the owner. The default value is `false`.")]
+        include_predicate: Option<bool>,
+    ) -> async_graphql::Result<Vec<Vec<InputFragment>>> {
+        let witness_index: u16 = witness_index.map(Into::into).unwrap_or_default();
+        let owner = selection.owner.0;
+        let (selection, query_per_asset) = selection.prepare(ctx)?;
+        let result = select_coins(ctx, owner, &query_per_asset, &selection).await?;
+
+        let predicate = if include_predicate.unwrap_or_default() {
+            ctx.read_view()?.predicate(&owner)?
         } else {
-            let base_asset_id = params.base_asset_id();
-            coins_to_spend_without_cache(
-                owner,
-                query_per_asset,
-                excluded,
-                max_input,
-                base_asset_id,
-                self,
+            None
+        };
+
+        let inputs = result
+            .into_iter()
+            .map(|coins| {
+                coins
+                    .into_iter()
+                    .map(|coin| {
+                        InputFragment::new(coin, witness_index, predicate.as_ref())
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Ok(inputs)
+    }
+
+    /// The same as `coins_to_spend`, but the result also contains the skeleton of
+    /// the script transaction spending the selected coins. The skeleton contains:
+    /// - The signed inputs of the selected coins, all signed by the witness at index `0`.
+    /// - The `Coin` output sending the target amount of each asset to the `recipient`.
+    /// - The `Change` output returning the rest of each asset to the `owner`.
+    /// - One empty witness for the signature of the `owner`.
+    ///
+    /// The skeleton has no script, gas limit or policies, and the fee isn't covered.
+    /// The query fails if the skeleton exceeds the `max_inputs` or `max_outputs`
+    /// of the current consensus parameters.
+    /// It is not ready for submission: the client must sign it, and may adjust it,
+    /// for example, set the policies and add the coins to cover the fee, before
+    /// submitting it. Any adjustment of the skeleton requires the signature to be
+    /// made again.
+    #[graphql(complexity = "query_costs().coins_to_spend")]
+    async fn coins_to_spend_as_transaction(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The coins to select.")] selection: CoinsSelectionInput,
+        #[graphql(desc = "The `Address` receiving the target amounts of the assets.")]
+        recipient: Address,
+    ) -> async_graphql::Result<CoinsToSpendAsTransaction> {
+        let owner = selection.owner;
+        let (selection, query_per_asset) = selection.prepare(ctx)?;
+        let mut outputs = Vec::with_capacity(query_per_asset.len().saturating_mul(2));
+        for query in &query_per_asset {
+            let asset_id: fuel_tx::AssetId = query.asset_id.into();
+            let amount = u64::try_from(query.amount.0).map_err(|_| {
+                anyhow::anyhow!(
+                    "The amount of the asset {asset_id} doesn't fit into the coin output"
+                )
+            })?;
+            outputs.push(fuel_tx::Output::coin(recipient.into(), amount, asset_id));
+            outputs.push(fuel_tx::Output::change(owner.into(), 0, asset_id));
+        }
+
+        let params = &selection.params;
+        let max_outputs = params.tx_params().max_outputs();
+        if outputs.len() > usize::from(max_outputs) {
+            return Err(anyhow::anyhow!(
+                "The transaction requires {} outputs, but the maximum is {max_outputs}",
+                outputs.len()
             )
-            .await
+            .into())
         }
-    }
-}
 
-async fn coins_to_spend_without_cache(
-    owner: fuel_tx::Address,
-    query_per_asset: &[SpendQueryElementInput],
-    exclude: &Exclude,
-    max_input: u16,
-    base_asset_id: &fuel_tx::AssetId,
-    db: &ReadView,
-) -> Result<Vec<Vec<CoinType>>, CoinsQueryError> {
-    let query_per_asset = query_per_asset
-        .iter()
-        .map(|e| {
-            AssetSpendTarget::new(
-                e.asset_id.0,
-                e.amount.0,
-                e.max.map(|max| max.0).unwrap_or(max_input).min(max_input),
+        let coins = select_coins(ctx, owner.0, &query_per_asset, &selection).await?;
+
+        let inputs: Vec<_> = coins
+            .iter()
+            .flatten()
+            .map(|coin| signed_input(coin, 0))
+            .collect();
+        let max_inputs = params.tx_params().max_inputs();
+        if inputs.len() > usize::from(max_inputs) {
+            return Err(anyhow::anyhow!(
+                "The transaction requires {} inputs, but the maximum is {max_inputs}",
+                inputs.len()
             )
-        })
-        .collect_vec();
+            .into())
+        }
 
-    let spend_query = SpendQuery::new(
+        let transaction: fuel_tx::Transaction = fuel_tx::Transaction::script(
+            0,
+            vec![],
+            vec![],
+            fuel_tx::policies::Policies::new(),
+            inputs,
+            outputs,
+            vec![fuel_tx::Witness::default()],
+        )
+        .into();
+
+        Ok(CoinsToSpendAsTransaction {
+            coins,
+            raw_payload: HexString(transaction.to_bytes()),
+        })
+    }
+
+    /// The same as `coins_to_spend`, but the result also contains the summary of
+    /// the selection per asset: the summed amount of the selected coins and whether
+    /// it meets or exactly matches the target amount from the query, and the estimated
+    /// gas cost of the selected inputs under the current consensus parameters.
+    #[graphql(complexity = "query_costs().coins_to_spend")]
+    async fn coins_to_spend_with_summary(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The coins to select.")] selection: CoinsSelectionInput,
+    ) -> async_graphql::Result<CoinsToSpendWithSummary> {
+        let owner = selection.owner.0;
+        let (selection, query_per_asset) = selection.prepare(ctx)?;
+        let coins = select_coins(ctx, owner, &query_per_asset, &selection).await?;
+
+        let summary = query_per_asset
+            .iter()
+            .zip(coins.iter())
+            .map(|(query, coins)| {
+                let (asset_id, target) = (query.asset_id, query.amount.0);
+                let total = coins.iter().fold(0u128, |total, coin| {
+                    total.saturating_add(coin.amount().into())
+                });
+                AssetSelectionSummary {
+                    asset_id,
+                    target: target.into(),
+                    total: total.into(),
+                    meets_target: total >= target,
+                    exact_match: total == target,
+                }
+            })
+            .collect();
+
+        let gas_cost = selection_gas_cost(&coins, &selection.params);
+
+        Ok(CoinsToSpendWithSummary {
+            coins,
+            summary,
+            gas_cost,
+        })
+    }
+
+    /// The best effort version of the `coins_to_spend`. Instead of failing the whole query
+    /// when the selection for one of the assets fails, returns the result of the selection
+    /// for each asset with the failure reason for the assets that couldn't be covered.
+    ///
+    /// Returns:
+    ///     The result of the selection per asset from the query. The length of the result is
+    ///     the same as the length of `query_per_asset`. The ordering of assets and `query_per_asset`
+    ///     is the same. The total number of selected coins can't exceed `max_inputs`.
+    ///
+    /// If `allow_partial` is set and the `max` number of coins can't reach the target,
+    /// the biggest coins up to `max` are returned as a partial selection.
+    #[graphql(complexity = "query_costs().coins_to_spend")]
+    async fn coins_to_spend_best_effort(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The coins to select.")] selection: CoinsSelectionInput,
+        #[graphql(desc = "\
+            Return the biggest coins up to `max` as a partial selection instead of failing \
+            when the target can't be reached within the `max` number of coins.")]
+        allow_partial: Option<bool>,
+    ) -> async_graphql::Result<Vec<CoinsToSpendAssetResult>> {
+        let owner: fuel_tx::Address = selection.owner.0;
+        let (selection, query_per_asset) = selection.prepare(ctx)?;
+        let SelectionInput {
+            params,
+            max_input,
+            exclude,
+            cache_fallback,
+        } = selection;
+        let read_view = ctx.read_view()?;
+
+        let allow_partial = allow_partial.unwrap_or(false);
+        let mut remaining_inputs = max_input;
+        let mut results = Vec::with_capacity(query_per_asset.len());
+        for query in query_per_asset {
+            let asset_id = query.asset_id;
+            let partial_query = allow_partial.then(|| query.clone());
+            let selection = read_view
+                .coins_to_spend_for_asset(
+                    owner,
+                    query,
+                    &exclude,
+                    &params,
+                    remaining_inputs,
+                    cache_fallback,
+                )
+                .await;
+
+            let result = match (selection, partial_query) {
+                (Ok(coins), _) => {
+                    let selected = u16::try_from(coins.len()).unwrap_or(u16::MAX);
+                    remaining_inputs = remaining_inputs.saturating_sub(selected);
+                    CoinsToSpendAssetResult {
+                        asset_id,
+                        success: true,
+                        partial: false,
+                        coins,
+                        error: None,
+                    }
+                }
+                (
+                    Err(
+                        err @ (CoinsQueryError::InsufficientCoinsForTheMax { .. }
+                        | CoinsQueryError::InsufficientCoinsAboveReserve { .. }),
+                    ),
+                    Some(partial_query),
+                ) => {
+                    let coins = read_view
+                        .partial_coins_to_spend_for_asset(
+                            owner,
+                            &partial_query,
+                            &exclude,
+                            &params,
+                            remaining_inputs,
+                        )
+                        .await?;
+                    let selected = u16::try_from(coins.len()).unwrap_or(u16::MAX);
+                    remaining_inputs = remaining_inputs.saturating_sub(selected);
+                    CoinsToSpendAssetResult {
+                        asset_id,
+                        success: false,
+                        partial: true,
+                        coins,
+                        error: Some(err.to_string()),
+                    }
+                }
+                (Err(err), _) => CoinsToSpendAssetResult {
+                    asset_id,
+                    success: false,
+                    partial: false,
+                    coins: vec![],
+                    error: Some(err.to_string()),
+                },
+            };
+            results.push(result);
+        }
+
+        Ok(results)
+    }
+
+    /// Estimates the number of inputs required to cover the `query_per_asset` target.
+    /// The selection runs independently for each asset, the same way as `coins_to_spend`
+    /// does, and the number of selected coins is summed up. It allows verifying that
+    /// the transaction will not exceed the `max_inputs` limit before building it.
+    #[graphql(complexity = "query_costs().coins_to_spend")]
+    async fn input_count_estimate(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The coins to select.")] selection: CoinsSelectionInput,
+    ) -> async_graphql::Result<InputCountEstimate> {
+        let owner: fuel_tx::Address = selection.owner.0;
+        let (selection, query_per_asset) = selection.prepare(ctx)?;
+        let SelectionInput {
+            params,
+            max_input,
+            exclude,
+            cache_fallback,
+        } = selection;
+        let read_view = ctx.read_view()?;
+
+        let mut total_inputs = 0u32;
+        let mut all_covered = true;
+        let mut inputs_per_asset = Vec::with_capacity(query_per_asset.len());
+        for query in query_per_asset {
+            let asset_id = query.asset_id;
+            let selection = read_view
+                .coins_to_spend_for_asset(
+                    owner,
+                    query,
+                    &exclude,
+                    &params,
+                    max_input,
+                    cache_fallback,
+                )
+                .await;
+
+            let result = match selection {
+                Ok(coins) => {
+                    let inputs = u32::try_from(coins.len()).unwrap_or(u32::MAX);
+                    total_inputs = total_inputs.saturating_add(inputs);
+                    AssetInputCount {
+                        asset_id,
+                        success: true,
+                        inputs: inputs.into(),
+                        error: None,
+                    }
+                }
+                Err(err) => {
+                    all_covered = false;
+                    AssetInputCount {
+                        asset_id,
+                        success: false,
+                        inputs: U32(0),
+                        error: Some(err.to_string()),
+                    }
+                }
+            };
+            inputs_per_asset.push(result);
+        }
+
+        Ok(InputCountEstimate {
+            total_inputs: total_inputs.into(),
+            max_inputs: max_input.into(),
+            within_limit: all_covered && total_inputs <= u32::from(max_input),
+            inputs_per_asset,
+        })
+    }
+
+    /// Estimates the work of the `coins_to_spend` selection with the same arguments
+    /// without running it. The coins available to the selection are counted without
+    /// loading them, and at most `MAX_SELECTION_COST_COINS` of them are counted per
+    /// asset. It allows narrowing the query before it hits the scan limits.
+    #[graphql(
+        complexity = "query_costs().storage_iterator * selection.query_per_asset.len()"
+    )]
+    async fn coins_to_spend_cost(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The coins to select.")] selection: CoinsSelectionInput,
+    ) -> async_graphql::Result<SelectionCost> {
+        let owner: fuel_tx::Address = selection.owner.0;
+        let (selection, query_per_asset) = selection.prepare(ctx)?;
+        let SelectionInput {
+            params,
+            max_input,
+            exclude,
+            ..
+        } = selection;
+
+        let query = ctx.read_view()?;
+        let indexed = query.selection_uses_index(&query_per_asset);
+        let excluded = u64::try_from(exclude.coin_ids.len()).unwrap_or(u64::MAX);
+
+        // Without the index, the selection of each asset scans all coins of the owner.
+        let owned_coins = if indexed {
+            None
+        } else {
+            Some(count_at_most(
+                query
+                    .off_chain
+                    .owned_coins_ids(&owner, None, IterDirection::Forward),
+                MAX_SELECTION_COST_COINS,
+            )?)
+        };
+
+        let mut complete = true;
+        let mut total_entries = 0u64;
+        let mut cost_per_asset = Vec::with_capacity(query_per_asset.len());
+        for asset in query_per_asset {
+            let asset_id = asset.asset_id.0;
+            let (coin_count, counted_all, entries) = match owned_coins {
+                None => {
+                    let (coins, counted_all) = count_at_most(
+                        query
+                            .off_chain
+                            .coins_to_spend_index(&owner, &asset_id)
+                            .big_coins_iter,
+                        MAX_SELECTION_COST_COINS,
+                    )?;
+                    // The selection reads the biggest coins up to `max` and the same
+                    // number of the smallest coins at most, skipping the excluded ones.
+                    // All coins are read only if the target can't be met.
+                    let max = asset.max.map(|max| max.0).unwrap_or(max_input);
+                    let max = u64::from(max.min(max_input));
+                    let entries =
+                        coins.min(max.saturating_mul(2).saturating_add(excluded));
+                    (coins, counted_all, entries)
+                }
+                Some((mut coins, mut counted_all)) => {
+                    if asset_id == *params.base_asset_id() {
+                        let (messages, counted_all_messages) = count_at_most(
+                            query.off_chain.owned_message_ids(
+                                &owner,
+                                None,
+                                IterDirection::Forward,
+                            ),
+                            MAX_SELECTION_COST_COINS,
+                        )?;
+                        coins = coins.saturating_add(messages);
+                        counted_all &= counted_all_messages;
+                    }
+                    (coins, counted_all, coins)
+                }
+            };
+
+            complete &= counted_all;
+            total_entries = total_entries.saturating_add(entries);
+            cost_per_asset.push(AssetSelectionCost {
+                asset_id: asset_id.into(),
+                coin_count: coin_count.into(),
+                entries_to_scan: entries.into(),
+            });
+        }
+
+        Ok(SelectionCost {
+            indexed,
+            entries_to_scan: total_entries.into(),
+            complete,
+            cost_per_asset,
+        })
+    }
+
+    /// Checks whether the `amount` of the `asset_id` can be covered by the coins of
+    /// the `owner` without returning them. The biggest coins are counted first, and
+    /// the scan stops as soon as the `amount` is reached or more than `max_inputs`
+    /// coins are needed, so the query is cheaper than `coins_to_spend`. Requires the coins to spend indexation.
+    #[graphql(complexity = "query_costs().storage_iterator")]
+    async fn coins_feasibility(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The `Address` of the coins owner.")] owner: Address,
+        #[graphql(desc = "The asset id of the coins.")] asset_id: AssetId,
+        #[graphql(desc = "The target amount the user wants to reach.")] amount: U128,
+    ) -> async_graphql::Result<CoinsFeasibility> {
+        let read_view = ctx.read_view()?;
+        if !read_view
+            .indexation_flags
+            .contains(&IndexationKind::CoinsToSpend)
+        {
+            return Err(anyhow::anyhow!(
+                "The coins to spend indexation is required to check the feasibility"
+            )
+            .into())
+        }
+
+        let SelectionInput {
+            max_input, exclude, ..
+        } = SelectionInput::new(ctx, &owner, None)?;
+
+        let feasibility = coins_feasibility(
+            read_view.off_chain.coins_to_spend_index(&owner.0, &asset_id.0),
+            amount.0,
+            max_input,
+            &exclude,
+            read_view.batch_size,
+        )
+        .await?;
+
+        Ok(feasibility.into())
+    }
+
+    /// Resolves the coins with the `utxo_ids` and the message coins with the `nonces`
+    /// against the current state and checks whether the unspent coins of the `asset_id`
+    /// sum up to at least the `target`. It allows verifying the selection assembled
+    /// out-of-band before building the transaction.
+    #[graphql(complexity = "{\
+        query_costs().storage_read * (utxo_ids.len() + nonces.len()) \
+    }")]
+    async fn coins_coverage(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The asset id of the coins.")] asset_id: AssetId,
+        #[graphql(desc = "The target amount the coins should cover.")] target: U128,
+        #[graphql(desc = "The ids of the coins.")] utxo_ids: Vec<UtxoId>,
+        #[graphql(desc = "The nonces of the message coins.")] nonces: Vec<Nonce>,
+    ) -> async_graphql::Result<CoinsCoverage> {
+        let params = ctx
+            .data_unchecked::<ChainInfoProvider>()
+            .current_consensus_params();
+        let max_input = params.tx_params().max_inputs();
+        if utxo_ids.len().saturating_add(nonces.len()) > usize::from(max_input) {
+            return Err(anyhow::anyhow!(
+                "The number of coins exceeds the maximum number of inputs {max_input}"
+            )
+            .into())
+        }
+
+        let query = ctx.read_view()?;
+        let mut total = 0u128;
+        let mut resolve = |coin_asset_id: &fuel_tx::AssetId, amount: u64| {
+            if *coin_asset_id != asset_id.0 {
+                return CoinResolutionStatus::OtherAsset
+            }
+            total = total.saturating_add(u128::from(amount));
+            CoinResolutionStatus::Unspent
+        };
+
+        let mut coins = Vec::with_capacity(utxo_ids.len());
+        for utxo_id in utxo_ids {
+            let coin: Option<CoinModel> = query
+                .coin(utxo_id.0)
+                .into_api_result::<_, fuel_core_storage::Error>()?;
+            let (status, amount) = match coin {
+                Some(coin) => (resolve(&coin.asset_id, coin.amount), Some(coin.amount)),
+                None => (CoinResolutionStatus::NotFound, None),
+            };
+            coins.push(CoinResolution {
+                utxo_id,
+                status,
+                amount: amount.map(Into::into),
+            });
+        }
+
+        let base_asset_id = params.base_asset_id();
+        let mut message_coins = Vec::with_capacity(nonces.len());
+        for nonce in nonces {
+            let message: Option<Message> = query
+                .message(&nonce.0)
+                .into_api_result::<_, fuel_core_storage::Error>()?;
+            let (status, amount) = match message {
+                Some(message) if message.is_retryable_message() => {
+                    (CoinResolutionStatus::NotACoin, Some(message.amount()))
+                }
+                Some(message) => (
+                    resolve(base_asset_id, message.amount()),
+                    Some(message.amount()),
+                ),
+                None => (CoinResolutionStatus::NotFound, None),
+            };
+            message_coins.push(MessageCoinResolution {
+                nonce,
+                status,
+                amount: amount.map(Into::into),
+            });
+        }
+
+        Ok(CoinsCoverage {
+            asset_id,
+            target,
+            total: total.into(),
+            covers_target: total >= target.0,
+            coins,
+            message_coins,
+        })
+    }
+
+    /// Returns the coins of the `owner` created and spent by the transactions included
+    /// in the blocks from the `start_height` up to the `end_height` inclusive, in the
+    /// order of the transactions. It allows building the statement of the owner for
+    /// the period. The range can't exceed `MAX_COIN_ACTIVITY_BLOCKS` blocks.
+    ///
+    /// The activity is paginated by the transactions: each page covers at most
+    /// `first` transactions, and the next page starts after the `last_tx_pointer`.
+    #[graphql(complexity = "{\
+        query_costs().storage_iterator \
+        + query_costs().tx_get * coin_activity_page_size(first) as usize \
+    }")]
+    async fn coin_activity(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The `Address` of the coins owner.")] owner: Address,
+        #[graphql(desc = "The height of the first block.")] start_height: U32,
+        #[graphql(desc = "The height of the last block.")] end_height: U32,
+        #[graphql(desc = "\
+            The pointer of the last transaction of the previous page. The page starts \
+            after this transaction.")]
+        after: Option<TxPointer>,
+        #[graphql(desc = "\
+            The maximum number of the transactions covered by the page. It can't exceed \
+            `MAX_COIN_ACTIVITY_TRANSACTIONS`, which is also the default.")]
+        first: Option<U32>,
+    ) -> async_graphql::Result<CoinActivity> {
+        let start: u32 = start_height.into();
+        let end: u32 = end_height.into();
+        let Some(blocks) = end.checked_sub(start) else {
+            return Err(
+                anyhow::anyhow!("The end height can't be below the start height").into(),
+            )
+        };
+        if blocks >= MAX_COIN_ACTIVITY_BLOCKS {
+            return Err(anyhow::anyhow!(
+                "The number of blocks exceeds the limit of {MAX_COIN_ACTIVITY_BLOCKS}"
+            )
+            .into())
+        }
+
+        let query = ctx.read_view()?;
+        let params = ctx
+            .data_unchecked::<ChainInfoProvider>()
+            .current_consensus_params();
+        let base_asset_id = params.base_asset_id();
+        let page_size = coin_activity_page_size(first) as usize;
+        let heights = BlockHeight::from(start)..=BlockHeight::from(end);
+        let mut txs: Vec<_> = query
+            .owned_transactions_within(
+                owner.0,
+                heights,
+                after.map(|after| after.0),
+                page_size.saturating_add(1),
+            )
+            .try_collect()
+            .await?;
+        let has_next_page = txs.len() > page_size;
+        txs.truncate(page_size);
+        let last_tx_pointer = txs.last().map(|(tx_pointer, _)| TxPointer(*tx_pointer));
+
+        let mut created = vec![];
+        let mut spent = vec![];
+        for (tx_pointer, tx) in txs {
+            let tx_id = tx.id(&params.chain_id());
+            let block_height = tx_pointer.block_height();
+
+            let inputs = tx.inputs().map(Vec::as_slice).unwrap_or_default();
+            for input in inputs {
+                if input.input_owner() != Some(&owner.0) {
+                    continue
+                }
+                if let (Some(utxo_id), Some(asset_id), Some(amount)) = (
+                    input.utxo_id(),
+                    input.asset_id(base_asset_id),
+                    input.amount(),
+                ) {
+                    spent.push(CoinMovement {
+                        utxo_id: (*utxo_id).into(),
+                        asset_id: (*asset_id).into(),
+                        amount: amount.into(),
+                        block_height: block_height.into(),
+                        transaction_id: tx_id.into(),
+                    });
+                }
+            }
+
+            for (index, output) in tx.outputs().iter().enumerate() {
+                // The same as the executor, only the outputs with some amount
+                // are added to the UTXO set.
+                let (Some(to), Some(asset_id), Some(amount)) =
+                    (output.to(), output.asset_id(), output.amount())
+                else {
+                    continue
+                };
+                if *to != owner.0 || amount == 0 {
+                    continue
+                }
+                let output_index = u16::try_from(index)?;
+                created.push(CoinMovement {
+                    utxo_id: fuel_tx::UtxoId::new(tx_id, output_index).into(),
+                    asset_id: (*asset_id).into(),
+                    amount: amount.into(),
+                    block_height: block_height.into(),
+                    transaction_id: tx_id.into(),
+                });
+            }
+        }
+
+        Ok(CoinActivity {
+            owner,
+            start_height,
+            end_height,
+            created,
+            spent,
+            last_tx_pointer,
+            has_next_page,
+        })
+    }
+
+    /// Selects the coins for the largest subset of the `query_per_asset` assets that
+    /// fits into the `max_inputs` budget of one transaction. The number of inputs
+    /// is estimated for each asset the same way as in `input_count_estimate`, and
+    /// assets are taken in the order defined by the `priority` until the budget is
+    /// exhausted. Assets that can't be covered or don't fit are dropped.
+    #[graphql(complexity = "query_costs().coins_to_spend")]
+    async fn coins_to_spend_within_budget(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The coins to select.")] selection: CoinsSelectionInput,
+        #[graphql(desc = "\
+            The order in which assets are taken into the budget. The order of the query \
+            is used by default.")]
+        priority: Option<AssetPriority>,
+    ) -> async_graphql::Result<CoinsWithinBudget> {
+        let owner: fuel_tx::Address = selection.owner.0;
+        let (selection, query_per_asset) = selection.prepare(ctx)?;
+        let SelectionInput {
+            params,
+            max_input,
+            exclude,
+            cache_fallback,
+        } = selection;
+        let read_view = ctx.read_view()?;
+
+        let mut selections = Vec::with_capacity(query_per_asset.len());
+        for query in query_per_asset {
+            let asset_id = query.asset_id;
+            let selection = read_view
+                .coins_to_spend_for_asset(
+                    owner,
+                    query,
+                    &exclude,
+                    &params,
+                    max_input,
+                    cache_fallback,
+                )
+                .await;
+            selections.push((asset_id, selection));
+        }
+
+        if priority.unwrap_or_default() == AssetPriority::FewestInputs {
+            selections.sort_by_key(|(_, selection)| {
+                selection.as_ref().map_or(usize::MAX, |coins| coins.len())
+            });
+        }
+
+        let mut remaining_inputs = max_input;
+        let mut covered = vec![];
+        let mut dropped = vec![];
+        for (asset_id, selection) in selections {
+            let coins = match selection {
+                Ok(coins) => coins,
+                Err(err) => {
+                    dropped.push(AssetInputCount {
+                        asset_id,
+                        success: false,
+                        inputs: U32(0),
+                        error: Some(err.to_string()),
+                    });
+                    continue
+                }
+            };
+
+            let inputs = u16::try_from(coins.len()).unwrap_or(u16::MAX);
+            if inputs > remaining_inputs {
+                dropped.push(AssetInputCount {
+                    asset_id,
+                    success: true,
+                    inputs: u32::from(inputs).into(),
+                    error: Some(format!(
+                        "The asset requires {inputs} inputs, but only {remaining_inputs} \
+                        are left in the budget"
+                    )),
+                });
+                continue
+            }
+
+            remaining_inputs = remaining_inputs.saturating_sub(inputs);
+            covered.push(CoinsToSpendAssetResult {
+                asset_id,
+                success: true,
+                partial: false,
+                coins,
+                error: None,
+            });
+        }
+
+        Ok(CoinsWithinBudget {
+            max_inputs: max_input.into(),
+            used_inputs: max_input.saturating_sub(remaining_inputs).into(),
+            covered,
+            dropped,
+        })
+    }
+
+    /// Selects the biggest coins of the `asset_id` that cover the `amount`, partitioned
+    /// into groups of at most `max_inputs` coins, so each group fits into one
+    /// transaction. It allows reaching the target that requires more inputs than one
+    /// transaction can have, by consolidating each group into one coin with a separate
+    /// transaction. The number of groups can't exceed 16. Requires the coins to spend
+    /// indexation.
+    #[graphql(complexity = "MAX_COIN_GROUPS as usize * query_costs().coins_to_spend")]
+    async fn coins_to_spend_in_groups(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The `Address` of the coins owner.")] owner: Address,
+        #[graphql(desc = "The asset id of the coins.")] asset_id: AssetId,
+        #[graphql(desc = "The target amount the user wants to reach.")] amount: U128,
+        #[graphql(desc = "The excluded coins from the selection.")] excluded_ids: Option<
+            ExcludeInput,
+        >,
+    ) -> async_graphql::Result<CoinGroups> {
+        let SelectionInput {
+            max_input, exclude, ..
+        } = SelectionInput::new(ctx, &owner, excluded_ids)?;
+        let max_input = max_input.max(1);
+
+        let read_view = ctx.read_view()?;
+        if !read_view
+            .indexation_flags
+            .contains(&IndexationKind::CoinsToSpend)
+        {
+            return Err(anyhow::anyhow!(
+                "The coins to spend indexation is required to select the coins in groups"
+            )
+            .into())
+        }
+
+        let selected = largest_from_index(
+            read_view
+                .off_chain
+                .coins_to_spend_index(&owner.0, &asset_id.0),
+            amount.0,
+            max_input.saturating_mul(MAX_COIN_GROUPS),
+            &asset_id.0,
+            &exclude,
+            read_view.batch_size,
+        )
+        .await
+        .map_err(|e| e.extend())?;
+        let mut coins = index_keys_into_coins(&read_view, &selected)?.into_iter();
+
+        let groups = selected
+            .chunks(max_input as usize)
+            .map(|keys| {
+                let total = keys.iter().fold(0u128, |total, key| {
+                    total.saturating_add(key.amount() as u128)
+                });
+                CoinGroup {
+                    coins: coins.by_ref().take(keys.len()).collect(),
+                    total: total.into(),
+                }
+            })
+            .collect::<Vec<_>>();
+        let total = groups
+            .iter()
+            .fold(0u128, |total, group| total.saturating_add(group.total.0));
+
+        Ok(CoinGroups {
+            max_inputs: max_input.into(),
+            total: total.into(),
+            groups,
+        })
+    }
+
+    /// Selects the coins in two phases. First, the coins for the `query_per_asset`
+    /// assets are selected the same way as in `coins_to_spend`. Then, knowing the number
+    /// of inputs, the base asset coins are selected to cover the `base_amount` and
+    /// the fee `base_fee + fee_per_input * inputs`, where `inputs` also includes
+    /// the base asset coins added by the second phase. The base asset coins are
+    /// reselected while their fee grows, at most `MAX_FEE_SELECTION_ROUNDS` times.
+    #[graphql(
+        complexity = "(1 + MAX_FEE_SELECTION_ROUNDS) * query_costs().coins_to_spend"
+    )]
+    async fn coins_to_spend_with_fee(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "\
+            The coins to select. The entries with the base asset are not allowed.")]
+        selection: CoinsSelectionInput,
+        #[graphql(desc = "The amount of the base asset to spend besides the fee.")]
+        base_amount: Option<U128>,
+        #[graphql(desc = "The fee of the transaction without the inputs.")] base_fee: U64,
+        #[graphql(desc = "The increase of the fee caused by each input.")]
+        fee_per_input: U64,
+    ) -> async_graphql::Result<CoinsToSpendWithFee> {
+        let owner: fuel_tx::Address = selection.owner.0;
+        let (selection, query_per_asset) = selection.prepare(ctx)?;
+        let SelectionInput {
+            params,
+            max_input,
+            exclude,
+            cache_fallback,
+        } = selection;
+        let base_asset_id = *params.base_asset_id();
+        if query_per_asset
+            .iter()
+            .any(|query| query.asset_id.0 == base_asset_id)
+        {
+            return Err(anyhow::anyhow!(
+                "The base asset is selected to cover the fee, use `base_amount` to spend it"
+            )
+            .into());
+        }
+
+        let read_view = ctx.read_view()?;
+
+        let coins = read_view
+            .coins_to_spend(
+                owner,
+                &query_per_asset,
+                &exclude,
+                &params,
+                max_input,
+                cache_fallback,
+            )
+            .await?;
+        let value_inputs: usize = coins.iter().map(Vec::len).sum();
+        let remaining_inputs =
+            max_input.saturating_sub(u16::try_from(value_inputs).unwrap_or(u16::MAX));
+
+        let base_amount = base_amount.map(|amount| amount.0).unwrap_or_default();
+        let fee = |inputs: usize| {
+            let inputs = u64::try_from(inputs).unwrap_or(u64::MAX);
+            base_fee.0.saturating_add(fee_per_input.0.saturating_mul(inputs))
+        };
+
+        // Adding the base asset coins increases the fee, which may require more
+        // base asset coins. The number of base asset coins only grows between
+        // rounds, so the selection ends once it fits into its own fee.
+        let mut base_inputs = 0usize;
+        let mut rounds = 0usize;
+        let base_coins = loop {
+            if rounds >= MAX_FEE_SELECTION_ROUNDS {
+                return Err(anyhow::anyhow!(
+                    "The base asset coins covering their own fee weren't found \
+                    in {MAX_FEE_SELECTION_ROUNDS} selections"
+                )
+                .into());
+            }
+            rounds = rounds.saturating_add(1);
+
+            let total_fee = fee(value_inputs.saturating_add(base_inputs));
+            let target = base_amount.saturating_add(u128::from(total_fee));
+            if target == 0 {
+                break vec![];
+            }
+
+            let query = SpendQueryElementInput {
+                asset_id: base_asset_id.into(),
+                amount: target.into(),
+                max: None,
+                preferred_count: None,
+                max_overshoot: None,
+                reserve_count: None,
+                avoid_largest_coin: None,
+                prefer_deep_coins: None,
+                scan_direction: None,
+                preferred_change_outputs: None,
+            };
+            let selected = read_view
+                .coins_to_spend_for_asset(
+                    owner,
+                    query,
+                    &exclude,
+                    &params,
+                    remaining_inputs,
+                    cache_fallback,
+                )
+                .await?;
+
+            if selected.len() <= base_inputs {
+                break selected;
+            }
+            base_inputs = selected.len();
+        };
+
+        let fee = fee(value_inputs.saturating_add(base_coins.len()));
+
+        Ok(CoinsToSpendWithFee {
+            coins,
+            base_coins,
+            fee: fee.into(),
+        })
+    }
+
+    /// Returns up to `count` candidate selections of the coins covering the `amount`
+    /// of a single asset, ranked by the composite cost: the number of inputs plus
+    /// the estimated gas of the inputs plus the overshoot of the target. Unlike
+    /// `coins_to_spend`, which returns one selection, it lets the client choose the
+    /// selection. At most `MAX_ENUMERATED_COIN_SELECTIONS` candidates are enumerated
+    /// before ranking, starting from the ones with the biggest coins.
+    #[graphql(complexity = "query_costs().coins_to_spend")]
+    #[allow(clippy::too_many_arguments)]
+    async fn coin_selection_candidates(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The `Address` of the coins owner.")] owner: Address,
+        #[graphql(desc = "The asset id of the coins.")] asset_id: AssetId,
+        #[graphql(desc = "The target amount the user wants to reach.")] amount: U128,
+        #[graphql(desc = "\
+            The maximum number of coins in a candidate. \
+            The `max_inputs` is used if not set.")]
+        max: Option<U16>,
+        #[graphql(desc = "The number of the best candidates to return.")] count: U16,
+        #[graphql(desc = "The excluded coins from the selection.")] excluded_ids: Option<
+            ExcludeInput,
+        >,
+    ) -> async_graphql::Result<Vec<CoinSelectionCandidate>> {
+        let count = count.0;
+        if count == 0 || count > MAX_COIN_SELECTION_CANDIDATES {
+            return Err(anyhow::anyhow!(
+                "The `count` must be between 1 and {MAX_COIN_SELECTION_CANDIDATES}"
+            )
+            .into())
+        }
+
+        let SelectionInput {
+            params,
+            max_input,
+            exclude,
+            ..
+        } = SelectionInput::new(ctx, &owner, excluded_ids)?;
+
+        let read_view = ctx.read_view()?;
+        let asset = AssetSpendTarget::new(
+            asset_id.0,
+            amount.0,
+            max.map(|max| max.0).unwrap_or(max_input).min(max_input),
+        );
+        let query = AssetQuery::new(
+            &owner.0,
+            &asset,
+            params.base_asset_id(),
+            Some(&exclude),
+            &read_view,
+        );
+        let candidates = candidate_selections(query, MAX_ENUMERATED_COIN_SELECTIONS)
+            .await
+            .map_err(|e| e.extend())?;
+
+        let mut candidates = candidates
+            .into_iter()
+            .map(|coins| {
+                let coins = coins.into_iter().map(Into::into).collect();
+                CoinSelectionCandidate::new(coins, amount.0, &params)
+            })
+            .collect_vec();
+        // The sort is stable, so the candidates with the same cost
+        // keep the order of the enumeration.
+        candidates.sort_by_key(|candidate| candidate.cost.0);
+        candidates.truncate(count as usize);
+
+        Ok(candidates)
+    }
+
+    /// Computes the change outputs required to balance a transaction that spends
+    /// the `selected` coins to pay the `spend` amounts. The change is returned per asset
+    /// in the order of the first appearance of the asset in `selected`, and assets
+    /// without change are omitted. The coins are not looked up in the database.
+    async fn change_outputs(
+        &self,
+        #[graphql(desc = "The asset id and the amount of each selected coin.")]
+        selected: Vec<AssetAmountInput>,
+        #[graphql(desc = "The amounts of the assets spent by the transaction.")]
+        spend: Vec<AssetAmountInput>,
+    ) -> async_graphql::Result<Vec<AssetAmount>> {
+        let mut balances: Vec<(fuel_tx::AssetId, u128)> = vec![];
+        for coin in selected {
+            let asset_id = coin.asset_id.0;
+            match balances.iter_mut().find(|(id, _)| *id == asset_id) {
+                Some((_, amount)) => *amount = amount.saturating_add(coin.amount.0),
+                None => balances.push((asset_id, coin.amount.0)),
+            }
+        }
+
+        for target in spend {
+            let asset_id = target.asset_id.0;
+            let spent = target.amount.0;
+            match balances.iter_mut().find(|(id, _)| *id == asset_id) {
+                Some((_, amount)) if *amount >= spent => {
+                    *amount = amount.saturating_sub(spent);
+                }
+                _ => {
+                    return Err(anyhow::anyhow!(
+                        "The selected coins of the asset {asset_id} don't cover the spent amount"
+                    )
+                    .into())
+                }
+            }
+        }
+
+        let change = balances
+            .into_iter()
+            .filter(|(_, amount)| *amount > 0)
+            .map(|(asset_id, amount)| AssetAmount::new(asset_id, amount))
+            .collect();
+
+        Ok(change)
+    }
+
+    /// Returns the number and the total amount of the spendable coins of the `owner`
+    /// with an amount below the `threshold` per asset. The coins are counted using
+    /// the coins to spend index, and at most `MAX_DUST_COINS` of them are counted.
+    #[graphql(complexity = "query_costs().storage_iterator + child_complexity")]
+    async fn dust_coins(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The `Address` of the coins owner.")] owner: Address,
+        #[graphql(desc = "The coins with an amount below the threshold are dust.")]
+        threshold: U64,
+    ) -> async_graphql::Result<DustCoins> {
+        let query = ctx.read_view()?;
+        if !query
+            .indexation_flags
+            .contains(&IndexationKind::CoinsToSpend)
+        {
+            return Err(anyhow::anyhow!(
+                "The coins to spend indexation is required to count the dust coins"
+            )
+            .into())
+        }
+
+        let mut assets: Vec<AssetDustCoinsSummary> = vec![];
+        let mut complete = true;
+        let keys = query
+            .off_chain
+            .owned_dust_coins_to_spend_index_keys(&owner.0, threshold.0);
+        for (counted, key) in keys.enumerate() {
+            if counted >= MAX_DUST_COINS {
+                complete = false;
+                break
+            }
+
+            // The keys are sorted by the asset id, so the coins of an asset are adjacent.
+            let key = key?;
+            let amount = u128::from(key.amount());
+            match assets.last_mut() {
+                Some(asset) if asset.asset_id.0 == *key.asset_id() => {
+                    asset.count.0 = asset.count.0.saturating_add(1);
+                    asset.amount.0 = asset.amount.0.saturating_add(amount);
+                }
+                _ => assets.push(AssetDustCoinsSummary {
+                    asset_id: (*key.asset_id()).into(),
+                    count: 1u32.into(),
+                    amount: amount.into(),
+                }),
+            }
+        }
+
+        Ok(DustCoins { assets, complete })
+    }
+
+    /// Returns the unspent coins of the `owner` that are locked on this node.
+    /// The node doesn't track protocol-level locks like collateral, so a coin is
+    /// locked if the node excludes it from the `coins_to_spend` selection: the node
+    /// operator blacklisted it, or a session reserved it with `reserve_coins`.
+    /// Locked coins still belong to the owner and can be spent by a transaction built
+    /// without the coin selection of this node. If the owner is blacklisted, all coins
+    /// of the owner are locked, and only `owner_locked` is set. At most
+    /// `MAX_LOCKED_COINS_SCANNED` coins and messages of the owner are checked.
+    #[graphql(complexity = "query_costs().storage_iterator + child_complexity")]
+    async fn locked_coins(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The `Address` of the coins owner.")] owner: Address,
+    ) -> async_graphql::Result<LockedCoins> {
+        let blacklist = ctx.data_unchecked::<CoinsBlacklistProvider>();
+        if blacklist.contains_owner(&owner.0) {
+            return Ok(LockedCoins {
+                owner_locked: true,
+                coins: vec![],
+                complete: true,
+            })
+        }
+
+        let blacklisted: HashSet<_> = blacklist.coins().into_iter().collect();
+        let reservations = ctx.data_unchecked::<CoinReservations>();
+        let lock_reason = |coin_id: &CoinId| {
+            if blacklisted.contains(coin_id) {
+                Some(CoinLockReason::Blacklisted)
+            } else if reservations.is_reserved_by_others(coin_id, None) {
+                Some(CoinLockReason::Reserved)
+            } else {
+                None
+            }
+        };
+
+        let read_view = ctx.read_view()?;
+        let coin_ids = read_view
+            .off_chain
+            .owned_coins_ids(&owner.0, None, IterDirection::Forward)
+            .map(|utxo_id| utxo_id.map(CoinId::Utxo));
+        let message_ids = read_view
+            .off_chain
+            .owned_message_ids(&owner.0, None, IterDirection::Forward)
+            .map(|nonce| nonce.map(CoinId::Message));
+
+        let mut coins = vec![];
+        let mut complete = true;
+        for (scanned, coin_id) in coin_ids.chain(message_ids).enumerate() {
+            if scanned >= MAX_LOCKED_COINS_SCANNED {
+                complete = false;
+                break
+            }
+
+            let coin_id = coin_id?;
+            let Some(reason) = lock_reason(&coin_id) else {
+                continue
+            };
+            let coin = match coin_id {
+                CoinId::Utxo(utxo_id) => read_view
+                    .coin(utxo_id)
+                    .into_api_result::<CoinModel, fuel_core_storage::Error>()?
+                    .map(|coin| CoinType::Coin(coin.into())),
+                CoinId::Message(nonce) => read_view
+                    .message(&nonce)
+                    .into_api_result::<Message, fuel_core_storage::Error>()?
+                    // Messages with data are not coins.
+                    .and_then(|message| MessageCoinModel::try_from(message).ok())
+                    .map(|coin| CoinType::MessageCoin(coin.into())),
+            };
+
+            if let Some(coin) = coin {
+                coins.push(LockedCoin { coin, reason });
+            }
+        }
+
+        Ok(LockedCoins {
+            owner_locked: false,
+            coins,
+            complete,
+        })
+    }
+
+    /// Returns the coins of the `owner` with the `asset_id`, each with whether the
+    /// `coins_to_spend` selection may use it and the reason if it can't. The coins
+    /// below the minimum amount of the asset are still selected if the target
+    /// can't be met without them. At most `MAX_COIN_ELIGIBILITY_COINS` coins are
+    /// returned. Requires `debug` to be enabled.
+    #[graphql(complexity = "query_costs().coins_to_spend + child_complexity")]
+    async fn coins_eligibility(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The `Address` of the coins owner.")] owner: Address,
+        #[graphql(desc = "The asset id of the coins.")] asset_id: AssetId,
+        #[graphql(desc = "\
+            The session on behalf of which the coins are selected. Coins reserved by other \
+            sessions are not eligible. The session of a client without a trusted API key \
+            is ignored.")]
+        session: Option<String>,
+    ) -> async_graphql::Result<CoinsEligibility> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        if !config.debug {
+            return Err(
+                anyhow::anyhow!("`debug` must be enabled to use this endpoint").into(),
+            )
+        }
+
+        // The same sources of the exclusions as used by `SelectionInput::with_params`.
+        let blacklist = ctx.data_unchecked::<CoinsBlacklistProvider>();
+        let owner_blacklisted = blacklist.contains_owner(&owner.0);
+        let blacklisted: HashSet<_> = blacklist.coins().into_iter().collect();
+        let reservations = ctx.data_unchecked::<CoinReservations>();
+        let session = reservation_session(ctx, session);
+        let min_amount = config
+            .config
+            .coins_to_spend_min_amounts
+            .get(&asset_id.0)
+            .copied();
+        let reason = |coin: &coins::CoinType| {
+            if owner_blacklisted {
+                Some(CoinIneligibilityReason::OwnerBlacklisted)
+            } else if blacklisted.contains(&coin.coin_id()) {
+                Some(CoinIneligibilityReason::Blacklisted)
+            } else if reservations
+                .is_reserved_by_others(&coin.coin_id(), session.as_ref())
+            {
+                Some(CoinIneligibilityReason::Reserved)
+            } else if min_amount.is_some_and(|min_amount| coin.amount() < min_amount) {
+                Some(CoinIneligibilityReason::BelowMinAmount)
+            } else {
+                None
+            }
+        };
+
+        let params = ctx
+            .data_unchecked::<ChainInfoProvider>()
+            .current_consensus_params();
+        let query = ctx.read_view()?;
+        let owner = owner.0;
+        let asset_id = asset_id.0;
+        let mut coins: Vec<_> = AssetsQuery::new(
+            &owner,
+            Some(HashSet::from([&asset_id])),
+            None,
+            &query,
+            params.base_asset_id(),
+        )
+        .coins()
+        .take(MAX_COIN_ELIGIBILITY_COINS.saturating_add(1))
+        .try_collect()
+        .await?;
+
+        let complete = coins.len() <= MAX_COIN_ELIGIBILITY_COINS;
+        coins.truncate(MAX_COIN_ELIGIBILITY_COINS);
+        let coins = coins
+            .into_iter()
+            .map(|coin| {
+                let reason = reason(&coin);
+                CoinEligibility {
+                    coin: coin.into(),
+                    eligible: reason.is_none(),
+                    reason,
+                }
+            })
+            .collect();
+
+        Ok(CoinsEligibility { coins, complete })
+    }
+
+    /// Returns the total number of unspent coins in the UTXO set. The count is
+    /// maintained incrementally on block import. It is only available on nodes
+    /// whose off-chain database was indexed from the genesis.
+    #[graphql(complexity = "query_costs().storage_read")]
+    async fn utxo_count(&self, ctx: &Context<'_>) -> async_graphql::Result<UtxoCount> {
+        let query = ctx.read_view()?;
+        if !query.indexation_flags.contains(&IndexationKind::UtxoCount) {
+            return Err(anyhow::anyhow!("UTXO count index is not available").into());
+        }
+        let utxo_count = query.off_chain.utxo_count()?;
+
+        Ok(UtxoCount {
+            count: utxo_count.count.into(),
+            block_height: utxo_count.block_height.map(|height| height.into()),
+        })
+    }
+
+    /// Returns the Merkle root of the unspent coins at the latest block height.
+    /// The leaves of the binary Merkle tree are the coins ordered by the `UtxoId`,
+    /// each encoded as `tx_id ++ output_index ++ owner ++ amount ++ asset_id
+    /// ++ block_created ++ tx_created_idx` with big-endian integers.
+    /// Requires the coin set root to be enabled in the node config and the API key
+    /// of a trusted client, because the root is computed over the whole coin set.
+    #[graphql(complexity = "query_costs().coin_set_root")]
+    async fn coin_set_root(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<CoinSetRoot> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        if !config.config.coin_set_root {
+            return Err(anyhow::anyhow!("The coin set root is disabled").into())
+        }
+        trusted_client(ctx)?;
+
+        let query = ctx.read_view()?;
+        let block_height = query.latest_height()?;
+        let (root, count) = query.coin_set_root().await?;
+
+        Ok(CoinSetRoot {
+            root: root.into(),
+            block_height: block_height.into(),
+            count: count.into(),
+        })
+    }
+
+    /// Returns the status of the coins to spend index rebuild started by the
+    /// `rebuild_coins_to_spend_index` mutation. `null` if the rebuild is unknown,
+    /// or the node forgot it after `MAX_COINS_TO_SPEND_INDEX_REBUILDS` newer rebuilds.
+    /// Requires `debug` to be enabled and the API key of a trusted client.
+    #[graphql(complexity = "query_costs().storage_read")]
+    async fn coins_to_spend_index_rebuild(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The id of the rebuild returned by the mutation.")] id: U64,
+    ) -> async_graphql::Result<Option<CoinsToSpendIndexRebuild>> {
+        debug_with_trusted_client(ctx)?;
+
+        let status = ctx
+            .data_unchecked::<CoinsToSpendIndexRebuilder>()
+            .status(id.0);
+
+        Ok(status.map(Into::into))
+    }
+}
+
+/// The Merkle root of the unspent coins.
+#[derive(async_graphql::SimpleObject)]
+pub struct CoinSetRoot {
+    /// The root of the binary Merkle tree over the unspent coins.
+    root: Bytes32,
+    /// The height of the block the root commits to.
+    block_height: U32,
+    /// The number of the unspent coins.
+    count: U64,
+}
+
+/// The state of the coins to spend index rebuild.
+#[derive(async_graphql::Enum, Copy, Clone, Debug, Eq, PartialEq)]
+pub enum CoinsToSpendIndexRebuildState {
+    /// The rebuild is in progress.
+    Pending,
+    /// The index entries of the owner are replaced with the rebuilt ones.
+    Completed,
+    /// The rebuild failed, and the index is left unchanged.
+    Failed,
+}
+
+/// The status of the coins to spend index rebuild.
+#[derive(async_graphql::SimpleObject)]
+pub struct CoinsToSpendIndexRebuild {
+    /// The state of the rebuild.
+    state: CoinsToSpendIndexRebuildState,
+    /// The number of entries in the rebuilt index. `null` until the rebuild is completed.
+    entries: Option<U64>,
+    /// The reason why the rebuild failed.
+    error: Option<String>,
+}
+
+impl From<CoinsToSpendIndexRebuildStatus> for CoinsToSpendIndexRebuild {
+    fn from(status: CoinsToSpendIndexRebuildStatus) -> Self {
+        match status {
+            CoinsToSpendIndexRebuildStatus::Pending => Self {
+                state: CoinsToSpendIndexRebuildState::Pending,
+                entries: None,
+                error: None,
+            },
+            CoinsToSpendIndexRebuildStatus::Completed { entries } => Self {
+                state: CoinsToSpendIndexRebuildState::Completed,
+                entries: Some((entries as u64).into()),
+                error: None,
+            },
+            CoinsToSpendIndexRebuildStatus::Failed { error } => Self {
+                state: CoinsToSpendIndexRebuildState::Failed,
+                entries: None,
+                error: Some(error),
+            },
+        }
+    }
+}
+
+/// The coin with the transaction that created it.
+#[derive(async_graphql::SimpleObject)]
+pub struct CoinWithTransaction {
+    /// The coin.
+    coin: Coin,
+    /// The transaction that created the coin.
+    /// `null` if the transaction can't be resolved, e.g. because it was pruned.
+    transaction: Option<Transaction>,
+}
+
+/// The number of unspent coins in the UTXO set.
+#[derive(async_graphql::SimpleObject)]
+pub struct UtxoCount {
+    /// The total number of unspent coins.
+    count: U64,
+    /// The height of the last block that updated the count.
+    /// `null` if no block was imported since the genesis.
+    block_height: Option<U32>,
+}
+
+/// The dust coins of the owner.
+#[derive(async_graphql::SimpleObject)]
+pub struct DustCoins {
+    /// The number and the total amount of the dust coins per asset.
+    assets: Vec<AssetDustCoinsSummary>,
+    /// Whether all dust coins of the owner are counted. `false` if the owner has more
+    /// dust coins than the query can count.
+    complete: bool,
+}
+
+/// The dust coins of the owner of one asset.
+#[derive(async_graphql::SimpleObject)]
+pub struct AssetDustCoinsSummary {
+    /// Identifier of the asset.
+    asset_id: AssetId,
+    /// The number of the dust coins.
+    count: U32,
+    /// The total amount of the dust coins.
+    amount: U128,
+}
+
+/// The estimated number of inputs required to cover the target.
+#[derive(async_graphql::SimpleObject)]
+pub struct InputCountEstimate {
+    /// The total number of inputs for all assets that could be covered.
+    total_inputs: U32,
+    /// The maximum number of inputs allowed in the transaction.
+    max_inputs: U16,
+    /// Whether all assets are covered and the total number of inputs
+    /// doesn't exceed `max_inputs`.
+    within_limit: bool,
+    /// The number of inputs per asset. The ordering is the same as in the query.
+    inputs_per_asset: Vec<AssetInputCount>,
+}
+
+/// Whether the coins of the owner can cover the amount of one asset
+/// within the maximum number of inputs.
+#[derive(async_graphql::SimpleObject)]
+pub struct CoinsFeasibility {
+    /// Whether the amount can be covered by at most `max_inputs` coins.
+    feasible: bool,
+    /// The number of the biggest coins required to cover the amount,
+    /// or the number of all available coins if they don't cover it.
+    /// The coins are counted up to `max_inputs + 1`.
+    coins_needed: U16,
+    /// The amount of the counted coins. It is the amount of all available coins
+    /// only if they don't cover the amount within `max_inputs` coins.
+    total_available: U128,
+}
+
+impl From<CoinsFeasibilityModel> for CoinsFeasibility {
+    fn from(value: CoinsFeasibilityModel) -> Self {
+        Self {
+            feasible: value.feasible,
+            coins_needed: value.coins_needed.into(),
+            total_available: value.total_available.into(),
+        }
+    }
+}
+
+/// The candidate selection from the `coin_selection_candidates` query with its cost.
+#[derive(async_graphql::SimpleObject)]
+pub struct CoinSelectionCandidate {
+    /// The selected coins.
+    coins: Vec<CoinType>,
+    /// The number of the selected coins.
+    inputs: U16,
+    /// The sum of the amounts of the selected coins.
+    total: U128,
+    /// The amount by which the `total` exceeds the target.
+    overshoot: U128,
+    /// The estimated gas of the inputs, the same as the `total_gas`
+    /// of the `SelectionGasCost`.
+    gas: U64,
+    /// The composite cost used for the ranking: the sum of the `inputs`,
+    /// the `gas` and the `overshoot`.
+    cost: U128,
+}
+
+impl CoinSelectionCandidate {
+    fn new(coins: Vec<CoinType>, target: u128, params: &ConsensusParameters) -> Self {
+        let total = coins.iter().fold(0u128, |total, coin| {
+            total.saturating_add(coin.amount().into())
+        });
+        let overshoot = total.saturating_sub(target);
+        let inputs = u16::try_from(coins.len()).unwrap_or(u16::MAX);
+        let gas = selection_gas_cost(std::slice::from_ref(&coins), params)
+            .total_gas
+            .0;
+        let cost = u128::from(inputs)
+            .saturating_add(gas.into())
+            .saturating_add(overshoot);
+
+        Self {
+            coins,
+            inputs: inputs.into(),
+            total: total.into(),
+            overshoot: overshoot.into(),
+            gas: gas.into(),
+            cost: cost.into(),
+        }
+    }
+}
+
+/// The coin created or spent by the transaction from the `coin_activity` query.
+#[derive(async_graphql::SimpleObject)]
+pub struct CoinMovement {
+    /// The id of the coin.
+    utxo_id: UtxoId,
+    /// The asset id of the coin.
+    asset_id: AssetId,
+    /// The amount of the coin.
+    amount: U64,
+    /// The height of the block with the transaction.
+    block_height: U32,
+    /// The id of the transaction that created or spent the coin.
+    transaction_id: TransactionId,
+}
+
+/// The coins of the owner created and spent within the range of blocks.
+#[derive(async_graphql::SimpleObject)]
+pub struct CoinActivity {
+    /// The owner from the query.
+    owner: Address,
+    /// The height of the first block from the query.
+    start_height: U32,
+    /// The height of the last block from the query.
+    end_height: U32,
+    /// The coins created for the owner, in the order of the transactions.
+    created: Vec<CoinMovement>,
+    /// The coins of the owner spent, in the order of the transactions.
+    spent: Vec<CoinMovement>,
+    /// The pointer of the last transaction covered by the page.
+    last_tx_pointer: Option<TxPointer>,
+    /// Whether more transactions of the owner are within the blocks after the page.
+    has_next_page: bool,
+}
+
+/// Returns the number of the transactions covered by one page of the `coin_activity`.
+fn coin_activity_page_size(first: Option<U32>) -> u32 {
+    first
+        .map(u32::from)
+        .unwrap_or(MAX_COIN_ACTIVITY_TRANSACTIONS)
+        .min(MAX_COIN_ACTIVITY_TRANSACTIONS)
+}
+
+/// The state of the coin from the `coins_coverage` query.
+#[derive(async_graphql::Enum, Copy, Clone, Debug, Eq, PartialEq)]
+pub enum CoinResolutionStatus {
+    /// The coin is unspent and counts towards the target.
+    Unspent,
+    /// The coin is unspent, but holds another asset, so it doesn't count
+    /// towards the target.
+    OtherAsset,
+    /// The message has data, so it can't be spent as a coin.
+    NotACoin,
+    /// The coin is already spent or has never existed.
+    NotFound,
+}
+
+/// The resolution of the coin from the `coins_coverage` query.
+#[derive(async_graphql::SimpleObject)]
+pub struct CoinResolution {
+    /// The id of the coin from the query.
+    utxo_id: UtxoId,
+    /// The state of the coin.
+    status: CoinResolutionStatus,
+    /// The amount of the coin. `null` if the coin is not found.
+    amount: Option<U64>,
+}
+
+/// The resolution of the message coin from the `coins_coverage` query.
+#[derive(async_graphql::SimpleObject)]
+pub struct MessageCoinResolution {
+    /// The nonce of the message from the query.
+    nonce: Nonce,
+    /// The state of the message coin.
+    status: CoinResolutionStatus,
+    /// The amount of the message. `null` if the message is not found.
+    amount: Option<U64>,
+}
+
+/// Whether the unspent coins of one asset cover the target.
+#[derive(async_graphql::SimpleObject)]
+pub struct CoinsCoverage {
+    /// Identifier of the asset from the query.
+    asset_id: AssetId,
+    /// The target amount from the query.
+    target: U128,
+    /// The sum of the amounts of the unspent coins of the asset.
+    total: U128,
+    /// Whether the `total` meets or exceeds the `target`.
+    covers_target: bool,
+    /// The resolution of each coin in the order of the query.
+    coins: Vec<CoinResolution>,
+    /// The resolution of each message coin in the order of the query.
+    message_coins: Vec<MessageCoinResolution>,
+}
+
+/// The number of inputs required to cover the target of one asset.
+#[derive(async_graphql::SimpleObject)]
+pub struct AssetInputCount {
+    /// Identifier of the asset from the query.
+    asset_id: AssetId,
+    /// Whether the coins of the owner can cover the target amount of the asset.
+    success: bool,
+    /// The number of inputs required to cover the target amount.
+    inputs: U32,
+    /// The reason why the asset isn't covered.
+    error: Option<String>,
+}
+
+/// The estimated work of the `coins_to_spend` selection.
+#[derive(async_graphql::SimpleObject)]
+pub struct SelectionCost {
+    /// Whether the selection uses the coins to spend index. Without the index,
+    /// the selection scans all coins of the owner for each asset.
+    indexed: bool,
+    /// The approximate number of the entries scanned by the selection for all assets.
+    entries_to_scan: U64,
+    /// Whether all coins were counted. If not, the counts are capped, and the actual
+    /// work of the selection may be higher.
+    complete: bool,
+    /// The estimated work per asset. The ordering is the same as in the query.
+    cost_per_asset: Vec<AssetSelectionCost>,
+}
+
+/// The estimated work of the selection of one asset.
+#[derive(async_graphql::SimpleObject)]
+pub struct AssetSelectionCost {
+    /// Identifier of the asset from the query.
+    asset_id: AssetId,
+    /// The number of the coins available to the selection of the asset: the coins
+    /// of the asset in the index, or all coins of the owner without the index.
+    coin_count: U64,
+    /// The approximate number of the entries scanned by the selection of the asset.
+    entries_to_scan: U64,
+}
+
+/// The coins of one group of `coins_to_spend_in_groups`.
+#[derive(async_graphql::SimpleObject)]
+pub struct CoinGroup {
+    /// The coins of the group, at most `max_inputs` of them.
+    coins: Vec<CoinType>,
+    /// The total amount of the coins of the group.
+    total: U128,
+}
+
+/// The coins selected by `coins_to_spend_in_groups`.
+#[derive(async_graphql::SimpleObject)]
+pub struct CoinGroups {
+    /// The maximum number of coins in one group.
+    max_inputs: U16,
+    /// The total amount of the coins of all groups.
+    total: U128,
+    /// The groups of coins, starting from the group with the biggest coins.
+    groups: Vec<CoinGroup>,
+}
+
+/// The coins selected by the two phase selection of `coins_to_spend_with_fee`.
+#[derive(async_graphql::SimpleObject)]
+pub struct CoinsToSpendWithFee {
+    /// The selected coins per asset. The ordering is the same as in the query.
+    coins: Vec<Vec<CoinType>>,
+    /// The base asset coins covering the `base_amount` and the fee.
+    base_coins: Vec<CoinType>,
+    /// The fee of the transaction with all selected inputs.
+    fee: U64,
+}
+
+/// The reason why the coin is locked on the node.
+#[derive(async_graphql::Enum, Copy, Clone, Debug, Eq, PartialEq)]
+pub enum CoinLockReason {
+    /// The coin is blacklisted by the node operator.
+    Blacklisted,
+    /// The coin is reserved by a session.
+    Reserved,
+}
+
+#[derive(async_graphql::SimpleObject)]
+pub struct LockedCoin {
+    coin: CoinType,
+    reason: CoinLockReason,
+}
+
+#[derive(async_graphql::SimpleObject)]
+pub struct LockedCoins {
+    /// Whether the owner is blacklisted, so all coins of the owner are locked.
+    owner_locked: bool,
+    /// The locked coins of the owner. Empty if the owner is locked.
+    coins: Vec<LockedCoin>,
+    /// Whether all coins of the owner were checked. Otherwise, only the first
+    /// `MAX_LOCKED_COINS_SCANNED` coins and messages of the owner were checked.
+    complete: bool,
+}
+
+/// The reason why the `coins_to_spend` selection can't use the coin.
+#[derive(async_graphql::Enum, Copy, Clone, Debug, Eq, PartialEq)]
+pub enum CoinIneligibilityReason {
+    /// The owner of the coin is blacklisted by the node operator.
+    OwnerBlacklisted,
+    /// The coin is blacklisted by the node operator.
+    Blacklisted,
+    /// The coin is reserved by another session.
+    Reserved,
+    /// The amount of the coin is below the minimum amount of the asset.
+    BelowMinAmount,
+}
+
+#[derive(async_graphql::SimpleObject)]
+pub struct CoinEligibility {
+    coin: CoinType,
+    /// Whether the `coins_to_spend` selection may use the coin.
+    eligible: bool,
+    /// The reason why the coin isn't eligible.
+    reason: Option<CoinIneligibilityReason>,
+}
+
+#[derive(async_graphql::SimpleObject)]
+pub struct CoinsEligibility {
+    /// The coins of the owner with the asset.
+    coins: Vec<CoinEligibility>,
+    /// Whether all coins of the owner with the asset are returned.
+    complete: bool,
+}
+
+/// The order of the message coins.
+#[derive(async_graphql::Enum, Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum MessageCoinOrder {
+    /// Message coins are sorted by the amount.
+    #[default]
+    Amount,
+    /// Message coins are sorted by the DA height of the message.
+    DaHeight,
+}
+
+impl From<MessageCoinOrder> for MessageCoinSortOrder {
+    fn from(order: MessageCoinOrder) -> Self {
+        match order {
+            MessageCoinOrder::Amount => MessageCoinSortOrder::Amount,
+            MessageCoinOrder::DaHeight => MessageCoinSortOrder::DaHeight,
+        }
+    }
+}
+
+/// The order in which assets are taken into the input budget.
+#[derive(async_graphql::Enum, Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum AssetPriority {
+    /// Assets are taken in the same order as in the query.
+    #[default]
+    QueryOrder,
+    /// Assets that require fewer inputs are taken first,
+    /// maximizing the number of covered assets.
+    FewestInputs,
+}
+
+/// The strategy of the `coins_to_spend` selection.
+#[derive(async_graphql::Enum, Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum SelectionStrategy {
+    /// The coins are selected to reach the target within the `max` number of coins,
+    /// and the dust coins are added to prevent dust accumulation.
+    #[default]
+    Default,
+    /// The biggest coins are selected while the estimated size of the inputs
+    /// stays within the `max_size`.
+    WithinSize,
+}
+
+/// The direction in which the coins to spend index is scanned by the selection.
+#[derive(async_graphql::Enum, Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum IndexScanDirection {
+    /// The largest coins are taken first, and the smallest ones are added as dust.
+    #[default]
+    LargestFirst,
+    /// The smallest coins are taken first until they cover the target,
+    /// which consolidates them. No dust is added.
+    SmallestFirst,
+}
+
+/// The coins of the assets that fit into the input budget of one transaction.
+#[derive(async_graphql::SimpleObject)]
+pub struct CoinsWithinBudget {
+    /// The maximum number of inputs allowed in the transaction.
+    max_inputs: U16,
+    /// The number of inputs used by the covered assets.
+    used_inputs: U16,
+    /// The assets that are fully covered within the budget, in the priority order.
+    covered: Vec<CoinsToSpendAssetResult>,
+    /// The assets that can't be covered or don't fit into the remaining budget.
+    dropped: Vec<AssetInputCount>,
+}
+
+/// The coin from the flat `coins_to_spend` selection tagged with its asset.
+#[derive(async_graphql::SimpleObject)]
+pub struct AssetCoin {
+    /// Identifier of the asset from the query.
+    asset_id: AssetId,
+    coin: CoinType,
+}
+
+/// The `coins_to_spend` selection with the unsigned transaction spending it.
+#[derive(async_graphql::SimpleObject)]
+pub struct CoinsToSpendAsTransaction {
+    /// The selected coins per asset, the same as the result of `coins_to_spend`.
+    coins: Vec<Vec<CoinType>>,
+    /// The serialized skeleton of the transaction spending the selected coins.
+    /// It must be signed before submission.
+    raw_payload: HexString,
+}
+
+/// The `coins_to_spend` selection with the summary of the selected amounts.
+#[derive(async_graphql::SimpleObject)]
+pub struct CoinsToSpendWithSummary {
+    /// The selected coins per asset, the same as the result of `coins_to_spend`.
+    coins: Vec<Vec<CoinType>>,
+    /// The summary of the selection per asset in the order of the query.
+    summary: Vec<AssetSelectionSummary>,
+    /// The estimated gas cost contributed by the selected inputs.
+    gas_cost: SelectionGasCost,
+}
+
+/// The gas contributed to the transaction by the inputs of the selection.
+/// The inputs are assumed to be signed by the owner with a single witness,
+/// because the gas used by predicates isn't known until they are estimated.
+#[derive(async_graphql::SimpleObject)]
+pub struct SelectionGasCost {
+    /// The number of the inputs in the selection.
+    inputs: U32,
+    /// The gas of the signature verification. The signature of the owner
+    /// is verified once for all inputs sharing the witness.
+    signature_gas: U64,
+    /// The size in bytes of the inputs and the signature witness.
+    bytes: U64,
+    /// The gas charged for the `bytes`.
+    bytes_gas: U64,
+    /// The sum of the `signature_gas` and the `bytes_gas`.
+    total_gas: U64,
+}
+
+/// The summed amount of the coins selected for one asset.
+#[derive(async_graphql::SimpleObject)]
+pub struct AssetSelectionSummary {
+    /// Identifier of the asset from the query.
+    asset_id: AssetId,
+    /// The target amount from the query.
+    target: U128,
+    /// The sum of the amounts of the selected coins.
+    total: U128,
+    /// Whether the `total` meets or exceeds the `target`.
+    meets_target: bool,
+    /// Whether the `total` equals the `target`, so no change output is needed.
+    exact_match: bool,
+}
+
+/// The result of the best effort coins selection for one asset.
+#[derive(async_graphql::SimpleObject)]
+pub struct CoinsToSpendAssetResult {
+    /// Identifier of the asset from the query.
+    asset_id: AssetId,
+    /// Whether the selected coins cover the target amount of the asset.
+    success: bool,
+    /// Whether the selection failed, but the biggest coins up to `max` are returned
+    /// because the partial selection was requested.
+    partial: bool,
+    /// The selected coins. Empty if the selection failed, unless it is `partial`.
+    coins: Vec<CoinType>,
+    /// The reason why the selection failed.
+    error: Option<String>,
+}
+
+/// Resolves the transaction that created the `coin` via its `tx_pointer`.
+fn creating_transaction(
+    query: &ReadView,
+    coin: &CoinModel,
+) -> StorageResult<Option<Transaction>> {
+    let tx_id = *coin.utxo_id.tx_id();
+    let block: Option<CompressedBlock> = query
+        .block(&coin.tx_pointer.block_height())
+        .into_api_result::<_, fuel_core_storage::Error>()?;
+    let pointed_tx_id = block.and_then(|block| {
+        block
+            .transactions()
+            .get(usize::from(coin.tx_pointer.tx_index()))
+            .copied()
+    });
+
+    // Coins from the genesis state aren't created by an on-chain transaction,
+    // so their pointers can point to unrelated transactions.
+    if pointed_tx_id != Some(tx_id) {
+        return Ok(None);
+    }
+
+    let transaction: Option<fuel_tx::Transaction> = query
+        .transaction(&tx_id)
+        .into_api_result::<_, fuel_core_storage::Error>()?;
+
+    Ok(transaction.map(|tx| Transaction::from_tx(tx_id, tx)))
+}
+
+/// Selects the coins for the `query_per_asset` with the default strategy of
+/// the `coins_to_spend`.
+async fn select_coins(
+    ctx: &Context<'_>,
+    owner: fuel_tx::Address,
+    query_per_asset: &[SpendQueryElementInput],
+    selection: &SelectionInput,
+) -> async_graphql::Result<Vec<Vec<CoinType>>> {
+    let SelectionInput {
+        params,
+        max_input,
+        exclude,
+        cache_fallback,
+    } = selection;
+
+    let mut read_view = ctx.read_view()?;
+    // The index is built with the current base asset id,
+    // so the selection with another base asset id doesn't use it.
+    let current_params = ctx
+        .data_unchecked::<ChainInfoProvider>()
+        .current_consensus_params();
+    if params.base_asset_id() != current_params.base_asset_id() {
+        read_view
+            .to_mut()
+            .indexation_flags
+            .remove(IndexationKind::CoinsToSpend);
+    }
+    let max_total_coins = ctx
+        .data_unchecked::<GraphQLConfig>()
+        .config
+        .coins_to_spend_max_total_coins;
+    let result = read_view
+        .coins_to_spend(
+            owner,
+            query_per_asset,
+            exclude,
+            params,
+            *max_input,
+            *cache_fallback,
+        )
+        .await
+        .and_then(|result| {
+            check_max_total_coins(&result, max_total_coins)?;
+            Ok(result)
+        })
+        .map_err(|e| e.extend())?;
+
+    Ok(result)
+}
+
+/// Selects the coins for the `query_per_asset` with the `WITHIN_SIZE` strategy
+/// of the `coins_to_spend`.
+async fn select_coins_within_size(
+    ctx: &Context<'_>,
+    owner: fuel_tx::Address,
+    query_per_asset: &[SpendQueryElementInput],
+    selection: &SelectionInput,
+    max_size: Option<u64>,
+    predicate_size: Option<u64>,
+) -> async_graphql::Result<Vec<Vec<CoinType>>> {
+    let max_input = selection.max_input;
+    let max_tx_size = selection.params.tx_params().max_size();
+    let max_size = max_size.map_or(max_tx_size, |size| size.min(max_tx_size));
+    // The signed inputs share one witness with the signature.
+    let max_size = match predicate_size {
+        Some(_) => max_size,
+        None => max_size.saturating_sub(signature_witness_size()),
+    };
+
+    let assets = query_per_asset
+        .iter()
+        .map(|e| {
+            AssetSpendTarget::new(
+                e.asset_id.0,
+                e.amount.0,
+                e.max.map(|max| max.0).unwrap_or(max_input).min(max_input),
+            )
+            .with_reserve_count(e.reserve_count.map(|count| count.0))
+        })
+        .collect_vec();
+    let spend_query = selection.spend_query(owner, &assets)?;
+
+    let read_view = ctx.read_view()?;
+    let coins = largest_first_within_size(
+        &read_view,
+        &spend_query,
+        |coin| estimated_input_size(coin, predicate_size),
+        max_size,
+    )
+    .await
+    .map_err(|e| e.extend())?
+    .into_iter()
+    .map(|coins| coins.into_iter().map(Into::into).collect())
+    .collect();
+
+    Ok(coins)
+}
+
+/// The common input of the coin selection queries: the consensus parameters,
+/// the coins excluded from the selection and the node configuration.
+struct SelectionInput {
+    params: Arc<ConsensusParameters>,
+    max_input: u16,
+    exclude: Exclude,
+    cache_fallback: bool,
+}
+
+impl SelectionInput {
+    /// Prepares the selection of the coins of the `owner` under the current
+    /// consensus parameters.
+    fn new(
+        ctx: &Context<'_>,
+        owner: &Address,
+        excluded_ids: Option<ExcludeInput>,
+    ) -> Result<Self, CoinsQueryError> {
+        let params = ctx
+            .data_unchecked::<ChainInfoProvider>()
+            .current_consensus_params();
+        Self::with_params(ctx, params, owner, excluded_ids)
+    }
+
+    /// Prepares the selection of the coins of the `owner` under the `params`.
+    /// Besides the `excluded_ids`, it excludes the coins reserved by other sessions,
+    /// the blacklisted coins and the coins below the configured minimal amounts.
+    fn with_params(
+        ctx: &Context<'_>,
+        params: Arc<ConsensusParameters>,
+        owner: &Address,
+        excluded_ids: Option<ExcludeInput>,
+    ) -> Result<Self, CoinsQueryError> {
+        let max_input = params.tx_params().max_inputs();
+        let excluded_id_count = excluded_ids.as_ref().map_or(0, |exclude| {
+            exclude.utxos.len().saturating_add(exclude.messages.len())
+        });
+        if excluded_id_count > max_input as usize {
+            return Err(CoinsQueryError::TooManyExcludedId {
+                provided: excluded_id_count,
+                allowed: max_input,
+            });
+        }
+
+        let session = excluded_ids
+            .as_ref()
+            .and_then(|exclude| reservation_session(ctx, exclude.session.clone()));
+        let mut exclude: Exclude = excluded_ids.into();
+        apply_reservations(
+            ctx.data_unchecked::<CoinReservations>(),
+            session,
+            &mut exclude,
+        );
+
+        let blacklist = ctx.data_unchecked::<CoinsBlacklistProvider>();
+        apply_blacklist(blacklist.as_ref(), [owner.0], &mut exclude)?;
+
+        let config = &ctx.data_unchecked::<GraphQLConfig>().config;
+        for (asset_id, min_amount) in &config.coins_to_spend_min_amounts {
+            exclude.exclude_below(*asset_id, *min_amount);
+        }
+
+        Ok(Self {
+            params,
+            max_input,
+            exclude,
+            cache_fallback: config.coins_to_spend_cache_fallback,
+        })
+    }
+
+    /// Checks the requested assets for duplicates and limits them to the maximum
+    /// number of inputs.
+    fn query_per_asset(
+        &self,
+        mut query_per_asset: Vec<SpendQueryElementInput>,
+    ) -> Result<Vec<SpendQueryElementInput>, CoinsQueryError> {
+        check_duplicate_assets(&query_per_asset)?;
+
+        // `coins_to_spend` exists to help select inputs for the transactions.
+        // It doesn't make sense to allow the user to request more than the maximum number
+        // of inputs.
+        // TODO: To avoid breaking changes, we will truncate request for now.
+        //  In the future, we should return an error if the input is too large.
+        //  https://github.com/FuelLabs/fuel-core/issues/2343
+        query_per_asset.truncate(self.max_input as usize);
+        Ok(query_per_asset)
+    }
+
+    /// Builds the query to select the `assets` of the `owner`.
+    fn spend_query(
+        &self,
+        owner: fuel_tx::Address,
+        assets: &[AssetSpendTarget],
+    ) -> Result<SpendQuery<'_>, CoinsQueryError> {
+        SpendQuery::new(
+            owner,
+            assets,
+            Cow::Borrowed(&self.exclude),
+            *self.params.base_asset_id(),
+        )
+    }
+}
+
+fn check_duplicate_assets(
+    query_per_asset: &[SpendQueryElementInput],
+) -> Result<(), CoinsQueryError> {
+    let mut duplicate_checker = HashSet::with_capacity(query_per_asset.len());
+    for query in query_per_asset {
+        let asset_id: fuel_tx::AssetId = query.asset_id.into();
+        if !duplicate_checker.insert(asset_id) {
+            return Err(CoinsQueryError::DuplicateAssets(asset_id));
+        }
+    }
+
+    Ok(())
+}
+
+/// Counts at most `limit` entries of the `iter`. Returns the number of the counted
+/// entries and whether all entries were counted.
+pub(crate) fn count_at_most<T>(
+    iter: impl Iterator<Item = StorageResult<T>>,
+    limit: usize,
+) -> StorageResult<(u64, bool)> {
+    let mut counted = 0u64;
+    for (index, entry) in iter.enumerate() {
+        if index >= limit {
+            return Ok((counted, false))
+        }
+        entry?;
+        counted = counted.saturating_add(1);
+    }
+
+    Ok((counted, true))
+}
+
+/// Checks that the selection doesn't contain more coins across all assets
+/// than allowed by the node, to prevent the pathological responses.
+fn check_max_total_coins(
+    result: &[Vec<CoinType>],
+    max_total_coins: usize,
+) -> Result<(), CoinsQueryError> {
+    let selected = result.iter().map(Vec::len).sum();
+    if selected > max_total_coins {
+        return Err(CoinsQueryError::TooManyCoinsInResult {
+            selected,
+            max: max_total_coins,
+        });
+    }
+
+    Ok(())
+}
+
+#[derive(Default)]
+pub struct CoinMutation;
+
+#[async_graphql::Object]
+impl CoinMutation {
+    /// Reserves the coins under the `session` for `ttl_seconds`. While reserved,
+    /// the coins are excluded from the `coins_to_spend` selection of other sessions.
+    /// Fails if any of the coins is already reserved by another session.
+    ///
+    /// Requires the API key of a trusted client. The session belongs to the client
+    /// that created it, and the number of the reserved coins is limited per session
+    /// and per node.
+    async fn reserve_coins(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The name of the session reserving the coins.")] session: String,
+        #[graphql(desc = "Utxos to reserve.")] utxos: Vec<UtxoId>,
+        #[graphql(desc = "Messages to reserve.")] messages: Vec<Nonce>,
+        #[graphql(desc = "The number of seconds after which the reservation expires.")]
+        ttl_seconds: U32,
+    ) -> async_graphql::Result<bool> {
+        let client = trusted_client(ctx)?;
+        let params = ctx
+            .data_unchecked::<ChainInfoProvider>()
+            .current_consensus_params();
+        let max_input = params.tx_params().max_inputs();
+
+        let coin_count = utxos.len().saturating_add(messages.len());
+        if coin_count > max_input as usize {
+            return Err(anyhow::anyhow!(
+                "Can't reserve more than {max_input} coins at once, provided {coin_count}"
+            )
+            .into());
+        }
+
+        let utxos = utxos
+            .into_iter()
+            .map(|utxo| coins::CoinId::Utxo(utxo.into()));
+        let messages = messages
+            .into_iter()
+            .map(|message| coins::CoinId::Message(message.into()));
+        let ttl = Duration::from_secs(ttl_seconds.0.into());
+
+        ctx.data_unchecked::<CoinReservations>().reserve(
+            &SessionId::new(client.api_key.as_str(), session),
+            utxos.chain(messages).collect(),
+            ttl,
+        )?;
+
+        Ok(true)
+    }
+
+    /// Releases all coins reserved by the `session`.
+    /// Returns `true` if the session had any reserved coins.
+    ///
+    /// Requires the API key of the trusted client that created the session.
+    async fn release_coins(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The name of the session releasing its coins.")] session: String,
+    ) -> async_graphql::Result<bool> {
+        let client = trusted_client(ctx)?;
+        let released = ctx
+            .data_unchecked::<CoinReservations>()
+            .release(&SessionId::new(client.api_key.as_str(), session));
+
+        Ok(released > 0)
+    }
+
+    /// Starts the rebuild of the coins to spend index entries of the `owner`,
+    /// optionally only for the `asset_id`, and returns its id. The rebuild runs in
+    /// the background over the coins and messages of the owner, and the off-chain
+    /// worker applies the rebuilt entries between blocks. Use the
+    /// `coins_to_spend_index_rebuild` query to get the status of the rebuild.
+    /// Requires `debug` to be enabled and the API key of a trusted client.
+    async fn rebuild_coins_to_spend_index(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The owner of the coins and messages.")] owner: Address,
+        #[graphql(desc = "Rebuild only the entries of this asset.")]
+        asset_id: Option<AssetId>,
+    ) -> async_graphql::Result<U64> {
+        debug_with_trusted_client(ctx)?;
+
+        let database = ctx.data_unchecked::<ReadDatabase>().clone();
+        let base_asset_id = *ctx
+            .data_unchecked::<ChainInfoProvider>()
+            .current_consensus_params()
+            .base_asset_id();
+        let id = ctx.data_unchecked::<CoinsToSpendIndexRebuilder>().start(
+            database,
+            owner.0,
+            asset_id.map(|id| id.0),
+            base_asset_id,
+        )?;
+
+        Ok(id.into())
+    }
+}
+
+/// Fails unless `debug` is enabled and the request has the API key of a trusted client.
+fn debug_with_trusted_client(ctx: &Context<'_>) -> async_graphql::Result<()> {
+    let config = ctx.data_unchecked::<GraphQLConfig>();
+    if !config.debug {
+        return Err(
+            anyhow::anyhow!("`debug` must be enabled to use this endpoint").into(),
+        )
+    }
+    trusted_client(ctx)?;
+    Ok(())
+}
+
+/// Returns the trusted client of the request, or an error if the request
+/// doesn't have the API key of a trusted client.
+/// Returns the `session` of the trusted client making the request. The sessions
+/// of untrusted clients are ignored, so they can't use the reservations of others.
+pub(crate) fn reservation_session(
+    ctx: &Context<'_>,
+    session: Option<String>,
+) -> Option<SessionId> {
+    let client = ctx.data_opt::<TrustedClient>()?;
+    session.map(|name| SessionId::new(client.api_key.as_str(), name))
+}
+
+fn trusted_client<'a>(ctx: &Context<'a>) -> async_graphql::Result<&'a TrustedClient> {
+    ctx.data_opt::<TrustedClient>().ok_or_else(|| {
+        anyhow::anyhow!("The request requires the API key of a trusted client").into()
+    })
+}
+
+impl ReadView {
+    pub async fn coins_to_spend(
+        &self,
+        owner: fuel_tx::Address,
+        query_per_asset: &[SpendQueryElementInput],
+        excluded: &Exclude,
+        params: &ConsensusParameters,
+        max_input: u16,
+        cache_fallback: bool,
+    ) -> Result<Vec<Vec<CoinType>>, CoinsQueryError> {
+        let mut excluded = Cow::Borrowed(excluded);
+        loop {
+            let result = self
+                .coins_to_spend_attempt(
+                    owner,
+                    query_per_asset,
+                    excluded.as_ref(),
+                    params,
+                    max_input,
+                    cache_fallback,
+                )
+                .await;
+
+            // The coins below the minimum amount of the asset are selected
+            // only if the target can't be met without them.
+            let asset_id = match &result {
+                Err(
+                    CoinsQueryError::InsufficientCoinsForTheMax { asset_id, .. }
+                    | CoinsQueryError::InsufficientCoinsAboveReserve { asset_id, .. }
+                    | CoinsQueryError::OvershootCapExceeded { asset_id, .. },
+                ) => *asset_id,
+                _ => return result,
+            };
+            if excluded.min_amount(&asset_id).is_none() {
+                return result
+            }
+            excluded.to_mut().allow_below_min(&asset_id);
+        }
+    }
+
+    /// Returns whether the selection of the `query_per_asset` uses the coins
+    /// to spend index.
+    fn selection_uses_index(&self, query_per_asset: &[SpendQueryElementInput]) -> bool {
+        let indexation_available = self
+            .indexation_flags
+            .contains(&IndexationKind::CoinsToSpend);
+        // The selection from the index targets twice the amount and doesn't
+        // know the number of the coins, so it can't respect the overshoot cap,
+        // the reserved coins, avoid the largest coin, prefer the deep coins
+        // or the number of change outputs.
+        let unsupported_by_index = query_per_asset.iter().any(|query| {
+            query.max_overshoot.is_some()
+                || query.reserve_count.is_some()
+                || query.avoid_largest_coin.unwrap_or_default()
+                || query.prefer_deep_coins.unwrap_or_default()
+                || query.preferred_change_outputs.is_some()
+        });
+        indexation_available && !unsupported_by_index
+    }
+
+    async fn coins_to_spend_attempt(
+        &self,
+        owner: fuel_tx::Address,
+        query_per_asset: &[SpendQueryElementInput],
+        excluded: &Exclude,
+        params: &ConsensusParameters,
+        max_input: u16,
+        cache_fallback: bool,
+    ) -> Result<Vec<Vec<CoinType>>, CoinsQueryError> {
+        let base_asset_id = params.base_asset_id();
+        if self.selection_uses_index(query_per_asset) {
+            let result = coins_to_spend_with_cache(
+                owner,
+                query_per_asset,
+                excluded,
+                max_input,
+                self,
+            )
+            .await;
+
+            match result {
+                // The index may lag behind the latest coins,
+                // so retry without the cache if requested.
+                Err(CoinsQueryError::InsufficientCoinsForTheMax {
+                    asset_id,
+                    collected_amount,
+                    max,
+                    ..
+                }) if cache_fallback => {
+                    tracing::warn!(
+                        %owner,
+                        %asset_id,
+                        %collected_amount,
+                        %max,
+                        "Insufficient coins in the `coins_to_spend` index, \
+                        falling back to the selection without the cache"
+                    );
+                    coins_to_spend_without_cache(
+                        owner,
+                        query_per_asset,
+                        excluded,
+                        max_input,
+                        base_asset_id,
+                        self,
+                    )
+                    .await
+                }
+                result => result,
+            }
+        } else {
+            coins_to_spend_without_cache(
+                owner,
+                query_per_asset,
+                excluded,
+                max_input,
+                base_asset_id,
+                self,
+            )
+            .await
+        }
+    }
+
+    /// Selects the coins to spend for a single asset.
+    pub async fn coins_to_spend_for_asset(
+        &self,
+        owner: fuel_tx::Address,
+        query: SpendQueryElementInput,
+        excluded: &Exclude,
+        params: &ConsensusParameters,
+        max_input: u16,
+        cache_fallback: bool,
+    ) -> Result<Vec<CoinType>, CoinsQueryError> {
+        self.coins_to_spend(
+            owner,
+            &[query],
+            excluded,
+            params,
+            max_input,
+            cache_fallback,
+        )
+        .await?
+        .into_iter()
+        .next()
+        .ok_or(CoinsQueryError::UnexpectedInternalState(
+            "The selection result is missing for the asset",
+        ))
+    }
+
+    /// Selects the biggest coins for a single asset up to the `max` of the query,
+    /// even if they don't cover the target amount.
+    pub async fn partial_coins_to_spend_for_asset(
+        &self,
+        owner: fuel_tx::Address,
+        query: &SpendQueryElementInput,
+        excluded: &Exclude,
+        params: &ConsensusParameters,
+        max_input: u16,
+    ) -> Result<Vec<CoinType>, CoinsQueryError> {
+        let asset = AssetSpendTarget::new(
+            query.asset_id.0,
+            query.amount.0,
+            query.max.map(|max| max.0).unwrap_or(max_input).min(max_input),
+        )
+        .with_reserve_count(query.reserve_count.map(|count| count.0));
+        let asset_query = AssetQuery::new(
+            &owner,
+            &asset,
+            params.base_asset_id(),
+            Some(excluded),
+            self,
+        );
+        let coins = largest_up_to_max(asset_query)
+            .await?
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        Ok(coins)
+    }
+}
+
+async fn coins_to_spend_without_cache(
+    owner: fuel_tx::Address,
+    query_per_asset: &[SpendQueryElementInput],
+    exclude: &Exclude,
+    max_input: u16,
+    base_asset_id: &fuel_tx::AssetId,
+    db: &ReadView,
+) -> Result<Vec<Vec<CoinType>>, CoinsQueryError> {
+    let query_per_asset = query_per_asset
+        .iter()
+        .map(|e| {
+            AssetSpendTarget::new(
+                e.asset_id.0,
+                e.amount.0,
+                e.max.map(|max| max.0).unwrap_or(max_input).min(max_input),
+            )
+            .with_preferred_count(e.preferred_count.map(|count| count.0))
+            .with_max_overshoot(e.max_overshoot.map(|overshoot| overshoot.0))
+            .with_reserve_count(e.reserve_count.map(|count| count.0))
+            .with_avoid_largest_coin(e.avoid_largest_coin.unwrap_or_default())
+            .with_prefer_deep_coins(e.prefer_deep_coins.unwrap_or_default())
+            .with_preferred_change_outputs(
+                e.preferred_change_outputs.map(|count| count.0),
+            )
+        })
+        .collect_vec();
+
+    let spend_query = SpendQuery::new(
         owner,
         &query_per_asset,
         Cow::Borrowed(exclude),
@@ -427,35 +3518,59 @@ async fn coins_to_spend_with_cache(
             .unwrap_or(max_input)
             .min(max_input);
 
-        let selected_coins = select_coins_to_spend(
-            db.off_chain.coins_to_spend_index(&owner, &asset_id),
-            total_amount,
-            max,
-            &asset_id,
-            excluded,
-            db.batch_size,
-        )
-        .await?;
+        let coins_to_spend_index = db.off_chain.coins_to_spend_index(&owner, &asset_id);
+        let selected_coins = match asset.scan_direction.unwrap_or_default() {
+            IndexScanDirection::LargestFirst => {
+                select_coins_to_spend(
+                    coins_to_spend_index,
+                    total_amount,
+                    max,
+                    asset.preferred_count.map(|count| count.0),
+                    &asset_id,
+                    excluded,
+                    db.batch_size,
+                )
+                .await?
+            }
+            IndexScanDirection::SmallestFirst => {
+                smallest_from_index(
+                    coins_to_spend_index,
+                    total_amount,
+                    max,
+                    &asset_id,
+                    excluded,
+                    db.batch_size,
+                )
+                .await?
+            }
+        };
 
-        let mut coins_per_asset = Vec::with_capacity(selected_coins.len());
-        for coin_or_message_id in into_coin_id(&selected_coins) {
-            let coin_type = match coin_or_message_id {
-                coins::CoinId::Utxo(utxo_id) => {
-                    db.coin(utxo_id).map(|coin| CoinType::Coin(coin.into()))?
-                }
-                coins::CoinId::Message(nonce) => {
-                    let message = db.message(&nonce)?;
-                    let message_coin: message_coin::MessageCoin = message.try_into()?;
-                    CoinType::MessageCoin(message_coin.into())
-                }
-            };
+        all_coins.push(index_keys_into_coins(db, &selected_coins)?);
+    }
+    Ok(all_coins)
+}
 
-            coins_per_asset.push(coin_type);
-        }
+/// Resolves the coins selected from the coins to spend index.
+fn index_keys_into_coins(
+    db: &ReadView,
+    selected: &[CoinsToSpendIndexKey],
+) -> Result<Vec<CoinType>, CoinsQueryError> {
+    let mut coins = Vec::with_capacity(selected.len());
+    for coin_or_message_id in into_coin_id(selected) {
+        let coin_type = match coin_or_message_id {
+            coins::CoinId::Utxo(utxo_id) => {
+                db.coin(utxo_id).map(|coin| CoinType::Coin(coin.into()))?
+            }
+            coins::CoinId::Message(nonce) => {
+                let message = db.message(&nonce)?;
+                let message_coin: message_coin::MessageCoin = message.try_into()?;
+                CoinType::MessageCoin(message_coin.into())
+            }
+        };
 
-        all_coins.push(coins_per_asset);
+        coins.push(coin_type);
     }
-    Ok(all_coins)
+    Ok(coins)
 }
 
 fn into_coin_id(selected: &[CoinsToSpendIndexKey]) -> Vec<CoinId> {
@@ -4,16 +4,54 @@ use super::{
 };
 use crate::{
     fuel_core_graphql_api::{
+        api_service::{
+            ChainInfoProvider,
+            DaDecompressor,
+        },
+        block_height_subscription,
+        database::ReadDatabase,
         query_costs,
+        worker_service::DaCompressionConfig,
+        Config as GraphQLConfig,
         IntoApiResult,
     },
-    schema::scalars::U32,
+    schema::{
+        scalars::{
+            U32,
+            U64,
+        },
+        tx::types::Transaction,
+    },
 };
+use anyhow::anyhow;
 use async_graphql::{
     Context,
     Object,
+    SimpleObject,
+    Subscription,
+};
+use fuel_core_compression::VersionedCompressedBlock;
+use fuel_core_storage::{
+    iter::IterDirection,
+    Result as StorageResult,
+};
+use fuel_core_types::{
+    blockchain::block::CompressedBlock,
+    fuel_tx::UniqueIdentifier,
+    fuel_types::BlockHeight,
+};
+use futures::{
+    Stream,
+    StreamExt,
+    TryStreamExt,
 };
 
+/// The maximum size of the compressed block accepted by the `decompressDaBlock` query.
+pub const MAX_DECOMPRESSION_INPUT_SIZE: usize = 256 * 1024;
+
+/// The maximum number of blocks in the response of the `daCompressionRatios` query.
+pub const MAX_DA_COMPRESSION_RATIO_BLOCKS: usize = 100;
+
 pub struct DaCompressedBlock {
     bytes: Vec<u8>,
 }
@@ -31,6 +69,73 @@ impl DaCompressedBlock {
     }
 }
 
+pub struct LatestDaCompressedBlock {
+    height: BlockHeight,
+    block: DaCompressedBlock,
+}
+
+#[Object]
+impl LatestDaCompressedBlock {
+    /// The height of the latest block.
+    async fn height(&self) -> U32 {
+        self.height.into()
+    }
+
+    /// The compressed form of the latest block.
+    async fn block(&self) -> &DaCompressedBlock {
+        &self.block
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct DaCompressionRatio {
+    /// The height of the block.
+    height: U32,
+    /// The size of the compressed block in bytes.
+    compressed_size: U64,
+    /// The size of the serialized block with its transactions in bytes.
+    uncompressed_size: U64,
+    /// The uncompressed size divided by the compressed size.
+    ratio: f64,
+}
+
+impl DaCompressionRatio {
+    fn new(
+        height: BlockHeight,
+        compressed_size: usize,
+        uncompressed_size: usize,
+    ) -> Self {
+        let ratio = uncompressed_size as f64 / compressed_size as f64;
+        Self {
+            height: height.into(),
+            compressed_size: (compressed_size as u64).into(),
+            uncompressed_size: (uncompressed_size as u64).into(),
+            ratio,
+        }
+    }
+}
+
+pub struct DaCompressionInfo(DaCompressionConfig);
+
+#[Object]
+impl DaCompressionInfo {
+    /// Whether the node produces and stores DA compressed blocks.
+    async fn enabled(&self) -> bool {
+        matches!(self.0, DaCompressionConfig::Enabled(_))
+    }
+
+    /// How long the entries in the temporal registry are valid, in seconds.
+    /// `null` if the DA compression is disabled.
+    async fn temporal_registry_retention_seconds(&self) -> Option<U64> {
+        match &self.0 {
+            DaCompressionConfig::Enabled(config) => {
+                Some(config.temporal_registry_retention.as_secs().into())
+            }
+            DaCompressionConfig::Disabled => None,
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct DaCompressedBlockQuery;
 
@@ -47,4 +152,168 @@ impl DaCompressedBlockQuery {
             .da_compressed_block(&height.0.into())
             .into_api_result()
     }
+
+    /// Returns the compressed form of the latest block together with its height.
+    /// Returns `null` if the latest block wasn't compressed.
+    #[graphql(complexity = "query_costs().da_compressed_block_read")]
+    async fn latest_da_compressed_block(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<LatestDaCompressedBlock>> {
+        let query = ctx.read_view()?;
+        let height = query.latest_block_height()?;
+        let block: Option<DaCompressedBlock> = query
+            .da_compressed_block(&height)
+            .into_api_result::<_, fuel_core_storage::Error>()?;
+
+        Ok(block.map(|block| LatestDaCompressedBlock { height, block }))
+    }
+
+    /// The DA compression settings of the node. If the compression is disabled,
+    /// `daCompressedBlock` queries return `null`.
+    async fn da_compression_config(&self, ctx: &Context<'_>) -> DaCompressionInfo {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        DaCompressionInfo(config.da_compression.clone())
+    }
+
+    /// Returns the compressed and uncompressed sizes and the compression ratio of
+    /// the latest `last` blocks, starting from the latest block. Blocks that weren't
+    /// compressed by the node are skipped. The uncompressed size is the size of
+    /// the serialized block with its transactions.
+    #[graphql(complexity = "{\
+        (query_costs().da_compressed_block_read + query_costs().block_transactions) \
+        * last as usize + child_complexity \
+    }")]
+    async fn da_compression_ratios(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Number of the latest blocks to include, up to 100")] last: i32,
+    ) -> async_graphql::Result<Vec<DaCompressionRatio>> {
+        let last = usize::try_from(last)
+            .map_err(|_| anyhow!("The number of blocks can't be negative"))?;
+        let limit = MAX_DA_COMPRESSION_RATIO_BLOCKS;
+        if last > limit {
+            return Err(
+                anyhow!("The number of blocks exceeds the limit of {limit}").into()
+            )
+        }
+
+        let query = ctx.read_view()?;
+        let query = query.as_ref();
+        let blocks: Vec<CompressedBlock> = query
+            .compressed_blocks(None, IterDirection::Reverse)
+            .take(last)
+            .try_collect()
+            .await?;
+
+        let mut ratios = Vec::with_capacity(blocks.len());
+        for block in blocks {
+            let height = *block.header().height();
+            let compressed: Option<Vec<u8>> = query
+                .da_compressed_block(&height)
+                .into_api_result::<_, fuel_core_storage::Error>()?;
+            let Some(compressed) = compressed else {
+                continue
+            };
+
+            let transactions = query
+                .transactions(block.transactions().to_vec())
+                .await
+                .into_iter()
+                .collect::<StorageResult<Vec<_>>>()?;
+            let block = block.uncompress(transactions);
+            let uncompressed = postcard::to_allocvec(&block)
+                .map_err(|e| anyhow!("Failed to serialize the block: {e}"))?;
+
+            ratios.push(DaCompressionRatio::new(
+                height,
+                compressed.len(),
+                uncompressed.len(),
+            ));
+        }
+
+        Ok(ratios)
+    }
+
+    /// Decompresses the compressed block against the temporal registry of the node
+    /// and returns its transactions. It allows checking whether a block compressed
+    /// elsewhere can be decompressed by this node. The registrations of the block
+    /// are discarded, so the query doesn't modify the state of the node.
+    #[graphql(complexity = "query_costs().da_compressed_block_read + child_complexity")]
+    async fn decompress_da_block(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The bytes of the `VersionedCompressedBlock`")] bytes: HexString,
+    ) -> async_graphql::Result<Vec<Transaction>> {
+        let limit = MAX_DECOMPRESSION_INPUT_SIZE;
+        if bytes.0.len() > limit {
+            return Err(anyhow!(
+                "The compressed block exceeds the limit of {limit} bytes"
+            )
+            .into())
+        }
+
+        let block: VersionedCompressedBlock = postcard::from_bytes(&bytes.0)
+            .map_err(|e| anyhow!("Failed to deserialize the compressed block: {e}"))?;
+        let transactions = ctx
+            .data_unchecked::<DaDecompressor>()
+            .decompress(block)
+            .await
+            .map_err(|e| anyhow!("Failed to decompress the block: {e:#}"))?;
+
+        let chain_id = ctx
+            .data_unchecked::<ChainInfoProvider>()
+            .current_consensus_params()
+            .chain_id();
+
+        Ok(transactions
+            .into_iter()
+            .map(|tx| Transaction::from_tx(tx.id(&chain_id), tx))
+            .collect())
+    }
+}
+
+#[derive(Default)]
+pub struct DaCompressedBlockSubscription;
+
+#[Subscription]
+impl DaCompressedBlockSubscription {
+    /// Returns a stream of the compressed forms of the new blocks together with their
+    /// heights, starting from the block after the latest one. The block is emitted
+    /// once it is compressed and stored by the node. Fails if the compression is disabled.
+    #[graphql(complexity = "query_costs().da_compressed_block_read + child_complexity")]
+    async fn new_da_compressed_blocks<'a>(
+        &self,
+        ctx: &'a Context<'a>,
+    ) -> async_graphql::Result<
+        impl Stream<Item = async_graphql::Result<LatestDaCompressedBlock>> + 'a,
+    > {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        if matches!(config.da_compression, DaCompressionConfig::Disabled) {
+            return Err(anyhow!("The DA compression is disabled on the node").into())
+        }
+
+        let database = ctx.data_unchecked::<ReadDatabase>();
+        let subscriber = ctx.data_unchecked::<block_height_subscription::Subscriber>();
+        let next_height = subscriber.current_block_height().succ();
+
+        let stream = futures::stream::try_unfold(next_height, move |height| {
+            let subscriber = subscriber.clone();
+            async move {
+                let Some(height) = height else {
+                    return Ok(None)
+                };
+                subscriber.wait_for_block_height(height).await?;
+                let bytes = database.view()?.da_compressed_block(&height)?;
+                let block = LatestDaCompressedBlock {
+                    height,
+                    block: bytes.into(),
+                };
+
+                Ok::<_, async_graphql::Error>(Some((block, height.succ())))
+            }
+        });
+
+        Ok(stream)
+    }
 }
@@ -4,7 +4,12 @@ use super::scalars::{
 };
 use crate::{
     graphql_api::{
-        api_service::GasPriceProvider,
+        api_service::{
+            ChainInfoProvider,
+            GasPriceProvider,
+            TxPool,
+        },
+        ports::GasPriceComponents,
         query_costs,
     },
     schema::ReadViewProvider,
@@ -15,12 +20,22 @@ use async_graphql::{
 };
 use fuel_core_types::{
     blockchain::block::Block,
+    fuel_crypto::Signature,
     fuel_tx::{
         field::MintGasPrice,
+        policies::Policies,
+        Chargeable,
+        ConsensusParameters,
+        Input,
         Transaction,
+        Witness,
     },
 };
 
+/// The maximum number of the pool transactions examined by the estimation
+/// of the next block gas price.
+const MAX_POOL_TXS_FOR_ESTIMATION: usize = 10_000;
+
 pub struct LatestGasPrice {
     pub gas_price: U64,
     pub block_height: U32,
@@ -66,6 +81,7 @@ impl LatestGasPriceQuery {
 
 pub struct EstimateGasPrice {
     pub gas_price: U64,
+    pub components: Option<GasPriceComponents>,
 }
 
 #[Object]
@@ -73,6 +89,18 @@ impl EstimateGasPrice {
     async fn gas_price(&self) -> U64 {
         self.gas_price
     }
+
+    /// The part of the gas price that covers the execution.
+    /// `null` if the node doesn't track the gas price components separately.
+    async fn execution_gas_price(&self) -> Option<U64> {
+        self.components.map(|components| components.execution.into())
+    }
+
+    /// The part of the gas price that covers the DA commitment.
+    /// `null` if the node doesn't track the gas price components separately.
+    async fn da_gas_price(&self) -> Option<U64> {
+        self.components.map(|components| components.da.into())
+    }
 }
 
 #[derive(Default)]
@@ -92,18 +120,162 @@ impl EstimateGasPriceQuery {
         let block_horizon = block_horizon.map(|h| h.0);
 
         let gas_price = ctx.estimate_gas_price(block_horizon)?;
+        let components = ctx.estimate_gas_price_components(block_horizon)?;
 
         Ok(EstimateGasPrice {
             gas_price: gas_price.into(),
+            components,
+        })
+    }
+
+    /// Estimates the gas price and the tip per unit of gas required for inclusion into
+    /// the next block, based on the transactions in the pool and the block gas limit.
+    ///
+    /// The estimation is best-effort: the pool may change before the next block is
+    /// produced, and the block producer may include fewer transactions for reasons
+    /// other than gas, like the size of the block.
+    #[graphql(complexity = "query_costs().storage_iterator + child_complexity")]
+    async fn estimate_next_block_gas_price(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<NextBlockGasPrice> {
+        let gas_price = ctx.estimate_gas_price(Some(1))?;
+        let block_gas_limit = ctx
+            .data_unchecked::<ChainInfoProvider>()
+            .current_consensus_params()
+            .block_gas_limit();
+        let mut txs = ctx
+            .data_unchecked::<TxPool>()
+            .tips_and_max_gas(MAX_POOL_TXS_FOR_ESTIMATION)
+            .await?;
+
+        // The block producer selects the transactions with the highest tip per unit
+        // of gas first.
+        txs.sort_by(|(tip_a, gas_a), (tip_b, gas_b)| {
+            let ratio_a = u128::from(*tip_a).saturating_mul(u128::from(*gas_b));
+            let ratio_b = u128::from(*tip_b).saturating_mul(u128::from(*gas_a));
+            ratio_b.cmp(&ratio_a)
+        });
+
+        let mut pool_gas = 0u64;
+        let mut tip_per_gas = None;
+        for (tip, max_gas) in txs {
+            pool_gas = pool_gas.saturating_add(max_gas);
+            if tip_per_gas.is_none() && pool_gas > block_gas_limit {
+                // The transaction doesn't fit into the next block, so a new transaction
+                // has to outbid it.
+                let outbid = tip.checked_div(max_gas).unwrap_or(tip).saturating_add(1);
+                tip_per_gas = Some(outbid);
+            }
+        }
+
+        Ok(NextBlockGasPrice {
+            gas_price: gas_price.into(),
+            tip_per_gas: tip_per_gas.unwrap_or_default().into(),
+            pool_gas: pool_gas.into(),
+            block_gas_limit: block_gas_limit.into(),
+        })
+    }
+
+    /// Estimates the increase of the transaction fee caused by one more coin input
+    /// signed by its own witness, based on the current consensus parameters.
+    /// It is an upper bound for the coins of the owners already signing the transaction.
+    #[graphql(complexity = "query_costs().storage_read")]
+    async fn estimate_input_fee(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The gas price, the estimation for the next block by default")]
+        gas_price: Option<U64>,
+    ) -> async_graphql::Result<InputFeeEstimate> {
+        let gas_price = match gas_price {
+            Some(gas_price) => gas_price.0,
+            None => ctx.estimate_gas_price(Some(1))?,
+        };
+        let params = ctx
+            .data_unchecked::<ChainInfoProvider>()
+            .current_consensus_params();
+
+        let gas = gas_per_input(&params);
+        let fee = u128::from(gas)
+            .saturating_mul(u128::from(gas_price))
+            .div_ceil(u128::from(params.fee_params().gas_price_factor()));
+        let fee = u64::try_from(fee).unwrap_or(u64::MAX);
+
+        Ok(InputFeeEstimate {
+            gas_price: gas_price.into(),
+            gas: gas.into(),
+            fee: fee.into(),
         })
     }
 }
 
+/// Returns the increase of the max gas of a script transaction caused by
+/// one more `Input::CoinSigned` with its own signature witness.
+fn gas_per_input(params: &ConsensusParameters) -> u64 {
+    let max_gas = |inputs: u16| {
+        let (inputs, witnesses): (Vec<Input>, Vec<Witness>) = (0..inputs)
+            .map(|witness_index| {
+                let input = Input::coin_signed(
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                    witness_index,
+                );
+                (input, vec![0; Signature::LEN].into())
+            })
+            .unzip();
+        let tx = Transaction::script(
+            0,
+            vec![],
+            vec![],
+            Policies::new(),
+            inputs,
+            vec![],
+            witnesses,
+        );
+        tx.max_gas(params.gas_costs(), params.fee_params())
+    };
+
+    max_gas(2).saturating_sub(max_gas(1))
+}
+
+/// The increase of the transaction fee caused by one more coin input.
+#[derive(async_graphql::SimpleObject)]
+pub struct InputFeeEstimate {
+    /// The gas price used for the estimation.
+    gas_price: U64,
+    /// The gas charged for the input.
+    gas: U64,
+    /// The fee charged for the input at the `gas_price`.
+    fee: U64,
+}
+
+/// The best-effort estimation of the gas price for inclusion into the next block.
+#[derive(async_graphql::SimpleObject)]
+pub struct NextBlockGasPrice {
+    /// The gas price of the next block.
+    gas_price: U64,
+    /// The tip per unit of gas required to outbid the pool transactions that don't fit
+    /// into the next block. Zero if all pool transactions fit into the next block.
+    tip_per_gas: U64,
+    /// The total max gas of the pool transactions.
+    pool_gas: U64,
+    /// The gas limit of the block.
+    block_gas_limit: U64,
+}
+
 pub trait EstimateGasPriceExt {
     fn estimate_gas_price(
         &self,
         block_horizon: Option<u32>,
     ) -> async_graphql::Result<u64>;
+
+    fn estimate_gas_price_components(
+        &self,
+        block_horizon: Option<u32>,
+    ) -> async_graphql::Result<Option<GasPriceComponents>>;
 }
 
 impl EstimateGasPriceExt for Context<'_> {
@@ -111,14 +283,7 @@ impl EstimateGasPriceExt for Context<'_> {
         &self,
         block_horizon: Option<u32>,
     ) -> async_graphql::Result<u64> {
-        let query = self.read_view()?;
-
-        let latest_block_height: u32 = query.latest_block_height()?.into();
-        let target_block = block_horizon
-            .and_then(|h| h.checked_add(latest_block_height))
-            .ok_or(async_graphql::Error::new(format!(
-                "Invalid block horizon. Overflows latest block :{latest_block_height:?}"
-            )))?;
+        let target_block = target_block_height(self, block_horizon)?;
 
         let gas_price_provider = self.data_unchecked::<GasPriceProvider>();
         let gas_price = gas_price_provider
@@ -129,4 +294,28 @@ impl EstimateGasPriceExt for Context<'_> {
 
         Ok(gas_price)
     }
+
+    fn estimate_gas_price_components(
+        &self,
+        block_horizon: Option<u32>,
+    ) -> async_graphql::Result<Option<GasPriceComponents>> {
+        let target_block = target_block_height(self, block_horizon)?;
+
+        let gas_price_provider = self.data_unchecked::<GasPriceProvider>();
+        Ok(gas_price_provider.worst_case_gas_price_components(target_block.into()))
+    }
+}
+
+fn target_block_height(
+    ctx: &Context<'_>,
+    block_horizon: Option<u32>,
+) -> async_graphql::Result<u32> {
+    let query = ctx.read_view()?;
+
+    let latest_block_height: u32 = query.latest_block_height()?.into();
+    block_horizon
+        .and_then(|h| h.checked_add(latest_block_height))
+        .ok_or(async_graphql::Error::new(format!(
+            "Invalid block horizon. Overflows latest block :{latest_block_height:?}"
+        )))
 }
@@ -6,12 +6,21 @@ use super::{
         HexString,
         Nonce,
         TransactionId,
+        U128,
         U64,
     },
     ReadViewProvider,
 };
 use crate::{
-    fuel_core_graphql_api::query_costs,
+    fuel_core_graphql_api::{
+        api_service::RelayerProvider,
+        block_height_subscription,
+        database::{
+            ReadDatabase,
+            ReadView,
+        },
+        query_costs,
+    },
     graphql_api::IntoApiResult,
     schema::scalars::{
         BlockId,
@@ -29,8 +38,39 @@ use async_graphql::{
     Object,
 };
 use fuel_core_services::stream::IntoBoxStream;
-use fuel_core_types::entities;
-use futures::StreamExt;
+use fuel_core_storage::{
+    iter::IterDirection,
+    Result as StorageResult,
+};
+use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
+    entities,
+    fuel_tx::Receipt,
+    fuel_types::BlockHeight,
+    services::txpool::TransactionExecutionStatus,
+};
+use futures::{
+    StreamExt,
+    TryStreamExt,
+};
+use std::{
+    borrow::Cow,
+    time::Duration,
+};
+
+/// The maximum time the message proof generation waits for the requested
+/// confirmations of the commit block.
+const MAX_CONFIRMATIONS_WAIT: Duration = Duration::from_secs(10);
+
+/// The maximum number of message proofs generated concurrently by a batch request.
+const MAX_CONCURRENT_MESSAGE_PROOFS: usize = 16;
+
+/// The maximum number of blocks traversed by one `bridged_value` request.
+pub const MAX_BRIDGED_VALUE_RANGE: usize = 100;
+
+/// The maximum number of DA heights with events scanned in the relayer
+/// database by one request.
+pub const MAX_RELAYER_SCANNED_DA_HEIGHTS: usize = 10_000;
 
 pub struct Message(pub(crate) entities::relayer::message::Message);
 
@@ -128,7 +168,12 @@ impl MessageQuery {
     }
 
     // 256 * QUERY_COSTS.storage_read because the depth of the Merkle tree in the worst case is 256
-    #[graphql(complexity = "256 * query_costs().storage_read + child_complexity")]
+    // 32 * QUERY_COSTS.storage_read because the search of the anchored commit block
+    // takes at most 32 steps
+    #[graphql(
+        complexity = "256 * query_costs().storage_read + 32 * query_costs().storage_read + child_complexity"
+    )]
+    #[allow(clippy::too_many_arguments)]
     async fn message_proof(
         &self,
         ctx: &Context<'_>,
@@ -136,30 +181,196 @@ impl MessageQuery {
         nonce: Nonce,
         commit_block_id: Option<BlockId>,
         commit_block_height: Option<U32>,
+        #[graphql(
+            desc = "The number of blocks to wait for on top of the commit block before generating the proof."
+        )]
+        confirmations: Option<U32>,
+        #[graphql(
+            desc = "Return only the block headers and the block proof. The `messageProof` is left empty."
+        )]
+        headers_only: Option<bool>,
+        #[graphql(desc = "\
+            The DA height the commit block must be anchored at or below. \
+            If no commit block is provided, the latest block at or below \
+            the anchor is used as the commit block.")]
+        da_height_anchor: Option<U64>,
     ) -> async_graphql::Result<MessageProof> {
-        let query = ctx.read_view()?;
-        let height = match (commit_block_id, commit_block_height) {
-            (Some(commit_block_id), None) => {
-                query.block_height(&commit_block_id.0.into())?
-            },
-            (None, Some(commit_block_height)) => {
-                commit_block_height.0.into()
+        let da_height_anchor = da_height_anchor.map(|anchor| DaBlockHeight(anchor.0));
+        let commit_block_height = match (da_height_anchor, commit_block_id) {
+            (Some(anchor), None) if commit_block_height.is_none() => {
+                let height = ctx
+                    .read_view()?
+                    .last_block_at_or_below_da_height(anchor)?
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "No committed block exists at or below the DA height {anchor}"
+                        )
+                    })?;
+                Some(height.into())
             }
-            _ => Err(anyhow::anyhow!(
-                "Either `commit_block_id` or `commit_block_height` must be provided exclusively"
-            ))?,
+            _ => commit_block_height,
         };
-
-        let proof = crate::query::message_proof(
+        let (query, height) = message_proof_view(
+            ctx,
+            commit_block_id,
+            commit_block_height,
+            confirmations,
+        )
+        .await?;
+
+        let commit_block_header =
+            crate::query::commit_block_header(query.as_ref(), height)?;
+        if let Some(anchor) = da_height_anchor {
+            let da_height = commit_block_header.da_height();
+            if da_height > anchor {
+                return Err(anyhow!(
+                    "The commit block at height {height} has the DA height {da_height} above the anchor {anchor}"
+                )
+                .into())
+            }
+        }
+        let proof = crate::query::message_proof_for_commit_block(
             query.as_ref(),
             transaction_id.into(),
             nonce.into(),
-            height,
+            commit_block_header,
+            !headers_only.unwrap_or(false),
         )?;
 
         Ok(MessageProof(proof))
     }
 
+    /// Generates the proof for the outbound message with the `nonce`.
+    /// The transaction that emitted the message is resolved automatically.
+    /// It is only available on nodes whose off-chain database was indexed
+    /// from the genesis.
+    #[graphql(
+        complexity = "256 * query_costs().storage_read + query_costs().storage_read + child_complexity"
+    )]
+    async fn message_proof_by_nonce(
+        &self,
+        ctx: &Context<'_>,
+        nonce: Nonce,
+        commit_block_id: Option<BlockId>,
+        commit_block_height: Option<U32>,
+        #[graphql(
+            desc = "The number of blocks to wait for on top of the commit block before generating the proof."
+        )]
+        confirmations: Option<U32>,
+    ) -> async_graphql::Result<MessageProof> {
+        let (query, height) = message_proof_view(
+            ctx,
+            commit_block_id,
+            commit_block_height,
+            confirmations,
+        )
+        .await?;
+
+        let nonce = nonce.0;
+        let transaction_id = query.outbound_message_tx_id(&nonce)?.ok_or_else(|| {
+            anyhow!("No outbound message with the nonce {nonce} was found")
+        })?;
+
+        let proof =
+            crate::query::message_proof(query.as_ref(), transaction_id, nonce, height)?;
+
+        Ok(MessageProof(proof))
+    }
+
+    /// Generates the proofs for the outbound messages with the `nonces` relatively to
+    /// the same commit block. The proofs are returned in the order of the `nonces`.
+    /// Like `messageProofByNonce`, it requires the off-chain database indexed
+    /// from the genesis.
+    #[graphql(
+        complexity = "(256 * query_costs().storage_read + query_costs().storage_read + child_complexity) * nonces.len()"
+    )]
+    async fn message_proofs_by_nonces(
+        &self,
+        ctx: &Context<'_>,
+        nonces: Vec<Nonce>,
+        commit_block_id: Option<BlockId>,
+        commit_block_height: Option<U32>,
+        #[graphql(
+            desc = "The number of blocks to wait for on top of the commit block before generating the proofs."
+        )]
+        confirmations: Option<U32>,
+    ) -> async_graphql::Result<Vec<MessageProof>> {
+        let (query, height) = message_proof_view(
+            ctx,
+            commit_block_id,
+            commit_block_height,
+            confirmations,
+        )
+        .await?;
+        let query = query.into_owned();
+        let commit_block_header = crate::query::commit_block_header(&query, height)?;
+
+        // Building the Merkle paths is CPU-bound and independent for each message,
+        // so the proofs are generated in parallel on the rayon thread pool.
+        // `buffered` preserves the order of the `nonces` in the result.
+        let proofs: Vec<_> = futures::stream::iter(nonces)
+            .map(|nonce| {
+                let query = query.clone();
+                let commit_block_header = commit_block_header.clone();
+                tokio_rayon::spawn_fifo(move || -> StorageResult<_> {
+                    let nonce = nonce.0;
+                    let Some(transaction_id) = query.outbound_message_tx_id(&nonce)?
+                    else {
+                        return Err(anyhow!(
+                            "No outbound message with the nonce {nonce} was found"
+                        )
+                        .into())
+                    };
+
+                    crate::query::message_proof_for_commit_block(
+                        &query,
+                        transaction_id,
+                        nonce,
+                        commit_block_header,
+                        true,
+                    )
+                })
+            })
+            .buffered(MAX_CONCURRENT_MESSAGE_PROOFS)
+            .map_ok(MessageProof)
+            .try_collect()
+            .await?;
+
+        Ok(proofs)
+    }
+
+    /// Returns the height of the first block where the message with the `nonce`
+    /// could be spent, i.e. the first block that includes the DA height of the message.
+    // 32 * QUERY_COSTS.storage_read because the binary search over block heights
+    // takes at most 32 steps
+    #[graphql(
+        complexity = "32 * query_costs().storage_read + query_costs().storage_read + child_complexity"
+    )]
+    async fn message_spendable_height(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The Nonce of the message")] nonce: Nonce,
+    ) -> async_graphql::Result<Option<MessageSpendableHeight>> {
+        let query = ctx.read_view()?;
+        let message: Option<Message> = query
+            .message(&nonce.0)
+            .into_api_result::<_, fuel_core_storage::Error>()?;
+        let Some(message) = message else {
+            return Ok(None)
+        };
+
+        let da_height = message.0.da_height();
+        let block_height = query.first_block_with_da_height(da_height)?;
+
+        Ok(Some(MessageSpendableHeight {
+            da_height,
+            block_height,
+        }))
+    }
+
+    /// Returns whether the message with the `nonce` is unspent, already spent,
+    /// or has never existed on the chain. Spent messages are remembered,
+    /// so a spent message is distinguished from an unknown one.
     #[graphql(complexity = "query_costs().storage_read + child_complexity")]
     async fn message_status(
         &self,
@@ -170,7 +381,266 @@ impl MessageQuery {
         let status = crate::query::message_status(query.as_ref(), nonce.into())?;
         Ok(status.into())
     }
+
+    /// Returns whether the inbound message with the `nonce` is included
+    /// into the chain, is known to the relayer but not yet included,
+    /// or is unknown to the node. Fails if the relayer has synced more than
+    /// 10000 DA heights with events above the chain.
+    #[graphql(
+        complexity = "2 * query_costs().storage_read + query_costs().storage_iterator"
+    )]
+    async fn message_inclusion_status(
+        &self,
+        ctx: &Context<'_>,
+        nonce: Nonce,
+    ) -> async_graphql::Result<MessageInclusionState> {
+        let query = ctx.read_view()?;
+        let nonce = nonce.into();
+        let status = crate::query::message_status(query.as_ref(), nonce)?;
+        if !matches!(
+            status.state,
+            entities::relayer::message::MessageState::NotFound
+        ) {
+            return Ok(MessageInclusionState::Included)
+        }
+
+        // Messages from the DA blocks at or below the latest block's DA height
+        // are already processed, so only the newer events can be pending.
+        let da_height = query.da_height().unwrap_or_default();
+        let relayer = ctx.data_unchecked::<RelayerProvider>();
+        if relayer
+            .contains_message(&nonce, da_height, MAX_RELAYER_SCANNED_DA_HEIGHTS)
+            .await?
+        {
+            Ok(MessageInclusionState::Pending)
+        } else {
+            Ok(MessageInclusionState::Unknown)
+        }
+    }
+
+    /// Returns the number of the inbound messages synced by the relayer, but not yet
+    /// included into the chain, with the progress of the relayer.
+    /// `null` if the relayer is disabled. Fails if the relayer has synced more than
+    /// 10000 DA heights with events above the chain.
+    #[graphql(complexity = "query_costs().storage_read + query_costs().storage_iterator")]
+    async fn relayer_backlog(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<RelayerBacklog>> {
+        let query = ctx.read_view()?;
+        // The messages up to the latest block's DA height are already processed.
+        let included_da_height = query.da_height().unwrap_or_default();
+        let relayer = ctx.data_unchecked::<RelayerProvider>();
+        let backlog = relayer
+            .message_backlog(included_da_height, MAX_RELAYER_SCANNED_DA_HEIGHTS)
+            .await?;
+
+        Ok(backlog.map(|backlog| RelayerBacklog {
+            included_da_height: included_da_height.as_u64().into(),
+            synced_da_height: backlog.synced_da_height.as_u64().into(),
+            synced_to_tip: backlog.synced_to_tip,
+            pending_messages: backlog.pending_messages.into(),
+        }))
+    }
+
+    /// Returns the value bridged between the DA layer and the chain by the blocks from
+    /// the `start` up to the `end` height inclusive. The inbound value is the value of
+    /// the messages relayed into the blocks, and the outbound value is the value of
+    /// the `MessageOut` receipts of the successful transactions of the blocks.
+    #[graphql(complexity = "{\
+        (query_costs().block_transactions + query_costs().storage_iterator) \
+        * usize::from(end).saturating_sub(usize::from(start)).saturating_add(1) \
+    }")]
+    async fn bridged_value(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Height of the first block")] start: U32,
+        #[graphql(desc = "Height of the last block, at most 99 above the start")]
+        end: U32,
+    ) -> async_graphql::Result<BridgedValue> {
+        let start: u32 = start.into();
+        let end: u32 = end.into();
+        if end < start {
+            return Err(anyhow!("The end height can't be below the start height").into())
+        }
+        let count = usize::try_from(end.saturating_sub(start))?.saturating_add(1);
+        let limit = MAX_BRIDGED_VALUE_RANGE;
+        if count > limit {
+            return Err(
+                anyhow!("The number of blocks exceeds the limit of {limit}").into()
+            )
+        }
+
+        let query = ctx.read_view()?;
+        let blocks: Vec<_> = query
+            .compressed_blocks(Some(start.into()), IterDirection::Forward)
+            .take(count)
+            .try_collect()
+            .await?;
+        let Some(last_block) = blocks.last() else {
+            return Err(anyhow!("The block at the height {start} is not found").into())
+        };
+
+        // The messages relayed into the blocks are synced above the DA height
+        // of the block before the range.
+        let first_block_da_height = blocks
+            .first()
+            .map(|block| block.header().da_height())
+            .unwrap_or_default();
+        let after_da_height = match start.checked_sub(1) {
+            Some(previous) => query.block(&previous.into())?.header().da_height(),
+            None => first_block_da_height,
+        };
+        let relayer = ctx.data_unchecked::<RelayerProvider>();
+        let inbound = relayer
+            .relayed_messages(
+                after_da_height,
+                last_block.header().da_height(),
+                MAX_RELAYER_SCANNED_DA_HEIGHTS,
+            )
+            .await?;
+
+        let mut outbound_messages = 0u64;
+        let mut outbound_amount = 0u128;
+        for block in &blocks {
+            for tx_id in block.transactions() {
+                let TransactionExecutionStatus::Success { receipts, .. } =
+                    query.tx_status(tx_id)?
+                else {
+                    continue
+                };
+                for receipt in receipts.iter() {
+                    if let Receipt::MessageOut { amount, .. } = receipt {
+                        outbound_messages = outbound_messages.saturating_add(1);
+                        outbound_amount =
+                            outbound_amount.saturating_add(u128::from(*amount));
+                    }
+                }
+            }
+        }
+
+        Ok(BridgedValue {
+            inbound_messages: inbound.map(|inbound| inbound.count.into()),
+            inbound_amount: inbound.map(|inbound| inbound.amount.into()),
+            outbound_messages: outbound_messages.into(),
+            outbound_amount: outbound_amount.into(),
+        })
+    }
 }
+
+/// The value bridged between the DA layer and the chain over a range of blocks.
+#[derive(async_graphql::SimpleObject)]
+pub struct BridgedValue {
+    /// The number of the inbound messages relayed into the blocks.
+    /// `null` if the relayer is disabled.
+    inbound_messages: Option<U64>,
+    /// The total amount of the inbound messages. `null` if the relayer is disabled.
+    inbound_amount: Option<U128>,
+    /// The number of the outbound messages sent by the transactions of the blocks.
+    outbound_messages: U64,
+    /// The total amount of the outbound messages.
+    outbound_amount: U128,
+}
+
+/// The inbound messages known to the relayer, but not yet included into the chain.
+#[derive(async_graphql::SimpleObject)]
+pub struct RelayerBacklog {
+    /// The DA height of the latest block. The messages up to this height are included.
+    included_da_height: U64,
+    /// The DA height up to which the relayer synced the events.
+    synced_da_height: U64,
+    /// Whether the relayer reached the finalized tip of the DA layer. If not,
+    /// more messages may be pending above the `synced_da_height`.
+    synced_to_tip: bool,
+    /// The number of the messages synced by the relayer above
+    /// the `included_da_height`.
+    pending_messages: U64,
+}
+
+fn commit_height(
+    query: &ReadView,
+    commit_block_id: Option<BlockId>,
+    commit_block_height: Option<U32>,
+) -> async_graphql::Result<BlockHeight> {
+    let height = match (commit_block_id, commit_block_height) {
+        (Some(commit_block_id), None) => {
+            let commit_block_id = commit_block_id.0.into();
+            let height = query.block_height(&commit_block_id)?;
+            // The id to height mapping may still point to a block orphaned by a reorg.
+            if query.block(&height)?.id() != commit_block_id {
+                return Err(anyhow::anyhow!(
+                    "The commit block {commit_block_id} is not on the canonical chain"
+                )
+                .into())
+            }
+            height
+        }
+        (None, Some(commit_block_height)) => commit_block_height.0.into(),
+        _ => Err(anyhow::anyhow!(
+            "Either `commit_block_id` or `commit_block_height` must be provided exclusively"
+        ))?,
+    };
+    Ok(height)
+}
+
+/// Resolves the commit block height and returns the view to generate the message
+/// proof from. If `confirmations` are requested, waits until the chain is
+/// `confirmations` blocks above the commit block and returns the up-to-date view.
+async fn message_proof_view<'a>(
+    ctx: &'a Context<'_>,
+    commit_block_id: Option<BlockId>,
+    commit_block_height: Option<U32>,
+    confirmations: Option<U32>,
+) -> async_graphql::Result<(Cow<'a, ReadView>, BlockHeight)> {
+    let query = ctx.read_view()?;
+    let height = commit_height(&query, commit_block_id, commit_block_height)?;
+
+    let Some(confirmations) = confirmations else {
+        return Ok((query, height))
+    };
+
+    let confirmations: u32 = confirmations.into();
+    let confirmed_height = u32::from(height).saturating_add(confirmations).into();
+    tokio::time::timeout(
+        MAX_CONFIRMATIONS_WAIT,
+        ctx.data_unchecked::<block_height_subscription::Subscriber>()
+            .wait_for_block_height(confirmed_height),
+    )
+    .await
+    .map_err(|_| {
+        anyhow!(
+            "Timed out waiting for {confirmations} confirmations of the commit block at height {height}"
+        )
+    })??;
+
+    // The commit block could have been orphaned while waiting for the confirmations,
+    // so resolve it again using the up-to-date view.
+    let query = ctx.data_unchecked::<ReadDatabase>().view()?;
+    let height = commit_height(&query, commit_block_id, commit_block_height)?;
+
+    Ok((Cow::Owned(query), height))
+}
+
+pub struct MessageSpendableHeight {
+    da_height: DaBlockHeight,
+    block_height: Option<BlockHeight>,
+}
+
+#[Object]
+impl MessageSpendableHeight {
+    /// The DA height of the message.
+    async fn da_height(&self) -> U64 {
+        self.da_height.as_u64().into()
+    }
+
+    /// The height of the first block where the message could be spent.
+    /// `null` if the relayer hasn't synced up to the DA height of the message yet,
+    /// so the message is not spendable yet.
+    async fn block_height(&self) -> Option<U32> {
+        self.block_height.map(Into::into)
+    }
+}
+
 pub struct MerkleProof(pub(crate) entities::relayer::message::MerkleProof);
 
 #[Object]
@@ -244,10 +714,14 @@ impl From<entities::relayer::message::MerkleProof> for MerkleProof {
 
 pub struct MessageStatus(pub(crate) entities::relayer::message::MessageStatus);
 
+/// The state of the message in the storage.
 #[derive(Enum, Copy, Clone, Eq, PartialEq)]
 enum MessageState {
+    /// The message exists and can be spent.
     Unspent,
+    /// The message was already spent.
     Spent,
+    /// The message has never existed on the chain.
     NotFound,
 }
 
@@ -262,6 +736,17 @@ impl MessageStatus {
     }
 }
 
+/// The inclusion state of an inbound message.
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+enum MessageInclusionState {
+    /// The message is included into the chain and is either spendable or spent.
+    Included,
+    /// The message is synced by the relayer, but not yet included into the chain.
+    Pending,
+    /// The message is unknown to the node.
+    Unknown,
+}
+
 impl From<entities::relayer::message::MessageStatus> for MessageStatus {
     fn from(status: entities::relayer::message::MessageStatus) -> Self {
         MessageStatus(status)
@@ -7,6 +7,7 @@ use crate::{
     fuel_core_graphql_api::{
         query_costs,
         Config as GraphQLConfig,
+        HistoricalStateRetention,
     },
     graphql_api::{
         api_service::TxPool,
@@ -15,9 +16,11 @@ use crate::{
             ReadDatabase,
         },
     },
+    schema::ReadViewProvider,
 };
 use async_graphql::{
     Context,
+    Enum,
     Object,
 };
 use std::time::UNIX_EPOCH;
@@ -76,18 +79,41 @@ impl NodeInfo {
         Ok(TxPoolStats(tx_pool.latest_pool_stats()))
     }
 
+    /// Returns the connected peers. The peers can be filtered by the protocols
+    /// they advertised during the identification.
     #[graphql(complexity = "query_costs().get_peers + child_complexity")]
-    async fn peers(&self, _ctx: &Context<'_>) -> async_graphql::Result<Vec<PeerInfo>> {
+    async fn peers(
+        &self,
+        _ctx: &Context<'_>,
+        #[graphql(desc = "Only return the peers that support the protocol.")]
+        supported_protocol: Option<String>,
+        #[graphql(desc = "Only return the peers that don't support the protocol.")]
+        unsupported_protocol: Option<String>,
+    ) -> async_graphql::Result<Vec<PeerInfo>> {
         #[cfg(feature = "p2p")]
         {
             let p2p: &crate::fuel_core_graphql_api::api_service::P2pService =
                 _ctx.data_unchecked();
             let peer_info = p2p.all_peer_info().await?;
-            let peers = peer_info.into_iter().map(PeerInfo).collect();
+            let peers = peer_info
+                .into_iter()
+                .filter(|peer| {
+                    supported_protocol
+                        .as_ref()
+                        .map_or(true, |protocol| peer.protocols.contains(protocol))
+                })
+                .filter(|peer| {
+                    unsupported_protocol
+                        .as_ref()
+                        .map_or(true, |protocol| !peer.protocols.contains(protocol))
+                })
+                .map(PeerInfo)
+                .collect();
             Ok(peers)
         }
         #[cfg(not(feature = "p2p"))]
         {
+            let _ = (supported_protocol, unsupported_protocol);
             Err(async_graphql::Error::new(
                 "Peering is disabled in this build, try using the `p2p` feature flag.",
             ))
@@ -119,6 +145,147 @@ impl NodeQuery {
             indexation: read_view.indexation_flags,
         })
     }
+
+    /// Returns the status of each off-chain indexer. All enabled indexers are updated
+    /// by the off-chain worker together, so they share the processed height.
+    #[graphql(complexity = "query_costs().storage_read + child_complexity")]
+    async fn indexers(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<IndexerStatus>> {
+        let db = ctx.data_unchecked::<ReadDatabase>();
+        let indexation_flags = db.view()?.indexation_flags;
+        let (tip, processed_height) = db.latest_heights();
+
+        let indexers = IndexationKind::all()
+            .map(|kind| {
+                let enabled = indexation_flags.contains(&kind);
+                let processed_height = processed_height.filter(|_| enabled);
+                let lag = tip.zip(processed_height).map(|(tip, processed)| {
+                    u32::from(tip).saturating_sub(u32::from(processed)).into()
+                });
+                IndexerStatus {
+                    kind: kind.into(),
+                    enabled,
+                    processed_height: processed_height.map(Into::into),
+                    lag,
+                }
+            })
+            .collect();
+
+        Ok(indexers)
+    }
+
+    /// Returns the range of the block heights for which the node retains the historical
+    /// state, based on the state rewind policy of the node. The queries of the state
+    /// at a height below the `earliest_height` fail.
+    #[graphql(complexity = "query_costs().storage_read")]
+    async fn historical_state(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<HistoricalState> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        let db = ctx.data_unchecked::<ReadDatabase>();
+        let genesis_height = u32::from(db.view()?.genesis_height);
+        let latest_height = db
+            .latest_heights()
+            .0
+            .map(u32::from)
+            .unwrap_or(genesis_height);
+
+        let earliest_height = match config.historical_state {
+            HistoricalStateRetention::LatestOnly => latest_height,
+            HistoricalStateRetention::Full => genesis_height,
+            HistoricalStateRetention::Range { blocks } => {
+                let blocks = u32::try_from(blocks.get()).unwrap_or(u32::MAX);
+                latest_height.saturating_sub(blocks).max(genesis_height)
+            }
+        };
+
+        Ok(HistoricalState {
+            retains_history: earliest_height < latest_height,
+            earliest_height: earliest_height.into(),
+            latest_height: latest_height.into(),
+            historical_execution: config.historical_execution,
+        })
+    }
+
+    /// Returns the height of the latest block visible to the read view of the request.
+    /// All queries of one request are resolved against the same view, so the requests
+    /// that return different heights observed different states of the chain.
+    #[graphql(complexity = "query_costs().storage_read")]
+    async fn read_view_height(&self, ctx: &Context<'_>) -> async_graphql::Result<U32> {
+        let query = ctx.read_view()?;
+        Ok(query.latest_block_height()?.into())
+    }
+}
+
+/// The block heights for which the node retains the historical state.
+#[derive(async_graphql::SimpleObject)]
+struct HistoricalState {
+    /// Whether the state of any block before the latest one is available.
+    retains_history: bool,
+    /// The earliest block height with the available state.
+    earliest_height: U32,
+    /// The height of the latest block.
+    latest_height: U32,
+    /// Whether the node allows the execution of transactions at past heights.
+    historical_execution: bool,
+}
+
+/// The status of an off-chain indexer.
+#[derive(async_graphql::SimpleObject)]
+struct IndexerStatus {
+    /// The kind of the indexer.
+    kind: IndexerKind,
+    /// Whether the indexer is enabled.
+    enabled: bool,
+    /// The height of the latest block processed by the indexer.
+    /// `null` if the indexer is disabled or didn't process any block.
+    processed_height: Option<U32>,
+    /// The number of blocks between the processed height and the tip of the chain.
+    lag: Option<U32>,
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+enum IndexerKind {
+    /// The index of the balances of the owners.
+    Balances,
+    /// The index of the coins used by the coins to spend selection.
+    CoinsToSpend,
+    /// The index of the metadata of the assets.
+    AssetMetadata,
+    /// The statistic of the number of unspent coins.
+    UtxoCount,
+    /// The index of the asset ids seen on the chain.
+    KnownAssets,
+    /// The index of the messages by their sender on the DA layer.
+    SenderMessages,
+    /// The index of the transactions by the owners of their spent coins.
+    TransactionsBySpender,
+    /// The index of the transactions by the nonces of their outbound messages.
+    OutboundMessageTransactions,
+    /// Indexation of the message coins sorted by the amount and the DA height.
+    SortedMessageCoins,
+    /// The index of the predicates seen on the chain by their address.
+    Predicates,
+}
+
+impl From<IndexationKind> for IndexerKind {
+    fn from(kind: IndexationKind) -> Self {
+        match kind {
+            IndexationKind::Balances => IndexerKind::Balances,
+            IndexationKind::CoinsToSpend => IndexerKind::CoinsToSpend,
+            IndexationKind::AssetMetadata => IndexerKind::AssetMetadata,
+            IndexationKind::UtxoCount => IndexerKind::UtxoCount,
+            IndexationKind::KnownAssets => IndexerKind::KnownAssets,
+            IndexationKind::SenderMessages => IndexerKind::SenderMessages,
+            IndexationKind::TransactionsBySpender => IndexerKind::TransactionsBySpender,
+            IndexationKind::OutboundMessageTransactions => IndexerKind::OutboundMessageTransactions,
+            IndexationKind::SortedMessageCoins => IndexerKind::SortedMessageCoins,
+            IndexationKind::Predicates => IndexerKind::Predicates,
+        }
+    }
 }
 
 struct PeerInfo(fuel_core_types::services::p2p::PeerInfo);
@@ -140,6 +307,13 @@ impl PeerInfo {
         self.0.client_version.clone()
     }
 
+    /// The protocols advertised by the peer during the identification
+    async fn protocols(&self) -> Vec<String> {
+        let mut protocols: Vec<_> = self.0.protocols.iter().cloned().collect();
+        protocols.sort();
+        protocols
+    }
+
     /// The last reported height of the peer
     async fn block_height(&self) -> Option<U32> {
         self.0
@@ -200,4 +374,39 @@ impl IndexationFlags {
     async fn asset_metadata(&self) -> bool {
         self.contains(&IndexationKind::AssetMetadata)
     }
+
+    /// Is UTXO count indexation enabled
+    async fn utxo_count(&self) -> bool {
+        self.contains(&IndexationKind::UtxoCount)
+    }
+
+    /// Is known assets indexation enabled
+    async fn known_assets(&self) -> bool {
+        self.contains(&IndexationKind::KnownAssets)
+    }
+
+    /// Is messages by sender indexation enabled
+    async fn sender_messages(&self) -> bool {
+        self.contains(&IndexationKind::SenderMessages)
+    }
+
+    /// Is transactions by spender indexation enabled
+    async fn transactions_by_spender(&self) -> bool {
+        self.contains(&IndexationKind::TransactionsBySpender)
+    }
+
+    /// Is outbound message transactions indexation enabled
+    async fn outbound_message_transactions(&self) -> bool {
+        self.contains(&IndexationKind::OutboundMessageTransactions)
+    }
+
+    /// Whether the index of the message coins sorted by the amount and the DA height is available.
+    async fn sorted_message_coins(&self) -> bool {
+        self.contains(&IndexationKind::SortedMessageCoins)
+    }
+
+    /// Is predicates indexation enabled
+    async fn predicates(&self) -> bool {
+        self.contains(&IndexationKind::Predicates)
+    }
 }
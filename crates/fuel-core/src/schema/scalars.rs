@@ -157,6 +157,37 @@ impl CursorType for SortedTxCursor {
     }
 }
 
+/// The cursor of the message coins sorted by the `key`.
+/// The `nonce` orders the message coins with the same `key`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SortedMessageCoinCursor {
+    pub key: u64,
+    pub nonce: Nonce,
+}
+
+impl SortedMessageCoinCursor {
+    pub fn new(key: u64, nonce: Nonce) -> Self {
+        Self { key, nonce }
+    }
+}
+
+impl CursorType for SortedMessageCoinCursor {
+    type Error = String;
+
+    fn decode_cursor(s: &str) -> Result<Self, Self::Error> {
+        let (key, nonce) = s.split_once('#').ok_or("Incorrect format provided")?;
+
+        Ok(Self::new(
+            u64::from_str(key).map_err(|_| "Failed to decode key")?,
+            Nonce::decode_cursor(nonce)?,
+        ))
+    }
+
+    fn encode_cursor(&self) -> String {
+        format!("{}#{}", self.key, self.nonce)
+    }
+}
+
 #[derive(Clone, Debug, derive_more::Into, derive_more::From, PartialEq, Eq)]
 pub struct HexString(pub(crate) Vec<u8>);
 
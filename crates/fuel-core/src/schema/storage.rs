@@ -1,27 +1,42 @@
 use crate::{
-    fuel_core_graphql_api::database::ReadView,
+    fuel_core_graphql_api::{
+        database::ReadView,
+        query_costs,
+        IntoApiResult,
+    },
     graphql_api::{
         api_service::ReadDatabase,
         require_historical_execution,
     },
     schema::{
+        coins::Coin,
         contract::ContractBalance,
         scalars::{
             AssetId,
             Bytes32,
             ContractId,
             HexString,
+            TransactionId,
+            UtxoId,
             U32,
         },
     },
 };
 use async_graphql::{
     Context,
+    Enum,
     Object,
     Subscription,
 };
 use fuel_core_services::stream::Stream;
-use fuel_core_types::fuel_types;
+use fuel_core_types::{
+    fuel_types::{
+        self,
+        BlockHeight,
+    },
+    blockchain::transaction::TransactionExt,
+    services::txpool::TransactionExecutionStatus,
+};
 use futures::{
     StreamExt,
     TryStreamExt,
@@ -98,6 +113,210 @@ impl StorageQuery {
 
         Ok(stream)
     }
+
+    /// Get the state of the coin at a specific block height.
+    /// Requires historical execution config to be enabled.
+    #[graphql(complexity = "3 * query_costs().storage_read + child_complexity")]
+    async fn coin_at_height(
+        &self,
+        ctx: &Context<'_>,
+        utxo_id: UtxoId,
+        block_height: U32,
+    ) -> async_graphql::Result<HistoricalCoin> {
+        require_historical_execution(ctx)?;
+
+        let utxo_id = utxo_id.0;
+        let view_block_height: BlockHeight = block_height.0.into();
+        let read_database: &ReadDatabase = ctx.data_unchecked();
+        let view_at = read_database.view_at(view_block_height)?;
+
+        if let Some(coin) = view_at.coin(&utxo_id)? {
+            return Ok(HistoricalCoin {
+                state: HistoricalCoinState::Unspent,
+                coin: Some(coin.into()),
+            });
+        }
+
+        // The coin is missing at the height of the view, so either it was already spent
+        // or the transaction that creates it wasn't executed yet.
+        let read_view: &ReadView = ctx.data_unchecked();
+        let status: Option<TransactionExecutionStatus> = read_view
+            .tx_status(utxo_id.tx_id())
+            .into_api_result::<_, fuel_core_storage::Error>()?;
+        let created_before = match status {
+            Some(
+                TransactionExecutionStatus::Success { block_height, .. }
+                | TransactionExecutionStatus::Failed { block_height, .. },
+            ) => block_height <= view_block_height,
+            // Coins from the genesis state don't have a creating transaction.
+            _ => read_database
+                .view_at(read_view.genesis_height)?
+                .coin(&utxo_id)?
+                .is_some(),
+        };
+
+        let state = if created_before {
+            HistoricalCoinState::Spent
+        } else {
+            HistoricalCoinState::NotCreated
+        };
+
+        Ok(HistoricalCoin { state, coin: None })
+    }
+
+    /// Get the transaction and the block that spent the coin.
+    /// Requires historical execution config to be enabled.
+    #[graphql(complexity = "query_costs().storage_iterator + child_complexity")]
+    async fn coin_spender(
+        &self,
+        ctx: &Context<'_>,
+        utxo_id: UtxoId,
+    ) -> async_graphql::Result<CoinSpender> {
+        require_historical_execution(ctx)?;
+
+        let utxo_id = utxo_id.0;
+        let read_view: &ReadView = ctx.data_unchecked();
+        let read_database: &ReadDatabase = ctx.data_unchecked();
+        let coin_exists_at = |height: BlockHeight| -> async_graphql::Result<bool> {
+            Ok(read_database.view_at(height)?.coin(&utxo_id)?.is_some())
+        };
+
+        let latest_height = read_view.latest_height()?;
+        if coin_exists_at(latest_height)? {
+            return Ok(CoinSpender::unspent());
+        }
+
+        let status: Option<TransactionExecutionStatus> = read_view
+            .tx_status(utxo_id.tx_id())
+            .into_api_result::<_, fuel_core_storage::Error>()?;
+        let created_at = match status {
+            Some(
+                TransactionExecutionStatus::Success { block_height, .. }
+                | TransactionExecutionStatus::Failed { block_height, .. },
+            ) => block_height,
+            // Coins from the genesis state don't have a creating transaction.
+            _ if coin_exists_at(read_view.genesis_height)? => read_view.genesis_height,
+            _ => return Ok(CoinSpender::not_found()),
+        };
+
+        // The coin exists at the `exists_at` height and is missing at the
+        // `spent_at` height, so binary search the height where it was spent.
+        let spent_at = if coin_exists_at(created_at)? {
+            let mut exists_at = *created_at;
+            let mut spent_at = *latest_height;
+            while spent_at.saturating_sub(exists_at) > 1 {
+                let middle =
+                    exists_at.saturating_add(spent_at.saturating_sub(exists_at) / 2);
+                if coin_exists_at(middle.into())? {
+                    exists_at = middle;
+                } else {
+                    spent_at = middle;
+                }
+            }
+            spent_at.into()
+        } else {
+            // The coin was spent in the same block where it was created.
+            created_at
+        };
+
+        let block = read_view.block(&spent_at)?;
+        for tx_id in block.transactions() {
+            let tx = read_view.transaction(tx_id)?;
+            let Ok(inputs) = tx.inputs() else { continue };
+            if inputs.iter().any(|input| input.utxo_id() == Some(&utxo_id)) {
+                return Ok(CoinSpender {
+                    state: CoinSpenderState::Spent,
+                    transaction_id: Some(*tx_id),
+                    block_height: Some(spent_at),
+                });
+            }
+        }
+
+        Err(anyhow::anyhow!(
+            "The block {spent_at} doesn't contain a transaction spending the coin"
+        )
+        .into())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Enum, Eq, PartialEq)]
+pub enum HistoricalCoinState {
+    /// The coin existed and was unspent at the block height.
+    Unspent,
+    /// The coin was created and spent at or before the block height.
+    Spent,
+    /// The coin wasn't created yet at the block height.
+    NotCreated,
+}
+
+pub struct HistoricalCoin {
+    state: HistoricalCoinState,
+    coin: Option<Coin>,
+}
+
+#[Object]
+impl HistoricalCoin {
+    /// The state of the coin at the requested block height.
+    async fn state(&self) -> HistoricalCoinState {
+        self.state
+    }
+
+    /// The coin, if it was unspent at the requested block height.
+    async fn coin(&self) -> Option<&Coin> {
+        self.coin.as_ref()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Enum, Eq, PartialEq)]
+pub enum CoinSpenderState {
+    /// The coin is still unspent.
+    Unspent,
+    /// The coin was spent.
+    Spent,
+    /// The coin never existed.
+    NotFound,
+}
+
+pub struct CoinSpender {
+    state: CoinSpenderState,
+    transaction_id: Option<fuel_types::Bytes32>,
+    block_height: Option<BlockHeight>,
+}
+
+impl CoinSpender {
+    fn unspent() -> Self {
+        Self {
+            state: CoinSpenderState::Unspent,
+            transaction_id: None,
+            block_height: None,
+        }
+    }
+
+    fn not_found() -> Self {
+        Self {
+            state: CoinSpenderState::NotFound,
+            transaction_id: None,
+            block_height: None,
+        }
+    }
+}
+
+#[Object]
+impl CoinSpender {
+    /// The state of the coin.
+    async fn state(&self) -> CoinSpenderState {
+        self.state
+    }
+
+    /// The id of the transaction that spent the coin.
+    async fn transaction_id(&self) -> Option<TransactionId> {
+        self.transaction_id.map(Into::into)
+    }
+
+    /// The height of the block that spent the coin.
+    async fn block_height(&self) -> Option<U32> {
+        self.block_height.map(|height| (*height).into())
+    }
 }
 
 #[derive(Default)]
@@ -5,11 +5,16 @@ use super::scalars::{
     U64,
 };
 use crate::{
-    coins_query::CoinsQueryError,
+    coins_query::{
+        apply_blacklist,
+        apply_reservations,
+        CoinsQueryError,
+    },
     fuel_core_graphql_api::{
         api_service::{
             BlockProducer,
             ChainInfoProvider,
+            CoinsBlacklistProvider,
             DynTxStatusManager,
             TxPool,
         },
@@ -18,6 +23,7 @@ use crate::{
         IntoApiResult,
     },
     graphql_api::{
+        coin_reservations::CoinReservations,
         database::ReadView,
         ports::MemoryPool,
     },
@@ -27,7 +33,10 @@ use crate::{
         TxnStatusChangeState,
     },
     schema::{
-        coins::ExcludeInput,
+        coins::{
+            reservation_session,
+            ExcludeInput,
+        },
         gas_price::EstimateGasPriceExt,
         scalars::{
             Address,
@@ -41,9 +50,13 @@ use crate::{
                 AssembleArguments,
                 AssembleTx,
             },
+            output::TransactionOutputsBreakdown,
             types::{
+                get_tx_status,
                 AssembleTransactionResult,
                 TransactionStatus,
+                TransactionStatusLookup,
+                TransactionStatusWithReceipts,
             },
         },
         ReadViewProvider,
@@ -82,7 +95,9 @@ use fuel_core_types::{
     },
     fuel_vm::checked_transaction::{
         CheckPredicateParams,
+        CheckPredicates,
         EstimatePredicates,
+        IntoChecked,
     },
     services::txpool,
 };
@@ -97,11 +112,17 @@ use std::{
     sync::Arc,
 };
 use types::{
+    BatchFeeEstimate,
+    BlockGasLimitCheck,
     DryRunTransactionExecutionStatus,
+    PredicatesVerification,
     StorageReadReplayEvent,
     Transaction,
 };
 
+/// The maximum number of transactions in the `transactionStatuses` query.
+pub const MAX_TRANSACTION_STATUSES: usize = 100;
+
 mod assemble_tx;
 pub mod input;
 pub mod output;
@@ -134,6 +155,58 @@ impl TxQuery {
         }
     }
 
+    /// Returns the status of the transaction together with the receipts of its execution,
+    /// so the client doesn't need a separate query for the receipts after the inclusion.
+    /// The receipts are empty if the transaction is not executed yet.
+    #[graphql(complexity = "query_costs().tx_status_read + child_complexity")]
+    async fn transaction_status_with_receipts(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the transaction")] id: TransactionId,
+    ) -> async_graphql::Result<Option<TransactionStatusWithReceipts>> {
+        let query = ctx.read_view()?;
+        let tx_status_manager = ctx.data_unchecked::<DynTxStatusManager>();
+
+        let status = get_tx_status(id.0, query.as_ref(), tx_status_manager).await?;
+        Ok(status.map(Into::into))
+    }
+
+    /// Returns the statuses of the transactions in one request, in the order of the `ids`.
+    /// The transactions unknown to the node are marked as not found.
+    #[graphql(
+        complexity = "(query_costs().tx_status_read + child_complexity) * ids.len()"
+    )]
+    async fn transaction_statuses(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The IDs of the transactions, at most 100")] ids: Vec<
+            TransactionId,
+        >,
+    ) -> async_graphql::Result<Vec<TransactionStatusLookup>> {
+        if ids.len() > MAX_TRANSACTION_STATUSES {
+            return Err(anyhow::anyhow!(
+                "The number of transactions ({}) exceeds the limit of {MAX_TRANSACTION_STATUSES}",
+                ids.len()
+            )
+            .into());
+        }
+
+        let query = ctx.read_view()?;
+        let tx_status_manager = ctx.data_unchecked::<DynTxStatusManager>();
+
+        let mut statuses = Vec::with_capacity(ids.len());
+        for id in ids {
+            let status = get_tx_status(id.0, query.as_ref(), tx_status_manager).await?;
+            statuses.push(TransactionStatusLookup {
+                id,
+                found: status.is_some(),
+                status,
+            });
+        }
+
+        Ok(statuses)
+    }
+
     // We assume that each block has 100 transactions.
     #[graphql(complexity = "{\
         (query_costs().tx_get + child_complexity) \
@@ -263,6 +336,114 @@ impl TxQuery {
         .await
     }
 
+    /// Returns the ids of the transactions involving the `owner`, either as the owner
+    /// of an input or as the recipient of an output, ordered by recency: `first`
+    /// returns the most recent transactions, and `after` continues to older ones.
+    #[graphql(complexity = "{\
+        query_costs().storage_iterator\
+        + first.unwrap_or_default() as usize * query_costs().storage_read \
+        + last.unwrap_or_default() as usize * query_costs().storage_read\
+    }")]
+    async fn recent_transaction_ids_by_owner(
+        &self,
+        ctx: &Context<'_>,
+        owner: Address,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<Connection<TxPointer, TransactionId, EmptyFields, EmptyFields>>
+    {
+        use futures::stream::StreamExt;
+        let query = ctx.read_view()?;
+        let owner = fuel_types::Address::from(owner);
+
+        crate::schema::query_pagination(
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<TxPointer>, direction| {
+                let start = (*start).map(Into::into);
+                // The index is ordered from the oldest transactions,
+                // so the most recent ones come first in the reverse direction.
+                let direction = match direction {
+                    IterDirection::Forward => IterDirection::Reverse,
+                    IterDirection::Reverse => IterDirection::Forward,
+                };
+                let ids =
+                    query
+                        .owned_transactions_ids(owner, start, direction)
+                        .map(|result| {
+                            result.map(|(cursor, tx_id)| (cursor.into(), tx_id.into()))
+                        });
+                Ok(ids)
+            },
+        )
+        .await
+    }
+
+    /// Returns the transactions spending the coins of the `owner`, i.e. its outgoing
+    /// transactions, included in the blocks from the `start_height`
+    /// up to the `end_height` inclusive. It is only available on nodes whose
+    /// off-chain database was indexed from the genesis.
+    #[graphql(complexity = "{\
+        query_costs().storage_iterator\
+        + first.unwrap_or_default() as usize * (query_costs().storage_read + child_complexity) \
+        + last.unwrap_or_default() as usize * (query_costs().storage_read + child_complexity)\
+    }")]
+    #[allow(clippy::too_many_arguments)]
+    async fn transactions_by_spender(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The owner of the spent coins.")] owner: Address,
+        #[graphql(desc = "The height of the first block, the genesis by default.")]
+        start_height: Option<U32>,
+        #[graphql(desc = "The height of the last block, unbounded by default.")]
+        end_height: Option<U32>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<Connection<TxPointer, Transaction, EmptyFields, EmptyFields>>
+    {
+        use futures::stream::StreamExt;
+        let query = ctx.read_view()?;
+        let params = ctx
+            .data_unchecked::<ChainInfoProvider>()
+            .current_consensus_params();
+        let owner = fuel_types::Address::from(owner);
+        let start_height: u32 = start_height.map(Into::into).unwrap_or(0);
+        let end_height: u32 = end_height.map(Into::into).unwrap_or(u32::MAX);
+        if end_height < start_height {
+            return Err(
+                anyhow::anyhow!("The end height can't be below the start height").into(),
+            )
+        }
+        let heights = fuel_types::BlockHeight::from(start_height)
+            ..=fuel_types::BlockHeight::from(end_height);
+
+        crate::schema::query_pagination(
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<TxPointer>, direction| {
+                let start = (*start).map(Into::into);
+                let txs = query
+                    .spent_transactions(owner, start, direction, heights)?
+                    .map(|result| {
+                        result.map(|(cursor, tx)| {
+                            let tx_id = tx.id(&params.chain_id());
+                            (cursor.into(), Transaction::from_tx(tx_id, tx))
+                        })
+                    });
+                Ok(txs)
+            },
+        )
+        .await
+    }
+
     /// Assembles the transaction based on the provided requirements.
     /// The return transaction contains:
     /// - Input coins to cover `required_balances`
@@ -356,7 +537,22 @@ impl TxQuery {
 
         let required_balances: Vec<RequiredBalance> =
             required_balances.into_iter().map(Into::into).collect();
-        let exclude: Exclude = exclude_input.into();
+        let session = exclude_input
+            .as_ref()
+            .and_then(|exclude| reservation_session(ctx, exclude.session.clone()));
+        let mut exclude: Exclude = exclude_input.into();
+        apply_reservations(
+            ctx.data_unchecked::<CoinReservations>(),
+            session,
+            &mut exclude,
+        );
+        apply_blacklist(
+            ctx.data_unchecked::<CoinsBlacklistProvider>().as_ref(),
+            required_balances
+                .iter()
+                .map(|balance| balance.account.owner()),
+            &mut exclude,
+        )?;
 
         let gas_price = ctx.estimate_gas_price(Some(block_horizon.into()))?;
         let config = &ctx.data_unchecked::<GraphQLConfig>().config;
@@ -377,6 +573,7 @@ impl TxQuery {
             gas_price,
             dry_run_limit: config.assemble_tx_dry_run_limit,
             estimate_predicates_limit: config.assemble_tx_estimate_predicates_limit,
+            coins_to_spend_cache_fallback: config.coins_to_spend_cache_fallback,
             block_producer,
             read_view,
             shared_memory_pool,
@@ -484,6 +681,15 @@ impl TxQuery {
             .into());
         }
 
+        let dry_run_max_txs = config.config.dry_run_max_txs;
+        if txs.len() > dry_run_max_txs {
+            return Err(anyhow::anyhow!(
+                "The number of transactions in the dry run request ({}) exceeds the limit of {dry_run_max_txs}",
+                txs.len()
+            )
+            .into());
+        }
+
         let mut transactions = txs
             .iter()
             .map(|tx| FuelTx::from_bytes(&tx.0))
@@ -515,6 +721,110 @@ impl TxQuery {
         Ok(tx_statuses)
     }
 
+    /// Estimate the fees of a batch of dependent transactions. The transactions are
+    /// dry-run one after another on top of the same fork of the current state,
+    /// so later transactions can spend outputs of the earlier ones.
+    #[graphql(
+        complexity = "query_costs().dry_run * txs.len() + child_complexity * txs.len()"
+    )]
+    async fn estimate_batch_fees(
+        &self,
+        ctx: &Context<'_>,
+        txs: Vec<HexString>,
+        // If set to false, disable input utxo validation, overriding the configuration of the node.
+        utxo_validation: Option<bool>,
+        gas_price: Option<U64>,
+    ) -> async_graphql::Result<BatchFeeEstimate> {
+        let tx_statuses = self
+            .dry_run(ctx, txs, utxo_validation, gas_price, None)
+            .await?;
+
+        Ok(tx_statuses.into_iter().map(|status| status.0).collect())
+    }
+
+    /// Breaks down the outputs of the transaction without executing it: which outputs
+    /// are change, which are transfers to other parties, and which are set only during
+    /// execution.
+    async fn transaction_outputs_breakdown(
+        &self,
+        tx: HexString,
+    ) -> async_graphql::Result<TransactionOutputsBreakdown> {
+        let tx = FuelTx::from_bytes(&tx.0)?;
+        Ok((&tx).into())
+    }
+
+    /// Verifies the predicates of the transaction against the current state without
+    /// executing it. The check is the same as the one performed by the `TxPool` when
+    /// the UTXO validation is enabled.
+    #[graphql(complexity = "query_costs().estimate_predicates + child_complexity")]
+    async fn verify_predicates(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+    ) -> async_graphql::Result<PredicatesVerification> {
+        let tx = FuelTx::from_bytes(&tx.0)?;
+        let read_view = ctx.read_view()?.into_owned();
+        let params = ctx
+            .data_unchecked::<ChainInfoProvider>()
+            .current_consensus_params();
+
+        let predicate_inputs: Vec<_> = tx
+            .inputs()?
+            .iter()
+            .zip(0u16..)
+            .filter(|(input, _)| input.predicate().is_some())
+            .map(|(input, index)| {
+                (
+                    index,
+                    input.input_owner().copied().unwrap_or_default(),
+                    input.predicate_gas_used().unwrap_or_default(),
+                )
+            })
+            .collect();
+
+        if predicate_inputs.is_empty() {
+            return Ok(PredicatesVerification::new(vec![], None));
+        }
+
+        let latest_height = read_view.latest_block_height()?;
+        let block_height = latest_height.succ().unwrap_or(latest_height);
+        let checked_tx = tx
+            .into_checked_basic(block_height, &params)
+            .map_err(|err| anyhow::anyhow!("{err:?}"))?;
+
+        let memory = ctx.data_unchecked::<SharedMemoryPool>().get_memory().await;
+        let parameters = CheckPredicateParams::from(params.as_ref());
+        let result = tokio_rayon::spawn_fifo(move || {
+            checked_tx
+                .check_predicates(&parameters, memory, &read_view)
+                .map(|_| ())
+        })
+        .await;
+
+        let error = result.err().map(|err| format!("{err:?}"));
+        Ok(PredicatesVerification::new(predicate_inputs, error))
+    }
+
+    /// Checks whether the gas of the transaction exceeds the block gas limit, making
+    /// it impossible to include the transaction into a block. The predicates of the
+    /// transaction are estimated before computing its maximum gas.
+    #[graphql(complexity = "query_costs().estimate_predicates + child_complexity")]
+    async fn block_gas_limit_check(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+    ) -> async_graphql::Result<BlockGasLimitCheck> {
+        let query = ctx.read_view()?.into_owned();
+        let tx = FuelTx::from_bytes(&tx.0)?;
+        let tx = ctx.estimate_predicates(tx, query).await?;
+        let params = ctx
+            .data_unchecked::<ChainInfoProvider>()
+            .current_consensus_params();
+
+        let estimated_gas = tx.max_gas(&params)?;
+        Ok(BlockGasLimitCheck::new(estimated_gas, params.block_gas_limit()))
+    }
+
     /// Get execution trace for an already-executed block.
     #[graphql(complexity = "query_costs().storage_read_replay + child_complexity")]
     async fn storage_read_replay(
@@ -571,12 +881,23 @@ impl TxMutation {
     /// Submits transaction to the `TxPool`.
     ///
     /// Returns submitted transaction if the transaction is included in the `TxPool` without problems.
+    ///
+    /// If the `idempotency_key` is set and a transaction of the same owner was already
+    /// submitted with the same key, the originally submitted transaction is returned
+    /// instead of inserting the new one. The owner is the owner of the first input
+    /// with an owner. The key can't be longer than 128 bytes, and the node only
+    /// remembers a limited number of the latest keys until it restarts.
     #[graphql(complexity = "query_costs().submit + child_complexity")]
     async fn submit(
         &self,
         ctx: &Context<'_>,
         tx: HexString,
         estimate_predicates: Option<bool>,
+        #[graphql(
+            desc = "The key identifying the submission between retries of the client. \
+            Up to 128 bytes."
+        )]
+        idempotency_key: Option<String>,
     ) -> async_graphql::Result<Transaction> {
         let txpool = ctx.data_unchecked::<TxPool>();
         let mut tx = FuelTx::from_bytes(&tx.0)?;
@@ -586,10 +907,17 @@ impl TxMutation {
             tx = ctx.estimate_predicates(tx, query).await?;
         }
 
-        txpool
-            .insert(tx.clone())
-            .await
-            .map_err(|e| anyhow::anyhow!(e))?;
+        match idempotency_key {
+            Some(idempotency_key) => {
+                tx = txpool.insert_idempotent(idempotency_key, tx).await?;
+            }
+            None => {
+                txpool
+                    .insert(tx.clone())
+                    .await
+                    .map_err(|e| anyhow::anyhow!(e))?;
+            }
+        }
 
         let chain_id = ctx
             .data_unchecked::<ChainInfoProvider>()
@@ -88,6 +88,7 @@ pub struct AssembleArguments<'a> {
     pub gas_price: u64,
     pub dry_run_limit: usize,
     pub estimate_predicates_limit: usize,
+    pub coins_to_spend_cache_fallback: bool,
     pub read_view: Arc<ReadView>,
     pub block_producer: &'a BlockProducer,
     pub shared_memory_pool: &'a SharedMemoryPool,
@@ -109,6 +110,13 @@ impl<'a> AssembleArguments<'a> {
             asset_id: asset_id.into(),
             amount: (amount as u128).into(),
             max: None,
+            preferred_count: None,
+            max_overshoot: None,
+            reserve_count: None,
+            avoid_largest_coin: None,
+            prefer_deep_coins: None,
+            scan_direction: None,
+            preferred_change_outputs: None,
         };
 
         let result = self
@@ -119,6 +127,7 @@ impl<'a> AssembleArguments<'a> {
                 &self.exclude,
                 &self.consensus_parameters,
                 remaining_input_slots,
+                self.coins_to_spend_cache_fallback,
             )
             .await?
             .into_iter()
@@ -3,17 +3,25 @@ use crate::schema::scalars::{
     AssetId,
     Bytes32,
     ContractId,
+    U128,
     U16,
     U64,
 };
 use async_graphql::{
+    Enum,
     Object,
     Union,
 };
 use fuel_core_types::{
     fuel_asm::Word,
     fuel_tx,
-    fuel_tx::output,
+    fuel_tx::{
+        field::{
+            Inputs,
+            Outputs,
+        },
+        output,
+    },
     fuel_types,
 };
 
@@ -174,3 +182,234 @@ impl From<&output::contract::Contract> for ContractOutput {
         }
     }
 }
+
+/// The kind of the output in the breakdown of the transaction.
+#[derive(Enum, Copy, Clone, Debug, Eq, PartialEq)]
+pub enum OutputKind {
+    /// The coin output to an address that doesn't own any of the inputs.
+    Transfer,
+    /// The coin output to an address that owns some of the inputs.
+    SelfTransfer,
+    /// The change output that receives the unspent amount of the asset.
+    Change,
+    /// The variable output, its value is set by the contracts during execution.
+    Variable,
+    /// The contract output, its state is set by the contracts during execution.
+    Contract,
+    /// The output of the newly created contract.
+    ContractCreated,
+}
+
+pub struct OutputBreakdown {
+    index: u16,
+    kind: OutputKind,
+    to: Option<fuel_types::Address>,
+    asset_id: Option<fuel_types::AssetId>,
+    amount: Option<Word>,
+}
+
+#[Object]
+impl OutputBreakdown {
+    /// The index of the output in the transaction.
+    async fn index(&self) -> U16 {
+        self.index.into()
+    }
+
+    async fn kind(&self) -> OutputKind {
+        self.kind
+    }
+
+    /// Whether the output is known only after the execution of the transaction.
+    async fn dynamic(&self) -> bool {
+        matches!(self.kind, OutputKind::Variable | OutputKind::Contract)
+    }
+
+    async fn to(&self) -> Option<Address> {
+        self.to.map(Into::into)
+    }
+
+    async fn asset_id(&self) -> Option<AssetId> {
+        self.asset_id.map(Into::into)
+    }
+
+    /// The amount of the output. It is `null` if the amount is calculated
+    /// during execution, as for change, variable and contract outputs.
+    async fn amount(&self) -> Option<U64> {
+        self.amount.map(Into::into)
+    }
+}
+
+pub struct FixedCoin {
+    index: u16,
+    to: fuel_types::Address,
+    asset_id: fuel_types::AssetId,
+    amount: Word,
+}
+
+/// The coin output with the amount fixed by the transaction itself.
+#[Object]
+impl FixedCoin {
+    /// The index of the output in the transaction.
+    async fn index(&self) -> U16 {
+        self.index.into()
+    }
+
+    async fn to(&self) -> Address {
+        self.to.into()
+    }
+
+    async fn asset_id(&self) -> AssetId {
+        self.asset_id.into()
+    }
+
+    async fn amount(&self) -> U64 {
+        self.amount.into()
+    }
+}
+
+pub struct AssetAmount {
+    asset_id: fuel_types::AssetId,
+    amount: u128,
+}
+
+impl AssetAmount {
+    pub fn new(asset_id: fuel_types::AssetId, amount: u128) -> Self {
+        Self { asset_id, amount }
+    }
+}
+
+#[Object]
+impl AssetAmount {
+    async fn asset_id(&self) -> AssetId {
+        self.asset_id.into()
+    }
+
+    async fn amount(&self) -> U128 {
+        self.amount.into()
+    }
+}
+
+pub struct TransactionOutputsBreakdown {
+    outputs: Vec<OutputBreakdown>,
+}
+
+/// The breakdown of the outputs of the transaction, made without executing it.
+#[Object]
+impl TransactionOutputsBreakdown {
+    async fn outputs(&self) -> &[OutputBreakdown] {
+        &self.outputs
+    }
+
+    /// The total amount per asset sent to the addresses that don't own any
+    /// of the inputs, in the order of the first appearance of the asset.
+    async fn transferred_amounts(&self) -> Vec<AssetAmount> {
+        let mut amounts: Vec<AssetAmount> = vec![];
+        for output in &self.outputs {
+            let (OutputKind::Transfer, Some(asset_id), Some(amount)) =
+                (output.kind, output.asset_id, output.amount)
+            else {
+                continue
+            };
+
+            match amounts.iter_mut().find(|entry| entry.asset_id == asset_id) {
+                Some(entry) => {
+                    entry.amount = entry.amount.saturating_add(u128::from(amount));
+                }
+                None => amounts.push(AssetAmount {
+                    asset_id,
+                    amount: u128::from(amount),
+                }),
+            }
+        }
+        amounts
+    }
+
+    /// The coin outputs with the amounts fixed by the transaction itself.
+    /// The UTXO ids of the coins are known only after the transaction
+    /// is included into the block.
+    async fn fixed_coins(&self) -> Vec<FixedCoin> {
+        self.outputs
+            .iter()
+            .filter(|output| {
+                matches!(output.kind, OutputKind::Transfer | OutputKind::SelfTransfer)
+            })
+            .filter_map(|output| {
+                Some(FixedCoin {
+                    index: output.index,
+                    to: output.to?,
+                    asset_id: output.asset_id?,
+                    amount: output.amount?,
+                })
+            })
+            .collect()
+    }
+}
+
+impl From<&fuel_tx::Transaction> for TransactionOutputsBreakdown {
+    fn from(tx: &fuel_tx::Transaction) -> Self {
+        let (inputs, outputs) = match tx {
+            fuel_tx::Transaction::Script(tx) => {
+                (tx.inputs().as_slice(), tx.outputs().as_slice())
+            }
+            fuel_tx::Transaction::Create(tx) => {
+                (tx.inputs().as_slice(), tx.outputs().as_slice())
+            }
+            fuel_tx::Transaction::Mint(_) => (&[][..], &[][..]),
+            fuel_tx::Transaction::Upgrade(tx) => {
+                (tx.inputs().as_slice(), tx.outputs().as_slice())
+            }
+            fuel_tx::Transaction::Upload(tx) => {
+                (tx.inputs().as_slice(), tx.outputs().as_slice())
+            }
+            fuel_tx::Transaction::Blob(tx) => {
+                (tx.inputs().as_slice(), tx.outputs().as_slice())
+            }
+        };
+        let owners: Vec<_> = inputs
+            .iter()
+            .filter_map(|input| input.input_owner())
+            .collect();
+
+        let outputs = outputs
+            .iter()
+            .zip(0u16..)
+            .map(|(output, index)| {
+                let (kind, to, asset_id, amount) = match output {
+                    fuel_tx::Output::Coin {
+                        to,
+                        amount,
+                        asset_id,
+                    } => {
+                        let kind = if owners.contains(&to) {
+                            OutputKind::SelfTransfer
+                        } else {
+                            OutputKind::Transfer
+                        };
+                        (kind, Some(*to), Some(*asset_id), Some(*amount))
+                    }
+                    fuel_tx::Output::Change { to, asset_id, .. } => {
+                        (OutputKind::Change, Some(*to), Some(*asset_id), None)
+                    }
+                    fuel_tx::Output::Variable { .. } => {
+                        (OutputKind::Variable, None, None, None)
+                    }
+                    fuel_tx::Output::Contract(_) => {
+                        (OutputKind::Contract, None, None, None)
+                    }
+                    fuel_tx::Output::ContractCreated { .. } => {
+                        (OutputKind::ContractCreated, None, None, None)
+                    }
+                };
+                OutputBreakdown {
+                    index,
+                    kind,
+                    to,
+                    asset_id,
+                    amount,
+                }
+            })
+            .collect();
+
+        Self { outputs }
+    }
+}
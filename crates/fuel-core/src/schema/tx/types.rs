@@ -15,6 +15,7 @@ use crate::{
     schema::{
         block::Block,
         scalars::{
+            Address,
             AssetId,
             BlobId,
             Bytes32,
@@ -444,6 +445,53 @@ impl TransactionStatus {
             | TransactionStatus::PreconfirmationFailure(_) => false,
         }
     }
+
+    /// Returns the receipts of the execution of the transaction,
+    /// empty if the transaction is not executed.
+    pub fn receipts(&self) -> Vec<Receipt> {
+        let receipts = match self {
+            TransactionStatus::Success(success) => Some(&success.status.receipts),
+            TransactionStatus::Failure(failure) => Some(&failure.status.receipts),
+            TransactionStatus::PreconfirmationSuccess(success) => {
+                success.status.receipts.as_ref()
+            }
+            TransactionStatus::PreconfirmationFailure(failure) => {
+                failure.status.receipts.as_ref()
+            }
+            TransactionStatus::Submitted(_) | TransactionStatus::SqueezedOut(_) => None,
+        };
+
+        receipts
+            .map(|receipts| receipts.iter().map(Into::into).collect())
+            .unwrap_or_default()
+    }
+}
+
+/// The status of the transaction together with the receipts of its execution.
+#[derive(async_graphql::SimpleObject)]
+pub struct TransactionStatusWithReceipts {
+    /// The current status of the transaction.
+    pub status: TransactionStatus,
+    /// The receipts of the execution. Empty if the transaction is not executed yet.
+    pub receipts: Vec<Receipt>,
+}
+
+/// The status of the transaction from the `transaction_statuses` query.
+#[derive(async_graphql::SimpleObject)]
+pub struct TransactionStatusLookup {
+    /// The id of the transaction from the query.
+    pub id: TransactionId,
+    /// Whether the transaction is known to the node.
+    pub found: bool,
+    /// The current status of the transaction. `null` if the transaction is not found.
+    pub status: Option<TransactionStatus>,
+}
+
+impl From<TransactionStatus> for TransactionStatusWithReceipts {
+    fn from(status: TransactionStatus) -> Self {
+        let receipts = status.receipts();
+        Self { status, receipts }
+    }
 }
 
 pub struct Policies(fuel_tx::policies::Policies);
@@ -1054,6 +1102,202 @@ impl DryRunTransactionExecutionStatus {
     }
 }
 
+pub struct TransactionFeeEstimate {
+    id: TxId,
+    success: bool,
+    total_gas: u64,
+    total_fee: u64,
+}
+
+#[Object]
+impl TransactionFeeEstimate {
+    async fn id(&self) -> TransactionId {
+        TransactionId(self.id)
+    }
+
+    /// Whether the dry run of the transaction was successful.
+    async fn success(&self) -> bool {
+        self.success
+    }
+
+    async fn total_gas(&self) -> U64 {
+        self.total_gas.into()
+    }
+
+    async fn total_fee(&self) -> U64 {
+        self.total_fee.into()
+    }
+}
+
+impl From<TransactionExecutionStatus> for TransactionFeeEstimate {
+    fn from(status: TransactionExecutionStatus) -> Self {
+        Self {
+            id: status.id,
+            success: matches!(status.result, TransactionExecutionResult::Success { .. }),
+            total_gas: *status.result.total_gas(),
+            total_fee: *status.result.total_fee(),
+        }
+    }
+}
+
+pub struct BatchFeeEstimate {
+    transactions: Vec<TransactionFeeEstimate>,
+}
+
+impl FromIterator<TransactionExecutionStatus> for BatchFeeEstimate {
+    fn from_iter<T: IntoIterator<Item = TransactionExecutionStatus>>(iter: T) -> Self {
+        Self {
+            transactions: iter.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[Object]
+impl BatchFeeEstimate {
+    /// The sum of the fees of all transactions in the batch.
+    async fn total_fee(&self) -> U64 {
+        self.transactions
+            .iter()
+            .fold(0u64, |acc, tx| acc.saturating_add(tx.total_fee))
+            .into()
+    }
+
+    /// The sum of the gas used by all transactions in the batch.
+    async fn total_gas(&self) -> U64 {
+        self.transactions
+            .iter()
+            .fold(0u64, |acc, tx| acc.saturating_add(tx.total_gas))
+            .into()
+    }
+
+    /// The fee of each transaction, in the same order as the batch.
+    async fn transactions(&self) -> &[TransactionFeeEstimate] {
+        &self.transactions
+    }
+}
+
+/// The result of the predicate verification of one input.
+#[derive(Enum, Copy, Clone, Debug, Eq, PartialEq)]
+pub enum PredicateVerificationStatus {
+    /// The predicate returned `true` within `predicate_gas_used`.
+    Passed,
+    /// The predicate failed the verification.
+    Failed,
+    /// The verification of the transaction failed, but the VM doesn't report which
+    /// of several predicates caused the failure.
+    Unknown,
+}
+
+pub struct PredicateInputVerification {
+    index: u16,
+    owner: fuel_tx::Address,
+    predicate_gas_used: u64,
+    status: PredicateVerificationStatus,
+}
+
+#[Object]
+impl PredicateInputVerification {
+    /// The index of the input in the transaction.
+    async fn index(&self) -> U16 {
+        self.index.into()
+    }
+
+    async fn owner(&self) -> Address {
+        self.owner.into()
+    }
+
+    async fn predicate_gas_used(&self) -> U64 {
+        self.predicate_gas_used.into()
+    }
+
+    async fn status(&self) -> PredicateVerificationStatus {
+        self.status
+    }
+}
+
+pub struct PredicatesVerification {
+    inputs: Vec<PredicateInputVerification>,
+    error: Option<String>,
+}
+
+impl PredicatesVerification {
+    pub fn new(
+        predicate_inputs: Vec<(u16, fuel_tx::Address, u64)>,
+        error: Option<String>,
+    ) -> Self {
+        let status = match (&error, predicate_inputs.len()) {
+            (None, _) => PredicateVerificationStatus::Passed,
+            (Some(_), 1) => PredicateVerificationStatus::Failed,
+            (Some(_), _) => PredicateVerificationStatus::Unknown,
+        };
+        let inputs = predicate_inputs
+            .into_iter()
+            .map(
+                |(index, owner, predicate_gas_used)| PredicateInputVerification {
+                    index,
+                    owner,
+                    predicate_gas_used,
+                    status,
+                },
+            )
+            .collect();
+
+        Self { inputs, error }
+    }
+}
+
+#[Object]
+impl PredicatesVerification {
+    /// Whether all predicates of the transaction passed the verification.
+    async fn success(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// The reason why the verification failed.
+    async fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// The verification result of each predicate input, in the order of the inputs.
+    async fn inputs(&self) -> &[PredicateInputVerification] {
+        &self.inputs
+    }
+}
+
+pub struct BlockGasLimitCheck {
+    estimated_gas: u64,
+    block_gas_limit: u64,
+}
+
+impl BlockGasLimitCheck {
+    pub fn new(estimated_gas: u64, block_gas_limit: u64) -> Self {
+        Self {
+            estimated_gas,
+            block_gas_limit,
+        }
+    }
+}
+
+/// The comparison of the gas of a transaction with the block gas limit.
+#[Object]
+impl BlockGasLimitCheck {
+    /// Whether the transaction can't be included into any block because its gas
+    /// exceeds the block gas limit.
+    async fn exceeds_block_gas_limit(&self) -> bool {
+        self.estimated_gas > self.block_gas_limit
+    }
+
+    /// The maximum gas that the transaction can consume.
+    async fn estimated_gas(&self) -> U64 {
+        self.estimated_gas.into()
+    }
+
+    /// The block gas limit from the current consensus parameters.
+    async fn block_gas_limit(&self) -> U64 {
+        self.block_gas_limit.into()
+    }
+}
+
 pub struct StorageReadReplayEvent {
     column: U32,
     key: HexString,
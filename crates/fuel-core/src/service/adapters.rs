@@ -51,10 +51,17 @@ use fuel_core_types::services::p2p::peer_reputation::AppScore;
 
 use crate::{
     database::{
-        database_description::relayer::Relayer,
+        database_description::{
+            off_chain::OffChain,
+            on_chain::OnChain,
+            relayer::Relayer,
+        },
         Database,
     },
-    fuel_core_graphql_api::ports::GasPriceEstimate,
+    fuel_core_graphql_api::{
+        ports::GasPriceEstimate,
+        worker_service::DaCompressionConfig,
+    },
     service::{
         sub_services::{
             BlockProducerService,
@@ -63,10 +70,13 @@ use crate::{
         vm_pool::MemoryPool,
     },
 };
+use dry_run_limiter::DryRunLimiter;
+use submission_cache::SubmissionCache;
 
 pub mod block_importer;
 pub mod chain_state_info_provider;
 pub mod consensus_module;
+pub mod dry_run_limiter;
 pub mod executor;
 pub mod fuel_gas_price_provider;
 pub mod gas_price_adapters;
@@ -79,6 +89,7 @@ pub mod producer;
 pub mod relayer;
 #[cfg(feature = "shared-sequencer")]
 pub mod shared_sequencer;
+pub mod submission_cache;
 #[cfg(feature = "p2p")]
 pub mod sync;
 pub mod tx_status_manager;
@@ -306,11 +317,15 @@ pub struct PoAAdapter {
 #[derive(Clone)]
 pub struct TxPoolAdapter {
     service: TxPoolSharedState,
+    submissions: SubmissionCache,
 }
 
 impl TxPoolAdapter {
     pub fn new(service: TxPoolSharedState) -> Self {
-        Self { service }
+        Self {
+            service,
+            submissions: SubmissionCache::default(),
+        }
     }
 }
 
@@ -428,9 +443,17 @@ pub struct MaybeRelayerAdapter {
     pub da_deploy_height: fuel_core_types::blockchain::primitives::DaBlockHeight,
 }
 
+#[derive(Clone)]
+pub struct DaDecompressionAdapter {
+    pub config: DaCompressionConfig,
+    pub on_chain: Database<OnChain>,
+    pub off_chain: Database<OffChain>,
+}
+
 #[derive(Clone)]
 pub struct BlockProducerAdapter {
     pub block_producer: Arc<BlockProducerService>,
+    pub dry_runs: DryRunLimiter,
 }
 
 #[derive(Clone)]
@@ -0,0 +1,150 @@
+use std::{
+    future::Future,
+    sync::Arc,
+    time::Duration,
+};
+
+use tokio::sync::Semaphore;
+
+/// The maximum number of the concurrent dry runs supported by the limiter.
+pub const MAX_CONCURRENT_DRY_RUNS: usize = Semaphore::MAX_PERMITS;
+
+/// Limits the number of the concurrent dry runs, so a flood of heavy dry runs
+/// doesn't exhaust the memory pool of the executor. The dry runs above the limit
+/// wait for a free slot up to the `timeout` and fail if it is not released in time.
+#[derive(Clone)]
+pub struct DryRunLimiter {
+    semaphore: Arc<Semaphore>,
+    timeout: Duration,
+}
+
+impl Default for DryRunLimiter {
+    fn default() -> Self {
+        Self {
+            semaphore: Arc::new(Semaphore::new(MAX_CONCURRENT_DRY_RUNS)),
+            timeout: Duration::MAX,
+        }
+    }
+}
+
+impl DryRunLimiter {
+    /// Creates the limiter allowing `max_concurrent_dry_runs` concurrent dry runs.
+    /// Fails if it is `0` or above the [`MAX_CONCURRENT_DRY_RUNS`].
+    pub fn new(max_concurrent_dry_runs: usize, timeout: Duration) -> anyhow::Result<Self> {
+        validate_max_concurrent_dry_runs(max_concurrent_dry_runs)?;
+        Ok(Self {
+            semaphore: Arc::new(Semaphore::new(max_concurrent_dry_runs)),
+            timeout,
+        })
+    }
+
+    /// Runs the `dry_run` once the number of the concurrent dry runs is below the limit.
+    pub async fn run<F, Fut, T>(&self, dry_run: F) -> anyhow::Result<T>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = anyhow::Result<T>>,
+    {
+        let _permit = tokio::time::timeout(self.timeout, self.semaphore.acquire())
+            .await
+            .map_err(|_| {
+                anyhow::anyhow!(
+                    "The server is busy with other dry runs, please try again later"
+                )
+            })?
+            .expect("Semaphore is not closed");
+
+        dry_run().await
+    }
+}
+
+/// Checks that the `max_concurrent_dry_runs` is in the `1..=MAX_CONCURRENT_DRY_RUNS` range.
+pub fn validate_max_concurrent_dry_runs(
+    max_concurrent_dry_runs: usize,
+) -> anyhow::Result<()> {
+    if max_concurrent_dry_runs == 0 || max_concurrent_dry_runs > MAX_CONCURRENT_DRY_RUNS
+    {
+        return Err(anyhow::anyhow!(
+            "The max number of the concurrent dry runs must be in the range \
+            from 1 to {MAX_CONCURRENT_DRY_RUNS}, got {max_concurrent_dry_runs}"
+        ))
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    #[tokio::test]
+    async fn run__fails_when_no_slot_is_released_before_timeout() {
+        // Given
+        let limiter = DryRunLimiter::new(1, Duration::from_millis(10)).unwrap();
+        let (release, released) = tokio::sync::oneshot::channel::<()>();
+        let running = {
+            let limiter = limiter.clone();
+            tokio::spawn(async move {
+                limiter
+                    .run(|| async {
+                        let _ = released.await;
+                        Ok(())
+                    })
+                    .await
+            })
+        };
+        tokio::task::yield_now().await;
+
+        // When
+        let result = limiter.run(|| async { Ok(()) }).await;
+
+        // Then
+        assert!(result.is_err());
+        release.send(()).unwrap();
+        running.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn run__waits_for_the_released_slot() {
+        // Given
+        let limiter = DryRunLimiter::new(1, Duration::from_secs(10)).unwrap();
+        let (release, released) = tokio::sync::oneshot::channel::<()>();
+        let running = {
+            let limiter = limiter.clone();
+            tokio::spawn(async move {
+                limiter
+                    .run(|| async {
+                        let _ = released.await;
+                        Ok(())
+                    })
+                    .await
+            })
+        };
+        tokio::task::yield_now().await;
+
+        // When
+        let waiting = limiter.run(|| async { Ok(42) });
+        release.send(()).unwrap();
+        let result = waiting.await;
+
+        // Then
+        assert_eq!(result.unwrap(), 42);
+        running.await.unwrap().unwrap();
+    }
+
+    #[test]
+    fn new__rejects_the_limit_out_of_range() {
+        // When
+        let zero = DryRunLimiter::new(0, Duration::from_secs(10));
+        let too_big = DryRunLimiter::new(
+            MAX_CONCURRENT_DRY_RUNS.saturating_add(1),
+            Duration::from_secs(10),
+        );
+        let max = DryRunLimiter::new(MAX_CONCURRENT_DRY_RUNS, Duration::from_secs(10));
+
+        // Then
+        assert!(zero.is_err());
+        assert!(too_big.is_err());
+        assert!(max.is_ok());
+    }
+}
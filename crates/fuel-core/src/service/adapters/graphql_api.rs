@@ -2,23 +2,36 @@ use super::{
     BlockImporterAdapter,
     BlockProducerAdapter,
     ChainStateInfoProvider,
+    DaDecompressionAdapter,
     SharedMemoryPool,
     StaticGasPrice,
     TxStatusManagerAdapter,
 };
 use crate::{
     database::OnChainIterableKeyValueView,
-    fuel_core_graphql_api::ports::{
-        worker::{
-            self,
-            BlockAt,
+    fuel_core_graphql_api::{
+        da_compression::{
+            DbTx,
+            DecompressDbTx,
         },
-        BlockProducerPort,
-        ChainStateProvider,
-        DatabaseMessageProof,
-        GasPriceEstimate,
-        P2pPort,
-        TxPoolPort,
+        ports::{
+            worker::{
+                self,
+                BlockAt,
+            },
+            BlockProducerPort,
+            ChainStateProvider,
+            DaDecompressionPort,
+            DatabaseMessageProof,
+            GasPriceComponents,
+            GasPriceEstimate,
+            P2pPort,
+            RelayedMessages,
+            RelayerBacklog,
+            RelayerPort,
+            TxPoolPort,
+        },
+        worker_service::DaCompressionConfig,
     },
     graphql_api::ports::{
         MemoryPool,
@@ -27,6 +40,7 @@ use crate::{
     service::{
         adapters::{
             import_result_provider::ImportResultProvider,
+            MaybeRelayerAdapter,
             P2PAdapter,
             TxPoolAdapter,
         },
@@ -34,14 +48,27 @@ use crate::{
     },
 };
 use async_trait::async_trait;
+use fuel_core_compression::{
+    decompress::decompress,
+    VersionedCompressedBlock,
+};
 use fuel_core_services::stream::BoxStream;
-use fuel_core_storage::Result as StorageResult;
+use fuel_core_storage::{
+    transactional::{
+        AtomicView,
+        IntoTransaction,
+    },
+    Result as StorageResult,
+};
 use fuel_core_tx_status_manager::TxStatusMessage;
 use fuel_core_txpool::TxPoolStats;
 use fuel_core_types::{
-    blockchain::header::{
-        ConsensusParametersVersion,
-        StateTransitionBytecodeVersion,
+    blockchain::{
+        header::{
+            ConsensusParametersVersion,
+            StateTransitionBytecodeVersion,
+        },
+        primitives::DaBlockHeight,
     },
     entities::relayer::message::MerkleProof,
     fuel_tx::{
@@ -50,7 +77,10 @@ use fuel_core_types::{
         Transaction,
         TxId,
     },
-    fuel_types::BlockHeight,
+    fuel_types::{
+        BlockHeight,
+        Nonce,
+    },
     services::{
         block_importer::SharedImportResult,
         executor::{
@@ -102,6 +132,34 @@ impl TxPoolPort for TxPoolAdapter {
             .map_err(|e| anyhow::anyhow!(e))
     }
 
+    async fn insert_idempotent(
+        &self,
+        idempotency_key: String,
+        tx: Transaction,
+    ) -> anyhow::Result<Transaction> {
+        self.submissions
+            .submit(idempotency_key, tx, |tx| self.insert(tx))
+            .await
+    }
+
+    async fn tips_and_max_gas(&self, max_txs: usize) -> anyhow::Result<Vec<(u64, u64)>> {
+        let tx_ids = self
+            .service
+            .get_tx_ids(max_txs)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+        let txs = self
+            .service
+            .find(tx_ids)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+        Ok(txs
+            .into_iter()
+            .flatten()
+            .map(|info| (info.tx().tip(), info.tx().max_gas()))
+            .collect())
+    }
+
     fn latest_pool_stats(&self) -> TxPoolStats {
         self.service.latest_stats()
     }
@@ -127,8 +185,16 @@ impl BlockProducerPort for BlockProducerAdapter {
         utxo_validation: Option<bool>,
         gas_price: Option<u64>,
     ) -> anyhow::Result<Vec<(Transaction, TransactionExecutionStatus)>> {
-        self.block_producer
-            .dry_run(transactions, height, time, utxo_validation, gas_price)
+        self.dry_runs
+            .run(|| {
+                self.block_producer.dry_run(
+                    transactions,
+                    height,
+                    time,
+                    utxo_validation,
+                    gas_price,
+                )
+            })
             .await
     }
 
@@ -140,6 +206,29 @@ impl BlockProducerPort for BlockProducerAdapter {
     }
 }
 
+#[async_trait::async_trait]
+impl DaDecompressionPort for DaDecompressionAdapter {
+    async fn decompress(
+        &self,
+        block: VersionedCompressedBlock,
+    ) -> anyhow::Result<Vec<Transaction>> {
+        let DaCompressionConfig::Enabled(config) = &self.config else {
+            anyhow::bail!("The DA compression is disabled on the node")
+        };
+
+        // The transaction is dropped without committing, so the registrations
+        // of the block don't modify the temporal registry of the node.
+        let mut tx = self.off_chain.clone().into_transaction();
+        let db_tx = DecompressDbTx {
+            db_tx: DbTx { db_tx: &mut tx },
+            onchain_db: self.on_chain.latest_view()?,
+        };
+        let block = decompress(*config, db_tx, block).await?;
+
+        Ok(block.transactions)
+    }
+}
+
 #[async_trait::async_trait]
 impl P2pPort for P2PAdapter {
     async fn all_peer_info(&self) -> anyhow::Result<Vec<PeerInfo>> {
@@ -160,6 +249,7 @@ impl P2pPort for P2PAdapter {
                             .map(|addr| addr.to_string())
                             .collect(),
                         client_version: None,
+                        protocols: peer_info.protocols,
                         heartbeat_data: HeartbeatData {
                             block_height: peer_info.heartbeat_data.block_height,
                             last_heartbeat: peer_info.heartbeat_data.last_heartbeat_sys,
@@ -178,6 +268,166 @@ impl P2pPort for P2PAdapter {
     }
 }
 
+#[cfg(feature = "relayer")]
+impl MaybeRelayerAdapter {
+    /// Visits the events synced by the relayer at the DA heights above
+    /// the `after_da_height` up to the `to_da_height` on the rayon thread pool,
+    /// so the scan doesn't block the async runtime. The `visit` returns `true`
+    /// to stop the scan. Fails if the scan reaches more than `limit` DA heights.
+    async fn scan_events<T, F>(
+        &self,
+        after_da_height: DaBlockHeight,
+        to_da_height: Option<DaBlockHeight>,
+        limit: usize,
+        mut state: T,
+        mut visit: F,
+    ) -> anyhow::Result<T>
+    where
+        T: Send + 'static,
+        F: FnMut(&mut T, &fuel_core_types::services::relayer::Event) -> bool
+            + Send
+            + 'static,
+    {
+        use fuel_core_relayer::storage::EventsHistory;
+        use fuel_core_storage::iter::{
+            IterDirection,
+            IteratorOverTable,
+        };
+
+        let database = self.relayer_database.clone();
+        tokio_rayon::spawn_fifo(move || {
+            let start = DaBlockHeight(after_da_height.0.saturating_add(1));
+            let events = database.iter_all_by_start::<EventsHistory>(
+                Some(&start),
+                Some(IterDirection::Forward),
+            );
+            for (index, result) in events.enumerate() {
+                let (da_height, events) = result?;
+                if to_da_height.is_some_and(|to_da_height| da_height > to_da_height) {
+                    break;
+                }
+                if index >= limit {
+                    anyhow::bail!(
+                        "The relayer events exceed the limit of {limit} DA heights"
+                    );
+                }
+                for event in events.iter() {
+                    if visit(&mut state, event) {
+                        return Ok(state);
+                    }
+                }
+            }
+            Ok(state)
+        })
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl RelayerPort for MaybeRelayerAdapter {
+    async fn contains_message(
+        &self,
+        nonce: &Nonce,
+        after_da_height: DaBlockHeight,
+        limit: usize,
+    ) -> anyhow::Result<bool> {
+        #[cfg(feature = "relayer")]
+        {
+            use fuel_core_types::services::relayer::Event;
+
+            let nonce = *nonce;
+            self.scan_events(after_da_height, None, limit, false, move |found, event| {
+                *found =
+                    matches!(event, Event::Message(message) if *message.nonce() == nonce);
+                *found
+            })
+            .await
+        }
+        #[cfg(not(feature = "relayer"))]
+        {
+            let _ = (nonce, after_da_height, limit);
+            // If the relayer is not enabled, then it doesn't know any messages.
+            Ok(false)
+        }
+    }
+
+    async fn message_backlog(
+        &self,
+        after_da_height: DaBlockHeight,
+        limit: usize,
+    ) -> anyhow::Result<Option<RelayerBacklog>> {
+        #[cfg(feature = "relayer")]
+        {
+            use fuel_core_types::services::relayer::Event;
+
+            let Some(sync) = &self.relayer_synced else {
+                return Ok(None);
+            };
+
+            let pending_messages = self
+                .scan_events(after_da_height, None, limit, 0u64, |pending, event| {
+                    if matches!(event, Event::Message(_)) {
+                        *pending = pending.saturating_add(1);
+                    }
+                    false
+                })
+                .await?;
+
+            Ok(Some(RelayerBacklog {
+                synced_da_height: sync.get_finalized_da_height(),
+                synced_to_tip: sync.is_synced(),
+                pending_messages,
+            }))
+        }
+        #[cfg(not(feature = "relayer"))]
+        {
+            let _ = (after_da_height, limit);
+            Ok(None)
+        }
+    }
+
+    async fn relayed_messages(
+        &self,
+        after_da_height: DaBlockHeight,
+        to_da_height: DaBlockHeight,
+        limit: usize,
+    ) -> anyhow::Result<Option<RelayedMessages>> {
+        #[cfg(feature = "relayer")]
+        {
+            use fuel_core_types::services::relayer::Event;
+
+            if self.relayer_synced.is_none() {
+                return Ok(None);
+            }
+
+            let relayed = self
+                .scan_events(
+                    after_da_height,
+                    Some(to_da_height),
+                    limit,
+                    RelayedMessages::default(),
+                    |relayed, event| {
+                        if let Event::Message(message) = event {
+                            relayed.count = relayed.count.saturating_add(1);
+                            relayed.amount = relayed
+                                .amount
+                                .saturating_add(u128::from(message.amount()));
+                        }
+                        false
+                    },
+                )
+                .await?;
+
+            Ok(Some(relayed))
+        }
+        #[cfg(not(feature = "relayer"))]
+        {
+            let _ = (after_da_height, to_da_height, limit);
+            Ok(None)
+        }
+    }
+}
+
 impl worker::TxStatusCompletion for TxStatusManagerAdapter {
     fn send_complete(
         &self,
@@ -194,6 +444,18 @@ impl GasPriceEstimate for StaticGasPrice {
     fn worst_case_gas_price(&self, _height: BlockHeight) -> Option<u64> {
         Some(self.gas_price)
     }
+
+    /// The static gas price doesn't depend on the DA costs,
+    /// so all of it is attributed to the execution.
+    fn worst_case_gas_price_components(
+        &self,
+        _height: BlockHeight,
+    ) -> Option<GasPriceComponents> {
+        Some(GasPriceComponents {
+            execution: self.gas_price,
+            da: 0,
+        })
+    }
 }
 
 impl ChainStateProvider for ChainStateInfoProvider {
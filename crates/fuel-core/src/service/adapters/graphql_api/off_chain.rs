@@ -16,18 +16,29 @@ use crate::{
         },
         storage::{
             contracts::ContractsInfo,
-            da_compression::DaCompressedBlocks,
+            da_compression::{
+                timestamps::TimestampKey,
+                DaCompressedBlocks,
+            },
+            messages::{
+                MessageCoinSortOrder,
+                OutboundMessageTransactions,
+            },
             relayed_transactions::RelayedTransactionStatuses,
             transactions::OwnedTransactionIndexCursor,
         },
     },
     graphql_api::{
-        indexation::coins_to_spend::NON_RETRYABLE_BYTE,
+        indexation::coins_to_spend::{
+            NON_RETRYABLE_BYTE,
+            RETRYABLE_BYTE,
+        },
         ports::CoinsToSpendIndexIter,
         storage::{
             assets::{
                 AssetDetails,
                 AssetsInfo,
+                KnownAssets,
             },
             balances::{
                 CoinBalances,
@@ -36,12 +47,24 @@ use crate::{
                 MessageBalances,
                 TotalBalanceAmount,
             },
-            coins::CoinsToSpendIndex,
+            coins::{
+                CoinsToSpendIndex,
+                CoinsToSpendIndexKey,
+            },
             old::{
                 OldFuelBlockConsensus,
                 OldFuelBlocks,
                 OldTransactions,
             },
+            predicates::{
+                PredicateDetails,
+                Predicates,
+            },
+            statistic::{
+                StatisticTable,
+                UtxoCount,
+            },
+            UTXO_COUNT,
         },
     },
 };
@@ -88,6 +111,7 @@ use fuel_core_types::{
         Nonce,
     },
     services::txpool,
+    tai64::Tai64,
 };
 use std::iter;
 
@@ -141,6 +165,29 @@ impl OffChainDatabase for OffChainIterableKeyValueView {
             .into_boxed()
     }
 
+    fn sender_message_ids(
+        &self,
+        sender: &Address,
+        start_message_id: Option<Nonce>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<Nonce>> {
+        self.sender_message_ids(sender, start_message_id, Some(direction))
+            .map(|result| result.map_err(StorageError::from))
+            .into_boxed()
+    }
+
+    fn owned_sorted_message_coin_ids(
+        &self,
+        owner: &Address,
+        order: MessageCoinSortOrder,
+        start: Option<(u64, Nonce)>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(u64, Nonce)>> {
+        self.owned_sorted_message_coin_ids(owner, order, start, Some(direction))
+            .map(|result| result.map_err(StorageError::from))
+            .into_boxed()
+    }
+
     fn owned_transactions_ids(
         &self,
         owner: Address,
@@ -156,6 +203,21 @@ impl OffChainDatabase for OffChainIterableKeyValueView {
             .into_boxed()
     }
 
+    fn spent_transactions_ids(
+        &self,
+        owner: Address,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, TxId)>> {
+        let start = start.map(|tx_pointer| OwnedTransactionIndexCursor {
+            block_height: tx_pointer.block_height(),
+            tx_idx: tx_pointer.tx_index(),
+        });
+        self.spent_transactions(owner, start, Some(direction))
+            .map(|result| result.map_err(StorageError::from))
+            .into_boxed()
+    }
+
     fn contract_salt(&self, contract_id: &ContractId) -> StorageResult<Salt> {
         let salt = *self
             .storage_as_ref::<ContractsInfo>()
@@ -216,12 +278,32 @@ impl OffChainDatabase for OffChainIterableKeyValueView {
         self.message_is_spent(nonce)
     }
 
+    fn outbound_message_tx_id(&self, nonce: &Nonce) -> StorageResult<Option<TxId>> {
+        self.storage_as_ref::<OutboundMessageTransactions>()
+            .get(nonce)
+            .map(|opt| opt.map(|cow| cow.into_owned()))
+    }
+
     fn asset_info(&self, asset_id: &AssetId) -> StorageResult<Option<AssetDetails>> {
         self.storage_as_ref::<AssetsInfo>()
             .get(asset_id)
             .map(|opt| opt.map(|cow| cow.into_owned()))
     }
 
+    fn known_assets(
+        &self,
+        start: Option<AssetId>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<AssetId>> {
+        self.iter_all_by_start_keys::<KnownAssets>(start.as_ref(), Some(direction))
+    }
+
+    fn predicate(&self, address: &Address) -> StorageResult<Option<PredicateDetails>> {
+        self.storage_as_ref::<Predicates>()
+            .get(address)
+            .map(|opt| opt.map(|cow| cow.into_owned()))
+    }
+
     fn balance(
         &self,
         owner: &Address,
@@ -323,6 +405,65 @@ impl OffChainDatabase for OffChainIterableKeyValueView {
                 .into_boxed(),
         }
     }
+
+    fn owned_dust_coins_to_spend_index_keys(
+        &self,
+        owner: &Address,
+        threshold: u64,
+    ) -> BoxedIter<'_, StorageResult<CoinsToSpendIndexKey>> {
+        let owner = *owner;
+        let prefix: Vec<_> = NON_RETRYABLE_BYTE
+            .as_ref()
+            .iter()
+            .copied()
+            .chain(owner.iter().copied())
+            .collect();
+
+        let mut keys = self.iter_all_filtered_keys::<CoinsToSpendIndex, _>(
+            Some(&prefix),
+            None,
+            Some(IterDirection::Forward),
+        );
+        let mut skipped_asset_id = None;
+        iter::from_fn(move || loop {
+            let key = match keys.next()? {
+                Ok(key) => key,
+                Err(err) => return Some(Err(err)),
+            };
+            if key.amount() < threshold {
+                return Some(Ok(key))
+            }
+
+            // The keys of the asset are sorted by the amount, so the remaining coins
+            // of the asset are above the threshold and can be skipped.
+            let asset_id = *key.asset_id();
+            if skipped_asset_id == Some(asset_id) {
+                continue
+            }
+            skipped_asset_id = Some(asset_id);
+            let last_key_of_asset = CoinsToSpendIndexKey::Coin {
+                owner,
+                asset_id,
+                amount: u64::MAX,
+                utxo_id: UtxoId::new(TxId::from([u8::MAX; 32]), u16::MAX),
+            };
+            keys = self.iter_all_filtered_keys::<CoinsToSpendIndex, _>(
+                Some(&prefix),
+                Some(&last_key_of_asset),
+                Some(IterDirection::Forward),
+            );
+        })
+        .into_boxed()
+    }
+
+    fn utxo_count(&self) -> StorageResult<UtxoCount> {
+        let utxo_count = self
+            .storage::<StatisticTable<UtxoCount>>()
+            .get(UTXO_COUNT)?
+            .unwrap_or_default()
+            .into_owned();
+        Ok(utxo_count)
+    }
 }
 
 impl OffChainIterableKeyValueView {
@@ -429,9 +570,85 @@ impl worker::OffChainDatabase for Database<OffChain> {
         self.indexation_available(IndexationKind::CoinsToSpend)
     }
 
+    fn owned_coins_to_spend_index_keys(
+        &self,
+        owner: &Address,
+        asset_id: Option<&AssetId>,
+    ) -> StorageResult<Vec<CoinsToSpendIndexKey>> {
+        let mut keys = vec![];
+        for retryable_flag in [RETRYABLE_BYTE, NON_RETRYABLE_BYTE] {
+            let prefix: Vec<_> = retryable_flag
+                .iter()
+                .copied()
+                .chain(owner.iter().copied())
+                .chain(
+                    asset_id
+                        .into_iter()
+                        .flat_map(|asset_id| asset_id.iter().copied()),
+                )
+                .collect();
+            for result in self.iter_all_filtered::<CoinsToSpendIndex, _>(
+                Some(&prefix),
+                None,
+                Some(IterDirection::Forward),
+            ) {
+                let (key, _) = result?;
+                keys.push(key);
+            }
+        }
+        Ok(keys)
+    }
+
     fn asset_metadata_indexation_enabled(&self) -> StorageResult<bool> {
         self.indexation_available(IndexationKind::AssetMetadata)
     }
+
+    fn utxo_count_indexation_enabled(&self) -> StorageResult<bool> {
+        self.indexation_available(IndexationKind::UtxoCount)
+    }
+
+    fn known_assets_indexation_enabled(&self) -> StorageResult<bool> {
+        self.indexation_available(IndexationKind::KnownAssets)
+    }
+
+    fn sender_messages_indexation_enabled(&self) -> StorageResult<bool> {
+        self.indexation_available(IndexationKind::SenderMessages)
+    }
+
+    fn transactions_by_spender_indexation_enabled(&self) -> StorageResult<bool> {
+        self.indexation_available(IndexationKind::TransactionsBySpender)
+    }
+
+    fn outbound_message_transactions_indexation_enabled(&self) -> StorageResult<bool> {
+        self.indexation_available(IndexationKind::OutboundMessageTransactions)
+    }
+
+    fn sorted_message_coins_indexation_enabled(&self) -> StorageResult<bool> {
+        self.indexation_available(IndexationKind::SortedMessageCoins)
+    }
+
+    fn predicates_indexation_enabled(&self) -> StorageResult<bool> {
+        self.indexation_available(IndexationKind::Predicates)
+    }
+
+    fn stale_da_compression_registry_keys(
+        &self,
+        oldest_timestamp: Tai64,
+    ) -> StorageResult<Vec<TimestampKey>> {
+        #[cfg(feature = "fault-proving")]
+        use crate::graphql_api::storage::da_compression::v2::timestamps::DaCompressionTemporalRegistryTimestampsV2 as Timestamps;
+        #[cfg(not(feature = "fault-proving"))]
+        use crate::graphql_api::storage::da_compression::DaCompressionTemporalRegistryTimestamps as Timestamps;
+
+        let mut keys = vec![];
+        for result in self.iter_all::<Timestamps>(Some(IterDirection::Forward)) {
+            let (key, timestamp) = result?;
+            if timestamp < oldest_timestamp {
+                keys.push(key);
+            }
+        }
+        Ok(keys)
+    }
 }
 
 impl OffChainDatabaseAt for OffChainKeyValueView {}
@@ -8,6 +8,7 @@ use crate::{
     fuel_core_graphql_api::ports::{
         DatabaseBlocks,
         DatabaseChain,
+        DatabaseCoins,
         DatabaseContracts,
         DatabaseMessages,
         OnChainDatabase,
@@ -24,6 +25,7 @@ use fuel_core_storage::{
     },
     not_found,
     tables::{
+        Coins,
         ContractsAssets,
         ContractsState,
         FuelBlocks,
@@ -42,13 +44,17 @@ use fuel_core_types::{
         consensus::Consensus,
         primitives::DaBlockHeight,
     },
-    entities::relayer::message::Message,
+    entities::{
+        coins::coin::Coin,
+        relayer::message::Message,
+    },
     fuel_tx::{
         AssetId,
         Bytes32,
         ContractId,
         Transaction,
         TxId,
+        UtxoId,
     },
     fuel_types::{
         BlockHeight,
@@ -157,6 +163,14 @@ impl DatabaseContracts for OnChainIterableKeyValueView {
     }
 }
 
+impl DatabaseCoins for OnChainIterableKeyValueView {
+    fn all_coins(&self) -> BoxedIter<'_, StorageResult<Coin>> {
+        self.iter_all::<Coins>(None)
+            .map(|result| result.map(|(utxo_id, coin)| coin.uncompress(utxo_id)))
+            .into_boxed()
+    }
+}
+
 impl DatabaseChain for OnChainIterableKeyValueView {
     fn da_height(&self) -> StorageResult<DaBlockHeight> {
         self.latest_compressed_block()?
@@ -217,4 +231,13 @@ impl OnChainDatabaseAt for OnChainKeyValueView {
             .filter_map(|res| res.transpose())
             .into_boxed()
     }
+
+    fn coin(&self, utxo_id: &UtxoId) -> StorageResult<Option<Coin>> {
+        let coin = self
+            .storage::<Coins>()
+            .get(utxo_id)?
+            .map(|coin| coin.into_owned().uncompress(*utxo_id));
+
+        Ok(coin)
+    }
 }
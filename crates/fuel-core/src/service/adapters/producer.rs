@@ -2,6 +2,7 @@ use crate::{
     database::OnChainIterableKeyValueView,
     service::{
         adapters::{
+            dry_run_limiter::DryRunLimiter,
             BlockProducerAdapter,
             ChainStateInfoProvider,
             ExecutorAdapter,
@@ -77,9 +78,10 @@ use std::{
 use tokio::time::Instant;
 
 impl BlockProducerAdapter {
-    pub fn new(block_producer: BlockProducerService) -> Self {
+    pub fn new(block_producer: BlockProducerService, dry_runs: DryRunLimiter) -> Self {
         Self {
             block_producer: Arc::new(block_producer),
+            dry_runs,
         }
     }
 }
@@ -0,0 +1,314 @@
+use std::{
+    collections::{
+        HashMap,
+        VecDeque,
+    },
+    future::Future,
+    sync::Arc,
+};
+
+use fuel_core_types::{
+    blockchain::transaction::TransactionExt,
+    fuel_tx::{
+        Address,
+        Transaction,
+    },
+};
+use parking_lot::Mutex;
+use tokio::sync::OnceCell;
+
+/// The maximum number of idempotency keys remembered by the cache.
+pub const MAX_IDEMPOTENCY_KEYS: usize = 10_000;
+
+/// The maximum length of the idempotency key in bytes.
+pub const MAX_IDEMPOTENCY_KEY_LENGTH: usize = 128;
+
+type Submission = Arc<OnceCell<Transaction>>;
+
+/// The idempotency key scoped by the owner of the transaction.
+type ScopedKey = (Address, String);
+
+#[derive(Default)]
+struct Inner {
+    /// The successful submissions.
+    submissions: HashMap<ScopedKey, Submission>,
+    /// The keys of the successful submissions in the insertion order,
+    /// used to evict the oldest key.
+    order: VecDeque<ScopedKey>,
+    /// The submissions in progress. They are moved to the `submissions`
+    /// only once they succeed, so failed submissions never evict other keys.
+    pending: HashMap<ScopedKey, Submission>,
+}
+
+/// Transactions submitted with an idempotency key. A repeated submission
+/// with the same key returns the originally submitted transaction instead
+/// of inserting the new one. Failed submissions are not remembered,
+/// so they can be retried with the same key.
+///
+/// The keys are scoped by the owner of the first input of the transaction.
+/// The owner is only verified by the submission itself, so a key takes a slot
+/// in the cache only after its transaction was submitted successfully.
+/// The deduplication window is limited: only the last `capacity` keys are
+/// remembered, and all keys are forgotten when the node restarts.
+#[derive(Clone)]
+pub struct SubmissionCache {
+    inner: Arc<Mutex<Inner>>,
+    capacity: usize,
+}
+
+impl Default for SubmissionCache {
+    fn default() -> Self {
+        Self::new(MAX_IDEMPOTENCY_KEYS)
+    }
+}
+
+impl SubmissionCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: Default::default(),
+            capacity,
+        }
+    }
+
+    /// Submits the `tx` with the `submit` function unless a transaction of the same
+    /// owner was already submitted with the `key`. Concurrent submissions with
+    /// the same key wait for the first one to finish.
+    pub async fn submit<F, Fut>(
+        &self,
+        key: String,
+        tx: Transaction,
+        submit: F,
+    ) -> anyhow::Result<Transaction>
+    where
+        F: FnOnce(Transaction) -> Fut,
+        Fut: Future<Output = anyhow::Result<()>>,
+    {
+        if key.len() > MAX_IDEMPOTENCY_KEY_LENGTH {
+            return Err(anyhow::anyhow!(
+                "The idempotency key is longer than {MAX_IDEMPOTENCY_KEY_LENGTH} bytes"
+            ))
+        }
+        let owner = tx
+            .inputs()
+            .ok()
+            .and_then(|inputs| inputs.iter().find_map(|input| input.input_owner()))
+            .copied()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "The transaction submitted with an idempotency key must have \
+                    an input with an owner"
+                )
+            })?;
+
+        let key = (owner, key);
+        let submission = self.submission(&key);
+        let result = submission
+            .get_or_try_init(|| async move {
+                submit(tx.clone()).await?;
+                Ok::<_, anyhow::Error>(tx)
+            })
+            .await
+            .cloned();
+        self.finish(key, &submission);
+        result
+    }
+
+    /// Returns the successful or the pending submission with the `key`,
+    /// or starts a new pending submission.
+    fn submission(&self, key: &ScopedKey) -> Submission {
+        let mut inner = self.inner.lock();
+        if let Some(submission) = inner.submissions.get(key) {
+            return submission.clone();
+        }
+
+        inner.pending.entry(key.clone()).or_default().clone()
+    }
+
+    /// Remembers the pending `submission` if it succeeded, evicting the oldest
+    /// keys above the capacity, or forgets it if it failed.
+    fn finish(&self, key: ScopedKey, submission: &Submission) {
+        let mut inner = self.inner.lock();
+        let is_pending = inner
+            .pending
+            .get(&key)
+            .is_some_and(|pending| Arc::ptr_eq(pending, submission));
+        if !is_pending {
+            return
+        }
+        if !submission.initialized() {
+            // Other waiters of the same submission retry it themselves.
+            if Arc::strong_count(submission) <= 2 {
+                inner.pending.remove(&key);
+            }
+            return
+        }
+        inner.pending.remove(&key);
+
+        while inner.order.len() >= self.capacity {
+            let Some(oldest) = inner.order.pop_front() else {
+                break
+            };
+            inner.submissions.remove(&oldest);
+        }
+
+        inner.submissions.insert(key.clone(), submission.clone());
+        inner.order.push_back(key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use fuel_core_types::fuel_tx::{
+        Input,
+        TransactionBuilder,
+    };
+    use std::sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    };
+
+    fn owned_tx(owner: Address, gas_limit: u64) -> Transaction {
+        TransactionBuilder::script(vec![], vec![])
+            .script_gas_limit(gas_limit)
+            .add_input(Input::coin_signed(
+                Default::default(),
+                owner,
+                100,
+                Default::default(),
+                Default::default(),
+                0,
+            ))
+            .finalize_as_transaction()
+    }
+
+    fn tx(gas_limit: u64) -> Transaction {
+        owned_tx(Address::new([1; 32]), gas_limit)
+    }
+
+    #[tokio::test]
+    async fn submit__repeated_key_returns_original_transaction() {
+        // Given
+        let cache = SubmissionCache::default();
+        let submitted = AtomicUsize::new(0);
+        let submitted = &submitted;
+        let submit = |_| async move {
+            submitted.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        };
+        cache.submit("key".to_string(), tx(1), submit).await.unwrap();
+
+        // When
+        let result = cache.submit("key".to_string(), tx(2), submit).await;
+
+        // Then
+        assert_eq!(result.unwrap(), tx(1));
+        assert_eq!(submitted.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn submit__failed_submission_can_be_retried() {
+        // Given
+        let cache = SubmissionCache::default();
+        let result = cache
+            .submit("key".to_string(), tx(1), |_| async {
+                Err(anyhow::anyhow!("Network error"))
+            })
+            .await;
+        assert!(result.is_err());
+
+        // When
+        let result = cache
+            .submit("key".to_string(), tx(2), |_| async { Ok(()) })
+            .await;
+
+        // Then
+        assert_eq!(result.unwrap(), tx(2));
+    }
+
+    #[tokio::test]
+    async fn submit__oldest_key_is_evicted_when_cache_is_full() {
+        // Given
+        let cache = SubmissionCache::new(1);
+        let submit = |_| async { Ok(()) };
+        cache.submit("a".to_string(), tx(1), submit).await.unwrap();
+        cache.submit("b".to_string(), tx(2), submit).await.unwrap();
+
+        // When
+        let result = cache.submit("a".to_string(), tx(3), submit).await;
+
+        // Then
+        assert_eq!(result.unwrap(), tx(3));
+    }
+
+    #[tokio::test]
+    async fn submit__failed_submissions_do_not_evict_other_keys() {
+        // Given
+        let cache = SubmissionCache::new(1);
+        cache
+            .submit("a".to_string(), tx(1), |_| async { Ok(()) })
+            .await
+            .unwrap();
+        let failed = cache
+            .submit("b".to_string(), tx(2), |_| async {
+                Err(anyhow::anyhow!("Invalid transaction"))
+            })
+            .await;
+        assert!(failed.is_err());
+
+        // When
+        let result = cache
+            .submit("a".to_string(), tx(3), |_| async { Ok(()) })
+            .await;
+
+        // Then
+        assert_eq!(result.unwrap(), tx(1));
+    }
+
+    #[tokio::test]
+    async fn submit__keys_are_scoped_by_the_owner() {
+        // Given
+        let cache = SubmissionCache::default();
+        let submit = |_| async { Ok(()) };
+        let other_tx = owned_tx(Address::new([2; 32]), 2);
+        cache.submit("key".to_string(), tx(1), submit).await.unwrap();
+
+        // When
+        let result = cache
+            .submit("key".to_string(), other_tx.clone(), submit)
+            .await;
+
+        // Then
+        assert_eq!(result.unwrap(), other_tx);
+    }
+
+    #[tokio::test]
+    async fn submit__fails_for_too_long_key() {
+        // Given
+        let cache = SubmissionCache::default();
+        let key = "k".repeat(MAX_IDEMPOTENCY_KEY_LENGTH + 1);
+
+        // When
+        let result = cache.submit(key, tx(1), |_| async { Ok(()) }).await;
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn submit__fails_for_transaction_without_owner() {
+        // Given
+        let cache = SubmissionCache::default();
+        let tx = TransactionBuilder::script(vec![], vec![]).finalize_as_transaction();
+
+        // When
+        let result = cache
+            .submit("key".to_string(), tx, |_| async { Ok(()) })
+            .await;
+
+        // Then
+        assert!(result.is_err());
+    }
+}
@@ -159,6 +159,8 @@ impl Config {
                 database_batch_size: 100,
                 max_queries_depth: 16,
                 max_queries_complexity: 80000,
+                trusted_api_keys: Default::default(),
+                trusted_max_queries_complexity: 80000,
                 max_queries_recursive_depth: 16,
                 max_queries_resolver_recursive_depth: 1,
                 max_queries_directives: 10,
@@ -168,6 +170,15 @@ impl Config {
                 api_request_timeout: Duration::from_secs(60),
                 assemble_tx_dry_run_limit: 3,
                 assemble_tx_estimate_predicates_limit: 5,
+                coins_to_spend_cache_fallback: false,
+                coins_to_spend_blacklist: Default::default(),
+                coins_to_spend_tags: Default::default(),
+                coins_to_spend_max_total_coins: 1000,
+                coins_to_spend_min_amounts: Default::default(),
+                coin_set_root: false,
+                dry_run_max_txs: 1000,
+                dry_run_max_concurrency: 64,
+                dry_run_queue_timeout: Duration::from_secs(10),
                 costs: Default::default(),
                 required_fuel_block_height_tolerance: 10,
                 required_fuel_block_height_timeout: Duration::from_secs(30),
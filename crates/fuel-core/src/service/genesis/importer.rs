@@ -10,6 +10,7 @@ use crate::{
     },
     fuel_core_graphql_api::storage::messages::SpentMessages,
     graphql_api::storage::{
+        assets::KnownAssets,
         blocks::FuelBlockIdsToHeights,
         coins::OwnedCoins,
         contracts::ContractsInfo,
@@ -143,6 +144,7 @@ impl SnapshotImporter {
         self.spawn_worker_off_chain::<SpentMessages, SpentMessages>()?;
         self.spawn_worker_off_chain::<Messages, OwnedMessageIds>()?;
         self.spawn_worker_off_chain::<Coins, OwnedCoins>()?;
+        self.spawn_worker_off_chain::<ContractsAssets, KnownAssets>()?;
         self.spawn_worker_off_chain::<FuelBlocks, OldFuelBlocks>()?;
         self.spawn_worker_off_chain::<Transactions, OldTransactions>()?;
         self.spawn_worker_off_chain::<SealedBlockConsensus, OldFuelBlockConsensus>()?;
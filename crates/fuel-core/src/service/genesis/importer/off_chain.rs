@@ -5,7 +5,9 @@ use crate::{
     },
     fuel_core_graphql_api::storage::messages::SpentMessages,
     graphql_api::{
+        ports::worker::OffChainDatabaseTransaction,
         storage::{
+            assets::KnownAssets,
             blocks::FuelBlockIdsToHeights,
             coins::OwnedCoins,
             contracts::ContractsInfo,
@@ -27,6 +29,7 @@ use fuel_core_chain_config::TableEntry;
 use fuel_core_storage::{
     tables::{
         Coins,
+        ContractsAssets,
         FuelBlocks,
         Messages,
         SealedBlockConsensus,
@@ -78,6 +81,93 @@ fn coins_to_spend_indexation_enabled() -> bool {
     })
 }
 
+fn utxo_count_indexation_enabled() -> bool {
+    use std::sync::OnceLock;
+
+    static UTXO_COUNT_INDEXATION_ENABLED: OnceLock<bool> = OnceLock::new();
+
+    *UTXO_COUNT_INDEXATION_ENABLED.get_or_init(|| {
+        // During re-genesis process the metadata never exist.
+        let metadata = None;
+        let indexation_availability =
+            crate::database::database_description::indexation_availability::<OffChain>(
+                metadata,
+            );
+        indexation_availability
+            .contains(&crate::database::database_description::IndexationKind::UtxoCount)
+    })
+}
+
+fn known_assets_indexation_enabled() -> bool {
+    use std::sync::OnceLock;
+
+    static KNOWN_ASSETS_INDEXATION_ENABLED: OnceLock<bool> = OnceLock::new();
+
+    *KNOWN_ASSETS_INDEXATION_ENABLED.get_or_init(|| {
+        // During re-genesis process the metadata never exist.
+        let metadata = None;
+        let indexation_availability =
+            crate::database::database_description::indexation_availability::<OffChain>(
+                metadata,
+            );
+        indexation_availability
+            .contains(&crate::database::database_description::IndexationKind::KnownAssets)
+    })
+}
+
+fn sender_messages_indexation_enabled() -> bool {
+    use std::sync::OnceLock;
+
+    static SENDER_MESSAGES_INDEXATION_ENABLED: OnceLock<bool> = OnceLock::new();
+
+    *SENDER_MESSAGES_INDEXATION_ENABLED.get_or_init(|| {
+        // During re-genesis process the metadata never exist.
+        let metadata = None;
+        let indexation_availability =
+            crate::database::database_description::indexation_availability::<OffChain>(
+                metadata,
+            );
+        indexation_availability.contains(
+            &crate::database::database_description::IndexationKind::SenderMessages,
+        )
+    })
+}
+
+fn sorted_message_coins_indexation_enabled() -> bool {
+    use std::sync::OnceLock;
+
+    static SORTED_MESSAGE_COINS_INDEXATION_ENABLED: OnceLock<bool> = OnceLock::new();
+
+    *SORTED_MESSAGE_COINS_INDEXATION_ENABLED.get_or_init(|| {
+        // During re-genesis process the metadata never exist.
+        let metadata = None;
+        let indexation_availability =
+            crate::database::database_description::indexation_availability::<OffChain>(
+                metadata,
+            );
+        indexation_availability.contains(
+            &crate::database::database_description::IndexationKind::SortedMessageCoins,
+        )
+    })
+}
+
+fn predicates_indexation_enabled() -> bool {
+    use std::sync::OnceLock;
+
+    static PREDICATES_INDEXATION_ENABLED: OnceLock<bool> = OnceLock::new();
+
+    *PREDICATES_INDEXATION_ENABLED.get_or_init(|| {
+        // During re-genesis process the metadata never exist.
+        let metadata = None;
+        let indexation_availability =
+            crate::database::database_description::indexation_availability::<OffChain>(
+                metadata,
+            );
+        indexation_availability
+            .contains(&crate::database::database_description::IndexationKind::Predicates)
+    })
+}
+
 impl ImportTable for Handler<TransactionStatuses, TransactionStatuses> {
     type TableInSnapshot = TransactionStatuses;
     type TableBeingWritten = TransactionStatuses;
@@ -150,6 +240,9 @@ impl ImportTable for Handler<OwnedMessageIds, Messages> {
             tx,
             balances_indexation_enabled(),
             coins_to_spend_indexation_enabled(),
+            known_assets_indexation_enabled(),
+            sender_messages_indexation_enabled(),
+            sorted_message_coins_indexation_enabled(),
             &self.base_asset_id,
         )?;
         Ok(())
@@ -166,6 +259,7 @@ impl ImportTable for Handler<OwnedCoins, Coins> {
         group: Vec<TableEntry<Self::TableInSnapshot>>,
         tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
+        let imported_coins = group.len() as u64;
         let events = group.into_iter().map(|TableEntry { value, key }| {
             Cow::Owned(Event::CoinCreated(value.uncompress(key)))
         });
@@ -174,8 +268,35 @@ impl ImportTable for Handler<OwnedCoins, Coins> {
             tx,
             balances_indexation_enabled(),
             coins_to_spend_indexation_enabled(),
+            known_assets_indexation_enabled(),
+            sender_messages_indexation_enabled(),
+            sorted_message_coins_indexation_enabled(),
             &self.base_asset_id,
         )?;
+        if utxo_count_indexation_enabled() {
+            tx.update_utxo_count(imported_coins, 0, None)?;
+        }
+        Ok(())
+    }
+}
+
+impl ImportTable for Handler<KnownAssets, ContractsAssets> {
+    type TableInSnapshot = ContractsAssets;
+    type TableBeingWritten = KnownAssets;
+    type DbDesc = OffChain;
+
+    fn process(
+        &mut self,
+        group: Vec<TableEntry<Self::TableInSnapshot>>,
+        tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
+    ) -> anyhow::Result<()> {
+        if !known_assets_indexation_enabled() {
+            return Ok(());
+        }
+        for entry in group {
+            tx.storage::<KnownAssets>()
+                .insert(entry.key.asset_id(), &())?;
+        }
         Ok(())
     }
 }
@@ -209,7 +330,11 @@ impl ImportTable for Handler<ContractsInfo, Transactions> {
         tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
         let transactions = group.iter().map(|TableEntry { value, .. }| value);
-        worker_service::process_transactions(transactions, tx)?;
+        worker_service::process_transactions(
+            transactions,
+            tx,
+            predicates_indexation_enabled(),
+        )?;
         Ok(())
     }
 }
@@ -225,7 +350,11 @@ impl ImportTable for Handler<ContractsInfo, OldTransactions> {
         tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
         let transactions = group.iter().map(|TableEntry { value, .. }| value);
-        worker_service::process_transactions(transactions, tx)?;
+        worker_service::process_transactions(
+            transactions,
+            tx,
+            predicates_indexation_enabled(),
+        )?;
         Ok(())
     }
 }
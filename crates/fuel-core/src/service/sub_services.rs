@@ -30,6 +30,9 @@ use fuel_core_types::signer::SignMode;
 #[cfg(feature = "relayer")]
 use crate::relayer::Config as RelayerConfig;
 
+#[cfg(feature = "rocksdb")]
+use crate::state::historical_rocksdb::StateRewindPolicy;
+
 #[cfg(feature = "p2p")]
 use crate::service::adapters::consensus_module::poa::pre_confirmation_signature::{
     key_generator::{
@@ -47,13 +50,23 @@ use crate::{
     fuel_core_graphql_api::{
         self,
         Config as GraphQLConfig,
+        HistoricalStateRetention,
+    },
+    graphql_api::{
+        api_service::{
+            CoinTagsProvider,
+            CoinsBlacklistProvider,
+            DaDecompressor,
+            RelayerProvider,
+        },
+        worker_service,
     },
-    graphql_api::worker_service,
     schema::build_schema,
     service::{
         adapters::{
             chain_state_info_provider,
             consensus_module::poa::InDirectoryPredefinedBlocks,
+            dry_run_limiter::DryRunLimiter,
             fuel_gas_price_provider::FuelGasPriceProvider,
             graphql_api::GraphQLBlockImporter,
             import_result_provider::ImportResultProvider,
@@ -61,6 +74,7 @@ use crate::{
             BlockImporterAdapter,
             BlockProducerAdapter,
             ChainStateInfoProvider,
+            DaDecompressionAdapter,
             ExecutorAdapter,
             MaybeRelayerAdapter,
             PoAAdapter,
@@ -305,7 +319,11 @@ pub fn init_sub_services(
         gas_price_provider: producer_gas_price_provider.clone(),
         chain_state_info_provider: chain_state_info_provider.clone(),
     };
-    let producer_adapter = BlockProducerAdapter::new(block_producer);
+    let dry_runs = DryRunLimiter::new(
+        config.graphql_config.dry_run_max_concurrency,
+        config.graphql_config.dry_run_queue_timeout,
+    )?;
+    let producer_adapter = BlockProducerAdapter::new(block_producer, dry_runs);
 
     let poa_config: fuel_core_poa::Config = config.into();
     let mut production_enabled = !matches!(poa_config.trigger, Trigger::Never);
@@ -368,13 +386,29 @@ pub fn init_sub_services(
         super::adapters::ConsensusAdapter::new(
             verifier.clone(),
             config.relayer_consensus_config.clone(),
-            relayer_adapter,
+            relayer_adapter.clone(),
         ),
         config.sync,
     )?;
 
+    let (coins_to_spend_index_rebuilder, coins_to_spend_index_rebuilds) =
+        worker_service::CoinsToSpendIndexRebuilder::new();
+
     let schema = crate::schema::dap::init(build_schema(), config.debug)
-        .data(database.on_chain().clone());
+        .data(database.on_chain().clone())
+        .data(coins_to_spend_index_rebuilder)
+        .data(Box::new(relayer_adapter) as RelayerProvider)
+        .data(Box::new(DaDecompressionAdapter {
+            config: config.da_compression.clone(),
+            on_chain: database.on_chain().clone(),
+            off_chain: database.off_chain().clone(),
+        }) as DaDecompressor)
+        .data(
+            Box::new(config.graphql_config.coins_to_spend_blacklist.clone())
+                as CoinsBlacklistProvider,
+        )
+        .data(Arc::new(config.graphql_config.coins_to_spend_tags.clone())
+            as CoinTagsProvider);
 
     let graphql_block_importer =
         GraphQLBlockImporter::new(importer_adapter.clone(), import_result_provider);
@@ -386,12 +420,27 @@ pub fn init_sub_services(
         da_compression_config: config.da_compression.clone(),
         continue_on_error: config.continue_on_error,
         consensus_parameters: &chain_config.consensus_parameters,
+        coins_to_spend_index_rebuilds,
     };
     let graphql_worker =
         fuel_core_graphql_api::worker_service::new_service(graphql_worker_context)?;
 
     let graphql_block_height_subscription_handle = graphql_worker.shared.clone();
 
+    #[cfg(feature = "rocksdb")]
+    let historical_state = match config.combined_db_config.database_type {
+        DbType::RocksDb => match config.combined_db_config.state_rewind_policy {
+            StateRewindPolicy::NoRewind => HistoricalStateRetention::LatestOnly,
+            StateRewindPolicy::RewindFullRange => HistoricalStateRetention::Full,
+            StateRewindPolicy::RewindRange { size } => {
+                HistoricalStateRetention::Range { blocks: size }
+            }
+        },
+        DbType::InMemory => HistoricalStateRetention::LatestOnly,
+    };
+    #[cfg(not(feature = "rocksdb"))]
+    let historical_state = HistoricalStateRetention::LatestOnly;
+
     let graphql_config = GraphQLConfig {
         config: config.graphql_config.clone(),
         utxo_validation: config.utxo_validation,
@@ -402,6 +451,8 @@ pub fn init_sub_services(
         max_size: config.txpool.pool_limits.max_bytes_size,
         max_txpool_dependency_chain_length: config.txpool.max_txs_chain_count,
         chain_name,
+        da_compression: config.da_compression.clone(),
+        historical_state,
     };
 
     let graph_ql = fuel_core_graphql_api::api_service::new_service(
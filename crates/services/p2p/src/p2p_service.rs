@@ -819,10 +819,17 @@ impl FuelP2PService {
                     addresses.truncate(MAX_IDENTIFY_ADDRESSES);
                 }
 
+                let protocols = info
+                    .protocols
+                    .iter()
+                    .map(|protocol| protocol.to_string())
+                    .collect();
+
                 self.peer_manager.handle_peer_identified(
                     &peer_id,
                     addresses.clone(),
                     agent_version,
+                    protocols,
                 );
 
                 self.swarm
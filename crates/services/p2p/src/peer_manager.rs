@@ -41,6 +41,8 @@ const MIN_GOSSIPSUB_SCORE_BEFORE_BAN: AppScore = GRAYLIST_THRESHOLD;
 pub struct PeerInfo {
     pub peer_addresses: HashSet<Multiaddr>,
     pub client_version: Option<String>,
+    /// The protocols advertised by the peer during the identification.
+    pub protocols: HashSet<String>,
     pub heartbeat_data: HeartbeatData,
     pub score: AppScore,
 }
@@ -50,6 +52,7 @@ impl PeerInfo {
         Self {
             peer_addresses: HashSet::new(),
             client_version: None,
+            protocols: HashSet::new(),
             heartbeat_data: HeartbeatData::new(heartbeat_avg_window),
             score: DEFAULT_APP_SCORE,
         }
@@ -133,9 +136,11 @@ impl PeerManager {
         peer_id: &PeerId,
         addresses: Vec<Multiaddr>,
         agent_version: String,
+        protocols: Vec<String>,
     ) {
         let peers = self.get_assigned_peer_table_mut(peer_id);
         insert_client_version(peers, peer_id, agent_version);
+        insert_protocols(peers, peer_id, protocols);
         insert_peer_addresses(peers, peer_id, addresses);
     }
 
@@ -360,6 +365,19 @@ fn insert_client_version(
     }
 }
 
+fn insert_protocols(
+    peers: &mut HashMap<PeerId, PeerInfo>,
+    peer_id: &PeerId,
+    protocols: Vec<String>,
+) {
+    if let Some(peer) = peers.get_mut(peer_id) {
+        // The identification replaces the previously advertised protocols.
+        peer.protocols = protocols.into_iter().collect();
+    } else {
+        log_missing_peer(peer_id);
+    }
+}
+
 fn log_missing_peer(peer_id: &PeerId) {
     debug!(target: "fuel-p2p", "Peer with PeerId: {:?} is not among the connected peers", peer_id)
 }
@@ -265,6 +265,7 @@ async fn peer_heartbeat_reputation_checks__slow_heartbeat_sends_reports() {
     let peer_info = PeerInfo {
         peer_addresses: Default::default(),
         client_version: None,
+        protocols: Default::default(),
         heartbeat_data,
         score: 100.0,
     };
@@ -359,6 +360,7 @@ async fn peer_heartbeat_reputation_checks__old_heartbeat_sends_reports() {
     let peer_info = PeerInfo {
         peer_addresses: Default::default(),
         client_version: None,
+        protocols: Default::default(),
         heartbeat_data,
         score: 100.0,
     };
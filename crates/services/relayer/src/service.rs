@@ -329,6 +329,11 @@ impl SharedState {
     pub fn get_finalized_da_height(&self) -> DaBlockHeight {
         self.synced.borrow().da_block_height()
     }
+
+    /// Returns `true` if the relayer is fully synced with the DA layer.
+    pub fn is_synced(&self) -> bool {
+        self.synced.borrow().is_synced()
+    }
 }
 
 impl<P, D> state::EthRemote for Task<P, D>
@@ -258,6 +258,8 @@ pub struct PeerInfo {
     pub peer_addresses: HashSet<String>,
     /// the version of fuel-core reported by the peer
     pub client_version: Option<String>,
+    /// the protocols advertised by the peer
+    pub protocols: HashSet<String>,
     /// recent heartbeat from the peer
     pub heartbeat_data: HeartbeatData,
     /// the current application reputation score of the peer
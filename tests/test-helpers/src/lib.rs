@@ -83,6 +83,25 @@ pub async fn send_graph_ql_query(url: &str, query: &str) -> String {
     response.text().await.unwrap()
 }
 
+pub async fn send_graph_ql_query_with_api_key(
+    url: &str,
+    query: &str,
+    api_key: &str,
+) -> String {
+    let client = reqwest::Client::new();
+    let mut map = std::collections::HashMap::new();
+    map.insert("query", query);
+    let response = client
+        .post(url)
+        .header("x-api-key", api_key)
+        .json(&map)
+        .send()
+        .await
+        .unwrap();
+
+    response.text().await.unwrap()
+}
+
 pub fn make_tx(
     rng: &mut (impl CryptoRng + RngCore),
     i: u64,
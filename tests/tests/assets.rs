@@ -1,6 +1,16 @@
-use fuel_core::service::Config;
+use fuel_core::{
+    chain_config::{
+        CoinConfig,
+        StateConfig,
+    },
+    service::Config,
+};
 use fuel_core_bin::FuelService;
 use fuel_core_client::client::{
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
     types::TransactionStatus,
     FuelClient,
 };
@@ -11,7 +21,9 @@ use fuel_core_types::{
         RegId,
     },
     fuel_tx::{
+        AssetId,
         Bytes32,
+        ContractId,
         ContractIdExt,
         Input,
         Output,
@@ -182,3 +194,195 @@ async fn asset_info_mint_burn() {
     // We should have the minted amount reduced by the burned amount
     assert_eq!(final_supply, (mint_amount - burn_amount) as u128);
 }
+
+// Register ids
+const REG_LEN: u8 = 0x10;
+const REG_MINT_AMOUNT: u8 = 0x11;
+const REG_BURN_AMOUNT: u8 = 0x12;
+const REG_JUMP_COND: u8 = 0x13;
+
+// The value of the jump condition register to make a mint
+const MINT: u32 = 0;
+
+/// Deploys the contract that mints or burns the asset with the sub id 0
+/// depending on the jump condition.
+async fn deploy_mint_burn_contract(
+    client: &FuelClient,
+    mint_amount: u32,
+    burn_amount: u32,
+) -> (ContractId, Bytes32) {
+    let mut ops = vec![
+        // Allocate space for sub asset id
+        op::movi(REG_LEN, 32),
+        op::aloc(REG_LEN),
+        // Put the sub id 0 in memory
+        op::sb(RegId::HP, 0, 0),
+    ];
+    // Set the mint amount in a register
+    ops.extend([op::movi(REG_MINT_AMOUNT, mint_amount)]);
+    // Set the burn amount in a register
+    ops.extend([op::movi(REG_BURN_AMOUNT, burn_amount)]);
+    ops.extend(vec![
+        // Read the register filled by the script data to either make a mint or burn
+        // If 0, mint, if 2, burn
+        op::jmpf(REG_JUMP_COND, 0),
+        op::mint(REG_MINT_AMOUNT, RegId::HP),
+        op::ret(RegId::ONE),
+        op::burn(REG_BURN_AMOUNT, RegId::HP),
+        op::ret(RegId::ONE),
+    ]);
+    // Contract code.
+    let bytecode: Witness = ops.into_iter().collect::<Vec<u8>>().into();
+
+    // Setup the contract.
+    let salt = Salt::zeroed();
+    let contract = Contract::from(bytecode.as_ref());
+    let root = contract.root();
+    let state_root = Contract::initial_state_root(std::iter::empty());
+    let contract_id = contract.id(&salt, &root, &state_root);
+    let output = Output::contract_created(contract_id, state_root);
+
+    // Create the contract deploy transaction.
+    let contract_deploy = TransactionBuilder::create(bytecode, salt, vec![])
+        .add_fee_input()
+        .add_output(output)
+        .finalize_as_transaction();
+    // Deploy the contract.
+    matches!(
+        client.submit_and_await_commit(&contract_deploy).await,
+        Ok(TransactionStatus::Success { .. })
+    );
+
+    (contract_id, state_root)
+}
+
+/// Calls the contract deployed by [`deploy_mint_burn_contract`] to either mint or burn.
+async fn call_mint_burn_contract(
+    client: &FuelClient,
+    contract_id: ContractId,
+    state_root: Bytes32,
+    jump_cond: u32,
+) {
+    let gas_limit = 1_000_000;
+    let script_ops = vec![
+        // Place the jump condition in the register to trigger the mint or burn
+        op::movi(REG_JUMP_COND, jump_cond),
+        // Call the contract that handle the asset and will mint or burn
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let script_data: Vec<u8> = [Call::new(contract_id, 0, 0).to_bytes().as_slice()]
+        .into_iter()
+        .flatten()
+        .copied()
+        .collect();
+    let script = TransactionBuilder::script(
+        script_ops.into_iter().collect::<Vec<u8>>(),
+        script_data,
+    )
+    // Add contract as input of the transaction
+    .add_input(Input::contract(
+        UtxoId::new(Bytes32::zeroed(), 0),
+        Bytes32::zeroed(),
+        state_root,
+        TxPointer::default(),
+        contract_id,
+    ))
+    .script_gas_limit(gas_limit)
+    .add_fee_input()
+    // Add contract as output of the transaction
+    .add_output(Output::contract(0, Bytes32::zeroed(), Bytes32::zeroed()))
+    .finalize_as_transaction();
+
+    // Submit and await commit of the transaction
+    let status = client.submit_and_await_commit(&script).await.unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+}
+
+#[tokio::test]
+async fn known_assets__contains_assets_of_genesis_coins() {
+    // Given
+    let asset_a = AssetId::new([1; 32]);
+    let asset_b = AssetId::new([2; 32]);
+    let coins = [asset_a, asset_b, asset_a]
+        .into_iter()
+        .enumerate()
+        .map(|(i, asset_id)| CoinConfig {
+            tx_id: [i as u8; 32].into(),
+            asset_id,
+            amount: 100,
+            ..Default::default()
+        })
+        .collect();
+    let state = StateConfig {
+        coins,
+        ..Default::default()
+    };
+    let config = Config::local_node_with_state_config(state);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let first_page = client
+        .known_assets(PaginationRequest {
+            cursor: None,
+            results: 1,
+            direction: PageDirection::Forward,
+        })
+        .await
+        .unwrap();
+    let second_page = client
+        .known_assets(PaginationRequest {
+            cursor: first_page.cursor.clone(),
+            results: 10,
+            direction: PageDirection::Forward,
+        })
+        .await
+        .unwrap();
+
+    // Then
+    let assets: Vec<_> = first_page
+        .results
+        .iter()
+        .chain(second_page.results.iter())
+        .map(|asset| asset.asset_id)
+        .collect();
+    assert!(first_page.has_next_page);
+    assert_eq!(assets, vec![asset_a, asset_b]);
+    assert!(first_page
+        .results
+        .iter()
+        .all(|asset| asset.total_supply.is_none()));
+}
+
+#[tokio::test]
+async fn known_assets__contains_asset_minted_after_genesis_with_total_supply() {
+    // Given
+    let mint_amount: u32 = 100;
+    let config = Config::local_node();
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let (contract_id, state_root) =
+        deploy_mint_burn_contract(&client, mint_amount, 0).await;
+
+    // When
+    call_mint_burn_contract(&client, contract_id, state_root, MINT).await;
+
+    // Then
+    let minted_asset_id = contract_id.asset_id(&Bytes32::zeroed());
+    let assets = client
+        .known_assets(PaginationRequest {
+            cursor: None,
+            results: 100,
+            direction: PageDirection::Forward,
+        })
+        .await
+        .unwrap()
+        .results;
+    let minted_asset = assets
+        .iter()
+        .find(|asset| asset.asset_id == minted_asset_id)
+        .expect("The minted asset is known");
+    assert_eq!(minted_asset.total_supply, Some(mint_amount as u128));
+}
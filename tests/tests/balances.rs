@@ -104,6 +104,127 @@ async fn balance() {
     assert_eq!(balance, 1);
 }
 
+#[tokio::test]
+async fn projected_balance__includes_fee_only_for_base_asset() {
+    // Given
+    let owner = Address::new([1; 32]);
+    let other_asset_id = AssetId::new([2; 32]);
+    let mut coin_generator = CoinConfigGenerator::new();
+    let state_config = StateConfig {
+        coins: vec![(AssetId::BASE, 300), (other_asset_id, 200)]
+            .into_iter()
+            .map(|(asset_id, amount)| CoinConfig {
+                owner,
+                amount,
+                asset_id,
+                ..coin_generator.generate()
+            })
+            .collect(),
+        ..Default::default()
+    };
+    let config = Config::local_node_with_state_config(state_config);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let base = client
+        .projected_balance(&owner, &AssetId::BASE, 100, Some(10))
+        .await
+        .unwrap();
+    let other = client
+        .projected_balance(&owner, &other_asset_id, 100, Some(10))
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(base.current_balance, 300);
+    assert_eq!(base.outflow, 110);
+    assert_eq!(base.remaining_balance, 190);
+    assert!(base.sufficient);
+    assert_eq!(other.current_balance, 200);
+    assert_eq!(other.outflow, 100);
+    assert_eq!(other.remaining_balance, 100);
+    assert!(other.sufficient);
+}
+
+#[tokio::test]
+async fn portfolio__returns_balance_and_coin_count_per_owner() {
+    // Given
+    let first_owner = Address::new([1; 32]);
+    let second_owner = Address::new([2; 32]);
+    let asset_id = AssetId::new([3; 32]);
+    let mut coin_generator = CoinConfigGenerator::new();
+    let state_config = StateConfig {
+        coins: vec![
+            (first_owner, AssetId::BASE, 300),
+            (first_owner, AssetId::BASE, 200),
+            (first_owner, asset_id, 100),
+            (second_owner, asset_id, 50),
+        ]
+        .into_iter()
+        .map(|(owner, asset_id, amount)| CoinConfig {
+            owner,
+            amount,
+            asset_id,
+            ..coin_generator.generate()
+        })
+        .collect(),
+        ..Default::default()
+    };
+    let config = Config::local_node_with_state_config(state_config);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let portfolio = client
+        .portfolio(&[second_owner, first_owner])
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(portfolio.len(), 2);
+    assert_eq!(portfolio[0].owner, second_owner);
+    assert_eq!(portfolio[0].assets, vec![(asset_id, 50, 1)]);
+    assert_eq!(portfolio[1].owner, first_owner);
+    let mut first_owner_assets = portfolio[1].assets.clone();
+    first_owner_assets.sort();
+    let mut expected = vec![(AssetId::BASE, 500, 2), (asset_id, 100, 1)];
+    expected.sort();
+    assert_eq!(first_owner_assets, expected);
+    assert!(portfolio.iter().all(|owner| owner.complete));
+}
+
+#[tokio::test]
+async fn projected_balance__is_insufficient_when_outflow_exceeds_balance() {
+    // Given
+    let owner = Address::new([1; 32]);
+    let mut coin_generator = CoinConfigGenerator::new();
+    let state_config = StateConfig {
+        coins: vec![CoinConfig {
+            owner,
+            amount: 50,
+            asset_id: AssetId::BASE,
+            ..coin_generator.generate()
+        }],
+        ..Default::default()
+    };
+    let config = Config::local_node_with_state_config(state_config);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let projected = client
+        .projected_balance(&owner, &AssetId::BASE, 45, Some(10))
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(projected.current_balance, 50);
+    assert_eq!(projected.outflow, 55);
+    assert_eq!(projected.remaining_balance, 0);
+    assert!(!projected.sufficient);
+}
+
 #[tokio::test]
 async fn balance_messages_only() {
     let owner = Address::default();
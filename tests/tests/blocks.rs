@@ -147,6 +147,236 @@ async fn produce_block() {
     };
 }
 
+#[tokio::test]
+async fn block_gas_utilization__returns_gas_limit_and_gas_used_by_latest_blocks() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let tx = Transaction::default_test_tx();
+    let status = client.submit_and_await_commit(&tx).await.unwrap();
+    let TransactionStatus::Success {
+        block_height,
+        total_gas,
+        ..
+    } = status
+    else {
+        panic!("Wrong tx status");
+    };
+    let empty_block_height = client.produce_blocks(1, None).await.unwrap();
+    let expected_gas_limit = client
+        .consensus_parameters(0)
+        .await
+        .unwrap()
+        .unwrap()
+        .block_gas_limit();
+
+    // When
+    let utilization = client.block_gas_utilization(2).await.unwrap();
+
+    // Then
+    assert_eq!(utilization.block_gas_limit, expected_gas_limit);
+    assert_eq!(utilization.blocks.len(), 2);
+    assert_eq!(utilization.blocks[0].height, *empty_block_height);
+    assert_eq!(utilization.blocks[0].gas_used, 0);
+    assert_eq!(utilization.blocks[1].height, *block_height);
+    assert_eq!(utilization.blocks[1].gas_used, total_gas);
+}
+
+#[tokio::test]
+async fn block_fees__returns_sum_of_transaction_fees_of_the_block() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let tx = Transaction::default_test_tx();
+    let status = client.submit_and_await_commit(&tx).await.unwrap();
+    let TransactionStatus::Success {
+        block_height,
+        total_fee,
+        ..
+    } = status
+    else {
+        panic!("Wrong tx status");
+    };
+    let empty_block_height = client.produce_blocks(1, None).await.unwrap();
+
+    // When
+    let fees = client.block_fees(block_height).await.unwrap();
+    let empty_block_fees = client.block_fees(empty_block_height).await.unwrap();
+    let missing_block_fees = client
+        .block_fees(empty_block_height.succ().unwrap())
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(fees, Some(total_fee));
+    assert_eq!(empty_block_fees, Some(0));
+    assert_eq!(missing_block_fees, None);
+}
+
+#[tokio::test]
+async fn block_summary__aggregates_transactions_gas_and_fees_of_the_block() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let tx = Transaction::default_test_tx();
+    let status = client.submit_and_await_commit(&tx).await.unwrap();
+    let TransactionStatus::Success {
+        block_height,
+        total_gas,
+        total_fee,
+        ..
+    } = status
+    else {
+        panic!("Wrong tx status");
+    };
+    let empty_block_height = client.produce_blocks(1, None).await.unwrap();
+
+    // When
+    let summary = client.block_summary(block_height).await.unwrap().unwrap();
+    let empty_summary = client
+        .block_summary(empty_block_height)
+        .await
+        .unwrap()
+        .unwrap();
+    let missing_summary = client
+        .block_summary(empty_block_height.succ().unwrap())
+        .await
+        .unwrap();
+
+    // Then
+    let block = client.block_by_height(block_height).await.unwrap().unwrap();
+    assert_eq!(summary.height, *block_height);
+    assert_eq!(summary.time, block.header.time);
+    // The script transaction and the mint transaction.
+    assert_eq!(summary.transaction_count, 2);
+    assert_eq!(summary.gas_used, total_gas);
+    assert_eq!(summary.fees, total_fee);
+    assert_eq!(empty_summary.transaction_count, 1);
+    assert_eq!(empty_summary.gas_used, 0);
+    assert_eq!(empty_summary.fees, 0);
+    assert!(missing_summary.is_none());
+}
+
+#[tokio::test]
+async fn chain_tip__returns_height_and_id_of_the_latest_block() {
+    // Given
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let latest_height = client.produce_blocks(3, None).await.unwrap();
+
+    // When
+    let chain_tip = client.chain_tip().await.unwrap();
+
+    // Then
+    let block = client.block_by_height(latest_height).await.unwrap().unwrap();
+    assert_eq!(chain_tip.height, *latest_height);
+    assert_eq!(chain_tip.id, block.id);
+}
+
+#[tokio::test]
+async fn block_headers__returns_headers_of_the_range_in_ascending_order() {
+    // Given
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(5, None).await.unwrap();
+
+    // When
+    let headers = client.block_headers(1.into(), 4.into()).await.unwrap();
+
+    // Then
+    let heights: Vec<_> = headers.iter().map(|header| header.height).collect();
+    assert_eq!(heights, vec![1, 2, 3, 4]);
+    for header in headers {
+        let block = client
+            .block_by_height(header.height.into())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(header, block.header);
+    }
+}
+
+#[tokio::test]
+async fn block_headers__fails_when_range_exceeds_the_limit() {
+    // Given
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let result = client.block_headers(0.into(), 100.into()).await;
+
+    // Then
+    let err = result.expect_err("The range contains 101 blocks");
+    assert!(
+        err.to_string()
+            .contains("The number of blocks exceeds the limit of 100"),
+        "{err}"
+    );
+}
+
+#[tokio::test]
+async fn message_outbox_roots__returns_roots_of_the_range_in_ascending_order() {
+    // Given
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(5, None).await.unwrap();
+
+    // When
+    let roots = client
+        .message_outbox_roots(2.into(), 5.into())
+        .await
+        .unwrap();
+
+    // Then
+    let heights: Vec<_> = roots.iter().map(|root| root.height).collect();
+    assert_eq!(heights, vec![2, 3, 4, 5]);
+    for root in roots {
+        let block = client
+            .block_by_height(root.height.into())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(root.message_outbox_root, block.header.message_outbox_root);
+    }
+}
+
+#[tokio::test]
+async fn message_outbox_roots__fails_when_end_is_below_start() {
+    // Given
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let result = client.message_outbox_roots(3.into(), 2.into()).await;
+
+    // Then
+    let err = result.expect_err("The end is below the start");
+    assert!(
+        err.to_string()
+            .contains("The end height can't be below the start height"),
+        "{err}"
+    );
+}
+
 #[tokio::test]
 async fn produce_block_manually() {
     let db = Database::default();
@@ -53,6 +53,40 @@ async fn chain_info() {
     );
 }
 
+#[tokio::test]
+async fn tx_params__returns_tx_params_of_the_current_consensus_parameters() {
+    // Given
+    let node_config = Config::local_node();
+    let srv = FuelService::new_node(node_config.clone()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let tx_params = client.tx_params().await.unwrap();
+
+    // Then
+    let chain_config = node_config.snapshot_reader.chain_config();
+    assert_eq!(&tx_params, chain_config.consensus_parameters.tx_params());
+}
+
+#[tokio::test]
+async fn network_info__returns_chain_id_and_name_of_the_chain() {
+    // Given
+    let node_config = Config::local_node();
+    let srv = FuelService::new_node(node_config.clone()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let network_info = client.network_info().await.unwrap();
+
+    // Then
+    let chain_config = node_config.snapshot_reader.chain_config();
+    assert_eq!(
+        network_info.chain_id,
+        chain_config.consensus_parameters.chain_id()
+    );
+    assert_eq!(network_info.name, chain_config.chain_name);
+}
+
 #[tokio::test]
 async fn network_operates_with_non_zero_chain_id() {
     let mut rng = rand::rngs::StdRng::seed_from_u64(0xBAADF00D);
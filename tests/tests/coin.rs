@@ -18,13 +18,21 @@ use fuel_core_client::client::{
     types::primitives::{
         Address,
         AssetId,
+        Bytes32,
         UtxoId,
     },
     FuelClient,
 };
 use fuel_core_types::{
     fuel_asm::*,
-    fuel_tx::TxId,
+    fuel_merkle::binary::root_calculator::MerkleRootCalculator,
+    fuel_tx::{
+        Finalizable,
+        Output,
+        Transaction,
+        TransactionBuilder,
+        TxId,
+    },
 };
 use rstest::rstest;
 
@@ -61,6 +69,127 @@ async fn coin() {
     assert!(coin.is_some());
 }
 
+#[tokio::test]
+async fn coin_with_transaction__returns_creating_transaction() {
+    let tx_id = TxId::new([1u8; 32]);
+    let genesis_coin = CoinConfig {
+        tx_id,
+        ..Default::default()
+    };
+    let srv = setup_service(vec![genesis_coin]).await;
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let create_coin: Transaction = TransactionBuilder::script(vec![], vec![])
+        .add_fee_input()
+        .add_output(Output::coin(Address::from([1; 32]), 1_000, AssetId::BASE))
+        .finalize_as_transaction();
+    let created_tx_id = create_coin.id(&Default::default());
+    client.submit_and_await_commit(&create_coin).await.unwrap();
+    let genesis_utxo_id = UtxoId::new(tx_id, 0);
+    let created_utxo_id = UtxoId::new(created_tx_id, 0);
+
+    // When
+    let genesis = client
+        .coin_with_transaction(&genesis_utxo_id)
+        .await
+        .unwrap()
+        .expect("The genesis coin should exist");
+    let created = client
+        .coin_with_transaction(&created_utxo_id)
+        .await
+        .unwrap()
+        .expect("The created coin should exist");
+
+    // Then
+    assert_eq!(genesis.coin.utxo_id, genesis_utxo_id);
+    assert_eq!(genesis.transaction, None);
+    assert_eq!(created.coin.utxo_id, created_utxo_id);
+    let transaction = created.transaction.expect("The transaction should exist");
+    assert_eq!(transaction.id(&Default::default()), created_tx_id);
+}
+
+#[tokio::test]
+async fn coin_set_root__commits_to_unspent_coins() {
+    let coins = (1..=3u8)
+        .map(|i| CoinConfig {
+            tx_id: TxId::new([i; 32]),
+            output_index: i.into(),
+            amount: i.into(),
+            ..Default::default()
+        })
+        .collect();
+    let state = StateConfig {
+        coins,
+        ..Default::default()
+    };
+    let mut config = Config::local_node_with_state_config(state);
+    config.graphql_config.coin_set_root = true;
+    config.graphql_config.trusted_api_keys = ["trusted".to_string()].into();
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let mut client = FuelClient::from(srv.bound_address);
+    client.with_api_key("trusted");
+
+    // Given
+    let mut calculator = MerkleRootCalculator::new();
+    for i in 1..=3u8 {
+        let utxo_id = UtxoId::new(TxId::new([i; 32]), i.into());
+        let coin = client.coin(&utxo_id).await.unwrap().unwrap();
+        let mut leaf = vec![];
+        leaf.extend_from_slice(coin.utxo_id.tx_id().as_ref());
+        leaf.extend_from_slice(&coin.utxo_id.output_index().to_be_bytes());
+        leaf.extend_from_slice(coin.owner.as_ref());
+        leaf.extend_from_slice(&coin.amount.to_be_bytes());
+        leaf.extend_from_slice(coin.asset_id.as_ref());
+        leaf.extend_from_slice(&coin.block_created.to_be_bytes());
+        leaf.extend_from_slice(&coin.tx_created_idx.to_be_bytes());
+        calculator.push(&leaf);
+    }
+    let expected_root: Bytes32 = calculator.root().into();
+
+    // When
+    let coin_set_root = client.coin_set_root().await.unwrap();
+
+    // Then
+    assert_eq!(coin_set_root.root, expected_root);
+    assert_eq!(coin_set_root.count, 3);
+    assert_eq!(
+        coin_set_root.block_height,
+        client.chain_info().await.unwrap().latest_block.header.height.into()
+    );
+}
+
+#[tokio::test]
+async fn coin_set_root__fails_when_disabled() {
+    let srv = setup_service(vec![]).await;
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let result = client.coin_set_root().await;
+
+    // Then
+    let error = result.expect_err("The coin set root is disabled by default");
+    assert!(error.to_string().contains("The coin set root is disabled"));
+}
+
+#[tokio::test]
+async fn coin_set_root__fails_for_untrusted_client() {
+    let mut config = Config::local_node();
+    config.graphql_config.coin_set_root = true;
+    config.graphql_config.trusted_api_keys = ["trusted".to_string()].into();
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let result = client.coin_set_root().await;
+
+    // Then
+    let error = result.expect_err("The coin set root requires a trusted client");
+    assert!(error.to_string().contains("Query is too complex"), "{error}");
+}
+
 // Backward fails, tracking in https://github.com/FuelLabs/fuel-core/issues/610
 #[rstest]
 #[tokio::test]
@@ -11,6 +11,10 @@ use fuel_core::{
     },
 };
 use fuel_core_client::client::{
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
     types::CoinType,
     FuelClient,
 };
@@ -26,16 +30,46 @@ use test_helpers::{
 
 mod coin {
     use super::*;
-    use fuel_core::chain_config::{
-        coin_config_helpers::CoinConfigGenerator,
-        ChainConfig,
+    use fuel_core::{
+        chain_config::{
+            coin_config_helpers::{
+                self,
+                CoinConfigGenerator,
+            },
+            ChainConfig,
+        },
+        fuel_core_graphql_api::{
+            coin_tags::CoinsToSpendTags,
+            coins_blacklist::CoinsToSpendBlacklist,
+        },
+        schema::coins::MAX_COIN_FILTER_ASSET_IDS,
+    };
+    use fuel_core_client::client::types::{
+        coins::{
+            AssetDustCoins,
+            AssetSelectionCost,
+            CoinIneligibilityReason,
+            CoinLockReason,
+            CoinResolutionStatus,
+            CoinsToSpendIndexRebuildStatus,
+        },
+        CoinType,
+        InputFragment,
     };
-    use fuel_core_client::client::types::CoinType;
     use fuel_core_types::{
+        entities::coins::CoinId,
         fuel_crypto::SecretKey,
-        fuel_tx::Address,
+        fuel_tx::{
+            field::{
+                Inputs,
+                Outputs,
+                Witnesses,
+            },
+            Address,
+        },
     };
     use rand::Rng;
+    use std::time::Duration;
     use test_helpers::assemble_tx::SigningAccount;
 
     async fn setup(
@@ -43,6 +77,67 @@ mod coin {
         asset_id_a: AssetId,
         asset_id_b: AssetId,
         consensus_parameters: &ConsensusParameters,
+    ) -> TestContext {
+        setup_with_blacklist(
+            owner,
+            asset_id_a,
+            asset_id_b,
+            consensus_parameters,
+            CoinsToSpendBlacklist::default(),
+        )
+        .await
+    }
+
+    async fn setup_with_blacklist(
+        owner: Address,
+        asset_id_a: AssetId,
+        asset_id_b: AssetId,
+        consensus_parameters: &ConsensusParameters,
+        blacklist: CoinsToSpendBlacklist,
+    ) -> TestContext {
+        setup_with_config(
+            owner,
+            asset_id_a,
+            asset_id_b,
+            consensus_parameters,
+            |config| {
+                config.graphql_config.coins_to_spend_blacklist = blacklist;
+            },
+        )
+        .await
+    }
+
+    const TRUSTED_API_KEY: &str = "trusted";
+
+    async fn setup_with_trusted_client(
+        owner: Address,
+        asset_id_a: AssetId,
+        asset_id_b: AssetId,
+        consensus_parameters: &ConsensusParameters,
+        update_config: impl FnOnce(&mut Config),
+    ) -> TestContext {
+        let mut context = setup_with_config(
+            owner,
+            asset_id_a,
+            asset_id_b,
+            consensus_parameters,
+            |config| {
+                config.graphql_config.trusted_api_keys =
+                    [TRUSTED_API_KEY.to_string()].into();
+                update_config(config);
+            },
+        )
+        .await;
+        context.client.with_api_key(TRUSTED_API_KEY);
+        context
+    }
+
+    async fn setup_with_config(
+        owner: Address,
+        asset_id_a: AssetId,
+        asset_id_b: AssetId,
+        consensus_parameters: &ConsensusParameters,
+        update_config: impl FnOnce(&mut Config),
     ) -> TestContext {
         // setup config
         let mut coin_generator = CoinConfigGenerator::new();
@@ -69,7 +164,8 @@ mod coin {
         };
         let chain =
             ChainConfig::local_testnet_with_consensus_parameters(consensus_parameters);
-        let config = Config::local_node_with_configs(chain, state);
+        let mut config = Config::local_node_with_configs(chain, state);
+        update_config(&mut config);
 
         let srv = FuelService::new_node(config).await.unwrap();
         let client = FuelClient::from(srv.bound_address);
@@ -97,54 +193,1511 @@ mod coin {
     }
 
     #[tokio::test]
-    async fn excludes_spent_coins() {
-        let mut rng = StdRng::seed_from_u64(1234);
-        let asset_id_a: AssetId = rng.gen();
-        let asset_id_b: AssetId = rng.gen();
-        let secret_key: SecretKey = SecretKey::random(&mut rng);
-        let pk = secret_key.public_key();
-        let owner = Input::owner(&pk);
-        let cp = ConsensusParameters::default();
-        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+    async fn excludes_spent_coins() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let asset_id_a: AssetId = rng.gen();
+        let asset_id_b: AssetId = rng.gen();
+        let secret_key: SecretKey = SecretKey::random(&mut rng);
+        let pk = secret_key.public_key();
+        let owner = Input::owner(&pk);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        let burn_address: Address = rng.gen();
+
+        let balance_a = context
+            .client
+            .balance(&owner, Some(&asset_id_a))
+            .await
+            .unwrap();
+        let balance_b = context
+            .client
+            .balance(&owner, Some(&asset_id_b))
+            .await
+            .unwrap();
+        let recipients = vec![
+            (burn_address, asset_id_a, balance_a),
+            (burn_address, asset_id_b, balance_b),
+        ];
+
+        context
+            .client
+            .run_transfer(SigningAccount::Wallet(secret_key), recipients)
+            .await
+            .unwrap();
+
+        // select all available asset a coins to spend
+        let remaining_coins_a = context
+            .client
+            .coins_to_spend(&owner, vec![(asset_id_a, 1, None)], None)
+            .await;
+        // there should be none left
+        assert!(remaining_coins_a.is_err());
+
+        // select all available asset a coins to spend
+        let remaining_coins_b = context
+            .client
+            .coins_to_spend(&owner, vec![(asset_id_b, 1, None)], None)
+            .await;
+        // there should be none left
+        assert!(remaining_coins_b.is_err())
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_for_session__excludes_coins_reserved_by_other_sessions() {
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup_with_trusted_client(owner, asset_id_a, asset_id_b, &cp, |_| {}).await;
+
+        // Given
+        let all_coins_a = context
+            .client
+            .coins_to_spend(&owner, vec![(asset_id_a, 300, None)], None)
+            .await
+            .unwrap();
+        let utxos: Vec<UtxoId> = all_coins_a[0]
+            .iter()
+            .filter_map(|coin| match coin {
+                CoinType::Coin(coin) => Some(coin.utxo_id),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(utxos.len(), 3);
+        let reserved = context
+            .client
+            .reserve_coins("alice", &utxos, &[], 60)
+            .await
+            .unwrap();
+        assert!(reserved);
+
+        // When
+        let for_bob = context
+            .client
+            .coins_to_spend_for_session(&owner, vec![(asset_id_a, 1, None)], None, "bob")
+            .await;
+        let without_session = context
+            .client
+            .coins_to_spend(&owner, vec![(asset_id_a, 1, None)], None)
+            .await;
+        let for_alice = context
+            .client
+            .coins_to_spend_for_session(
+                &owner,
+                vec![(asset_id_a, 300, None)],
+                None,
+                "alice",
+            )
+            .await
+            .unwrap();
+
+        // Then
+        assert!(for_bob.is_err());
+        assert!(without_session.is_err());
+        assert_eq!(for_alice[0].len(), 3);
+
+        // When
+        let released = context.client.release_coins("alice").await.unwrap();
+        let for_bob = context
+            .client
+            .coins_to_spend_for_session(&owner, vec![(asset_id_a, 1, None)], None, "bob")
+            .await;
+
+        // Then
+        assert!(released);
+        assert!(for_bob.is_ok());
+    }
+
+    #[tokio::test]
+    async fn reserve_coins__fails_for_coins_reserved_by_another_session() {
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup_with_trusted_client(owner, asset_id_a, asset_id_b, &cp, |_| {}).await;
+        let coins = context
+            .client
+            .coins_to_spend(&owner, vec![(asset_id_a, 1, Some(1))], None)
+            .await
+            .unwrap();
+        let CoinType::Coin(coin) = &coins[0][0] else {
+            panic!("Expected a coin");
+        };
+
+        // Given
+        context
+            .client
+            .reserve_coins("alice", &[coin.utxo_id], &[], 60)
+            .await
+            .unwrap();
+
+        // When
+        let result = context
+            .client
+            .reserve_coins("bob", &[coin.utxo_id], &[], 60)
+            .await;
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_for_session__ignores_the_session_of_an_untrusted_client() {
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context =
+            setup_with_trusted_client(owner, asset_id_a, asset_id_b, &cp, |_| {}).await;
+        let coins = context
+            .client
+            .coins_to_spend(&owner, vec![(asset_id_a, 1, Some(1))], None)
+            .await
+            .unwrap();
+        let CoinType::Coin(coin) = &coins[0][0] else {
+            panic!("Expected a coin");
+        };
+
+        // Given
+        context
+            .client
+            .reserve_coins("alice", &[coin.utxo_id], &[], 60)
+            .await
+            .unwrap();
+        let untrusted_client = FuelClient::from(context.srv.bound_address);
+
+        // When
+        let for_alice = untrusted_client
+            .coins_to_spend_for_session(
+                &owner,
+                vec![(asset_id_a, 300, None)],
+                None,
+                "alice",
+            )
+            .await;
+
+        // Then
+        // All three coins are needed for the target, but the reserved one is excluded.
+        assert!(for_alice.is_err());
+    }
+
+    #[tokio::test]
+    async fn reserve_coins__fails_without_the_api_key_of_a_trusted_client() {
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let mut context =
+            setup_with_trusted_client(owner, asset_id_a, asset_id_b, &cp, |_| {}).await;
+        let utxo_id = UtxoId::new(coin_config_helpers::tx_id(0), 0);
+
+        // Given
+        context.client.with_api_key("untrusted");
+
+        // When
+        let result = context.client.reserve_coins("alice", &[utxo_id], &[], 60).await;
+
+        // Then
+        let err = result.expect_err("Reservation should require a trusted client");
+        assert!(
+            err.to_string().contains("API key of a trusted client"),
+            "{err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_best_effort__returns_failure_only_for_uncovered_assets() {
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        // Given
+        let spend_query = vec![(asset_id_a, 1_000, None), (asset_id_b, 300, None)];
+
+        // When
+        let all_or_nothing = context
+            .client
+            .coins_to_spend(&owner, spend_query.clone(), None)
+            .await;
+        let best_effort = context
+            .client
+            .coins_to_spend_best_effort(&owner, spend_query, None, None)
+            .await
+            .unwrap();
+
+        // Then
+        assert!(all_or_nothing.is_err());
+        assert_eq!(best_effort.len(), 2);
+        assert_eq!(best_effort[0].asset_id, asset_id_a);
+        assert!(best_effort[0].coins.is_err());
+        assert_eq!(best_effort[1].asset_id, asset_id_b);
+        let coins_b = best_effort[1].coins.as_ref().unwrap();
+        assert_eq!(coins_b.len(), 3);
+        assert_eq!(coins_b.amount(), 300);
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_best_effort__returns_partial_selection_when_allowed() {
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        // Given
+        let spend_query = vec![(asset_id_a, 300, Some(2)), (asset_id_b, 100, None)];
+
+        // When
+        let without_partial = context
+            .client
+            .coins_to_spend_best_effort(&owner, spend_query.clone(), None, None)
+            .await
+            .unwrap();
+        let with_partial = context
+            .client
+            .coins_to_spend_best_effort_allow_partial(&owner, spend_query, None, None)
+            .await
+            .unwrap();
+
+        // Then
+        assert!(without_partial[0].coins.is_err());
+        assert!(without_partial[0].partial_coins.is_none());
+        assert!(with_partial[0].coins.is_err());
+        let partial_coins = with_partial[0].partial_coins.as_ref().unwrap();
+        assert_eq!(partial_coins.len(), 2);
+        assert_eq!(partial_coins.amount(), 250);
+        assert!(with_partial[1].coins.is_ok());
+        assert!(with_partial[1].partial_coins.is_none());
+    }
+
+    #[tokio::test]
+    async fn input_count_estimate__sums_inputs_of_all_assets() {
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = consensus_parameters_with_max_inputs(4);
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        // Given
+        let spend_query = vec![(asset_id_a, 300, None), (asset_id_b, 300, None)];
+
+        // When
+        let estimate = context
+            .client
+            .input_count_estimate(&owner, spend_query, None, None)
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(estimate.max_inputs, 4);
+        assert_eq!(estimate.total_inputs, 6);
+        assert!(!estimate.within_limit);
+        assert_eq!(
+            estimate.inputs_per_asset,
+            vec![(asset_id_a, Ok(3)), (asset_id_b, Ok(3))]
+        );
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_flat__tags_coins_with_assets_in_query_order() {
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        // Given
+        let spend_query = vec![(asset_id_b, 300, None), (asset_id_a, 300, None)];
+
+        // When
+        let coins = context
+            .client
+            .coins_to_spend_flat(&owner, spend_query, None, None)
+            .await
+            .unwrap();
+
+        // Then
+        let tags: Vec<_> = coins.iter().map(|(asset_id, _)| *asset_id).collect();
+        assert_eq!(
+            tags,
+            vec![asset_id_b, asset_id_b, asset_id_b, asset_id_a, asset_id_a, asset_id_a]
+        );
+        for (asset_id, coin) in coins {
+            let CoinType::Coin(coin) = coin else {
+                panic!("Expected a coin, got {coin:?}");
+            };
+            assert_eq!(coin.asset_id, asset_id);
+        }
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_as_inputs__returns_signed_inputs_of_selected_coins() {
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        // Given
+        let spend_query = vec![(asset_id_a, 300, None), (asset_id_b, 300, None)];
+        let coins = context
+            .client
+            .coins_to_spend(&owner, spend_query.clone(), None)
+            .await
+            .unwrap();
+
+        // When
+        let inputs = context
+            .client
+            .coins_to_spend_as_inputs(&owner, spend_query, None, Some(2), false)
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(inputs.len(), coins.len());
+        for (inputs, coins) in inputs.into_iter().zip(coins) {
+            assert_eq!(inputs.len(), coins.len());
+            for (input, coin) in inputs.into_iter().zip(coins) {
+                let InputFragment::Coin(fragment) = input else {
+                    panic!("Expected a coin, got {input:?}");
+                };
+                let CoinType::Coin(coin) = coin else {
+                    panic!("Expected a coin, got {coin:?}");
+                };
+                assert_eq!(fragment.utxo_id, coin.utxo_id);
+                assert_eq!(fragment.witness_index, 2);
+                assert_eq!(fragment.predicate, None);
+
+                let input = fragment.into_signed_input();
+                assert_eq!(input.utxo_id(), Some(&coin.utxo_id));
+                assert_eq!(input.input_owner(), Some(&owner));
+                assert_eq!(input.amount(), Some(coin.amount));
+                assert_eq!(input.asset_id(&AssetId::BASE), Some(&coin.asset_id));
+                assert_eq!(input.witness_index(), Some(2));
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_as_transaction__returns_unsigned_skeleton_of_selection() {
+        let owner = Address::from([5; 32]);
+        let recipient = Address::from([6; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        // Given
+        let spend_query = vec![(asset_id_a, 120, None)];
+
+        // When
+        let selection = context
+            .client
+            .coins_to_spend_as_transaction(&owner, &recipient, spend_query, None)
+            .await
+            .unwrap();
+
+        // Then
+        let Transaction::Script(script) = selection.transaction else {
+            panic!("Expected a script, got {:?}", selection.transaction);
+        };
+        let selected_utxo_ids = selection.coins[0]
+            .iter()
+            .map(|coin| match coin {
+                CoinType::Coin(coin) => coin.utxo_id,
+                _ => panic!("Expected a coin, got {coin:?}"),
+            })
+            .collect::<Vec<_>>();
+        let input_utxo_ids = script
+            .inputs()
+            .iter()
+            .map(|input| *input.utxo_id().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(input_utxo_ids, selected_utxo_ids);
+        assert_eq!(
+            script.outputs(),
+            &vec![
+                Output::coin(recipient, 120, asset_id_a),
+                Output::change(owner, 0, asset_id_a),
+            ]
+        );
+        assert_eq!(script.witnesses(), &vec![Witness::default()]);
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_as_transaction__fails_when_outputs_exceed_max_outputs() {
+        let owner = Address::from([5; 32]);
+        let recipient = Address::from([6; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let mut cp = ConsensusParameters::default();
+        cp.set_tx_params(TxParameters::default().with_max_outputs(3));
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        // Given
+        let spend_query = vec![(asset_id_a, 120, None), (asset_id_b, 120, None)];
+
+        // When
+        let result = context
+            .client
+            .coins_to_spend_as_transaction(&owner, &recipient, spend_query, None)
+            .await;
+
+        // Then
+        let err = result.expect_err("The skeleton has 4 outputs");
+        assert!(
+            err.to_string()
+                .contains("requires 4 outputs, but the maximum is 3"),
+            "{err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_within_budget__drops_assets_that_do_not_fit() {
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let asset_id_c = AssetId::new([3u8; 32]);
+        let cp = consensus_parameters_with_max_inputs(4);
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        // Given
+        let spend_query = vec![
+            (asset_id_c, 1, None),
+            (asset_id_a, 300, None),
+            (asset_id_b, 300, None),
+        ];
+
+        // When
+        let coins = context
+            .client
+            .coins_to_spend_within_budget(&owner, spend_query, None, None, None)
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(coins.max_inputs, 4);
+        assert_eq!(coins.used_inputs, 3);
+        assert_eq!(coins.covered.len(), 1);
+        assert_eq!(coins.covered[0].0, asset_id_a);
+        assert_eq!(coins.covered[0].1.len(), 3);
+        let dropped: Vec<_> = coins.dropped.iter().map(|(asset_id, _)| *asset_id).collect();
+        assert_eq!(dropped, vec![asset_id_c, asset_id_b]);
+        assert!(coins.dropped[1].1.contains("left in the budget"));
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_with_preferred_count__selects_preferred_number_of_coins() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        // When
+        let coins_per_asset = context
+            .client
+            .coins_to_spend_with_preferred_count(
+                &owner,
+                vec![(asset_id_a, 200, None, Some(3))],
+                None,
+            )
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(coins_per_asset.len(), 1);
+        assert_eq!(coins_per_asset[0].len(), 3);
+        assert_eq!(
+            coins_per_asset[0]
+                .iter()
+                .map(|coin| coin.amount())
+                .sum::<u64>(),
+            300
+        );
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_with_max_overshoot__selects_coins_within_the_cap() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        // When
+        let coins_per_asset = context
+            .client
+            .coins_to_spend_with_max_overshoot(
+                &owner,
+                vec![(asset_id_a, 200, None, Some(0))],
+                None,
+            )
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(coins_per_asset.len(), 1);
+        let mut amounts = coins_per_asset[0]
+            .iter()
+            .map(|coin| coin.amount())
+            .collect::<Vec<_>>();
+        amounts.sort();
+        assert_eq!(amounts, vec![50, 150]);
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_with_max_overshoot__fails_when_cap_cannot_be_met() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        // When
+        let result = context
+            .client
+            .coins_to_spend_with_max_overshoot(
+                &owner,
+                vec![(asset_id_a, 120, None, Some(10))],
+                None,
+            )
+            .await;
+
+        // Then
+        let error = result.expect_err("The overshoot cap can't be met");
+        assert!(
+            error
+                .to_string()
+                .contains("without exceeding it by more than 10"),
+            "{error}"
+        );
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend__fails_when_total_coins_exceed_the_node_limit() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup_with_config(owner, asset_id_a, asset_id_b, &cp, |config| {
+            config.graphql_config.coins_to_spend_max_total_coins = 3;
+        })
+        .await;
+
+        // When
+        let within_limit = context
+            .client
+            .coins_to_spend(&owner, vec![(asset_id_a, 300, None)], None)
+            .await;
+        let above_limit = context
+            .client
+            .coins_to_spend(
+                &owner,
+                vec![(asset_id_a, 300, None), (asset_id_b, 300, None)],
+                None,
+            )
+            .await;
+
+        // Then
+        assert_eq!(within_limit.unwrap()[0].len(), 3);
+        let err = above_limit.expect_err("The selection contains 6 coins");
+        assert!(
+            err.to_string()
+                .contains("the selection contains too many coins across all assets"),
+            "{err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend__skips_coins_below_the_min_amount_unless_necessary() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup_with_config(owner, asset_id_a, asset_id_b, &cp, |config| {
+            config
+                .graphql_config
+                .coins_to_spend_min_amounts
+                .insert(asset_id_a, 100);
+        })
+        .await;
+
+        // When
+        let above_min = context
+            .client
+            .coins_to_spend(&owner, vec![(asset_id_a, 200, None)], None)
+            .await
+            .unwrap();
+        let all_coins = context
+            .client
+            .coins_to_spend(&owner, vec![(asset_id_a, 300, None)], None)
+            .await
+            .unwrap();
+
+        // Then
+        assert!(above_min[0].iter().all(|coin| coin.amount() >= 100));
+        assert_eq!(all_coins[0].len(), 3);
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_with_summary__sums_selected_amounts_per_asset() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        // When
+        let selection = context
+            .client
+            .coins_to_spend_with_summary(
+                &owner,
+                vec![(asset_id_a, 300, None), (asset_id_b, 120, None)],
+                None,
+            )
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(selection.summary.len(), 2);
+        for ((summary, coins), (asset_id, target)) in selection
+            .summary
+            .iter()
+            .zip(selection.coins.iter())
+            .zip([(asset_id_a, 300), (asset_id_b, 120)])
+        {
+            let total: u128 = coins.iter().map(|coin| coin.amount() as u128).sum();
+            assert_eq!(summary.asset_id, asset_id);
+            assert_eq!(summary.target, target);
+            assert_eq!(summary.total, total);
+            assert!(summary.meets_target);
+            assert_eq!(summary.exact_match, total == target);
+        }
+        assert_eq!(selection.summary[0].total, 300);
+        assert!(selection.summary[0].exact_match);
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_with_summary__estimates_gas_cost_of_selected_inputs() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        // When
+        let selection = context
+            .client
+            .coins_to_spend_with_summary(
+                &owner,
+                vec![(asset_id_a, 300, None), (asset_id_b, 120, None)],
+                None,
+            )
+            .await
+            .unwrap();
+
+        // Then
+        let gas_cost = selection.gas_cost;
+        let inputs: usize = selection.coins.iter().map(Vec::len).sum();
+        assert_eq!(gas_cost.inputs as usize, inputs);
+        assert_eq!(gas_cost.signature_gas, cp.gas_costs().eck1());
+        assert_eq!(
+            gas_cost.bytes_gas,
+            gas_cost.bytes * cp.fee_params().gas_per_byte()
+        );
+        assert_eq!(
+            gas_cost.total_gas,
+            gas_cost.signature_gas + gas_cost.bytes_gas
+        );
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_in_groups__partitions_coins_by_max_inputs() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = consensus_parameters_with_max_inputs(2);
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        // When
+        let coin_groups = context
+            .client
+            .coins_to_spend_in_groups(&owner, &asset_id_a, 300, None, None)
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(coin_groups.max_inputs, 2);
+        assert_eq!(coin_groups.total, 300);
+        let groups = coin_groups
+            .groups
+            .iter()
+            .map(|group| {
+                let amounts: Vec<_> =
+                    group.coins.iter().map(|coin| coin.amount()).collect();
+                (amounts, group.total)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(groups, vec![(vec![150, 100], 250), (vec![50], 50)]);
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_in_groups__fails_when_balance_is_below_the_amount() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = consensus_parameters_with_max_inputs(2);
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        // When
+        let result = context
+            .client
+            .coins_to_spend_in_groups(&owner, &asset_id_a, 301, None, None)
+            .await;
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn coin_selection_candidates__ranks_candidates_by_cost() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        // When
+        let candidates = context
+            .client
+            .coin_selection_candidates(&owner, &asset_id_a, 120, None, 3, None, None)
+            .await
+            .unwrap();
+
+        // Then
+        let amounts = candidates
+            .iter()
+            .map(|candidate| {
+                candidate
+                    .coins
+                    .iter()
+                    .map(|coin| coin.amount())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(amounts, vec![vec![150], vec![100, 50]]);
+        for candidate in &candidates {
+            assert_eq!(candidate.overshoot, 30);
+            assert_eq!(
+                candidate.cost,
+                u128::from(candidate.inputs) + u128::from(candidate.gas) + 30
+            );
+        }
+        assert!(candidates[0].cost < candidates[1].cost);
+    }
+
+    #[tokio::test]
+    async fn coins_feasibility__counts_biggest_coins_covering_the_amount() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        // When
+        let feasibility = context
+            .client
+            .coins_feasibility(&owner, &asset_id_a, 200)
+            .await
+            .unwrap();
+
+        // Then
+        assert!(feasibility.feasible);
+        assert_eq!(feasibility.coins_needed, 2);
+        assert_eq!(feasibility.total_available, 150 + 100);
+    }
+
+    #[tokio::test]
+    async fn coins_feasibility__is_not_feasible_when_balance_is_below_the_amount() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        // When
+        let feasibility = context
+            .client
+            .coins_feasibility(&owner, &asset_id_a, 400)
+            .await
+            .unwrap();
+
+        // Then
+        assert!(!feasibility.feasible);
+        assert_eq!(feasibility.coins_needed, 3);
+        assert_eq!(feasibility.total_available, 150 + 100 + 50);
+    }
+
+    #[tokio::test]
+    async fn coins_coverage__sums_unspent_coins_of_the_asset() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+        let coins = context
+            .client
+            .coins_to_spend(
+                &owner,
+                vec![(asset_id_a, 300, None), (asset_id_b, 1, Some(1))],
+                None,
+            )
+            .await
+            .unwrap();
+        let mut utxo_ids: Vec<_> = coins
+            .into_iter()
+            .flatten()
+            .filter_map(|coin| match coin {
+                CoinType::Coin(coin) => Some(coin.utxo_id),
+                _ => None,
+            })
+            .collect();
+        let unknown_utxo_id = UtxoId::new([7; 32].into(), 0);
+        utxo_ids.push(unknown_utxo_id);
+
+        // When
+        let coverage = context
+            .client
+            .coins_coverage(&asset_id_a, 300, utxo_ids, vec![])
+            .await
+            .unwrap();
+
+        // Then
+        assert!(coverage.covers_target);
+        assert_eq!(coverage.total, 150 + 100 + 50);
+        let statuses: Vec<_> = coverage
+            .coins
+            .iter()
+            .map(|(_, status, _)| *status)
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                CoinResolutionStatus::Unspent,
+                CoinResolutionStatus::Unspent,
+                CoinResolutionStatus::Unspent,
+                CoinResolutionStatus::OtherAsset,
+                CoinResolutionStatus::NotFound,
+            ]
+        );
+        assert_eq!(
+            coverage.coins[4],
+            (unknown_utxo_id, CoinResolutionStatus::NotFound, None)
+        );
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_cost__counts_coins_scanned_from_the_index() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        // When
+        let cost = context
+            .client
+            .coins_to_spend_cost(
+                &owner,
+                vec![(asset_id_a, 100, Some(1)), (asset_id_b, 100, None)],
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        // Then
+        assert!(cost.indexed);
+        assert!(cost.complete);
+        assert_eq!(cost.entries_to_scan, 5);
+        assert_eq!(
+            cost.cost_per_asset,
+            vec![
+                AssetSelectionCost {
+                    asset_id: asset_id_a,
+                    coin_count: 3,
+                    entries_to_scan: 2,
+                },
+                AssetSelectionCost {
+                    asset_id: asset_id_b,
+                    coin_count: 3,
+                    entries_to_scan: 3,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn dust_coins__counts_coins_below_threshold_per_asset() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        // When
+        let dust_coins = context.client.dust_coins(&owner, 101).await.unwrap();
+
+        // Then
+        assert!(dust_coins.complete);
+        assert_eq!(
+            dust_coins.assets,
+            vec![
+                AssetDustCoins {
+                    asset_id: asset_id_a,
+                    count: 2,
+                    amount: 150,
+                },
+                AssetDustCoins {
+                    asset_id: asset_id_b,
+                    count: 2,
+                    amount: 150,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_with_reserve_count__keeps_smallest_coins_unspent() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        // When
+        let coins_per_asset = context
+            .client
+            .coins_to_spend_with_reserve_count(
+                &owner,
+                vec![(asset_id_a, 200, None, Some(1))],
+                None,
+            )
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(coins_per_asset.len(), 1);
+        let mut amounts = coins_per_asset[0]
+            .iter()
+            .map(|coin| coin.amount())
+            .collect::<Vec<_>>();
+        amounts.sort();
+        assert_eq!(amounts, vec![100, 150]);
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_avoiding_largest_coin__skips_it_when_other_coins_suffice() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        // When
+        let coins_per_asset = context
+            .client
+            .coins_to_spend_avoiding_largest_coin(
+                &owner,
+                vec![(asset_id_a, 150, None)],
+                None,
+            )
+            .await
+            .unwrap();
+
+        // Then
+        let mut amounts = coins_per_asset[0]
+            .iter()
+            .map(|coin| coin.amount())
+            .collect::<Vec<_>>();
+        amounts.sort();
+        assert_eq!(amounts, vec![50, 100]);
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_avoiding_largest_coin__uses_it_when_necessary() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        // When
+        let coins_per_asset = context
+            .client
+            .coins_to_spend_avoiding_largest_coin(
+                &owner,
+                vec![(asset_id_a, 150, Some(1))],
+                None,
+            )
+            .await
+            .unwrap();
+
+        // Then
+        let amounts = coins_per_asset[0]
+            .iter()
+            .map(|coin| coin.amount())
+            .collect::<Vec<_>>();
+        assert_eq!(amounts, vec![150]);
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_preferring_deep_coins__meets_the_target() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        // When
+        let coins_per_asset = context
+            .client
+            .coins_to_spend_preferring_deep_coins(
+                &owner,
+                vec![(asset_id_a, 150, Some(1))],
+                None,
+            )
+            .await
+            .unwrap();
+
+        // Then
+        let amounts = coins_per_asset[0]
+            .iter()
+            .map(|coin| coin.amount())
+            .collect::<Vec<_>>();
+        assert_eq!(amounts, vec![150]);
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_with_change_outputs__no_change_matches_the_amount_exactly() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        // When
+        let coins_per_asset = context
+            .client
+            .coins_to_spend_with_change_outputs(
+                &owner,
+                vec![(asset_id_a, 150, Some(3), 0)],
+                None,
+            )
+            .await
+            .unwrap();
+
+        // Then
+        let total: u64 = coins_per_asset[0].iter().map(|coin| coin.amount()).sum();
+        assert_eq!(total, 150);
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_smallest_first__takes_the_smallest_coins() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        // When
+        let coins_per_asset = context
+            .client
+            .coins_to_spend_smallest_first(&owner, vec![(asset_id_a, 60, Some(2))], None)
+            .await
+            .unwrap();
+
+        // Then
+        let amounts = coins_per_asset[0]
+            .iter()
+            .map(|coin| coin.amount())
+            .collect::<Vec<_>>();
+        assert_eq!(amounts, vec![50, 100]);
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_with_reserve_count__fails_when_target_exceeds_the_rest() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        // When
+        let result = context
+            .client
+            .coins_to_spend_with_reserve_count(
+                &owner,
+                vec![(asset_id_a, 200, None, Some(2))],
+                None,
+            )
+            .await;
+
+        // Then
+        let error = result.expect_err("The target can't be met above the reserve");
+        assert!(
+            error
+                .to_string()
+                .contains("while keeping 2 coins unspent"),
+            "{error}"
+        );
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend__excludes_blacklisted_coins() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        // The third generated coin is the coin of the asset A with the amount 150.
+        let blacklisted_coin = UtxoId::new(coin_config_helpers::tx_id(2), 2);
+        let blacklist =
+            CoinsToSpendBlacklist::new(vec![], vec![blacklisted_coin], vec![]);
+        let context =
+            setup_with_blacklist(owner, asset_id_a, asset_id_b, &cp, blacklist).await;
+
+        // When
+        let coins_per_asset = context
+            .client
+            .coins_to_spend(&owner, vec![(asset_id_a, 150, None)], None)
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(coins_per_asset.len(), 1);
+        let mut amounts = coins_per_asset[0]
+            .iter()
+            .map(|coin| coin.amount())
+            .collect::<Vec<_>>();
+        amounts.sort();
+        assert_eq!(amounts, vec![50, 100]);
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend__fails_for_blacklisted_owner() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let blacklist = CoinsToSpendBlacklist::new(vec![owner], vec![], vec![]);
+        let context =
+            setup_with_blacklist(owner, asset_id_a, asset_id_b, &cp, blacklist).await;
+
+        // When
+        let result = context
+            .client
+            .coins_to_spend(&owner, vec![(asset_id_a, 1, None)], None)
+            .await;
+
+        // Then
+        let error = result.expect_err("The coins of the owner are blacklisted");
+        assert!(error.to_string().contains("are blacklisted"), "{error}");
+    }
+
+    async fn coins_to_spend_with_tags(
+        tags: CoinsToSpendTags,
+        target: u64,
+        allowed_tags: Option<Vec<String>>,
+        denied_tags: Option<Vec<String>>,
+    ) -> Vec<u64> {
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup_with_config(owner, asset_id_a, asset_id_b, &cp, |config| {
+            config.graphql_config.coins_to_spend_tags = tags;
+        })
+        .await;
+
+        let coins_per_asset = context
+            .client
+            .coins_to_spend_with_tags(
+                &owner,
+                vec![(asset_id_a, target as u128, None)],
+                None,
+                allowed_tags,
+                denied_tags,
+            )
+            .await
+            .unwrap();
+
+        let mut amounts = coins_per_asset[0]
+            .iter()
+            .map(|coin| coin.amount())
+            .collect::<Vec<_>>();
+        amounts.sort();
+        amounts
+    }
+
+    fn coin_tag(index: u16, tag: &str) -> (CoinId, String) {
+        // The first three generated coins are the coins of the asset A
+        // with the amounts 50, 100 and 150.
+        let utxo_id = UtxoId::new(coin_config_helpers::tx_id(index), index);
+        (CoinId::Utxo(utxo_id), tag.to_string())
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend__selects_only_coins_with_allowed_tags() {
+        // Given
+        let tags = CoinsToSpendTags::new(vec![
+            coin_tag(0, "hot"),
+            coin_tag(1, "hot"),
+            coin_tag(2, "cold"),
+        ]);
+
+        // When
+        let amounts =
+            coins_to_spend_with_tags(tags, 150, Some(vec!["hot".to_string()]), None)
+                .await;
+
+        // Then
+        assert_eq!(amounts, vec![50, 100]);
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend__excludes_coins_with_denied_tags() {
+        // Given
+        let tags = CoinsToSpendTags::new(vec![coin_tag(2, "settlement")]);
+
+        // When
+        let amounts = coins_to_spend_with_tags(
+            tags,
+            150,
+            None,
+            Some(vec!["settlement".to_string()]),
+        )
+        .await;
+
+        // Then
+        assert_eq!(amounts, vec![50, 100]);
+    }
+
+    async fn coins_to_spend_within_size(
+        target: u128,
+        max_inputs_in_size: u64,
+    ) -> std::io::Result<Vec<u64>> {
+        use fuel_core_types::fuel_types::canonical::Serialize;
+
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+        let input_size = Input::coin_signed(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )
+        .size() as u64;
+        let witness_size = Witness::from(vec![0u8; 64]).size() as u64;
+        let max_size = input_size * max_inputs_in_size + witness_size;
+
+        let coins_per_asset = context
+            .client
+            .coins_to_spend_within_size(
+                &owner,
+                vec![(asset_id_a, target, None)],
+                Some(max_size),
+                None,
+                None,
+                None,
+            )
+            .await?;
+
+        let mut amounts = coins_per_asset[0]
+            .iter()
+            .map(|coin| coin.amount())
+            .collect::<Vec<_>>();
+        amounts.sort();
+        Ok(amounts)
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_within_size__selects_biggest_coins_within_size_limit() {
+        // When
+        let amounts = coins_to_spend_within_size(250, 2).await.unwrap();
+
+        // Then
+        assert_eq!(amounts, vec![100, 150]);
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_within_size__fails_when_target_exceeds_size_limit() {
+        // When
+        let result = coins_to_spend_within_size(300, 2).await;
+
+        // Then
+        let error = result.expect_err("Three inputs don't fit into the size limit");
+        assert!(
+            error
+                .to_string()
+                .contains("without exceeding the size limit"),
+            "{error}"
+        );
+    }
 
-        let burn_address: Address = rng.gen();
+    #[tokio::test]
+    async fn coins_to_spend__ignores_tags_if_tagging_is_not_configured() {
+        // Given
+        let tags = CoinsToSpendTags::default();
 
-        let balance_a = context
-            .client
-            .balance(&owner, Some(&asset_id_a))
-            .await
-            .unwrap();
-        let balance_b = context
+        // When
+        let amounts =
+            coins_to_spend_with_tags(tags, 300, Some(vec!["hot".to_string()]), None)
+                .await;
+
+        // Then
+        assert_eq!(amounts, vec![50, 100, 150]);
+    }
+
+    #[tokio::test]
+    async fn locked_coins__returns_blacklisted_and_reserved_coins_of_owner() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let blacklisted_coin = UtxoId::new(coin_config_helpers::tx_id(2), 2);
+        let reserved_coin = UtxoId::new(coin_config_helpers::tx_id(0), 0);
+        let blacklist =
+            CoinsToSpendBlacklist::new(vec![], vec![blacklisted_coin], vec![]);
+        let context =
+            setup_with_trusted_client(owner, asset_id_a, asset_id_b, &cp, |config| {
+                config.graphql_config.coins_to_spend_blacklist = blacklist;
+            })
+            .await;
+        context
             .client
-            .balance(&owner, Some(&asset_id_b))
+            .reserve_coins("alice", &[reserved_coin], &[], 60)
             .await
             .unwrap();
-        let recipients = vec![
-            (burn_address, asset_id_a, balance_a),
-            (burn_address, asset_id_b, balance_b),
-        ];
 
-        context
+        // When
+        let locked_coins = context.client.locked_coins(&owner).await.unwrap();
+
+        // Then
+        assert!(!locked_coins.owner_locked);
+        assert!(locked_coins.complete);
+        let locked = locked_coins
+            .coins
+            .into_iter()
+            .map(|(coin, reason)| {
+                let CoinType::Coin(coin) = coin else {
+                    panic!("Expected a coin, got {coin:?}");
+                };
+                (coin.utxo_id, reason)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            locked,
+            vec![
+                (blacklisted_coin, CoinLockReason::Blacklisted),
+                (reserved_coin, CoinLockReason::Reserved),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn coins_eligibility__returns_reason_per_coin() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let blacklisted_coin = UtxoId::new(coin_config_helpers::tx_id(2), 2);
+        let context = setup_with_config(owner, asset_id_a, asset_id_b, &cp, |config| {
+            config.graphql_config.coins_to_spend_blacklist =
+                CoinsToSpendBlacklist::new(vec![], vec![blacklisted_coin], vec![]);
+            config
+                .graphql_config
+                .coins_to_spend_min_amounts
+                .insert(asset_id_a, 100);
+        })
+        .await;
+
+        // When
+        let eligibility = context
             .client
-            .run_transfer(SigningAccount::Wallet(secret_key), recipients)
+            .coins_eligibility(&owner, &asset_id_a, None)
             .await
             .unwrap();
 
-        // select all available asset a coins to spend
-        let remaining_coins_a = context
-            .client
-            .coins_to_spend(&owner, vec![(asset_id_a, 1, None)], None)
-            .await;
-        // there should be none left
-        assert!(remaining_coins_a.is_err());
+        // Then
+        assert!(eligibility.complete);
+        let mut reasons = eligibility
+            .coins
+            .into_iter()
+            .map(|(coin, reason)| (coin.amount(), reason))
+            .collect::<Vec<_>>();
+        reasons.sort_by_key(|(amount, _)| *amount);
+        assert_eq!(
+            reasons,
+            vec![
+                (50, Some(CoinIneligibilityReason::BelowMinAmount)),
+                (100, None),
+                (150, Some(CoinIneligibilityReason::Blacklisted)),
+            ]
+        );
+    }
 
-        // select all available asset a coins to spend
-        let remaining_coins_b = context
-            .client
-            .coins_to_spend(&owner, vec![(asset_id_b, 1, None)], None)
-            .await;
-        // there should be none left
-        assert!(remaining_coins_b.is_err())
+    #[tokio::test]
+    async fn locked_coins__marks_blacklisted_owner_as_locked() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let blacklist = CoinsToSpendBlacklist::new(vec![owner], vec![], vec![]);
+        let context =
+            setup_with_blacklist(owner, asset_id_a, asset_id_b, &cp, blacklist).await;
+
+        // When
+        let locked_coins = context.client.locked_coins(&owner).await.unwrap();
+
+        // Then
+        assert!(locked_coins.owner_locked);
+        assert!(locked_coins.coins.is_empty());
     }
 
     async fn query_target_1(owner: Address, asset_id_a: AssetId, asset_id_b: AssetId) {
@@ -235,7 +1788,8 @@ mod coin {
             CoinsQueryError::InsufficientCoinsForTheMax {
                 asset_id: asset_id_a,
                 collected_amount: 0,
-                max: MAX_INPUTS
+                max: MAX_INPUTS,
+                diagnosis: Default::default(),
             }
             .to_str_error_string()
         );
@@ -265,7 +1819,8 @@ mod coin {
             CoinsQueryError::InsufficientCoinsForTheMax {
                 asset_id: asset_id_a,
                 collected_amount: 300,
-                max: MAX_INPUTS
+                max: MAX_INPUTS,
+                diagnosis: Default::default(),
             }
             .to_str_error_string()
         );
@@ -295,11 +1850,419 @@ mod coin {
             CoinsQueryError::InsufficientCoinsForTheMax {
                 asset_id: asset_id_a,
                 collected_amount: 0,
-                max: MAX
+                max: MAX,
+                diagnosis: Default::default(),
             }
             .to_str_error_string()
         );
     }
+
+    async fn wait_for_rebuild(
+        client: &FuelClient,
+        id: u64,
+    ) -> CoinsToSpendIndexRebuildStatus {
+        for _ in 0..100 {
+            let status = client
+                .coins_to_spend_index_rebuild(id)
+                .await
+                .unwrap()
+                .expect("The rebuild should be known");
+            if status != CoinsToSpendIndexRebuildStatus::Pending {
+                return status;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        panic!("The rebuild {id} is not finished");
+    }
+
+    #[tokio::test]
+    async fn rebuild_coins_to_spend_index__reports_number_of_rebuilt_entries() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1; 32]);
+        let asset_id_b = AssetId::new([2; 32]);
+        let cp = ConsensusParameters::default();
+        let context =
+            setup_with_trusted_client(owner, asset_id_a, asset_id_b, &cp, |_| {}).await;
+
+        // When
+        let rebuild_a = context
+            .client
+            .rebuild_coins_to_spend_index(&owner, Some(&asset_id_a))
+            .await
+            .unwrap();
+        let status_a = wait_for_rebuild(&context.client, rebuild_a).await;
+        let rebuild_all = context
+            .client
+            .rebuild_coins_to_spend_index(&owner, None)
+            .await
+            .unwrap();
+        let status_all = wait_for_rebuild(&context.client, rebuild_all).await;
+
+        // Then
+        assert_ne!(rebuild_a, rebuild_all);
+        assert_eq!(
+            status_a,
+            CoinsToSpendIndexRebuildStatus::Completed { entries: 3 }
+        );
+        assert_eq!(
+            status_all,
+            CoinsToSpendIndexRebuildStatus::Completed { entries: 6 }
+        );
+        let coins_per_asset = context
+            .client
+            .coins_to_spend(&owner, vec![(asset_id_a, 300, None)], None)
+            .await
+            .unwrap();
+        assert_eq!(coins_per_asset[0].len(), 3);
+    }
+
+    #[tokio::test]
+    async fn rebuild_coins_to_spend_index__fails_without_a_trusted_client() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1; 32]);
+        let asset_id_b = AssetId::new([2; 32]);
+        let cp = ConsensusParameters::default();
+        let mut context =
+            setup_with_trusted_client(owner, asset_id_a, asset_id_b, &cp, |_| {}).await;
+        context.client.with_api_key("untrusted");
+
+        // When
+        let result = context
+            .client
+            .rebuild_coins_to_spend_index(&owner, None)
+            .await;
+
+        // Then
+        let err = result.expect_err("Rebuild should require a trusted client");
+        assert!(err.to_string().contains("API key of a trusted client"));
+    }
+
+    #[tokio::test]
+    async fn rebuild_coins_to_spend_index__fails_without_debug() {
+        // Given
+        let mut config = Config::local_node();
+        config.debug = false;
+        config.utxo_validation = true;
+        let srv = FuelService::new_node(config).await.unwrap();
+        let client = FuelClient::from(srv.bound_address);
+
+        // When
+        let result = client
+            .rebuild_coins_to_spend_index(&Address::default(), None)
+            .await;
+
+        // Then
+        let err = result.expect_err("Rebuild should require debug");
+        assert!(err.to_string().contains("`debug` must be enabled"));
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_with_fee__base_coins_cover_fee_for_all_inputs() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id = AssetId::new([1; 32]);
+        let cp = ConsensusParameters::default();
+        let base_asset_id = *cp.base_asset_id();
+        let context = setup(owner, asset_id, base_asset_id, &cp).await;
+        let base_fee = 10;
+        let fee_per_input = 20;
+
+        // When
+        let selection = context
+            .client
+            .coins_to_spend_with_fee(
+                &owner,
+                vec![(asset_id, 100, None)],
+                None,
+                base_fee,
+                fee_per_input,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        // Then
+        let value_inputs = selection.coins[0].len() as u64;
+        let base_inputs = selection.base_coins.len() as u64;
+        assert!(value_inputs > 0);
+        assert!(base_inputs > 0);
+        assert_eq!(
+            selection.fee,
+            base_fee + fee_per_input * (value_inputs + base_inputs)
+        );
+        let covered: u64 = selection.base_coins.iter().map(|coin| coin.amount()).sum();
+        assert!(covered >= selection.fee);
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_with_fee__rejects_base_asset_in_query() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let cp = ConsensusParameters::default();
+        let base_asset_id = *cp.base_asset_id();
+        let context = setup(owner, AssetId::new([1; 32]), base_asset_id, &cp).await;
+
+        // When
+        let result = context
+            .client
+            .coins_to_spend_with_fee(
+                &owner,
+                vec![(base_asset_id, 100, None)],
+                None,
+                10,
+                20,
+                None,
+                None,
+            )
+            .await;
+
+        // Then
+        let err = result.expect_err("The base asset is not allowed in the query");
+        assert!(err.to_string().contains("use `base_amount` to spend it"));
+    }
+
+    #[tokio::test]
+    async fn coins_of_assets__returns_coins_of_any_asset_from_the_set() {
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let asset_id_c = AssetId::new([3u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+        let request = || PaginationRequest {
+            cursor: None,
+            results: 10,
+            direction: PageDirection::Forward,
+        };
+
+        // Given
+        let watchlist = [asset_id_a, asset_id_c];
+        let all_assets = [asset_id_a, asset_id_b];
+
+        // When
+        let watched = context
+            .client
+            .coins_of_assets(&owner, &watchlist, request())
+            .await
+            .unwrap();
+        let all = context
+            .client
+            .coins_of_assets(&owner, &all_assets, request())
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(watched.results.len(), 3);
+        assert!(watched
+            .results
+            .iter()
+            .all(|coin| coin.asset_id == asset_id_a));
+        assert_eq!(all.results.len(), 6);
+    }
+
+    #[tokio::test]
+    async fn coins_of_assets__fails_for_too_many_asset_ids() {
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        // Given
+        let asset_ids: Vec<_> = (0..=MAX_COIN_FILTER_ASSET_IDS)
+            .map(|i| AssetId::new([i as u8; 32]))
+            .collect();
+
+        // When
+        let result = context
+            .client
+            .coins_of_assets(
+                &owner,
+                &asset_ids,
+                PaginationRequest {
+                    cursor: None,
+                    results: 10,
+                    direction: PageDirection::Forward,
+                },
+            )
+            .await;
+
+        // Then
+        let err = result.expect_err("The filter has too many asset ids");
+        assert!(err.to_string().contains("can't have more than"));
+    }
+
+    #[tokio::test]
+    async fn coins_with_min_confirmations__excludes_coins_from_recent_blocks() {
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+        let request = || PaginationRequest {
+            cursor: None,
+            results: 10,
+            direction: PageDirection::Forward,
+        };
+
+        // Given
+        let min_confirmations = 2;
+        let unconfirmed = context
+            .client
+            .coins_with_min_confirmations(&owner, min_confirmations, request())
+            .await
+            .unwrap();
+        assert!(unconfirmed.results.is_empty());
+
+        // When
+        context
+            .client
+            .produce_blocks(min_confirmations, None)
+            .await
+            .unwrap();
+        let confirmed = context
+            .client
+            .coins_with_min_confirmations(&owner, min_confirmations, request())
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(confirmed.results.len(), 6);
+    }
+
+    #[tokio::test]
+    async fn utxo_count__counts_genesis_coins_and_tracks_block_height() {
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+
+        // Given
+        let genesis_count = context.client.utxo_count().await.unwrap();
+        assert_eq!(genesis_count.count, 6);
+        assert_eq!(genesis_count.block_height, None);
+
+        // When
+        let height = context.client.produce_blocks(1, None).await.unwrap();
+        let utxo_count = context.client.utxo_count().await.unwrap();
+
+        // Then
+        assert_eq!(utxo_count.count, 6);
+        assert_eq!(utxo_count.block_height, Some(height));
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_at_version__uses_consensus_parameters_of_the_version() {
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup(owner, asset_id_a, asset_id_b, &cp).await;
+        let spend_query = || vec![(asset_id_a, 300, None), (asset_id_b, 300, Some(3))];
+
+        // Given
+        let genesis_version = 0;
+        let unknown_version = 99;
+
+        // When
+        let coins_per_asset = context
+            .client
+            .coins_to_spend_at_version(&owner, spend_query(), None, genesis_version)
+            .await
+            .unwrap();
+        let unknown_version_result = context
+            .client
+            .coins_to_spend_at_version(&owner, spend_query(), None, unknown_version)
+            .await;
+
+        // Then
+        assert_eq!(coins_per_asset.len(), 2);
+        assert!(coins_per_asset[0].amount() >= 300);
+        assert_eq!(coins_per_asset[1].len(), 3);
+        assert!(unknown_version_result.is_err());
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_at_version__selects_coins_below_the_min_amount_if_necessary() {
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let cp = ConsensusParameters::default();
+        let context = setup_with_config(owner, asset_id_a, asset_id_b, &cp, |config| {
+            config
+                .graphql_config
+                .coins_to_spend_min_amounts
+                .insert(asset_id_a, 100);
+        })
+        .await;
+
+        // Given
+        let genesis_version = 0;
+
+        // When
+        let coins_per_asset = context
+            .client
+            .coins_to_spend_at_version(
+                &owner,
+                vec![(asset_id_a, 300, None)],
+                None,
+                genesis_version,
+            )
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(coins_per_asset[0].len(), 3);
+    }
+
+    #[tokio::test]
+    async fn change_outputs__returns_remaining_amount_per_asset() {
+        let context = empty_setup().await;
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let asset_id_c = AssetId::new([3u8; 32]);
+
+        // Given
+        let selected = [
+            (asset_id_a, 100),
+            (asset_id_b, 50),
+            (asset_id_a, 150),
+            (asset_id_c, 70),
+        ];
+        let spend = [(asset_id_a, 200), (asset_id_b, 50)];
+
+        // When
+        let change = context
+            .client
+            .change_outputs(&selected, &spend)
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(change, vec![(asset_id_a, 50), (asset_id_c, 70)]);
+    }
+
+    #[tokio::test]
+    async fn change_outputs__fails_when_selection_does_not_cover_spend() {
+        let context = empty_setup().await;
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+
+        // Given
+        let selected = [(asset_id_a, 100)];
+        let spend = [(asset_id_a, 50), (asset_id_b, 10)];
+
+        // When
+        let result = context.client.change_outputs(&selected, &spend).await;
+
+        // Then
+        let err = result.expect_err("The asset `b` is not covered");
+        assert!(err.to_string().contains("don't cover the spent amount"));
+    }
 }
 
 mod message_coin {
@@ -461,7 +2424,8 @@ mod message_coin {
             CoinsQueryError::InsufficientCoinsForTheMax {
                 asset_id: base_asset_id,
                 collected_amount: 0,
-                max: max_inputs
+                max: max_inputs,
+                diagnosis: Default::default(),
             }
             .to_str_error_string()
         );
@@ -481,7 +2445,8 @@ mod message_coin {
             CoinsQueryError::InsufficientCoinsForTheMax {
                 asset_id: base_asset_id,
                 collected_amount: 300,
-                max: max_inputs
+                max: max_inputs,
+                diagnosis: Default::default(),
             }
             .to_str_error_string()
         );
@@ -503,7 +2468,8 @@ mod message_coin {
             CoinsQueryError::InsufficientCoinsForTheMax {
                 asset_id: base_asset_id,
                 collected_amount: 0,
-                max: MAX
+                max: MAX,
+                diagnosis: Default::default(),
             }
             .to_str_error_string()
         );
@@ -679,7 +2645,8 @@ mod all_coins {
             CoinsQueryError::InsufficientCoinsForTheMax {
                 asset_id: asset_id_a,
                 collected_amount: 0,
-                max: max_inputs
+                max: max_inputs,
+                diagnosis: Default::default(),
             }
             .to_str_error_string()
         );
@@ -703,7 +2670,8 @@ mod all_coins {
             CoinsQueryError::InsufficientCoinsForTheMax {
                 asset_id: asset_id_a,
                 collected_amount: 300,
-                max: max_inputs
+                max: max_inputs,
+                diagnosis: Default::default(),
             }
             .to_str_error_string()
         );
@@ -732,11 +2700,41 @@ mod all_coins {
             CoinsQueryError::InsufficientCoinsForTheMax {
                 asset_id: asset_id_a,
                 collected_amount: 0,
-                max: MAX
+                max: MAX,
+                diagnosis: Default::default(),
             }
             .to_str_error_string()
         );
     }
+
+    #[tokio::test]
+    async fn coins_excluding_base_asset__returns_only_non_base_asset_coins() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_b = AssetId::new([1u8; 32]);
+        let (base_asset_id, context, _) = setup(owner, asset_id_b).await;
+
+        // When
+        let coins = context
+            .client
+            .coins_excluding_base_asset(
+                &owner,
+                PaginationRequest {
+                    cursor: None,
+                    results: 10,
+                    direction: PageDirection::Forward,
+                },
+            )
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(coins.results.len(), 3);
+        assert!(coins
+            .results
+            .iter()
+            .all(|coin| coin.asset_id == asset_id_b && coin.asset_id != base_asset_id));
+    }
 }
 
 async fn empty_setup() -> TestContext {
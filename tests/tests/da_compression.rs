@@ -6,9 +6,14 @@ use fuel_core::{
             DbTx,
             DecompressDbTx,
         },
+        ports::worker::OffChainDatabase,
         worker_service::DaCompressionConfig,
     },
     p2p_test_helpers::*,
+    schema::da_compressed::{
+        MAX_DA_COMPRESSION_RATIO_BLOCKS,
+        MAX_DECOMPRESSION_INPUT_SIZE,
+    },
     service::{
         Config,
         FuelService,
@@ -19,7 +24,10 @@ use fuel_core_client::client::{
     FuelClient,
 };
 use fuel_core_compression::{
-    decompress::decompress,
+    decompress::{
+        decompress,
+        decompress_transaction_ids,
+    },
     VersionedCompressedBlock,
 };
 use fuel_core_storage::transactional::{
@@ -39,7 +47,9 @@ use fuel_core_types::{
     },
     secrecy::Secret,
     signer::SignMode,
+    tai64::Tai64,
 };
+use futures::StreamExt;
 use rand::{
     rngs::StdRng,
     SeedableRng,
@@ -62,6 +72,8 @@ async fn can_fetch_da_compressed_block_from_graphql() {
     config.consensus_signer = SignMode::Key(Secret::new(poa_secret.into()));
     let compression_config = fuel_core_compression::Config {
         temporal_registry_retention: Duration::from_secs(3600),
+        temporal_registry_max_entries: None,
+        temporal_registry_compaction_interval: None,
     };
     config.da_compression = DaCompressionConfig::Enabled(compression_config);
     let chain_id = config
@@ -131,6 +143,74 @@ async fn can_fetch_da_compressed_block_from_graphql() {
     }
 }
 
+#[tokio::test]
+async fn decompress_transaction_ids__matches_ids_of_the_original_block() {
+    // Given
+    let mut config = config_with_fee();
+    let compression_config = fuel_core_compression::Config {
+        temporal_registry_retention: Duration::from_secs(3600),
+        temporal_registry_max_entries: None,
+        temporal_registry_compaction_interval: None,
+    };
+    config.da_compression = DaCompressionConfig::Enabled(compression_config);
+    let chain_id = config
+        .snapshot_reader
+        .chain_config()
+        .consensus_parameters
+        .chain_id();
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let wallet_secret =
+        SecretKey::from_str(TESTNET_WALLET_SECRETS[1]).expect("Expected valid secret");
+    let status = client
+        .run_script(
+            vec![op::ret(RegId::ONE)],
+            vec![],
+            SigningAccount::Wallet(wallet_secret),
+        )
+        .await
+        .unwrap();
+    let TransactionStatus::Success { block_height, .. } = status else {
+        panic!("unexpected result {status:?}")
+    };
+    let block = client
+        .da_compressed_block(block_height)
+        .await
+        .unwrap()
+        .expect("Unable to get compressed block");
+    let block: VersionedCompressedBlock = postcard::from_bytes(&block).unwrap();
+    let db = &srv.shared.database;
+    let on_chain_before_execution = db.on_chain().view_at(&0u32.into()).unwrap();
+    let mut tx_inner = db.off_chain().clone().into_transaction();
+    let db_tx = DecompressDbTx {
+        db_tx: DbTx {
+            db_tx: &mut tx_inner,
+        },
+        onchain_db: on_chain_before_execution,
+    };
+
+    // When
+    let tx_ids =
+        decompress_transaction_ids(compression_config, db_tx, block, &chain_id)
+            .await
+            .unwrap();
+
+    // Then
+    let block_from_on_chain_db = db
+        .on_chain()
+        .latest_view()
+        .unwrap()
+        .get_full_block(&block_height)
+        .unwrap()
+        .unwrap();
+    let expected_ids: Vec<_> = block_from_on_chain_db
+        .transactions()
+        .iter()
+        .map(|tx| tx.id(&chain_id))
+        .collect();
+    assert_eq!(tx_ids, expected_ids);
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn da_compressed_blocks_are_available_from_non_block_producing_nodes() {
     let mut rng = StdRng::seed_from_u64(line!() as u64);
@@ -142,6 +222,8 @@ async fn da_compressed_blocks_are_available_from_non_block_producing_nodes() {
     let mut config = Config::local_node();
     config.da_compression = DaCompressionConfig::Enabled(fuel_core_compression::Config {
         temporal_registry_retention: Duration::from_secs(3600),
+        temporal_registry_max_entries: None,
+        temporal_registry_compaction_interval: None,
     });
 
     let Nodes {
@@ -176,3 +258,342 @@ async fn da_compressed_blocks_are_available_from_non_block_producing_nodes() {
         .expect("Compressed block not available from validator");
     let _: VersionedCompressedBlock = postcard::from_bytes(&block).unwrap();
 }
+
+#[tokio::test]
+async fn latest_da_compressed_block__returns_compressed_latest_block_with_height() {
+    // Given
+    let mut config = config_with_fee();
+    config.da_compression = DaCompressionConfig::Enabled(fuel_core_compression::Config {
+        temporal_registry_retention: Duration::from_secs(3600),
+        temporal_registry_max_entries: None,
+        temporal_registry_compaction_interval: None,
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let wallet_secret =
+        SecretKey::from_str(TESTNET_WALLET_SECRETS[1]).expect("Expected valid secret");
+    let status = client
+        .run_script(
+            vec![op::ret(RegId::ONE)],
+            vec![],
+            SigningAccount::Wallet(wallet_secret),
+        )
+        .await
+        .unwrap();
+    let TransactionStatus::Success { block_height, .. } = status else {
+        panic!("unexpected result {status:?}")
+    };
+
+    // When
+    let (height, bytes) = client
+        .latest_da_compressed_block()
+        .await
+        .unwrap()
+        .expect("Unable to get latest compressed block");
+
+    // Then
+    assert_eq!(height, block_height);
+    let expected = client
+        .da_compressed_block(block_height)
+        .await
+        .unwrap()
+        .expect("Unable to get compressed block");
+    assert_eq!(bytes, expected);
+}
+
+#[tokio::test]
+async fn subscribe_da_compressed_blocks__emits_compressed_form_of_new_blocks() {
+    // Given
+    let mut config = Config::local_node();
+    config.da_compression = DaCompressionConfig::Enabled(fuel_core_compression::Config {
+        temporal_registry_retention: Duration::from_secs(3600),
+        temporal_registry_max_entries: None,
+        temporal_registry_compaction_interval: None,
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let mut stream = client.subscribe_da_compressed_blocks().await.unwrap();
+
+    // When
+    let produced_height = client.produce_blocks(1, None).await.unwrap();
+    let (height, bytes) = tokio::time::timeout(Duration::from_secs(10), stream.next())
+        .await
+        .expect("The compressed block should be emitted")
+        .expect("The stream should not end")
+        .unwrap();
+
+    // Then
+    assert_eq!(height, produced_height);
+    let expected = client
+        .da_compressed_block(produced_height)
+        .await
+        .unwrap()
+        .expect("Unable to get compressed block");
+    assert_eq!(bytes, expected);
+}
+
+#[tokio::test]
+async fn subscribe_da_compressed_blocks__fails_when_compression_is_disabled() {
+    // Given
+    let mut config = Config::local_node();
+    config.da_compression = DaCompressionConfig::Disabled;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let mut stream = client.subscribe_da_compressed_blocks().await.unwrap();
+
+    // When
+    let result = stream.next().await;
+
+    // Then
+    assert!(matches!(result, Some(Err(_))));
+}
+
+#[tokio::test]
+async fn latest_da_compressed_block__returns_none_when_compression_is_disabled() {
+    // Given
+    let mut config = Config::local_node();
+    config.da_compression = DaCompressionConfig::Disabled;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let latest = client.latest_da_compressed_block().await.unwrap();
+
+    // Then
+    assert!(latest.is_none());
+}
+
+#[tokio::test]
+async fn da_compression_retention__returns_configured_retention() {
+    // Given
+    let mut config = Config::local_node();
+    config.da_compression = DaCompressionConfig::Enabled(fuel_core_compression::Config {
+        temporal_registry_retention: Duration::from_secs(3600),
+        temporal_registry_max_entries: None,
+        temporal_registry_compaction_interval: None,
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let retention = client.da_compression_retention().await.unwrap();
+
+    // Then
+    assert_eq!(retention, Some(Duration::from_secs(3600)));
+}
+
+#[tokio::test]
+async fn da_compression_retention__returns_none_when_compression_is_disabled() {
+    // Given
+    let mut config = Config::local_node();
+    config.da_compression = DaCompressionConfig::Disabled;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let retention = client.da_compression_retention().await.unwrap();
+
+    // Then
+    assert_eq!(retention, None);
+}
+
+#[tokio::test]
+async fn decompress_da_block__returns_transactions_of_the_compressed_block() {
+    // Given
+    let mut config = Config::local_node();
+    config.da_compression = DaCompressionConfig::Enabled(fuel_core_compression::Config {
+        temporal_registry_retention: Duration::from_secs(3600),
+        temporal_registry_max_entries: None,
+        temporal_registry_compaction_interval: None,
+    });
+    let chain_id = config
+        .snapshot_reader
+        .chain_config()
+        .consensus_parameters
+        .chain_id();
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let height = client.produce_blocks(1, None).await.unwrap();
+    let bytes = client
+        .da_compressed_block(height)
+        .await
+        .unwrap()
+        .expect("Unable to get compressed block");
+
+    // When
+    let transactions = client.decompress_da_block(&bytes).await.unwrap();
+
+    // Then
+    let block = client.block_by_height(height).await.unwrap().unwrap();
+    let ids: Vec<_> = transactions.iter().map(|tx| tx.id(&chain_id)).collect();
+    assert_eq!(ids, block.transactions);
+}
+
+#[tokio::test]
+async fn decompress_da_block__fails_for_invalid_bytes() {
+    // Given
+    let mut config = Config::local_node();
+    config.da_compression = DaCompressionConfig::Enabled(fuel_core_compression::Config {
+        temporal_registry_retention: Duration::from_secs(3600),
+        temporal_registry_max_entries: None,
+        temporal_registry_compaction_interval: None,
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let result = client.decompress_da_block(&[0xff; 32]).await;
+
+    // Then
+    let err = result.expect_err("Invalid bytes should not be decompressed");
+    assert!(err
+        .to_string()
+        .contains("Failed to deserialize the compressed block"));
+}
+
+#[tokio::test]
+async fn decompress_da_block__fails_when_input_exceeds_limit() {
+    // Given
+    let mut config = Config::local_node();
+    config.da_compression = DaCompressionConfig::Enabled(fuel_core_compression::Config {
+        temporal_registry_retention: Duration::from_secs(3600),
+        temporal_registry_max_entries: None,
+        temporal_registry_compaction_interval: None,
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let bytes = vec![0; MAX_DECOMPRESSION_INPUT_SIZE + 1];
+
+    // When
+    let result = client.decompress_da_block(&bytes).await;
+
+    // Then
+    let err = result.expect_err("The input should exceed the limit");
+    assert!(err.to_string().contains("exceeds the limit"));
+}
+
+#[tokio::test]
+async fn da_compression_ratios__returns_sizes_of_latest_compressed_blocks() {
+    // Given
+    let mut config = Config::local_node();
+    config.da_compression = DaCompressionConfig::Enabled(fuel_core_compression::Config {
+        temporal_registry_retention: Duration::from_secs(3600),
+        temporal_registry_max_entries: None,
+        temporal_registry_compaction_interval: None,
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let mut stream = client.subscribe_da_compressed_blocks().await.unwrap();
+    let produced_height = client.produce_blocks(3, None).await.unwrap();
+    loop {
+        let (height, _) = tokio::time::timeout(Duration::from_secs(10), stream.next())
+            .await
+            .expect("The compressed block should be emitted")
+            .expect("The stream should not end")
+            .unwrap();
+        if height == produced_height {
+            break
+        }
+    }
+
+    // When
+    let ratios = client.da_compression_ratios(2).await.unwrap();
+
+    // Then
+    let heights: Vec<_> = ratios.iter().map(|ratio| ratio.height).collect();
+    let latest: u32 = produced_height.into();
+    assert_eq!(heights, vec![latest, latest - 1]);
+    for ratio in ratios {
+        let compressed = client
+            .da_compressed_block(ratio.height.into())
+            .await
+            .unwrap()
+            .expect("Unable to get compressed block");
+        assert_eq!(ratio.compressed_size, compressed.len() as u64);
+        assert!(ratio.uncompressed_size > 0);
+        assert_eq!(
+            ratio.ratio,
+            ratio.uncompressed_size as f64 / ratio.compressed_size as f64
+        );
+    }
+}
+
+#[tokio::test]
+async fn da_compression_ratios__fails_when_number_of_blocks_exceeds_limit() {
+    // Given
+    let mut config = Config::local_node();
+    config.da_compression = DaCompressionConfig::Enabled(fuel_core_compression::Config {
+        temporal_registry_retention: Duration::from_secs(3600),
+        temporal_registry_max_entries: None,
+        temporal_registry_compaction_interval: None,
+    });
+    config.graphql_config.max_queries_complexity = usize::MAX;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let last = MAX_DA_COMPRESSION_RATIO_BLOCKS as u32 + 1;
+
+    // When
+    let result = client.da_compression_ratios(last).await;
+
+    // Then
+    let err = result.expect_err("The number of blocks should exceed the limit");
+    assert!(err.to_string().contains("exceeds the limit"));
+}
+
+#[tokio::test]
+async fn da_compression_compaction__removes_registry_entries_past_retention() {
+    // Given
+    let retention = Duration::from_secs(10);
+    let mut config = config_with_fee();
+    config.da_compression = DaCompressionConfig::Enabled(fuel_core_compression::Config {
+        temporal_registry_retention: retention,
+        temporal_registry_max_entries: None,
+        temporal_registry_compaction_interval: Some(Duration::from_secs(1)),
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let wallet_secret =
+        SecretKey::from_str(TESTNET_WALLET_SECRETS[1]).expect("Expected valid secret");
+    let status = client
+        .run_script(
+            vec![op::ret(RegId::ONE)],
+            vec![],
+            SigningAccount::Wallet(wallet_secret),
+        )
+        .await
+        .unwrap();
+    let TransactionStatus::Success { block_height, .. } = status else {
+        panic!("unexpected result {status:?}")
+    };
+    let block = client.block_by_height(block_height).await.unwrap().unwrap();
+    let next_block_time = block.header.time.0 + retention.as_secs() + 1;
+    let off_chain = srv.shared.database.off_chain();
+    let stale_keys = off_chain
+        .stale_da_compression_registry_keys(Tai64(next_block_time))
+        .unwrap();
+    assert!(!stale_keys.is_empty());
+    let mut stream = client.subscribe_da_compressed_blocks().await.unwrap();
+
+    // When
+    let produced_height = client
+        .produce_blocks(1, Some(next_block_time))
+        .await
+        .unwrap();
+    loop {
+        let (height, _) = tokio::time::timeout(Duration::from_secs(10), stream.next())
+            .await
+            .expect("The compressed block should be emitted")
+            .expect("The stream should not end")
+            .unwrap();
+        if height == produced_height {
+            break
+        }
+    }
+
+    // Then
+    let oldest_accessible = Tai64(next_block_time - retention.as_secs());
+    let stale_keys = off_chain
+        .stale_da_compression_registry_keys(oldest_accessible)
+        .unwrap();
+    assert!(stale_keys.is_empty());
+}
@@ -12,7 +12,10 @@ use fuel_core::service::{
 use fuel_core_client::client::FuelClient;
 use fuel_core_types::blockchain::header::LATEST_STATE_TRANSITION_VERSION;
 use test_case::test_case;
-use test_helpers::send_graph_ql_query;
+use test_helpers::{
+    send_graph_ql_query,
+    send_graph_ql_query_with_api_key,
+};
 
 #[tokio::test]
 async fn complex_queries__recursion() {
@@ -249,6 +252,32 @@ async fn complex_queries__41_full_block__query_too_complex() {
     assert!(result.contains("Query is too complex."));
 }
 
+#[tokio::test]
+async fn complex_queries__41_full_block__trusted_client__works() {
+    let query = FULL_BLOCK_QUERY.to_string();
+    let query = query.replace("$NUMBER_OF_BLOCKS", "41");
+
+    // Given
+    let mut config = Config::local_node();
+    config.graphql_config.trusted_api_keys = ["trusted".to_string()].into();
+    config.graphql_config.trusted_max_queries_complexity =
+        config.graphql_config.max_queries_complexity * 2;
+    let node = FuelService::new_node(config).await.unwrap();
+    let url = format!("http://{}/v1/graphql", node.bound_address);
+
+    // When
+    let trusted =
+        send_graph_ql_query_with_api_key(&url, query.as_str(), "trusted").await;
+    let untrusted =
+        send_graph_ql_query_with_api_key(&url, query.as_str(), "untrusted").await;
+    let anonymous = send_graph_ql_query(&url, query.as_str()).await;
+
+    // Then
+    assert!(trusted.contains("transactions"));
+    assert!(untrusted.contains("Query is too complex."));
+    assert!(anonymous.contains("Query is too complex."));
+}
+
 #[tokio::test]
 async fn complex_queries__increased_block_header_cost__failed_to_initialize_service() {
     let mut config = Config::local_node();
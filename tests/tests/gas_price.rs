@@ -344,6 +344,79 @@ async fn produce_block__dont_raises_gas_price_with_default_parameters() {
     driver.kill().await;
 }
 
+#[tokio::test]
+async fn estimate_gas_price__components_are_not_returned_when_not_tracked_separately() {
+    // given
+    let node_config = Config::local_node();
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // when
+    let estimate = client.estimate_gas_price(10).await.unwrap();
+
+    // then
+    assert_eq!(estimate.execution_gas_price, None);
+    assert_eq!(estimate.da_gas_price, None);
+}
+
+#[tokio::test]
+async fn estimate_next_block_gas_price__empty_pool_requires_no_tip() {
+    // given
+    let node_config = Config::local_node();
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // when
+    let estimate = client.estimate_next_block_gas_price().await.unwrap();
+
+    // then
+    let next_block_estimate = client.estimate_gas_price(1).await.unwrap();
+    assert_eq!(estimate.gas_price, next_block_estimate.gas_price);
+    assert_eq!(u64::from(estimate.tip_per_gas), 0);
+    assert_eq!(u64::from(estimate.pool_gas), 0);
+    assert!(u64::from(estimate.block_gas_limit) > 0);
+}
+
+#[tokio::test]
+async fn estimate_input_fee__fee_is_gas_of_the_input_at_the_gas_price() {
+    // given
+    let node_config = Config::local_node();
+    let gas_price_factor = node_config
+        .snapshot_reader
+        .chain_config()
+        .consensus_parameters
+        .fee_params()
+        .gas_price_factor();
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let gas_price = 1000;
+
+    // when
+    let estimate = client.estimate_input_fee(Some(gas_price)).await.unwrap();
+
+    // then
+    let gas = u64::from(estimate.gas);
+    let expected_fee = (gas * gas_price).div_ceil(gas_price_factor);
+    assert!(gas > 0);
+    assert_eq!(u64::from(estimate.gas_price), gas_price);
+    assert_eq!(u64::from(estimate.fee), expected_fee);
+}
+
+#[tokio::test]
+async fn estimate_input_fee__uses_next_block_gas_price_by_default() {
+    // given
+    let node_config = Config::local_node();
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // when
+    let estimate = client.estimate_input_fee(None).await.unwrap();
+
+    // then
+    let next_block_estimate = client.estimate_gas_price(1).await.unwrap();
+    assert_eq!(estimate.gas_price, next_block_estimate.gas_price);
+}
+
 #[tokio::test]
 async fn estimate_gas_price__is_greater_than_actual_price_at_desired_height() {
     // given
@@ -18,12 +18,15 @@ use fuel_core_client::client::{
             ChangePolicy,
             RequiredBalance,
         },
+        coins::MessageCoinOrder,
         message::MessageStatus,
+        MessageInclusionState,
         TransactionStatus,
     },
     FuelClient,
 };
 use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
     fuel_asm::{
         op,
         GTFArgs,
@@ -225,6 +228,143 @@ async fn message_status__can_get_unspent() {
     assert_eq!(status, MessageStatus::Unspent);
 }
 
+#[tokio::test]
+async fn message_inclusion_status__returns_included_for_existing_message() {
+    // Given
+    let nonce = 1.into();
+    let msg = MessageConfig {
+        nonce,
+        ..Default::default()
+    };
+    let config = setup_config(vec![msg]);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let state = client.message_inclusion_status(&nonce).await.unwrap();
+
+    // Then
+    assert_eq!(state, MessageInclusionState::Included);
+}
+
+#[tokio::test]
+async fn message_inclusion_status__returns_unknown_for_unknown_message() {
+    // Given
+    let config = setup_config(vec![]);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let state = client
+        .message_inclusion_status(&[7; 32].into())
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(state, MessageInclusionState::Unknown);
+}
+
+#[tokio::test]
+async fn relayer_backlog__returns_none_when_relayer_is_disabled() {
+    // Given
+    let config = setup_config(vec![]);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let backlog = client.relayer_backlog().await.unwrap();
+
+    // Then
+    assert_eq!(backlog, None);
+}
+
+#[tokio::test]
+async fn bridged_value__returns_no_inbound_value_when_relayer_is_disabled() {
+    // Given
+    let config = setup_config(vec![]);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(3, None).await.unwrap();
+
+    // When
+    let value = client.bridged_value(0u32.into(), 3u32.into()).await.unwrap();
+
+    // Then
+    assert_eq!(value.inbound_messages, None);
+    assert_eq!(value.inbound_amount, None);
+    assert_eq!(value.outbound_messages, 0);
+    assert_eq!(value.outbound_amount, 0);
+}
+
+#[tokio::test]
+async fn bridged_value__fails_when_range_exceeds_the_limit() {
+    // Given
+    let config = setup_config(vec![]);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let result = client.bridged_value(0u32.into(), 100u32.into()).await;
+
+    // Then
+    let err = result.expect_err("The range should exceed the limit");
+    assert!(
+        err.to_string().contains("exceeds the limit"),
+        "Unexpected error: {err}"
+    );
+}
+
+#[tokio::test]
+async fn message_spendable_height__returns_first_block_with_message_da_height() {
+    // Given
+    let nonce = 1.into();
+    let msg = MessageConfig {
+        nonce,
+        da_height: DaBlockHeight(0),
+        ..Default::default()
+    };
+    let config = setup_config(vec![msg]);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(3, None).await.unwrap();
+
+    // When
+    let spendable_height = client
+        .message_spendable_height(&nonce)
+        .await
+        .unwrap()
+        .expect("The message should exist");
+
+    // Then
+    assert_eq!(spendable_height.da_height, 0);
+    assert_eq!(spendable_height.block_height, Some(0u32.into()));
+}
+
+#[tokio::test]
+async fn message_spendable_height__returns_none_when_relayer_is_behind() {
+    // Given
+    let nonce = 1.into();
+    let msg = MessageConfig {
+        nonce,
+        da_height: DaBlockHeight(10),
+        ..Default::default()
+    };
+    let config = setup_config(vec![msg]);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let spendable_height = client
+        .message_spendable_height(&nonce)
+        .await
+        .unwrap()
+        .expect("The message should exist");
+
+    // Then
+    assert_eq!(spendable_height.da_height, 10);
+    assert_eq!(spendable_height.block_height, None);
+}
+
 #[tokio::test]
 async fn message_status__can_get_spent() {
     // Given
@@ -455,13 +595,48 @@ async fn can_get_message_proof() {
         // Check we actually go the correct amount of ids back.
         assert_eq!(nonces.len(), args.len(), "{receipts:?}");
 
-        for nonce in nonces.clone() {
+        // Request all proofs at once.
+        let batch_nonces: Vec<_> = nonces.iter().map(|nonce| **nonce).collect();
+        let batch_proofs = client
+            .message_proofs_by_nonces(&batch_nonces, None, Some(last_height))
+            .await
+            .unwrap();
+        assert_eq!(batch_proofs.len(), nonces.len());
+
+        for (nonce, batch_proof) in nonces.clone().into_iter().zip(batch_proofs) {
             // Request the proof.
             let result = client
                 .message_proof(&transaction_id, nonce, None, Some(last_height))
                 .await
                 .unwrap();
 
+            // The proof by nonce resolves the same transaction.
+            let result_by_nonce = client
+                .message_proof_by_nonce(nonce, None, Some(last_height))
+                .await
+                .unwrap();
+            assert_eq!(result, result_by_nonce);
+
+            // The batch request returns the proofs in the order of the nonces.
+            assert_eq!(result, batch_proof);
+
+            // The latest block at or below the DA height anchor is the commit block.
+            let anchored = client
+                .message_proof_anchored(&transaction_id, nonce, None, None, u64::MAX)
+                .await
+                .unwrap();
+            assert_eq!(result, anchored);
+
+            // The headers only proof skips the message path.
+            let headers = client
+                .message_proof_headers(&transaction_id, nonce, None, Some(last_height))
+                .await
+                .unwrap();
+            assert!(headers.message_proof.proof_set.is_empty());
+            assert_eq!(headers.block_proof, result.block_proof);
+            assert_eq!(headers.message_block_header, result.message_block_header);
+            assert_eq!(headers.commit_block_header, result.commit_block_header);
+
             // 1. Generate the message id (message fields)
             // Produce message id.
             let generated_message_id = compute_message_id(
@@ -536,6 +711,50 @@ async fn can_get_message_proof() {
     }
 }
 
+#[tokio::test]
+async fn message_proof_by_nonce__fails_for_unknown_nonce() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let last_height = client.produce_blocks(2, None).await.unwrap();
+
+    // Given
+    let unknown_nonce = Nonce::from([7; 32]);
+
+    // When
+    let result = client
+        .message_proof_by_nonce(&unknown_nonce, None, Some(last_height))
+        .await;
+
+    // Then
+    let err = result.expect_err("Proof should not exist for an unknown nonce");
+    assert!(
+        err.to_string().contains("No outbound message with the nonce"),
+        "{err}"
+    );
+}
+
+#[tokio::test]
+async fn message_proofs_by_nonces__fails_if_any_nonce_is_unknown() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let last_height = client.produce_blocks(2, None).await.unwrap();
+
+    // Given
+    let unknown_nonce = Nonce::from([7; 32]);
+
+    // When
+    let result = client
+        .message_proofs_by_nonces(&[unknown_nonce], None, Some(last_height))
+        .await;
+
+    // Then
+    let err = result.expect_err("Proofs should not exist for an unknown nonce");
+    assert!(
+        err.to_string().contains("No outbound message with the nonce"),
+        "{err}"
+    );
+}
+
 // TODO: Others test:  Data missing etc.
 fn verify_merkle<D: AsRef<[u8]>>(
     root: Bytes32,
@@ -589,3 +808,227 @@ async fn can_get_empty_message() {
     let message_response = client.message(&1.into()).await.unwrap();
     assert!(message_response.is_none());
 }
+
+#[tokio::test]
+async fn message_proof__waits_for_confirmations_of_the_commit_block() {
+    // Given
+    let config = Config::local_node();
+    let service = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(service.bound_address);
+    let commit_height = client.produce_blocks(1, None).await.unwrap();
+
+    // When
+    let proof = tokio::spawn({
+        let client = client.clone();
+        async move {
+            client
+                .message_proof_with_confirmations(
+                    &TxId::zeroed(),
+                    &Nonce::zeroed(),
+                    None,
+                    Some(commit_height),
+                    2,
+                )
+                .await
+        }
+    });
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    let waited_for_confirmations = !proof.is_finished();
+    client.produce_blocks(2, None).await.unwrap();
+
+    // Then
+    assert!(waited_for_confirmations);
+    let err = proof
+        .await
+        .unwrap()
+        .expect_err("The transaction doesn't exist");
+    assert!(
+        err.to_string()
+            .contains("Unable to obtain the message block height"),
+        "{err}"
+    );
+}
+
+#[tokio::test]
+async fn message_coins__returns_messages_without_data_sorted_by_order() {
+    // Given
+    let owner = Address::new([1; 32]);
+    let message =
+        |nonce: u64, amount: u64, da_height: u64, data: Vec<u8>| MessageConfig {
+            recipient: owner,
+            nonce: nonce.into(),
+            amount,
+            da_height: da_height.into(),
+            data,
+            ..Default::default()
+        };
+    let messages = vec![
+        message(1, 300, 1, vec![]),
+        message(2, 100, 3, vec![]),
+        message(3, 200, 2, vec![]),
+        message(4, 50, 4, vec![1, 2, 3]),
+    ];
+    let config = setup_config(messages);
+    let service = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(service.bound_address);
+
+    // When
+    let first_page = client
+        .message_coins(
+            &owner,
+            None,
+            PaginationRequest {
+                cursor: None,
+                results: 2,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+    let second_page = client
+        .message_coins(
+            &owner,
+            None,
+            PaginationRequest {
+                cursor: first_page.cursor.clone(),
+                results: 2,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+    let by_da_height = client
+        .message_coins(
+            &owner,
+            Some(MessageCoinOrder::DaHeight),
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+
+    // Then
+    let amounts: Vec<_> = first_page
+        .results
+        .iter()
+        .chain(second_page.results.iter())
+        .map(|coin| coin.amount)
+        .collect();
+    assert_eq!(amounts, vec![100, 200, 300]);
+    assert!(first_page.has_next_page);
+    assert!(!second_page.has_next_page);
+    let da_heights: Vec<_> = by_da_height
+        .results
+        .iter()
+        .map(|coin| coin.da_height)
+        .collect();
+    assert_eq!(da_heights, vec![1, 2, 3]);
+}
+
+#[tokio::test]
+async fn message_coins__excludes_spent_messages() {
+    // Given
+    let msg_recipient = Address::from([1; 32]);
+    let msg_sender = Address::from([3; 32]);
+    let message = |nonce: u64, amount: u64| MessageConfig {
+        sender: msg_sender,
+        recipient: msg_recipient,
+        nonce: nonce.into(),
+        amount,
+        ..Default::default()
+    };
+    let config = setup_config(vec![message(1, 1_000), message(2, 2_000)]);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let input = Input::message_coin_signed(
+        msg_sender,
+        msg_recipient,
+        1_000,
+        1.into(),
+        Default::default(),
+    );
+    let output = Output::coin(Address::from([2; 32]), 1_000, Default::default());
+    let tx = Transaction::script(
+        1_000_000,
+        vec![],
+        vec![],
+        policies::Policies::new().with_max_fee(0),
+        vec![input],
+        vec![output],
+        vec![Vec::new().into()],
+    )
+    .into();
+
+    // When
+    client.submit_and_await_commit(&tx).await.unwrap();
+    let message_coins = client
+        .message_coins(
+            &msg_recipient,
+            None,
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+
+    // Then
+    let nonces: Vec<_> = message_coins
+        .results
+        .iter()
+        .map(|coin| coin.nonce)
+        .collect();
+    assert_eq!(nonces, vec![2.into()]);
+}
+
+#[tokio::test]
+async fn message_coins_by_sender__returns_message_coins_of_the_sender() {
+    // Given
+    let sender = Address::new([2; 32]);
+    let other_sender = Address::new([3; 32]);
+    let message = |sender: Address, nonce: u64, data: Vec<u8>| MessageConfig {
+        sender,
+        recipient: Address::new([1; 32]),
+        nonce: nonce.into(),
+        amount: 100,
+        data,
+        ..Default::default()
+    };
+    let messages = vec![
+        message(sender, 1, vec![]),
+        message(other_sender, 2, vec![]),
+        message(sender, 3, vec![]),
+        message(sender, 4, vec![1, 2, 3]),
+    ];
+    let config = setup_config(messages);
+    let service = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(service.bound_address);
+
+    // When
+    let message_coins = client
+        .message_coins_by_sender(
+            &sender,
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+
+    // Then
+    let nonces: Vec<_> = message_coins
+        .results
+        .iter()
+        .map(|coin| coin.nonce)
+        .collect();
+    assert_eq!(nonces, vec![1.into(), 3.into()]);
+    assert!(message_coins.results.iter().all(|coin| coin.sender == sender));
+}
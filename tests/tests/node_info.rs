@@ -1,15 +1,20 @@
 #![allow(non_snake_case)]
 
-use fuel_core::service::{
-    Config,
-    FuelService,
+use fuel_core::{
+    service::{
+        Config,
+        FuelService,
+    },
+    state::historical_rocksdb::StateRewindPolicy,
 };
 use fuel_core_client::client::{
+    schema::node_info::IndexerKind,
     types::NodeInfo,
     FuelClient,
 };
 use fuel_core_poa::Trigger;
 use fuel_core_types::fuel_tx::Transaction;
+use std::num::NonZeroU64;
 
 #[tokio::test]
 async fn node_info() {
@@ -29,6 +34,111 @@ async fn node_info() {
     assert_eq!(max_tx, node_config.txpool.pool_limits.max_txs as u64);
 }
 
+#[tokio::test]
+async fn indexers__lists_each_indexer_with_its_progress() {
+    // Given
+    let node_config = Config::local_node();
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(2, None).await.unwrap();
+
+    // When
+    let indexers = client.indexers().await.unwrap();
+
+    // Then
+    let flags = client.node_info().await.unwrap().indexation;
+    let kinds: Vec<_> = indexers.iter().map(|indexer| indexer.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            IndexerKind::Balances,
+            IndexerKind::CoinsToSpend,
+            IndexerKind::AssetMetadata,
+            IndexerKind::UtxoCount,
+            IndexerKind::KnownAssets,
+            IndexerKind::SenderMessages,
+            IndexerKind::TransactionsBySpender,
+            IndexerKind::OutboundMessageTransactions,
+            IndexerKind::SortedMessageCoins,
+            IndexerKind::Predicates
+        ]
+    );
+    let enabled: Vec<_> = indexers.iter().map(|indexer| indexer.enabled).collect();
+    assert_eq!(
+        enabled,
+        vec![
+            flags.balances,
+            flags.coins_to_spend,
+            flags.asset_metadata,
+            flags.utxo_count,
+            flags.known_assets,
+            flags.sender_messages,
+            flags.transactions_by_spender,
+            flags.outbound_message_transactions,
+            flags.sorted_message_coins,
+            flags.predicates
+        ]
+    );
+    for indexer in indexers {
+        assert_eq!(indexer.processed_height.is_some(), indexer.enabled);
+        assert_eq!(indexer.lag.is_some(), indexer.enabled);
+    }
+}
+
+#[tokio::test]
+async fn historical_state__reports_heights_retained_by_rewind_range() {
+    // Given
+    let mut node_config = Config::local_node();
+    node_config.combined_db_config.state_rewind_policy = StateRewindPolicy::RewindRange {
+        size: NonZeroU64::new(2).unwrap(),
+    };
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(5, None).await.unwrap();
+
+    // When
+    let historical_state = client.historical_state().await.unwrap();
+
+    // Then
+    assert!(historical_state.retains_history);
+    assert_eq!(u32::from(historical_state.latest_height), 5);
+    assert_eq!(u32::from(historical_state.earliest_height), 3);
+}
+
+#[tokio::test]
+async fn read_view_height__matches_latest_block_height() {
+    // Given
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let latest_height = client.produce_blocks(3, None).await.unwrap();
+
+    // When
+    let read_view_height = client.read_view_height().await.unwrap();
+
+    // Then
+    assert_eq!(read_view_height, latest_height);
+}
+
+#[tokio::test]
+async fn historical_state__only_latest_height_without_rewind() {
+    // Given
+    let mut node_config = Config::local_node();
+    node_config.combined_db_config.state_rewind_policy = StateRewindPolicy::NoRewind;
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(2, None).await.unwrap();
+
+    // When
+    let historical_state = client.historical_state().await.unwrap();
+
+    // Then
+    assert!(!historical_state.retains_history);
+    assert_eq!(
+        historical_state.earliest_height,
+        historical_state.latest_height
+    );
+}
+
 #[tokio::test]
 async fn tx_pool_stats__should_be_updated_when_transaction_is_submitted() {
     // Given
@@ -147,3 +257,67 @@ async fn test_peer_info() {
         .unwrap();
     assert!(time_since_heartbeat < Duration::from_secs(10));
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn peers__filtered_by_advertised_protocol() {
+    use fuel_core::p2p_test_helpers::{
+        make_nodes,
+        BootstrapSetup,
+        Nodes,
+        ProducerSetup,
+        ValidatorSetup,
+    };
+    use fuel_core_types::{
+        fuel_tx::Input,
+        fuel_vm::SecretKey,
+    };
+    use rand::{
+        rngs::StdRng,
+        SeedableRng,
+    };
+    use std::time::Duration;
+
+    const REQUEST_RESPONSE_PROTOCOL: &str = "/fuel/req_res/0.0.2";
+
+    // Given
+    let mut rng = StdRng::seed_from_u64(line!() as u64);
+    let secret = SecretKey::random(&mut rng);
+    let pub_key = Input::owner(&secret.public_key());
+    let Nodes {
+        mut producers,
+        validators: _validators,
+        bootstrap_nodes: _dont_drop,
+    } = make_nodes(
+        [Some(BootstrapSetup::new(pub_key))],
+        [Some(ProducerSetup::new(secret).with_name("Alice"))],
+        [Some(ValidatorSetup::new(pub_key).with_name("Bob"))],
+        None,
+    )
+    .await;
+    let producer = producers.pop().unwrap();
+    let client = FuelClient::from(producer.node.bound_address);
+    let peers = loop {
+        let peers = client.connected_peers_info().await.unwrap();
+        if peers.len() == 2 {
+            break peers;
+        }
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    };
+
+    // When
+    let supported = client
+        .connected_peers_info_by_protocol(Some(REQUEST_RESPONSE_PROTOCOL), None)
+        .await
+        .unwrap();
+    let unsupported = client
+        .connected_peers_info_by_protocol(None, Some(REQUEST_RESPONSE_PROTOCOL))
+        .await
+        .unwrap();
+
+    // Then
+    assert!(peers
+        .iter()
+        .all(|info| info.protocols.contains(REQUEST_RESPONSE_PROTOCOL)));
+    assert_eq!(supported.len(), peers.len());
+    assert!(unsupported.is_empty());
+}
@@ -252,6 +252,53 @@ async fn messages_are_spendable_after_relayer_is_synced() {
     eth_node_handle.shutdown.send(()).unwrap();
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn relayer_backlog__counts_synced_messages_until_they_are_included() {
+    // Given
+    let mut config = config_with_fee();
+    config.relayer = Some(relayer::Config::default());
+    let relayer_config = config.relayer.as_mut().expect("Expected relayer config");
+    let eth_node = MockMiddleware::default();
+    let contract_address = relayer_config.eth_v2_listening_contracts[0];
+    let logs = vec![make_message_event(
+        Nonce::from(2u64),
+        5,
+        contract_address,
+        None,
+        None,
+        Some(100),
+        None,
+        0,
+    )];
+    eth_node.update_data(|data| data.logs_batch = vec![logs.clone()]);
+    eth_node.update_data(|data| data.best_block.number = Some(200.into()));
+    let eth_node_handle = spawn_eth_node(Arc::new(eth_node)).await;
+    relayer_config.relayer = Some(vec![format!("http://{}", eth_node_handle.address)
+        .as_str()
+        .try_into()
+        .unwrap()]);
+    let srv = FuelService::from_database(Database::in_memory(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    srv.await_relayer_synced().await.unwrap();
+
+    // When
+    let pending = client.relayer_backlog().await.unwrap().unwrap();
+    client.produce_blocks(1, None).await.unwrap();
+    let included = client.relayer_backlog().await.unwrap().unwrap();
+
+    // Then
+    assert!(pending.synced_to_tip);
+    assert!(pending.synced_da_height >= 5);
+    assert_eq!(pending.pending_messages, 1);
+    assert!(included.included_da_height >= 5);
+    assert_eq!(included.pending_messages, 0);
+
+    srv.send_stop_signal_and_await_shutdown().await.unwrap();
+    eth_node_handle.shutdown.send(()).unwrap();
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn can_find_failed_relayed_tx() {
     let mut db = CombinedDatabase::in_memory();
@@ -6,17 +6,31 @@ use fuel_core::{
     state::historical_rocksdb::StateRewindPolicy,
 };
 use fuel_core_client::client::{
-    types::TransactionStatus,
+    types::{
+        coins::{
+            CoinSpender,
+            HistoricalCoin,
+        },
+        TransactionStatus,
+    },
     FuelClient,
 };
 use fuel_core_poa::Trigger;
-use fuel_core_types::fuel_tx::{
-    field::Outputs,
-    Bytes32,
-    Create,
-    Finalizable,
-    StorageSlot,
-    TransactionBuilder,
+use fuel_core_types::{
+    fuel_crypto::SecretKey,
+    fuel_tx::{
+        field::Outputs,
+        Address,
+        AssetId,
+        Bytes32,
+        Create,
+        Finalizable,
+        Output,
+        StorageSlot,
+        Transaction,
+        TransactionBuilder,
+        UtxoId,
+    },
 };
 use futures::TryStreamExt;
 use itertools::Itertools;
@@ -125,3 +139,129 @@ async fn contract_storage_values_create_tx() {
             .collect_vec()
     );
 }
+
+#[tokio::test]
+async fn coin_at_height__returns_state_of_the_coin_at_each_height() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Instant;
+    config.combined_db_config.state_rewind_policy = StateRewindPolicy::RewindFullRange;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let amount = 1_000;
+    let owner = Address::from([1; 32]);
+    let create_coin: Transaction = TransactionBuilder::script(vec![], vec![])
+        .add_fee_input()
+        .add_output(Output::coin(owner, amount, AssetId::BASE))
+        .finalize_as_transaction();
+    let utxo_id = UtxoId::new(create_coin.id(&Default::default()), 0);
+    let spend_coin: Transaction = TransactionBuilder::script(vec![], vec![])
+        .add_unsigned_coin_input(
+            SecretKey::default(),
+            utxo_id,
+            amount,
+            AssetId::BASE,
+            Default::default(),
+        )
+        .add_fee_input()
+        .finalize_as_transaction();
+
+    let height_before = client.produce_blocks(1, None).await.unwrap();
+    let TransactionStatus::Success {
+        block_height: height_created,
+        ..
+    } = client.submit_and_await_commit(&create_coin).await.unwrap()
+    else {
+        panic!("Failed to create the coin");
+    };
+    let TransactionStatus::Success {
+        block_height: height_spent,
+        ..
+    } = client.submit_and_await_commit(&spend_coin).await.unwrap()
+    else {
+        panic!("Failed to spend the coin");
+    };
+
+    // When
+    let before = client
+        .coin_at_height(&utxo_id, height_before)
+        .await
+        .unwrap();
+    let created = client
+        .coin_at_height(&utxo_id, height_created)
+        .await
+        .unwrap();
+    let spent = client
+        .coin_at_height(&utxo_id, height_spent)
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(before, HistoricalCoin::NotCreated);
+    let HistoricalCoin::Unspent(coin) = created else {
+        panic!("Expected the coin to be unspent, got {created:?}");
+    };
+    assert_eq!(coin.utxo_id, utxo_id);
+    assert_eq!(coin.owner, owner);
+    assert_eq!(coin.amount, amount);
+    assert_eq!(spent, HistoricalCoin::Spent);
+}
+
+#[tokio::test]
+async fn coin_spender__returns_transaction_and_block_that_spent_the_coin() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Instant;
+    config.combined_db_config.state_rewind_policy = StateRewindPolicy::RewindFullRange;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let amount = 1_000;
+    let owner = Address::from([1; 32]);
+    let create_coin: Transaction = TransactionBuilder::script(vec![], vec![])
+        .add_fee_input()
+        .add_output(Output::coin(owner, amount, AssetId::BASE))
+        .add_output(Output::coin(owner, amount, AssetId::BASE))
+        .finalize_as_transaction();
+    let spent_utxo_id = UtxoId::new(create_coin.id(&Default::default()), 0);
+    let unspent_utxo_id = UtxoId::new(create_coin.id(&Default::default()), 1);
+    let unknown_utxo_id = UtxoId::new([7; 32].into(), 0);
+    let spend_coin: Transaction = TransactionBuilder::script(vec![], vec![])
+        .add_unsigned_coin_input(
+            SecretKey::default(),
+            spent_utxo_id,
+            amount,
+            AssetId::BASE,
+            Default::default(),
+        )
+        .add_fee_input()
+        .finalize_as_transaction();
+
+    client.submit_and_await_commit(&create_coin).await.unwrap();
+    client.produce_blocks(3, None).await.unwrap();
+    let TransactionStatus::Success {
+        block_height: height_spent,
+        ..
+    } = client.submit_and_await_commit(&spend_coin).await.unwrap()
+    else {
+        panic!("Failed to spend the coin");
+    };
+    client.produce_blocks(2, None).await.unwrap();
+
+    // When
+    let spent = client.coin_spender(&spent_utxo_id).await.unwrap();
+    let unspent = client.coin_spender(&unspent_utxo_id).await.unwrap();
+    let unknown = client.coin_spender(&unknown_utxo_id).await.unwrap();
+
+    // Then
+    assert_eq!(
+        spent,
+        CoinSpender::Spent {
+            transaction_id: spend_coin.id(&Default::default()),
+            block_height: height_spent,
+        }
+    );
+    assert_eq!(unspent, CoinSpender::Unspent);
+    assert_eq!(unknown, CoinSpender::NotFound);
+}
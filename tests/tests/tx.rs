@@ -13,10 +13,14 @@ use fuel_core::{
 use fuel_core_client::client::{
     pagination::{
         PageDirection,
+        PaginatedResult,
         PaginationRequest,
     },
     types::{
+        coins::CoinActivity,
+        output_breakdown::OutputKind,
         StatusWithTransaction,
+        TransactionResponse,
         TransactionStatus,
     },
     FuelClient,
@@ -206,6 +210,104 @@ async fn dry_run_above_block_gas_limit() {
     }
 }
 
+#[tokio::test]
+async fn dry_run__fails_when_number_of_transactions_exceeds_the_limit() {
+    let mut config = Config::local_node();
+    config.graphql_config.dry_run_max_txs = 2;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let script: Vec<u8> = [op::ret(RegId::ONE)].into_iter().collect();
+    let txs: Vec<_> = (0..3)
+        .map(|_| {
+            TransactionBuilder::script(script.clone(), vec![])
+                .script_gas_limit(10_000)
+                .add_fee_input()
+                .finalize_as_transaction()
+        })
+        .collect();
+
+    // When
+    let result = client.dry_run(&txs).await;
+
+    // Then
+    let err = result.expect_err("Dry run should fail");
+    assert_eq!(
+        err.to_string(),
+        "Response errors; The number of transactions in the dry run request (3) exceeds the limit of 2"
+    );
+    let tx_statuses = client.dry_run(&txs[..2]).await.unwrap();
+    assert_eq!(tx_statuses.len(), 2);
+}
+
+#[tokio::test]
+async fn dry_run__fails_when_no_dry_run_slot_is_free_before_timeout() {
+    let mut config = Config::local_node();
+    config.graphql_config.dry_run_max_concurrency = 0;
+    config.graphql_config.dry_run_queue_timeout = Duration::from_millis(10);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let script: Vec<u8> = [op::ret(RegId::ONE)].into_iter().collect();
+    let tx = TransactionBuilder::script(script, vec![])
+        .script_gas_limit(10_000)
+        .add_fee_input()
+        .finalize_as_transaction();
+
+    // When
+    let result = client.dry_run(&[tx]).await;
+
+    // Then
+    let err = result.expect_err("Dry run should fail");
+    assert_eq!(
+        err.to_string(),
+        "Response errors; The server is busy with other dry runs, please try again later"
+    );
+}
+
+#[tokio::test]
+async fn estimate_batch_fees__returns_per_tx_fees_and_their_sum() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let script: Vec<u8> = [op::ret(RegId::ONE)].into_iter().collect();
+    let txs: Vec<Transaction> = (0..2u64)
+        .map(|i| {
+            TransactionBuilder::script(script.clone(), vec![])
+                .script_gas_limit(1_000_000 + i)
+                .max_fee_limit(1_000_000)
+                .add_fee_input()
+                .finalize_as_transaction()
+        })
+        .collect();
+    let gas_price = Some(1);
+    let dry_run_statuses = client
+        .dry_run_opt(&txs, None, gas_price, None)
+        .await
+        .unwrap();
+
+    // When
+    let estimate = client
+        .estimate_batch_fees(&txs, None, gas_price)
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(estimate.transactions.len(), txs.len());
+    for (fee, status) in estimate.transactions.iter().zip(dry_run_statuses.iter()) {
+        assert_eq!(fee.id, status.id);
+        assert!(fee.success);
+        assert_eq!(fee.total_fee, *status.result.total_fee());
+        assert_eq!(fee.total_gas, *status.result.total_gas());
+    }
+    let expected_total_fee: u64 =
+        estimate.transactions.iter().map(|tx| tx.total_fee).sum();
+    assert_eq!(estimate.total_fee, expected_total_fee);
+}
+
 fn arb_large_script_tx<R: Rng + rand::CryptoRng>(
     max_fee_limit: Word,
     size: usize,
@@ -243,6 +345,88 @@ fn config_with_size_limit(block_transaction_size_limit: u32) -> Config {
     config
 }
 
+#[tokio::test]
+async fn transaction_outputs_breakdown__classifies_outputs_and_sums_transfers() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let sender = Address::from([1; 32]);
+    let recipient = Address::from([2; 32]);
+    let asset_id = AssetId::from([3; 32]);
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .add_input(Input::coin_signed(
+            Default::default(),
+            sender,
+            1_000,
+            asset_id,
+            Default::default(),
+            0,
+        ))
+        .add_output(Output::coin(recipient, 100, asset_id))
+        .add_output(Output::coin(sender, 10, asset_id))
+        .add_output(Output::coin(recipient, 50, asset_id))
+        .add_output(Output::change(sender, 0, asset_id))
+        .add_output(Output::variable(Default::default(), 0, Default::default()))
+        .finalize_as_transaction();
+
+    // When
+    let breakdown = client.transaction_outputs_breakdown(&tx).await.unwrap();
+
+    // Then
+    let kinds: Vec<_> = breakdown
+        .outputs
+        .iter()
+        .map(|output| (output.kind, output.dynamic, output.amount))
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            (OutputKind::Transfer, false, Some(100)),
+            (OutputKind::SelfTransfer, false, Some(10)),
+            (OutputKind::Transfer, false, Some(50)),
+            (OutputKind::Change, false, None),
+            (OutputKind::Variable, true, None),
+        ]
+    );
+    assert_eq!(breakdown.transferred_amounts, vec![(asset_id, 150)]);
+    assert_eq!(
+        breakdown.fixed_coins,
+        vec![
+            (0, recipient, asset_id, 100),
+            (1, sender, asset_id, 10),
+            (2, recipient, asset_id, 50),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn block_gas_limit_check__detects_transactions_exceeding_block_gas_limit() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let script: Vec<u8> = [op::ret(RegId::ONE)].into_iter().collect();
+    let tx_with_gas_limit = |script_gas_limit| {
+        TransactionBuilder::script(script.clone(), vec![])
+            .script_gas_limit(script_gas_limit)
+            .add_fee_input()
+            .finalize_as_transaction()
+    };
+
+    // Given
+    let small_tx = tx_with_gas_limit(1_000);
+    let small_check = client.block_gas_limit_check(&small_tx).await.unwrap();
+    let oversized_tx = tx_with_gas_limit(small_check.block_gas_limit);
+
+    // When
+    let oversized_check = client.block_gas_limit_check(&oversized_tx).await.unwrap();
+
+    // Then
+    assert!(!small_check.exceeds_block_gas_limit);
+    assert!(small_check.estimated_gas > 1_000);
+    assert!(oversized_check.exceeds_block_gas_limit);
+    assert!(oversized_check.estimated_gas > oversized_check.block_gas_limit);
+}
+
 #[tokio::test]
 async fn transaction_selector_can_saturate_block_according_to_block_transaction_size_limit(
 ) {
@@ -368,6 +552,48 @@ async fn submit() {
     assert_eq!(tx.id(&ChainId::default()), ret_tx.id(&ChainId::default()));
 }
 
+#[tokio::test]
+async fn submit_with_idempotency_key__repeated_key_returns_original_transaction() {
+    let srv = FuelService::new_node(config_with_fee()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let script = |value| {
+        vec![
+            op::addi(0x10, RegId::ZERO, value),
+            op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+            op::ret(RegId::ONE),
+        ]
+    };
+    let original_tx = client
+        .assemble_script(script(0xca), vec![], default_signing_wallet())
+        .await
+        .unwrap();
+    let retried_tx = client
+        .assemble_script(script(0xba), vec![], default_signing_wallet())
+        .await
+        .unwrap();
+
+    // Given
+    let original_id = client
+        .submit_with_idempotency_key(&original_tx, None, "payment-1")
+        .await
+        .unwrap();
+
+    // When
+    let retried_id = client
+        .submit_with_idempotency_key(&retried_tx, None, "payment-1")
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(original_id, original_tx.id(&ChainId::default()));
+    assert_eq!(retried_id, original_id);
+    let retried_tx = client
+        .transaction(&retried_tx.id(&ChainId::default()))
+        .await
+        .unwrap();
+    assert!(retried_tx.is_none());
+}
+
 #[tokio::test]
 async fn submit_and_await_status() {
     let srv = FuelService::new_node(config_with_fee()).await.unwrap();
@@ -458,6 +684,88 @@ async fn receipts() {
     assert!(receipts.is_some());
 }
 
+#[tokio::test]
+async fn transaction_status_with_receipts__returns_receipts_of_executed_transaction() {
+    // Given
+    let transaction = Transaction::default_test_tx();
+    let id = transaction.id(&ChainId::default());
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client
+        .submit_and_await_commit(&transaction)
+        .await
+        .expect("transaction should insert");
+
+    // When
+    let (status, receipts) = client.transaction_status_with_receipts(&id).await.unwrap();
+
+    // Then
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+    assert_eq!(Some(receipts), client.receipts(&id).await.unwrap());
+}
+
+#[tokio::test]
+async fn transaction_status_with_receipts__fails_for_unknown_transaction() {
+    // Given
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let result = client
+        .transaction_status_with_receipts(&TxId::zeroed())
+        .await;
+
+    // Then
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn transaction_statuses__returns_statuses_in_order_of_ids() {
+    // Given
+    let transaction = Transaction::default_test_tx();
+    let id = transaction.id(&ChainId::default());
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client
+        .submit_and_await_commit(&transaction)
+        .await
+        .expect("transaction should insert");
+    let unknown_id = TxId::zeroed();
+
+    // When
+    let statuses = client
+        .transaction_statuses(&[unknown_id, id, unknown_id])
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(statuses.len(), 3);
+    assert!(statuses[0].is_none());
+    assert!(matches!(
+        statuses[1],
+        Some(TransactionStatus::Success { .. })
+    ));
+    assert!(statuses[2].is_none());
+}
+
+#[tokio::test]
+async fn transaction_statuses__fails_when_ids_exceed_the_limit() {
+    // Given
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let ids = vec![TxId::zeroed(); 101];
+
+    // When
+    let result = client.transaction_statuses(&ids).await;
+
+    // Then
+    let err = result.expect_err("The number of ids exceeds the limit");
+    assert!(
+        err.to_string().contains("exceeds the limit of 100"),
+        "{err}"
+    );
+}
+
 #[tokio::test]
 async fn receipts_decoding() {
     let srv = FuelService::new_node(Config::local_node()).await.unwrap();
@@ -869,6 +1177,49 @@ async fn get_transactions_by_owner_supports_cursor(direction: PageDirection) {
     );
 }
 
+#[tokio::test]
+async fn recent_transaction_ids_by_owner__returns_the_most_recent_transactions_first() {
+    let alice = Address::from([1; 32]);
+    let bob = Address::from([2; 32]);
+
+    // Given
+    let mut context = TestContext::new(100).await;
+    let first = context.transfer(alice, bob, 1).await.unwrap();
+    let second = context.transfer(alice, bob, 2).await.unwrap();
+    let third = context.transfer(bob, alice, 3).await.unwrap();
+    let client = context.client;
+
+    // When
+    let latest_two = client
+        .recent_transaction_ids_by_owner(
+            &bob,
+            PaginationRequest {
+                cursor: None,
+                results: 2,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+    let older = client
+        .recent_transaction_ids_by_owner(
+            &bob,
+            PaginationRequest {
+                cursor: latest_two.cursor.clone(),
+                results: 2,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(latest_two.results, vec![third, second]);
+    assert!(latest_two.has_next_page);
+    assert_eq!(older.results, vec![first]);
+    assert!(!older.has_next_page);
+}
+
 #[tokio::test]
 async fn get_transactions_from_manual_blocks() {
     let context = TestContext::new(100).await;
@@ -1039,6 +1390,222 @@ async fn get_owned_transactions() {
     assert_eq!(&charlie_txs, &[tx1, tx2, tx3]);
 }
 
+#[tokio::test]
+async fn transactions_by_spender__returns_spending_transactions_within_heights() {
+    // Given
+    let alice = Address::from([1; 32]);
+    let bob = Address::from([2; 32]);
+    let charlie = Address::from([3; 32]);
+
+    let mut context = TestContext::new(100).await;
+    context.transfer(alice, charlie, 1).await.unwrap();
+    let tx2 = context.transfer(charlie, bob, 2).await.unwrap();
+    context.transfer(bob, charlie, 3).await.unwrap();
+    let tx4 = context.transfer(charlie, alice, 4).await.unwrap();
+
+    let client = context.client;
+    let TransactionStatus::Success { block_height, .. } =
+        client.transaction_status(&tx2).await.unwrap()
+    else {
+        panic!("The transaction should be executed successfully")
+    };
+    let page_request = PaginationRequest {
+        cursor: None,
+        results: 5,
+        direction: PageDirection::Forward,
+    };
+    let spent_tx_ids = |result: PaginatedResult<TransactionResponse, String>| {
+        result
+            .results
+            .into_iter()
+            .map(|tx| {
+                let tx: Transaction = tx.transaction.try_into().unwrap();
+
+                tx.id(&ChainId::default())
+            })
+            .collect_vec()
+    };
+
+    // When
+    let all_txs = client
+        .transactions_by_spender(&charlie, None, None, page_request.clone())
+        .await
+        .unwrap();
+    let txs_within_heights = client
+        .transactions_by_spender(
+            &charlie,
+            Some(block_height),
+            Some(block_height),
+            page_request.clone(),
+        )
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(spent_tx_ids(all_txs), vec![tx2, tx4]);
+    assert_eq!(spent_tx_ids(txs_within_heights), vec![tx2]);
+}
+
+#[tokio::test]
+async fn transactions_by_spender__seeks_to_start_height_when_cursor_is_below_it() {
+    // Given
+    let alice = Address::from([1; 32]);
+    let bob = Address::from([2; 32]);
+    let charlie = Address::from([3; 32]);
+
+    let mut context = TestContext::new(100).await;
+    context.transfer(alice, charlie, 1).await.unwrap();
+    context.transfer(charlie, bob, 2).await.unwrap();
+    context.transfer(bob, charlie, 3).await.unwrap();
+    let tx4 = context.transfer(charlie, alice, 4).await.unwrap();
+
+    let client = context.client;
+    let TransactionStatus::Success { block_height, .. } =
+        client.transaction_status(&tx4).await.unwrap()
+    else {
+        panic!("The transaction should be executed successfully")
+    };
+    let first_page = client
+        .transactions_by_spender(
+            &charlie,
+            None,
+            None,
+            PaginationRequest {
+                cursor: None,
+                results: 1,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+
+    // When
+    let txs = client
+        .transactions_by_spender(
+            &charlie,
+            Some(block_height),
+            None,
+            PaginationRequest {
+                cursor: first_page.cursor,
+                results: 5,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+
+    // Then
+    let tx_ids = txs
+        .results
+        .into_iter()
+        .map(|tx| {
+            let tx: Transaction = tx.transaction.try_into().unwrap();
+
+            tx.id(&ChainId::default())
+        })
+        .collect_vec();
+    assert_eq!(tx_ids, vec![tx4]);
+}
+
+#[tokio::test]
+async fn coin_activity__returns_created_and_spent_coins_within_heights() {
+    // Given
+    let alice = Address::from([1; 32]);
+    let bob = Address::from([2; 32]);
+    let charlie = Address::from([3; 32]);
+
+    let mut context = TestContext::new(100).await;
+    let tx1 = context.transfer(alice, charlie, 1).await.unwrap();
+    let tx2 = context.transfer(charlie, bob, 2).await.unwrap();
+    let tx3 = context.transfer(bob, charlie, 3).await.unwrap();
+    context.transfer(charlie, alice, 4).await.unwrap();
+
+    let client = context.client;
+    let block_height = |status| match status {
+        TransactionStatus::Success { block_height, .. } => block_height,
+        _ => panic!("The transaction should be executed successfully"),
+    };
+    let start_height = block_height(client.transaction_status(&tx1).await.unwrap());
+    let end_height = block_height(client.transaction_status(&tx3).await.unwrap());
+
+    // When
+    let activity = client
+        .coin_activity(&charlie, start_height, end_height, None, None)
+        .await
+        .unwrap();
+
+    // Then
+    let created = activity
+        .created
+        .iter()
+        .map(|coin| (coin.transaction_id, coin.amount))
+        .collect_vec();
+    let spent = activity
+        .spent
+        .iter()
+        .map(|coin| (coin.transaction_id, coin.amount))
+        .collect_vec();
+    assert_eq!(created, vec![(tx1, 1), (tx3, 3)]);
+    assert_eq!(spent, vec![(tx2, 2)]);
+    assert!(!activity.has_next_page);
+}
+
+#[tokio::test]
+async fn coin_activity__paginates_by_transactions() {
+    // Given
+    let alice = Address::from([1; 32]);
+    let bob = Address::from([2; 32]);
+    let charlie = Address::from([3; 32]);
+
+    let mut context = TestContext::new(100).await;
+    let tx1 = context.transfer(alice, charlie, 1).await.unwrap();
+    let tx2 = context.transfer(charlie, bob, 2).await.unwrap();
+    let tx3 = context.transfer(bob, charlie, 3).await.unwrap();
+
+    let client = context.client;
+    let block_height = |status| match status {
+        TransactionStatus::Success { block_height, .. } => block_height,
+        _ => panic!("The transaction should be executed successfully"),
+    };
+    let start_height = block_height(client.transaction_status(&tx1).await.unwrap());
+    let end_height = block_height(client.transaction_status(&tx3).await.unwrap());
+
+    // When
+    let first_page = client
+        .coin_activity(&charlie, start_height, end_height, None, Some(2))
+        .await
+        .unwrap();
+    let second_page = client
+        .coin_activity(
+            &charlie,
+            start_height,
+            end_height,
+            first_page.last_tx_pointer,
+            Some(2),
+        )
+        .await
+        .unwrap();
+
+    // Then
+    let transactions = |activity: &CoinActivity| {
+        activity
+            .created
+            .iter()
+            .chain(activity.spent.iter())
+            .map(|coin| coin.transaction_id)
+            .sorted()
+            .dedup()
+            .collect_vec()
+    };
+    assert!(first_page.has_next_page);
+    assert_eq!(
+        transactions(&first_page),
+        [tx1, tx2].into_iter().sorted().collect_vec()
+    );
+    assert!(!second_page.has_next_page);
+    assert_eq!(transactions(&second_page), vec![tx3]);
+}
+
 #[tokio::test]
 async fn pending_pool_returns_error_after_timeout_for_transaction_that_spends_already_spent_utxo(
 ) {
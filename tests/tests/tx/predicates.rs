@@ -1,6 +1,10 @@
 // Tests related to the predicate execution feature
 
 use crate::helpers::TestSetupBuilder;
+use fuel_core_client::client::types::{
+    predicate_verification::PredicateVerificationStatus,
+    InputFragment,
+};
 use fuel_core_types::{
     fuel_asm::*,
     fuel_tx::{
@@ -179,3 +183,141 @@ async fn transaction_with_predicates_that_exhaust_gas_limit_are_rejected() {
         "got unexpected error {err}"
     )
 }
+
+#[tokio::test]
+async fn verify_predicates__reports_status_of_predicate_input() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let amount = 500;
+    let asset_id = rng.gen();
+    let predicate_tx = |predicate: Vec<u8>, predicate_gas_used| {
+        let owner = Input::predicate_owner(&predicate);
+        TransactionBuilder::script(Default::default(), Default::default())
+            .add_input(Input::coin_predicate(
+                Default::default(),
+                owner,
+                amount,
+                asset_id,
+                Default::default(),
+                predicate_gas_used,
+                predicate,
+                vec![],
+            ))
+            .add_output(Output::change(Default::default(), 0, asset_id))
+            .script_gas_limit(1000)
+            .finalize()
+    };
+    let mut valid_tx = predicate_tx(op::ret(RegId::ONE).to_bytes().to_vec(), 0);
+    let invalid_tx = predicate_tx(op::ret(RegId::ZERO).to_bytes().to_vec(), 1_000);
+
+    let context = TestSetupBuilder::default()
+        .config_coin_inputs_from_transactions(&[&valid_tx])
+        .finalize()
+        .await;
+    valid_tx
+        .estimate_predicates(
+            &CheckPredicateParams::from(
+                &context
+                    .srv
+                    .shared
+                    .config
+                    .snapshot_reader
+                    .chain_config()
+                    .consensus_parameters,
+            ),
+            MemoryInstance::new(),
+            &EmptyStorage,
+        )
+        .expect("Predicate check failed");
+
+    // When
+    let valid = context
+        .client
+        .verify_predicates(&valid_tx.into())
+        .await
+        .unwrap();
+    let invalid = context
+        .client
+        .verify_predicates(&invalid_tx.into())
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(valid.result, Ok(()));
+    assert_eq!(valid.inputs.len(), 1);
+    assert_eq!(valid.inputs[0].status, PredicateVerificationStatus::Passed);
+    assert!(invalid.result.is_err());
+    assert_eq!(invalid.inputs.len(), 1);
+    assert_eq!(invalid.inputs[0].index, 0);
+    assert_eq!(invalid.inputs[0].status, PredicateVerificationStatus::Failed);
+}
+
+#[tokio::test]
+async fn coins_to_spend_as_inputs__includes_predicate_seen_on_chain() {
+    let mut rng = StdRng::seed_from_u64(2322);
+
+    // Given
+    let amount = 500;
+    let asset_id = rng.gen();
+    let predicate = op::ret(RegId::ONE).to_bytes().to_vec();
+    let predicate_data = vec![1, 2, 3];
+    let owner = Input::predicate_owner(&predicate);
+    let mut predicate_tx =
+        TransactionBuilder::script(Default::default(), Default::default())
+            .add_input(Input::coin_predicate(
+                rng.gen(),
+                owner,
+                amount,
+                asset_id,
+                Default::default(),
+                Default::default(),
+                predicate.clone(),
+                predicate_data.clone(),
+            ))
+            .add_output(Output::change(owner, 0, asset_id))
+            .script_gas_limit(1000)
+            .finalize();
+    let context = TestSetupBuilder::default()
+        .config_coin_inputs_from_transactions(&[&predicate_tx])
+        .finalize()
+        .await;
+    predicate_tx
+        .estimate_predicates(
+            &CheckPredicateParams::from(
+                &context
+                    .srv
+                    .shared
+                    .config
+                    .snapshot_reader
+                    .chain_config()
+                    .consensus_parameters,
+            ),
+            MemoryInstance::new(),
+            &EmptyStorage,
+        )
+        .expect("Predicate check failed");
+    context
+        .client
+        .submit_and_await_commit(&predicate_tx.into())
+        .await
+        .unwrap();
+
+    // When
+    let inputs = context
+        .client
+        .coins_to_spend_as_inputs(&owner, vec![(asset_id, 1, None)], None, None, true)
+        .await
+        .unwrap();
+
+    // Then
+    let [input] = inputs[0].as_slice() else {
+        panic!("Expected one input, got {inputs:?}");
+    };
+    let InputFragment::Coin(fragment) = input else {
+        panic!("Expected a coin, got {input:?}");
+    };
+    assert_eq!(fragment.predicate, Some(predicate));
+    assert_eq!(
+        fragment.predicate_data_length,
+        Some(predicate_data.len() as u64)
+    );
+}